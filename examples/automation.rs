@@ -30,7 +30,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // List all automations
-    let (automations, _pagination) = handler.list(None, Some(50), None).await?;
+    let (automations, _pagination) = handler.list(None, Some(50), None, None).await?;
     println!("\nTotal automations: {}", automations.len());
     for auto in automations {
         println!(
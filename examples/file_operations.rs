@@ -35,15 +35,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .await?;
     println!("Copied to: /backups/summary.pdf");
 
-    // Move file
+    // Move file, waiting in case Files.com moves it in the background
     file_handler
         .move_file("/backups/summary.pdf", "/archive/summary.pdf")
+        .await?
+        .wait()
         .await?;
     println!("Moved to: /archive/summary.pdf");
 
     // List folder contents
     let (files, _pagination) = folder_handler
-        .list_folder("/reports", Some(100), None)
+        .list_folder("/reports", Some(100), None, None)
         .await?;
     println!("\nFiles in /reports:");
     for file in files {
@@ -1,3 +1,4 @@
+use files_sdk::timestamp::timestamp;
 use files_sdk::{FilesClient, sharing::BundleHandler};
 
 #[tokio::main]
@@ -10,14 +11,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let bundle = handler
         .create(
             vec!["/reports/Q4.pdf".to_string()],
-            None,               // password
-            Some("2024-12-31"), // expires_at
-            None,               // max_uses
-            None,               // description
-            None,               // note
-            None,               // code
-            None,               // require_registration
-            None,               // permissions
+            None,                                     // password
+            Some(timestamp("2024-12-31T23:59:59Z")?), // expires_at
+            None,                                     // max_uses
+            None,                                     // description
+            None,                                     // note
+            None,                                     // code
+            None,                                     // require_registration
+            None,                                     // permissions
         )
         .await?;
 
@@ -32,14 +33,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let protected_bundle = handler
         .create(
             vec!["/sensitive/data.xlsx".to_string()],
-            Some("secure123"),  // password
-            Some("2024-10-10"), // expires_at
-            None,               // max_uses
-            None,               // description
-            None,               // note
-            None,               // code
-            None,               // require_registration
-            None,               // permissions
+            Some("secure123"),                        // password
+            Some(timestamp("2024-10-10T23:59:59Z")?), // expires_at
+            None,                                     // max_uses
+            None,                                     // description
+            None,                                     // note
+            None,                                     // code
+            None,                                     // require_registration
+            None,                                     // permissions
         )
         .await?;
 
@@ -51,7 +52,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("  Password: secure123");
 
     // List all bundles
-    let (bundles, _pagination) = handler.list(None, None, Some(100)).await?;
+    let (bundles, _pagination) = handler.list(None, None, Some(100), None).await?;
     println!("\nTotal active share links: {}", bundles.len());
     for bundle in bundles {
         println!(
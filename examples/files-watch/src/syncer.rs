@@ -346,7 +346,7 @@ impl Syncer {
         // List all files in remote directory (with pagination)
         loop {
             let (files, pagination) = folder_handler
-                .list_folder(&self.config.remote_path, None, cursor.clone())
+                .list_folder(&self.config.remote_path, None, cursor.clone(), None)
                 .await
                 .with_context(|| {
                     format!(
@@ -361,13 +361,7 @@ impl Syncer {
                     if let Some(path) = &file.path {
                         let size = file.size.unwrap_or(0);
 
-                        // Parse mtime
-                        let mtime = file
-                            .mtime
-                            .as_ref()
-                            .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
-                            .map(|dt| dt.with_timezone(&Utc))
-                            .unwrap_or_else(Utc::now);
+                        let mtime = file.mtime.unwrap_or_else(Utc::now);
 
                         remote_files.push((path.clone(), size, mtime));
                     }
@@ -1,115 +1,87 @@
 //! Mockall Testing Example
 //!
-//! Demonstrates using mockall to create trait-based mocks for testing.
+//! Demonstrates using the generated mocks of the SDK's handler traits.
 //!
 //! This approach is useful when you want to:
 //! - Test code that depends on the Files.com SDK without hitting the API
 //! - Verify specific methods are called with expected arguments
 //! - Control return values and test error paths
 //!
+//! Application code is written against `FileOps` instead of `FileHandler`.
+//! The `mockall` feature generates `MockFileOps` for tests.
+//!
 //! Run tests with:
 //! ```bash
-//! cargo test --example mockall_example
+//! cargo test --example mockall_example --features mockall
 //! ```
 
-#[cfg(test)]
-use mockall::automock;
+use files_sdk::{FileOps, FilesError, Result};
+use std::collections::HashMap;
 
-/// Trait representing file upload operations
+/// Example service that depends on any `FileOps` implementation
 ///
-/// By using a trait, we can create mock implementations for testing
-/// while the real implementation uses the actual Files.com SDK.
-#[cfg_attr(test, automock)]
-pub trait FileUploader {
-    fn upload(&self, path: &str, data: &[u8]) -> Result<(), String>;
-    fn upload_with_metadata(
-        &self,
-        path: &str,
-        data: &[u8],
-        metadata: std::collections::HashMap<String, String>,
-    ) -> Result<(), String>;
+/// In production this is a `FileHandler`; in tests it is a `MockFileOps`.
+pub struct BackupService<F: FileOps> {
+    files: F,
 }
 
-/// Real implementation using the Files.com SDK
-pub struct RealFileUploader {
-    _client: files_sdk::FilesClient,
-}
-
-impl RealFileUploader {
-    pub fn new(client: files_sdk::FilesClient) -> Self {
-        Self { _client: client }
+impl<F: FileOps> BackupService<F> {
+    pub fn new(files: F) -> Self {
+        Self { files }
     }
-}
 
-impl FileUploader for RealFileUploader {
-    fn upload(&self, _path: &str, _data: &[u8]) -> Result<(), String> {
-        // In a real implementation, this would use:
-        // let file_handler = FileHandler::new(self._client.clone());
-        // file_handler.upload_file(_path, _data).await.map_err(|e| e.to_string())
+    pub async fn backup_file(&self, local_path: &str, remote_path: &str) -> Result<()> {
+        let data = std::fs::read(local_path).map_err(|e| FilesError::IoError(e.to_string()))?;
+        self.files.upload_file(remote_path, &data).await?;
         Ok(())
     }
 
-    fn upload_with_metadata(
-        &self,
-        path: &str,
-        data: &[u8],
-        _metadata: std::collections::HashMap<String, String>,
-    ) -> Result<(), String> {
-        self.upload(path, data)
-    }
-}
-
-/// Example service that depends on FileUploader
-pub struct BackupService<U: FileUploader> {
-    uploader: U,
-}
-
-impl<U: FileUploader> BackupService<U> {
-    pub fn new(uploader: U) -> Self {
-        Self { uploader }
-    }
-
-    pub fn backup_file(&self, local_path: &str, remote_path: &str) -> Result<(), String> {
-        let data = std::fs::read(local_path).map_err(|e| e.to_string())?;
-        self.uploader.upload(remote_path, &data)
-    }
-
-    pub fn backup_with_tags(
+    pub async fn backup_with_tags(
         &self,
         local_path: &str,
         remote_path: &str,
-        tags: std::collections::HashMap<String, String>,
-    ) -> Result<(), String> {
-        let data = std::fs::read(local_path).map_err(|e| e.to_string())?;
-        self.uploader.upload_with_metadata(remote_path, &data, tags)
+        tags: HashMap<String, String>,
+    ) -> Result<()> {
+        self.backup_file(local_path, remote_path).await?;
+        self.files
+            .update_file(remote_path, Some(tags), None, None)
+            .await?;
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use files_sdk::FileEntity;
+    use files_sdk::ops::MockFileOps;
     use mockall::predicate::*;
-    use std::collections::HashMap;
 
-    #[test]
-    fn test_backup_service_calls_upload() {
-        // Arrange: Create a mock uploader
-        let mut mock_uploader = MockFileUploader::new();
+    fn entity() -> FileEntity {
+        serde_json::from_value(serde_json::json!({ "type": "file" })).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_backup_service_calls_upload() {
+        // Arrange: Create a mock file handler
+        let mut mock_files = MockFileOps::new();
 
-        // Expect upload to be called once with specific arguments
-        mock_uploader
-            .expect_upload()
+        // Expect upload_file to be called once with specific arguments
+        mock_files
+            .expect_upload_file()
             .with(eq("/backup/file.txt"), eq(b"test data".as_slice()))
             .times(1)
-            .returning(|_, _| Ok(()));
+            .returning(|_, _| Ok(entity()));
 
-        let service = BackupService::new(mock_uploader);
+        let service = BackupService::new(mock_files);
 
         // Act: Create a temporary file and backup
         let temp_file = std::env::temp_dir().join("test_file.txt");
         std::fs::write(&temp_file, b"test data").unwrap();
 
-        let result = service.backup_file(temp_file.to_str().unwrap(), "/backup/file.txt");
+        let result = service
+            .backup_file(temp_file.to_str().unwrap(), "/backup/file.txt")
+            .await;
 
         // Assert
         assert!(result.is_ok());
@@ -118,36 +90,37 @@ mod tests {
         std::fs::remove_file(temp_file).ok();
     }
 
-    #[test]
-    fn test_backup_service_handles_upload_failure() {
+    #[tokio::test]
+    async fn test_backup_service_handles_upload_failure() {
         // Arrange: Mock that returns an error
-        let mut mock_uploader = MockFileUploader::new();
+        let mut mock_files = MockFileOps::new();
 
-        mock_uploader
-            .expect_upload()
+        mock_files
+            .expect_upload_file()
             .times(1)
-            .returning(|_, _| Err("Upload failed".to_string()));
+            .returning(|_, _| Err(FilesError::not_found("Parent folder not found")));
 
-        let service = BackupService::new(mock_uploader);
+        let service = BackupService::new(mock_files);
 
         // Act
         let temp_file = std::env::temp_dir().join("test_file2.txt");
         std::fs::write(&temp_file, b"test data").unwrap();
 
-        let result = service.backup_file(temp_file.to_str().unwrap(), "/backup/file.txt");
+        let result = service
+            .backup_file(temp_file.to_str().unwrap(), "/backup/file.txt")
+            .await;
 
         // Assert
-        assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Upload failed");
+        assert!(matches!(result, Err(FilesError::NotFound { .. })));
 
         // Cleanup
         std::fs::remove_file(temp_file).ok();
     }
 
-    #[test]
-    fn test_backup_with_metadata() {
+    #[tokio::test]
+    async fn test_backup_with_metadata() {
         // Arrange
-        let mut mock_uploader = MockFileUploader::new();
+        let mut mock_files = MockFileOps::new();
 
         let expected_metadata = {
             let mut map = HashMap::new();
@@ -156,27 +129,35 @@ mod tests {
             map
         };
 
-        mock_uploader
-            .expect_upload_with_metadata()
+        mock_files
+            .expect_upload_file()
+            .with(eq("/backup/config.json"), eq(b"config data".as_slice()))
+            .times(1)
+            .returning(|_, _| Ok(entity()));
+        mock_files
+            .expect_update_file()
             .with(
                 eq("/backup/config.json"),
-                eq(b"config data".as_slice()),
-                eq(expected_metadata.clone()),
+                eq(Some(expected_metadata.clone())),
+                eq(None),
+                eq(None),
             )
             .times(1)
-            .returning(|_, _, _| Ok(()));
+            .returning(|_, _, _, _| Ok(entity()));
 
-        let service = BackupService::new(mock_uploader);
+        let service = BackupService::new(mock_files);
 
         // Act
         let temp_file = std::env::temp_dir().join("test_config.json");
         std::fs::write(&temp_file, b"config data").unwrap();
 
-        let result = service.backup_with_tags(
-            temp_file.to_str().unwrap(),
-            "/backup/config.json",
-            expected_metadata,
-        );
+        let result = service
+            .backup_with_tags(
+                temp_file.to_str().unwrap(),
+                "/backup/config.json",
+                expected_metadata,
+            )
+            .await;
 
         // Assert
         assert!(result.is_ok());
@@ -189,12 +170,12 @@ mod tests {
 fn main() {
     println!("Mockall Testing Example");
     println!("=======================\n");
-    println!("This example demonstrates trait-based mocking with mockall.");
+    println!("This example demonstrates mocking the SDK's handler traits.");
     println!("\nRun the tests with:");
-    println!("  cargo test --example mockall_example");
+    println!("  cargo test --example mockall_example --features mockall");
     println!("\nKey concepts:");
-    println!("- Define a trait for operations you want to mock");
-    println!("- Use #[cfg_attr(test, automock)] to generate mocks");
+    println!("- Write application code against FileOps, FolderOps or UserOps");
+    println!("- Enable the mockall feature to get MockFileOps and friends");
     println!("- Set expectations on method calls and return values");
     println!("- Verify behavior without hitting real APIs");
 }
@@ -13,8 +13,12 @@
 //! cargo test --example test_doubles_example
 //! ```
 
+use async_trait::async_trait;
+use files_sdk::{FileEntity, FileOps, FilesError, Result, Timestamp};
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 /// Uploaded file record
 #[derive(Debug, Clone, PartialEq)]
@@ -24,16 +28,16 @@ pub struct UploadedFile {
     pub metadata: HashMap<String, String>,
 }
 
-/// Fake Files.com client for testing
+/// Fake implementation of the SDK's `FileOps` trait for testing
 ///
 /// Records all uploads and provides methods to inspect state
 #[derive(Clone)]
-pub struct FakeFilesClient {
+pub struct FakeFiles {
     uploaded_files: Arc<Mutex<Vec<UploadedFile>>>,
     should_fail: Arc<Mutex<bool>>,
 }
 
-impl FakeFilesClient {
+impl FakeFiles {
     pub fn new() -> Self {
         Self {
             uploaded_files: Arc::new(Mutex::new(Vec::new())),
@@ -46,43 +50,6 @@ impl FakeFilesClient {
         *self.should_fail.lock().unwrap() = fail;
     }
 
-    /// Upload a file (records it in memory)
-    pub fn upload(&self, path: &str, data: &[u8]) -> Result<(), String> {
-        if *self.should_fail.lock().unwrap() {
-            return Err("Simulated upload failure".to_string());
-        }
-
-        let file = UploadedFile {
-            path: path.to_string(),
-            data: data.to_vec(),
-            metadata: HashMap::new(),
-        };
-
-        self.uploaded_files.lock().unwrap().push(file);
-        Ok(())
-    }
-
-    /// Upload a file with metadata
-    pub fn upload_with_metadata(
-        &self,
-        path: &str,
-        data: &[u8],
-        metadata: HashMap<String, String>,
-    ) -> Result<(), String> {
-        if *self.should_fail.lock().unwrap() {
-            return Err("Simulated upload failure".to_string());
-        }
-
-        let file = UploadedFile {
-            path: path.to_string(),
-            data: data.to_vec(),
-            metadata,
-        };
-
-        self.uploaded_files.lock().unwrap().push(file);
-        Ok(())
-    }
-
     /// Get all uploaded files
     pub fn get_uploaded_files(&self) -> Vec<UploadedFile> {
         self.uploaded_files.lock().unwrap().clone()
@@ -106,46 +73,152 @@ impl FakeFilesClient {
     pub fn clear(&self) {
         self.uploaded_files.lock().unwrap().clear();
     }
+
+    fn entity(path: &str, size: usize) -> FileEntity {
+        serde_json::from_value(serde_json::json!({
+            "path": path.trim_start_matches('/'),
+            "type": "file",
+            "size": size,
+        }))
+        .unwrap()
+    }
+
+    fn find(&self, path: &str) -> Result<UploadedFile> {
+        self.uploaded_files
+            .lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .find(|f| f.path == path)
+            .cloned()
+            .ok_or_else(|| FilesError::not_found(format!("{} not found", path)))
+    }
 }
 
-impl Default for FakeFilesClient {
+impl Default for FakeFiles {
     fn default() -> Self {
         Self::new()
     }
 }
 
-/// Example service using FakeFilesClient
-pub struct DocumentService {
-    client: FakeFilesClient,
+#[async_trait]
+impl FileOps for FakeFiles {
+    async fn download_file(&self, path: &str) -> Result<FileEntity> {
+        self.get_metadata(path).await
+    }
+
+    async fn download_content(&self, path: &str) -> Result<Vec<u8>> {
+        Ok(self.find(path)?.data)
+    }
+
+    async fn download_to_file(&self, remote_path: &str, local_path: &Path) -> Result<()> {
+        let data = self.find(remote_path)?.data;
+        std::fs::write(local_path, data).map_err(|e| FilesError::IoError(e.to_string()))
+    }
+
+    async fn get_metadata(&self, path: &str) -> Result<FileEntity> {
+        let file = self.find(path)?;
+        Ok(Self::entity(path, file.data.len()))
+    }
+
+    /// Upload a file (records it in memory)
+    async fn upload_file(&self, path: &str, data: &[u8]) -> Result<FileEntity> {
+        if *self.should_fail.lock().unwrap() {
+            return Err(FilesError::ServiceUnavailable {
+                message: "Simulated upload failure".to_string(),
+                retry_after: None,
+                detail: None,
+            });
+        }
+
+        let file = UploadedFile {
+            path: path.to_string(),
+            data: data.to_vec(),
+            metadata: HashMap::new(),
+        };
+
+        self.uploaded_files.lock().unwrap().push(file);
+        Ok(Self::entity(path, data.len()))
+    }
+
+    /// Attach metadata to the most recent upload of `path`
+    async fn update_file(
+        &self,
+        path: &str,
+        custom_metadata: Option<HashMap<String, String>>,
+        _provided_mtime: Option<Timestamp>,
+        _priority_color: Option<String>,
+    ) -> Result<FileEntity> {
+        let mut files = self.uploaded_files.lock().unwrap();
+        let file = files
+            .iter_mut()
+            .rev()
+            .find(|f| f.path == path)
+            .ok_or_else(|| FilesError::not_found(format!("{} not found", path)))?;
+        file.metadata.extend(custom_metadata.unwrap_or_default());
+        Ok(Self::entity(path, file.data.len()))
+    }
+
+    async fn delete_file(&self, path: &str, _recursive: bool) -> Result<()> {
+        self.find(path)?;
+        self.uploaded_files
+            .lock()
+            .unwrap()
+            .retain(|f| f.path != path);
+        Ok(())
+    }
+
+    async fn copy_file(&self, source: &str, destination: &str, _timeout: Duration) -> Result<()> {
+        let mut file = self.find(source)?;
+        file.path = destination.to_string();
+        self.uploaded_files.lock().unwrap().push(file);
+        Ok(())
+    }
+
+    async fn move_file(&self, source: &str, destination: &str, timeout: Duration) -> Result<()> {
+        self.copy_file(source, destination, timeout).await?;
+        self.delete_file(source, false).await
+    }
+}
+
+/// Example service that works with any `FileOps` implementation
+///
+/// In production this is a `FileHandler`; in tests it is a `FakeFiles`.
+pub struct DocumentService<F: FileOps> {
+    files: F,
 }
 
-impl DocumentService {
-    pub fn new(client: FakeFilesClient) -> Self {
-        Self { client }
+impl<F: FileOps> DocumentService<F> {
+    pub fn new(files: F) -> Self {
+        Self { files }
     }
 
-    pub fn save_document(&self, name: &str, content: &str) -> Result<(), String> {
+    pub async fn save_document(&self, name: &str, content: &str) -> Result<()> {
         let path = format!("/documents/{}", name);
-        self.client.upload(&path, content.as_bytes())
+        self.files.upload_file(&path, content.as_bytes()).await?;
+        Ok(())
     }
 
-    pub fn save_versioned_document(
+    pub async fn save_versioned_document(
         &self,
         name: &str,
         content: &str,
         version: &str,
-    ) -> Result<(), String> {
+    ) -> Result<()> {
         let path = format!("/documents/{}", name);
         let mut metadata = HashMap::new();
         metadata.insert("version".to_string(), version.to_string());
-        self.client
-            .upload_with_metadata(&path, content.as_bytes(), metadata)
+        self.files.upload_file(&path, content.as_bytes()).await?;
+        self.files
+            .update_file(&path, Some(metadata), None, None)
+            .await?;
+        Ok(())
     }
 
-    pub fn batch_save(&self, documents: Vec<(&str, &str)>) -> Result<usize, String> {
+    pub async fn batch_save(&self, documents: Vec<(&str, &str)>) -> Result<usize> {
         let mut count = 0;
         for (name, content) in documents {
-            self.save_document(name, content)?;
+            self.save_document(name, content).await?;
             count += 1;
         }
         Ok(count)
@@ -156,47 +229,48 @@ impl DocumentService {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_document_service_uploads_file() {
+    #[tokio::test]
+    async fn test_document_service_uploads_file() {
         // Arrange
-        let fake_client = FakeFilesClient::new();
-        let service = DocumentService::new(fake_client.clone());
+        let fake_files = FakeFiles::new();
+        let service = DocumentService::new(fake_files.clone());
 
         // Act
-        let result = service.save_document("readme.txt", "Hello, World!");
+        let result = service.save_document("readme.txt", "Hello, World!").await;
 
         // Assert
         assert!(result.is_ok());
-        assert_eq!(fake_client.upload_count(), 1);
-        assert!(fake_client.was_uploaded("/documents/readme.txt"));
+        assert_eq!(fake_files.upload_count(), 1);
+        assert!(fake_files.was_uploaded("/documents/readme.txt"));
 
-        let files = fake_client.get_uploaded_files();
+        let files = fake_files.get_uploaded_files();
         assert_eq!(files[0].path, "/documents/readme.txt");
         assert_eq!(files[0].data, b"Hello, World!");
     }
 
-    #[test]
-    fn test_versioned_document_includes_metadata() {
+    #[tokio::test]
+    async fn test_versioned_document_includes_metadata() {
         // Arrange
-        let fake_client = FakeFilesClient::new();
-        let service = DocumentService::new(fake_client.clone());
+        let fake_files = FakeFiles::new();
+        let service = DocumentService::new(fake_files.clone());
 
         // Act
-        let result =
-            service.save_versioned_document("config.json", "{\"key\": \"value\"}", "1.0.0");
+        let result = service
+            .save_versioned_document("config.json", "{\"key\": \"value\"}", "1.0.0")
+            .await;
 
         // Assert
         assert!(result.is_ok());
 
-        let files = fake_client.get_uploaded_files();
+        let files = fake_files.get_uploaded_files();
         assert_eq!(files[0].metadata.get("version"), Some(&"1.0.0".to_string()));
     }
 
-    #[test]
-    fn test_batch_save_tracks_all_uploads() {
+    #[tokio::test]
+    async fn test_batch_save_tracks_all_uploads() {
         // Arrange
-        let fake_client = FakeFilesClient::new();
-        let service = DocumentService::new(fake_client.clone());
+        let fake_files = FakeFiles::new();
+        let service = DocumentService::new(fake_files.clone());
 
         let documents = vec![
             ("file1.txt", "content1"),
@@ -205,53 +279,55 @@ mod tests {
         ];
 
         // Act
-        let result = service.batch_save(documents);
+        let result = service.batch_save(documents).await;
 
         // Assert
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 3);
-        assert_eq!(fake_client.upload_count(), 3);
-        assert!(fake_client.was_uploaded("/documents/file1.txt"));
-        assert!(fake_client.was_uploaded("/documents/file2.txt"));
-        assert!(fake_client.was_uploaded("/documents/file3.txt"));
+        assert_eq!(fake_files.upload_count(), 3);
+        assert!(fake_files.was_uploaded("/documents/file1.txt"));
+        assert!(fake_files.was_uploaded("/documents/file2.txt"));
+        assert!(fake_files.was_uploaded("/documents/file3.txt"));
     }
 
-    #[test]
-    fn test_handles_upload_failure() {
+    #[tokio::test]
+    async fn test_handles_upload_failure() {
         // Arrange
-        let fake_client = FakeFilesClient::new();
-        fake_client.set_should_fail(true);
-        let service = DocumentService::new(fake_client.clone());
+        let fake_files = FakeFiles::new();
+        fake_files.set_should_fail(true);
+        let service = DocumentService::new(fake_files.clone());
 
         // Act
-        let result = service.save_document("fail.txt", "should fail");
+        let result = service.save_document("fail.txt", "should fail").await;
 
         // Assert
-        assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Simulated upload failure");
-        assert_eq!(fake_client.upload_count(), 0);
+        assert!(matches!(result, Err(FilesError::ServiceUnavailable { .. })));
+        assert_eq!(fake_files.upload_count(), 0);
     }
 
-    #[test]
-    fn test_batch_save_stops_on_first_error() {
+    #[tokio::test]
+    async fn test_batch_save_stops_on_first_error() {
         // Arrange
-        let fake_client = FakeFilesClient::new();
-        let service = DocumentService::new(fake_client.clone());
+        let fake_files = FakeFiles::new();
+        let service = DocumentService::new(fake_files.clone());
 
         // Upload first file successfully
-        service.save_document("file1.txt", "content1").unwrap();
+        service
+            .save_document("file1.txt", "content1")
+            .await
+            .unwrap();
 
         // Now make it fail
-        fake_client.set_should_fail(true);
+        fake_files.set_should_fail(true);
 
         let documents = vec![("file2.txt", "content2"), ("file3.txt", "content3")];
 
         // Act
-        let result = service.batch_save(documents);
+        let result = service.batch_save(documents).await;
 
         // Assert
         assert!(result.is_err());
-        assert_eq!(fake_client.upload_count(), 1); // Only the first upload before batch
+        assert_eq!(fake_files.upload_count(), 1); // Only the first upload before batch
     }
 }
 
@@ -262,7 +338,7 @@ fn main() {
     println!("\nRun the tests with:");
     println!("  cargo test --example test_doubles_example");
     println!("\nKey concepts:");
-    println!("- Create fake implementations that track state");
+    println!("- Implement FileOps with a fake that tracks state");
     println!("- Use Arc<Mutex<Vec<T>>> to record operations");
     println!("- Provide inspection methods to verify behavior");
     println!("- No external mocking libraries required");
@@ -1,82 +1,62 @@
-//! Example: Using Tower middleware for observability with the Files.com SDK
+//! Example: Request logging with a custom Tower layer
 //!
-//! This example demonstrates how to use tower-http's tracing middleware
-//! to add observability to your Files.com API calls.
+//! This example writes a small middleware layer that logs the method, URL,
+//! status and latency of every request the client sends.
 //!
-//! To use tower middleware with this SDK, enable both features:
+//! To use tower middleware with this SDK, enable the `tower` feature:
 //! ```toml
-//! files-sdk = { version = "0.3", features = ["tower", "tracing"] }
-//! tower-http = { version = "0.6", features = ["trace"] }
+//! files-sdk = { version = "0.3", features = ["tower"] }
+//! tower = { version = "0.5", features = ["util"] }
 //! ```
+//!
+//! Run with: `FILES_API_KEY=... cargo run --example tower_observability --features tower`
 
-use files_sdk::FilesClient;
+use files_sdk::middleware::{HttpRequest, HttpService};
+use files_sdk::{FilesClient, UserHandler};
 use std::env;
-use tower::ServiceBuilder;
-use tower_http::trace::TraceLayer;
+use std::time::Instant;
+use tower::{ServiceExt, layer::layer_fn, service_fn};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Initialize tracing subscriber to see the logs
-    tracing_subscriber::fmt()
-        .with_env_filter("tower_http=debug,files_sdk=debug")
-        .init();
-
     let api_key = env::var("FILES_API_KEY").expect("FILES_API_KEY must be set");
 
-    // Create base FilesClient
-    let _client = FilesClient::builder().api_key(&api_key).build()?;
-
-    println!("Example 1: Request/Response tracing");
-    println!("====================================");
-
-    // Add TraceLayer for automatic request/response logging
-    let _traced_client = ServiceBuilder::new()
-        .layer(TraceLayer::new_for_http())
-        .service(_client.clone());
-
-    println!("Client wrapped with TraceLayer - all requests will be logged");
-
-    println!("\nExample 2: Custom trace configuration");
-    println!("======================================");
-
-    // You can customize what gets logged
-    let _custom_traced_client = ServiceBuilder::new()
-        .layer(
-            TraceLayer::new_for_http()
-                .make_span_with(
-                    tower_http::trace::DefaultMakeSpan::new().level(tracing::Level::INFO),
-                )
-                .on_request(tower_http::trace::DefaultOnRequest::new().level(tracing::Level::DEBUG))
-                .on_response(
-                    tower_http::trace::DefaultOnResponse::new().level(tracing::Level::INFO),
-                ),
-        )
-        .service(_client.clone());
-
-    println!("Client with custom trace levels configured");
-
-    println!("\nExample 3: Full observability stack");
-    println!("====================================");
-
-    let _production_client = ServiceBuilder::new()
-        // Timeout for all requests
-        .timeout(std::time::Duration::from_secs(30))
-        // Trace all requests
-        .layer(TraceLayer::new_for_http())
-        .service(_client);
-
-    println!("Production-ready client with:");
-    println!("- Timeouts");
-    println!("- Request/response tracing");
-
-    println!("\nKey Concepts:");
-    println!("- TraceLayer provides automatic request/response logging");
-    println!("- Customize span creation and log levels");
-    println!("- Use ServiceBuilder to compose multiple layers");
-
-    println!("\nSample Output (when making requests):");
-    println!("  DEBUG tower_http: request");
-    println!("  DEBUG tower_http: response status=200 latency=123ms");
+    println!("Tower Observability Example");
+    println!("===========================\n");
+
+    // A layer built from a closure: log each request and pass it on
+    let logging = layer_fn(|inner: HttpService| {
+        service_fn(move |request: HttpRequest| {
+            let inner = inner.clone();
+            async move {
+                let method = request.method().clone();
+                let uri = request.uri().clone();
+                let started = Instant::now();
+
+                let result = inner.oneshot(request).await;
+                match &result {
+                    Ok(response) => println!(
+                        "{} {} -> {} in {:?}",
+                        method,
+                        uri.path(),
+                        response.status(),
+                        started.elapsed()
+                    ),
+                    Err(e) => println!("{} {} -> error: {}", method, uri.path(), e),
+                }
+                result
+            }
+        })
+    });
+
+    let client = FilesClient::builder()
+        .api_key(&api_key)
+        .layer(logging)
+        .build()?;
+
+    let users = UserHandler::new(client);
+    let (users, _) = users.list(None, Some(5), None).await?;
+    println!("\nFetched {} users", users.len());
 
     Ok(())
 }
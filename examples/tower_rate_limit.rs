@@ -1,79 +1,56 @@
-//! Example: Using Tower middleware for rate limiting with the Files.com SDK
+//! Example: Limiting request concurrency with Tower middleware
 //!
-//! This example demonstrates how to use tower's rate limiting middleware.
-//! Tower provides flexible rate limiting through the governor crate integration.
+//! This example caps how many requests the client has in flight at once.
+//! The limit is shared by every handler created from the same client and by
+//! all of its clones.
 //!
 //! To use tower middleware with this SDK, enable the `tower` feature:
 //! ```toml
 //! files-sdk = { version = "0.3", features = ["tower"] }
+//! tower = { version = "0.5", features = ["limit", "timeout"] }
 //! ```
+//!
+//! Run with: `FILES_API_KEY=... cargo run --example tower_rate_limit --features tower`
 
-use files_sdk::FilesClient;
+use files_sdk::{FilesClient, UserHandler};
 use std::env;
+use std::time::Duration;
 use tower::ServiceBuilder;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let api_key = env::var("FILES_API_KEY").expect("FILES_API_KEY must be set");
 
-    // Create base FilesClient
-    let client = FilesClient::builder().api_key(&api_key).build()?;
-
-    println!("Example 1: Token bucket rate limiting");
-    println!("======================================");
-
-    // For actual rate limiting, you would use governor crate with tower
-    // Example configuration (requires governor crate as a direct dependency):
-    //
-    // use governor::{Quota, RateLimiter};
-    // use governor::clock::DefaultClock;
-    // use governor::state::{InMemoryState, NotKeyed};
-    //
-    // let quota = Quota::per_second(NonZeroU32::new(10).unwrap());
-    // let limiter = RateLimiter::<NotKeyed, InMemoryState, DefaultClock>::direct(quota);
-    //
-    // Then wrap your service with a custom middleware that checks the limiter
-
-    println!("Rate limiting with tower requires:");
-    println!("1. Add governor to your Cargo.toml");
-    println!("2. Create a RateLimiter with your desired quota");
-    println!("3. Implement a tower Layer that checks the limiter");
-    println!("4. Use ServiceBuilder to apply the layer");
-
-    println!("\nExample 2: Combining rate limiting with other middleware");
-    println!("=========================================================");
-
-    // Tower allows composing multiple middleware layers
-    let _complex_client = ServiceBuilder::new()
-        // Add timeout
-        .timeout(std::time::Duration::from_secs(30))
-        // Add rate limiting (custom layer)
-        // .layer(RateLimitLayer::new(limiter))
-        // Add retry
-        // .layer(RetryLayer::new(...))
-        .service(client.clone());
-
-    println!("Created client with timeout + rate limit + retry");
-
-    println!("\nKey Concepts:");
-    println!("- Tower's middleware system is composable and reusable");
-    println!("- Rate limiting can be implemented with governor");
-    println!("- Combine multiple middleware layers with ServiceBuilder");
-    println!("- Each layer wraps the service, adding behavior");
-
-    println!("\nSample Code:");
-    println!("```rust");
-    println!("use governor::{{Quota, RateLimiter}};");
-    println!("use std::num::NonZeroU32;");
-    println!();
-    println!("let quota = Quota::per_second(NonZeroU32::new(10).unwrap());");
-    println!("let limiter = RateLimiter::direct(quota);");
-    println!();
-    println!("// Use with tower middleware");
-    println!("let client = ServiceBuilder::new()");
-    println!("    .layer(RateLimitLayer::new(limiter))");
-    println!("    .service(files_client);");
-    println!("```");
+    println!("Tower Concurrency Limit Example");
+    println!("===============================\n");
+
+    // Several layers can be added at once with a ServiceBuilder
+    let client = FilesClient::builder()
+        .api_key(&api_key)
+        .layer(
+            ServiceBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .concurrency_limit(2),
+        )
+        .build()?;
+
+    // Ten concurrent calls, but at most two requests hit the API at a time
+    let mut tasks = tokio::task::JoinSet::new();
+    for i in 0..10 {
+        let users = UserHandler::new(client.clone());
+        tasks.spawn(async move {
+            let result = users.list(None, Some(1), None).await;
+            (i, result.map(|(users, _)| users.len()))
+        });
+    }
+
+    while let Some(joined) = tasks.join_next().await {
+        let (i, result) = joined?;
+        match result {
+            Ok(count) => println!("Request {} returned {} user(s)", i, count),
+            Err(e) => println!("Request {} failed: {}", i, e),
+        }
+    }
 
     Ok(())
 }
@@ -1,56 +1,83 @@
 //! Example: Using Tower middleware for retry logic with the Files.com SDK
 //!
-//! This example demonstrates how to use tower middleware for retry logic.
-//! Tower provides a flexible, composable middleware system.
+//! This example plugs a `tower::retry` policy into the client's HTTP stack.
+//! Every request made by any handler then goes through the retry layer.
+//!
+//! For most applications the built-in `RetryPolicy` is enough. A tower policy
+//! is useful when you need full control over which responses are retried.
 //!
 //! To use tower middleware with this SDK, enable the `tower` feature:
 //! ```toml
 //! files-sdk = { version = "0.3", features = ["tower"] }
+//! tower = { version = "0.5", features = ["retry"] }
 //! ```
 //!
-//! For retry functionality, you'll also need:
-//! ```toml
-//! tower = "0.5"
-//! tower-http = { version = "0.6", features = ["retry"] }
-//! ```
+//! Run with: `FILES_API_KEY=... cargo run --example tower_retry --features tower`
 
-use files_sdk::FilesClient;
+use files_sdk::middleware::{BoxError, HttpRequest, HttpResponse};
+use files_sdk::{FilesClient, UserHandler};
 use std::env;
+use std::time::Duration;
+use tower::retry::{Policy, RetryLayer};
+
+/// Retries server errors and transport failures with a fixed delay
+#[derive(Clone)]
+struct RetryServerErrors {
+    remaining: usize,
+}
+
+impl Policy<HttpRequest, HttpResponse, BoxError> for RetryServerErrors {
+    type Future = tokio::time::Sleep;
+
+    fn retry(
+        &mut self,
+        _request: &mut HttpRequest,
+        result: &mut Result<HttpResponse, BoxError>,
+    ) -> Option<Self::Future> {
+        let failed = match result {
+            Ok(response) => response.status().is_server_error(),
+            Err(_) => true,
+        };
+
+        if failed && self.remaining > 0 {
+            self.remaining -= 1;
+            println!("Request failed, retrying ({} retries left)", self.remaining);
+            Some(tokio::time::sleep(Duration::from_millis(500)))
+        } else {
+            None
+        }
+    }
+
+    fn clone_request(&mut self, request: &HttpRequest) -> Option<HttpRequest> {
+        // Streaming bodies cannot be replayed, so those requests are not retried
+        let body = request.body().as_bytes()?.to_vec();
+
+        let mut clone = HttpRequest::new(reqwest::Body::from(body));
+        *clone.method_mut() = request.method().clone();
+        *clone.uri_mut() = request.uri().clone();
+        *clone.headers_mut() = request.headers().clone();
+        *clone.version_mut() = request.version();
+        Some(clone)
+    }
+}
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let api_key = env::var("FILES_API_KEY").expect("FILES_API_KEY must be set");
 
-    // Create base FilesClient
-    let _client = FilesClient::builder().api_key(&api_key).build()?;
-
     println!("Tower Retry Example");
     println!("===================\n");
 
-    println!("To add retry logic with tower, add these dependencies:");
-    println!("  tower = \"0.5\"");
-    println!("  tower-http = {{ version = \"0.6\", features = [\"retry\"] }}");
-    println!();
-
-    println!("Then use RetryLayer:");
-    println!("```rust");
-    println!("use tower::ServiceBuilder;");
-    println!("use tower_http::retry::RetryLayer;");
-    println!("use tower_http::classify::ServerErrorsAsFailures;");
-    println!();
-    println!("let client = FilesClient::builder().api_key(\"key\").build()?;");
-    println!();
-    println!("let retrying_client = ServiceBuilder::new()");
-    println!("    .layer(RetryLayer::new(ServerErrorsAsFailures::default()))");
-    println!("    .service(client);");
-    println!("```");
-    println!();
-
-    println!("Key Features:");
-    println!("- Automatic retry on 5xx server errors");
-    println!("- Exponential backoff (configurable)");
-    println!("- Custom retry policies via RetryPolicy trait");
-    println!("- Composable with other middleware layers");
+    let client = FilesClient::builder()
+        .api_key(&api_key)
+        .layer(RetryLayer::new(RetryServerErrors { remaining: 3 }))
+        .build()?;
+
+    // Any handler call now retries server errors up to three times
+    let users = UserHandler::new(client);
+    let (users, _) = users.list(None, Some(5), None).await?;
+
+    println!("Fetched {} users", users.len());
 
     Ok(())
 }
@@ -7,7 +7,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let handler = UserHandler::new(client);
 
     // List users with pagination
-    let (users, pagination) = handler.list(None, Some(50)).await?;
+    let (users, pagination) = handler.list(None, Some(50), None).await?;
     println!("Found {} users", users.len());
     for user in &users {
         println!(
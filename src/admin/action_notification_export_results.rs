@@ -1,12 +1,56 @@
-use crate::{Result, client::FilesClient};
+use crate::pagination::Paginator;
+use crate::{PaginationInfo, Result, client::FilesClient};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Action notification export result entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActionNotificationExportResultEntity {
+    /// Notification ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// When the notification was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<i64>,
+
+    /// HTTP status code returned in the webhook response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<i64>,
+
+    /// Message indicating the overall status of the webhook notification
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+
+    /// Whether the webhook notification was successful
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+
+    /// Request headers sent with the webhook
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_headers: Option<String>,
+
+    /// HTTP method used for the webhook
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_method: Option<String>,
+
+    /// URL the webhook was sent to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_url: Option<String>,
+
+    /// Path of the file that triggered the notification
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// Folder of the file that triggered the notification
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub folder: Option<String>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+#[derive(Debug, Clone)]
 pub struct ActionNotificationExportResultHandler {
     client: FilesClient,
 }
@@ -21,4 +65,44 @@ impl ActionNotificationExportResultHandler {
         let response = self.client.get_raw(&endpoint).await?;
         Ok(serde_json::from_value(response)?)
     }
+
+    /// List the results of an action notification export
+    pub async fn list(
+        &self,
+        action_notification_export_id: i64,
+        cursor: Option<String>,
+        per_page: Option<i64>,
+    ) -> Result<(Vec<ActionNotificationExportResultEntity>, PaginationInfo)> {
+        let mut endpoint = format!(
+            "/action_notification_export_results?action_notification_export_id={}",
+            action_notification_export_id
+        );
+        if let Some(c) = cursor {
+            endpoint.push_str(&format!("&cursor={}", c));
+        }
+        if let Some(pp) = per_page {
+            endpoint.push_str(&format!("&per_page={}", pp));
+        }
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let results: Vec<ActionNotificationExportResultEntity> = serde_json::from_value(response)?;
+        Ok((results, pagination))
+    }
+
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        action_notification_export_id: i64,
+        per_page: Option<i64>,
+    ) -> Paginator<ActionNotificationExportResultEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            async move {
+                handler
+                    .list(action_notification_export_id, cursor, per_page)
+                    .await
+            }
+        })
+    }
 }
@@ -1,12 +1,181 @@
-use crate::{Result, client::FilesClient};
+use crate::admin::ActionNotificationExportResultHandler;
+use crate::admin::action_notification_export_results::ActionNotificationExportResultEntity;
+use crate::admin::export::{ExportOptions, ExportStream, created_id};
+use crate::{Result, Timestamp, client::FilesClient};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Action notification export entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActionNotificationExportEntity {
+    /// Export ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Version of the underlying records for the export
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub export_version: Option<String>,
+
+    /// Start of the date range for the export
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_at: Option<Timestamp>,
+
+    /// End of the date range for the export
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_at: Option<Timestamp>,
+
+    /// Status of the export
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// Only include notifications for this path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_path: Option<String>,
+
+    /// Only include notifications for this folder
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_folder: Option<String>,
+
+    /// Only include notifications with this message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_message: Option<String>,
+
+    /// Only include notifications with this request method
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_request_method: Option<String>,
+
+    /// Only include notifications with this request URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_request_url: Option<String>,
+
+    /// Only include notifications with this response status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_status: Option<String>,
+
+    /// Only include successful or failed notifications
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_success: Option<bool>,
+
+    /// Download URL of the export results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results_url: Option<String>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+/// Parameters for creating an action notification export
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateActionNotificationExportParams {
+    /// User ID, when acting on behalf of another user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
+
+    /// Start of the date range for the export
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_at: Option<Timestamp>,
+
+    /// End of the date range for the export
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_at: Option<Timestamp>,
+
+    /// Only include notifications with this message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_message: Option<String>,
+
+    /// Only include notifications with this request method
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_request_method: Option<String>,
+
+    /// Only include notifications with this request URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_request_url: Option<String>,
+
+    /// Only include notifications with this response status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_status: Option<String>,
+
+    /// Only include successful or failed notifications
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_success: Option<bool>,
+
+    /// Only include notifications for this path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_path: Option<String>,
+
+    /// Only include notifications for this folder
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_folder: Option<String>,
+}
+
+impl CreateActionNotificationExportParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// User ID, when acting on behalf of another user
+    pub fn user_id(mut self, user_id: i64) -> Self {
+        self.user_id = Some(user_id);
+        self
+    }
+
+    /// Start of the date range for the export
+    pub fn start_at(mut self, start_at: impl Into<Timestamp>) -> Self {
+        self.start_at = Some(start_at.into());
+        self
+    }
+
+    /// End of the date range for the export
+    pub fn end_at(mut self, end_at: impl Into<Timestamp>) -> Self {
+        self.end_at = Some(end_at.into());
+        self
+    }
+
+    /// Only include notifications with this message
+    pub fn query_message(mut self, query_message: impl Into<String>) -> Self {
+        self.query_message = Some(query_message.into());
+        self
+    }
+
+    /// Only include notifications with this request method
+    pub fn query_request_method(mut self, query_request_method: impl Into<String>) -> Self {
+        self.query_request_method = Some(query_request_method.into());
+        self
+    }
+
+    /// Only include notifications with this request URL
+    pub fn query_request_url(mut self, query_request_url: impl Into<String>) -> Self {
+        self.query_request_url = Some(query_request_url.into());
+        self
+    }
+
+    /// Only include notifications with this response status
+    pub fn query_status(mut self, query_status: impl Into<String>) -> Self {
+        self.query_status = Some(query_status.into());
+        self
+    }
+
+    /// Only include successful or failed notifications
+    pub fn query_success(mut self, query_success: bool) -> Self {
+        self.query_success = Some(query_success);
+        self
+    }
+
+    /// Only include notifications for this path
+    pub fn query_path(mut self, query_path: impl Into<String>) -> Self {
+        self.query_path = Some(query_path.into());
+        self
+    }
+
+    /// Only include notifications for this folder
+    pub fn query_folder(mut self, query_folder: impl Into<String>) -> Self {
+        self.query_folder = Some(query_folder.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct ActionNotificationExportHandler {
     client: FilesClient,
 }
@@ -24,7 +193,7 @@ impl ActionNotificationExportHandler {
 
     pub async fn create(
         &self,
-        params: serde_json::Value,
+        params: CreateActionNotificationExportParams,
     ) -> Result<ActionNotificationExportEntity> {
         let response = self
             .client
@@ -32,4 +201,31 @@ impl ActionNotificationExportHandler {
             .await?;
         Ok(serde_json::from_value(response)?)
     }
+
+    /// Create an export, wait until it is ready and stream its results
+    ///
+    /// See [`export`](crate::admin::export) for polling and cancellation.
+    pub fn run_export(
+        &self,
+        params: CreateActionNotificationExportParams,
+        options: ExportOptions,
+    ) -> ExportStream<ActionNotificationExportResultEntity> {
+        let creator = self.clone();
+        let poller = self.clone();
+        let results = ActionNotificationExportResultHandler::new(self.client.clone());
+        let per_page = options.per_page;
+
+        ExportStream::run(
+            options,
+            async move {
+                let export = creator.create(params).await?;
+                created_id(export.id, "/action_notification_exports")
+            },
+            move |id| {
+                let poller = poller.clone();
+                async move { Ok(poller.get(id).await?.status) }
+            },
+            move |id| results.list_stream(id, per_page),
+        )
+    }
 }
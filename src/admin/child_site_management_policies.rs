@@ -1,12 +1,184 @@
-use crate::{Result, client::FilesClient, types::PaginationInfo};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{Result, Timestamp, client::FilesClient, types::PaginationInfo};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Child site management policy entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChildSiteManagementPolicyEntity {
+    /// Policy ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Type of policy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy_type: Option<String>,
+
+    /// Name of the policy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Description of the policy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Policy configuration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<serde_json::Value>,
+
+    /// IDs of child sites the policy is applied to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applied_child_site_ids: Option<Vec<i64>>,
+
+    /// IDs of child sites excluded from the policy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_child_site_ids: Option<Vec<i64>>,
+
+    /// Creation time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+
+    /// Last update time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Timestamp>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parameters for creating a child site management policy
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateChildSiteManagementPolicyParams {
+    /// Type of policy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy_type: Option<String>,
+
+    /// Name of the policy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Description of the policy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Policy configuration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<serde_json::Value>,
+
+    /// IDs of child sites excluded from the policy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_child_site_ids: Option<Vec<i64>>,
 }
 
+impl CreateChildSiteManagementPolicyParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Type of policy
+    pub fn policy_type(mut self, policy_type: impl Into<String>) -> Self {
+        self.policy_type = Some(policy_type.into());
+        self
+    }
+
+    /// Name of the policy
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Description of the policy
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Policy configuration
+    pub fn value(mut self, value: serde_json::Value) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// IDs of child sites excluded from the policy
+    pub fn skip_child_site_ids(mut self, skip_child_site_ids: Vec<i64>) -> Self {
+        self.skip_child_site_ids = Some(skip_child_site_ids);
+        self
+    }
+}
+
+/// Parameters for updating a child site management policy
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateChildSiteManagementPolicyParams {
+    /// Type of policy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy_type: Option<String>,
+
+    /// Name of the policy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Description of the policy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Policy configuration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<serde_json::Value>,
+
+    /// IDs of child sites excluded from the policy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_child_site_ids: Option<Vec<i64>>,
+}
+
+impl UpdateChildSiteManagementPolicyParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Type of policy
+    pub fn policy_type(mut self, policy_type: impl Into<String>) -> Self {
+        self.policy_type = Some(policy_type.into());
+        self
+    }
+
+    /// Name of the policy
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Description of the policy
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Policy configuration
+    pub fn value(mut self, value: serde_json::Value) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// IDs of child sites excluded from the policy
+    pub fn skip_child_site_ids(mut self, skip_child_site_ids: Vec<i64>) -> Self {
+        self.skip_child_site_ids = Some(skip_child_site_ids);
+        self
+    }
+}
+
+query_fields! {
+    /// Fields that child site management policies can be sorted and filtered by
+    pub enum ChildSiteManagementPolicyField {
+        SiteId => "site_id",
+        PolicyType => "policy_type",
+        CreatedAt => "created_at",
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct ChildSiteManagementPolicyHandler {
     client: FilesClient,
 }
@@ -20,6 +192,7 @@ impl ChildSiteManagementPolicyHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<ChildSiteManagementPolicyField>>,
     ) -> Result<(Vec<ChildSiteManagementPolicyEntity>, PaginationInfo)> {
         let mut endpoint = "/child_site_management_policies".to_string();
         let mut query_params = Vec::new();
@@ -37,29 +210,29 @@ impl ChildSiteManagementPolicyHandler {
             endpoint.push_str(&query_params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
 
-        let entities: Vec<ChildSiteManagementPolicyEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let entities: Vec<ChildSiteManagementPolicyEntity> = serde_json::from_value(response)?;
         Ok((entities, pagination))
     }
 
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<ChildSiteManagementPolicyField>>,
+    ) -> Paginator<ChildSiteManagementPolicyEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
+
     pub async fn get(&self, id: i64) -> Result<ChildSiteManagementPolicyEntity> {
         let endpoint = format!("/child_site_management_policies/{}", id);
         let response = self.client.get_raw(&endpoint).await?;
@@ -68,7 +241,7 @@ impl ChildSiteManagementPolicyHandler {
 
     pub async fn create(
         &self,
-        params: serde_json::Value,
+        params: CreateChildSiteManagementPolicyParams,
     ) -> Result<ChildSiteManagementPolicyEntity> {
         let response = self
             .client
@@ -80,7 +253,7 @@ impl ChildSiteManagementPolicyHandler {
     pub async fn update(
         &self,
         id: i64,
-        params: serde_json::Value,
+        params: UpdateChildSiteManagementPolicyParams,
     ) -> Result<ChildSiteManagementPolicyEntity> {
         let endpoint = format!("/child_site_management_policies/{}", id);
         let response = self.client.patch_raw(&endpoint, params).await?;
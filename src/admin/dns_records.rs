@@ -1,12 +1,32 @@
-use crate::{Result, client::FilesClient};
+use crate::pagination::Paginator;
+use crate::{PaginationInfo, Result, client::FilesClient};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// DNS record entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DnsRecordEntity {
+    /// Unique label for the record
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// Domain name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+
+    /// DNS record type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rrtype: Option<String>,
+
+    /// DNS record value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+#[derive(Debug, Clone)]
 pub struct DnsRecordHandler {
     client: FilesClient,
 }
@@ -16,9 +36,37 @@ impl DnsRecordHandler {
         Self { client }
     }
 
-    pub async fn list(&self) -> Result<Vec<DnsRecordEntity>> {
-        let response = self.client.get_raw("/dns_records").await?;
+    pub async fn list(
+        &self,
+        cursor: Option<String>,
+        per_page: Option<i64>,
+    ) -> Result<(Vec<DnsRecordEntity>, PaginationInfo)> {
+        let mut endpoint = "/dns_records".to_string();
+        let mut params = Vec::new();
+
+        if let Some(c) = cursor {
+            params.push(format!("cursor={}", c));
+        }
+        if let Some(pp) = per_page {
+            params.push(format!("per_page={}", pp));
+        }
+
+        if !params.is_empty() {
+            endpoint.push('?');
+            endpoint.push_str(&params.join("&"));
+        }
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
         let entities: Vec<DnsRecordEntity> = serde_json::from_value(response)?;
-        Ok(entities)
+        Ok((entities, pagination))
+    }
+
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(&self, per_page: Option<i64>) -> Paginator<DnsRecordEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            async move { handler.list(cursor, per_page).await }
+        })
     }
 }
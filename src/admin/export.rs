@@ -0,0 +1,385 @@
+//! Running history and action notification exports to completion
+//!
+//! Files.com builds exports in the background. Running one by hand means
+//! creating it, polling its `status` until it is `ready` and then paging
+//! through its results. [`HistoryHandler::run_export`] and
+//! [`ActionNotificationExportHandler::run_export`] do all three and return
+//! the results as an [`ExportStream`].
+//!
+//! Polling starts at [`ExportOptions::poll_interval`] and backs off up to
+//! [`ExportOptions::max_poll_interval`]. An export that is not ready within
+//! [`ExportOptions::timeout`] ends the stream with
+//! [`FilesError::ExportTimedOut`]. A [`CancellationToken`] passed with
+//! [`ExportOptions::cancel_token`] stops the export at any point; the stream
+//! then ends with [`FilesError::Cancelled`].
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use files_sdk::admin::export::{CancellationToken, ExportOptions};
+//! use files_sdk::admin::{HistoryAction, HistoryQuery};
+//! use files_sdk::timestamp::timestamp;
+//! use files_sdk::{FilesClient, HistoryHandler};
+//! use futures::TryStreamExt;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = FilesClient::builder().api_key("your-api-key").build()?;
+//! let history = HistoryHandler::new(client);
+//!
+//! let cancel = CancellationToken::new();
+//! let query = HistoryQuery::new()
+//!     .start_at(timestamp("2024-06-01T00:00:00Z")?)
+//!     .end_at(timestamp("2024-06-02T00:00:00Z")?)
+//!     .action(HistoryAction::Login);
+//! let options = ExportOptions::default().cancel_token(cancel.clone());
+//!
+//! let mut results = history.run_export(query, options);
+//! while let Some(entry) = results.try_next().await? {
+//!     println!("{:?} {:?} {:?}", entry.created_at, entry.username, entry.action);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`HistoryHandler::run_export`]: crate::HistoryHandler::run_export
+//! [`ActionNotificationExportHandler::run_export`]: crate::ActionNotificationExportHandler::run_export
+
+use crate::pagination::Paginator;
+use crate::{FilesError, Result};
+use futures::future::BoxFuture;
+use futures::stream::{self, BoxStream};
+use futures::{Stream, StreamExt, TryStreamExt};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::time::Instant;
+
+pub use tokio_util::sync::CancellationToken;
+
+#[cfg(feature = "tracing")]
+use tracing::debug;
+
+/// Status of an export whose results are available
+const STATUS_READY: &str = "ready";
+
+/// Status of an export that could not be built
+const STATUS_FAILED: &str = "failed";
+
+/// Options for running an export
+#[derive(Debug, Clone)]
+pub struct ExportOptions {
+    poll_interval: Duration,
+    max_poll_interval: Duration,
+    timeout: Option<Duration>,
+    pub(crate) per_page: Option<i64>,
+    cancel: Option<CancellationToken>,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_secs(1),
+            max_poll_interval: Duration::from_secs(30),
+            timeout: None,
+            per_page: None,
+            cancel: None,
+        }
+    }
+}
+
+impl ExportOptions {
+    /// Sets the delay before the first status check (default: 1 second)
+    ///
+    /// The delay doubles after every check that finds the export still
+    /// building, up to [`max_poll_interval`](Self::max_poll_interval).
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Sets the longest delay between two status checks (default: 30 seconds)
+    pub fn max_poll_interval(mut self, interval: Duration) -> Self {
+        self.max_poll_interval = interval;
+        self
+    }
+
+    /// Sets how long to wait for the export to become ready (default: no limit)
+    ///
+    /// Giving up does not cancel the export on Files.com.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the number of results requested per page
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Stops the export when `token` is cancelled
+    ///
+    /// Cancelling only stops polling and paging on this side; Files.com
+    /// keeps building the export.
+    pub fn cancel_token(mut self, token: CancellationToken) -> Self {
+        self.cancel = Some(token);
+        self
+    }
+}
+
+/// Stream of the results of an export
+///
+/// Yields nothing until the export is ready. Errors creating, polling or
+/// paging the export end the stream.
+pub struct ExportStream<T> {
+    inner: BoxStream<'static, Result<T>>,
+}
+
+impl<T: Send + 'static> ExportStream<T> {
+    /// Creates, polls and pages an export
+    ///
+    /// `create` submits the export and returns its id, `status` fetches its
+    /// current status and `results` pages through the results of a ready export.
+    pub(crate) fn run<C, S, SFut, R>(
+        options: ExportOptions,
+        create: C,
+        status: S,
+        results: R,
+    ) -> Self
+    where
+        C: Future<Output = Result<i64>> + Send + 'static,
+        S: Fn(i64) -> SFut + Send + 'static,
+        SFut: Future<Output = Result<Option<String>>> + Send + 'static,
+        R: FnOnce(i64) -> Paginator<T> + Send + 'static,
+    {
+        let cancel = options.cancel.clone();
+        let ready: BoxFuture<'static, Result<Paginator<T>>> = Box::pin(async move {
+            let id = create.await?;
+            wait_until_ready(id, &options, status).await?;
+            Ok(results(id))
+        });
+
+        let inner = stream::unfold(
+            (State::Building(ready), cancel),
+            |(state, cancel)| async move {
+                match state {
+                    State::Building(ready) => {
+                        let paginator = tokio::select! {
+                            biased;
+                            _ = cancelled(&cancel) => Err(FilesError::Cancelled),
+                            paginator = ready => paginator,
+                        };
+                        match paginator {
+                            Ok(paginator) => next_result(paginator, cancel).await,
+                            Err(e) => Some((Err(e), (State::Done, cancel))),
+                        }
+                    }
+                    State::Streaming(paginator) => next_result(paginator, cancel).await,
+                    State::Done => None,
+                }
+            },
+        );
+
+        Self {
+            inner: inner.boxed(),
+        }
+    }
+
+    /// Collects all results of the export into a vector
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered, including
+    /// [`FilesError::Cancelled`] when the export was cancelled
+    pub async fn collect_all(self) -> Result<Vec<T>> {
+        self.try_collect().await
+    }
+}
+
+impl<T> Stream for ExportStream<T> {
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
+    }
+}
+
+enum State<T> {
+    Building(BoxFuture<'static, Result<Paginator<T>>>),
+    Streaming(Paginator<T>),
+    Done,
+}
+
+type Step<T> = Option<(Result<T>, (State<T>, Option<CancellationToken>))>;
+
+/// Yields the next result of a ready export
+async fn next_result<T: Send + 'static>(
+    mut paginator: Paginator<T>,
+    cancel: Option<CancellationToken>,
+) -> Step<T> {
+    let next = tokio::select! {
+        biased;
+        _ = cancelled(&cancel) => Some(Err(FilesError::Cancelled)),
+        next = paginator.next() => next,
+    };
+    match next {
+        Some(Ok(item)) => Some((Ok(item), (State::Streaming(paginator), cancel))),
+        Some(Err(e)) => Some((Err(e), (State::Done, cancel))),
+        None => None,
+    }
+}
+
+/// Polls the status of an export with backoff until it is ready
+async fn wait_until_ready<S, SFut>(id: i64, options: &ExportOptions, status: S) -> Result<()>
+where
+    S: Fn(i64) -> SFut,
+    SFut: Future<Output = Result<Option<String>>>,
+{
+    let started = Instant::now();
+    let mut interval = options.poll_interval;
+    loop {
+        let current = status(id).await?;
+
+        #[cfg(feature = "tracing")]
+        debug!(export_id = id, status = ?current, "Polled export");
+
+        match current.as_deref() {
+            Some(STATUS_READY) => return Ok(()),
+            Some(STATUS_FAILED) => {
+                return Err(FilesError::ExportFailed {
+                    export_id: id,
+                    status: STATUS_FAILED.to_string(),
+                });
+            }
+            _ => {}
+        }
+
+        let mut wait = interval;
+        if let Some(timeout) = options.timeout {
+            let elapsed = started.elapsed();
+            if elapsed >= timeout {
+                return Err(FilesError::ExportTimedOut {
+                    export_id: id,
+                    elapsed,
+                });
+            }
+            wait = wait.min(timeout - elapsed);
+        }
+        tokio::time::sleep(wait).await;
+        interval = (interval * 2).min(options.max_poll_interval);
+    }
+}
+
+/// Id of a created export, which Files.com always returns
+pub(crate) fn created_id(id: Option<i64>, endpoint: &str) -> Result<i64> {
+    id.ok_or_else(|| FilesError::ApiError {
+        code: 500,
+        message: "Created export has no id".to_string(),
+        endpoint: Some(endpoint.to_string()),
+        detail: None,
+    })
+}
+
+/// Completes when the token is cancelled, or never without a token
+async fn cancelled(token: &Option<CancellationToken>) {
+    match token {
+        Some(token) => token.cancelled().await,
+        None => std::future::pending().await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PaginationInfo;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn results(items: Vec<i64>) -> impl FnOnce(i64) -> Paginator<i64> + Send + 'static {
+        move |_| {
+            Paginator::new(move |_| {
+                let items = items.clone();
+                async move { Ok((items, PaginationInfo::default())) }
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_polls_until_ready_then_streams_results() {
+        let polls = Arc::new(AtomicUsize::new(0));
+        let counter = polls.clone();
+        let options = ExportOptions::default().poll_interval(Duration::from_millis(1));
+
+        let stream = ExportStream::run(
+            options,
+            async { Ok(7) },
+            move |id| {
+                assert_eq!(id, 7);
+                let n = counter.fetch_add(1, Ordering::SeqCst);
+                async move { Ok(Some(if n < 2 { "building" } else { "ready" }.to_string())) }
+            },
+            results(vec![1, 2, 3]),
+        );
+
+        assert_eq!(stream.collect_all().await.unwrap(), vec![1, 2, 3]);
+        assert_eq!(polls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_failed_export() {
+        let stream = ExportStream::run(
+            ExportOptions::default(),
+            async { Ok(7) },
+            |_| async { Ok(Some("failed".to_string())) },
+            results(vec![1]),
+        );
+
+        assert!(matches!(
+            stream.collect_all().await,
+            Err(FilesError::ExportFailed { export_id: 7, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_timeout_while_building() {
+        let options = ExportOptions::default()
+            .poll_interval(Duration::from_millis(10))
+            .timeout(Duration::from_millis(50));
+
+        let stream = ExportStream::run(
+            options,
+            async { Ok(7) },
+            |_| async { Ok(Some("building".to_string())) },
+            results(vec![1]),
+        );
+
+        match stream.collect_all().await {
+            Err(FilesError::ExportTimedOut { export_id, elapsed }) => {
+                assert_eq!(export_id, 7);
+                assert!(elapsed >= Duration::from_millis(50));
+            }
+            other => panic!("Expected ExportTimedOut, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cancel_while_building() {
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        let options = ExportOptions::default().cancel_token(cancel);
+
+        let stream = ExportStream::run(
+            options,
+            async { Ok(7) },
+            |_| async { Ok(Some("building".to_string())) },
+            results(vec![1]),
+        );
+
+        assert!(matches!(
+            stream.collect_all().await,
+            Err(FilesError::Cancelled)
+        ));
+    }
+}
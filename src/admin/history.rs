@@ -3,10 +3,79 @@
 //! History represents activity logs and audit trails. History queries must be
 //! exported for processing.
 
-use crate::{FilesClient, PaginationInfo, Result};
+use crate::admin::export::{ExportOptions, ExportStream, created_id};
+use crate::admin::history_query::{HistoryAction, HistoryInterface, HistoryQuery};
+use crate::admin::{HistoryExportResultEntity2, HistoryExportResultHandler2};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{FilesClient, PaginationInfo, Result, Timestamp};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
+/// An action recorded in history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionEntity {
+    /// Action ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Path of the file or folder acted on
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// When the action happened (`when` in the API)
+    #[serde(rename = "when", skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+
+    /// Destination path for moves and copies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<String>,
+
+    /// Friendly description of the action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display: Option<String>,
+
+    /// IP address the action was performed from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+
+    /// Source path for moves and copies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+
+    /// Objects affected by the action, such as permissions or API keys
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub targets: Option<Vec<serde_json::Value>>,
+
+    /// ID of the user who performed the action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
+
+    /// Username of the user who performed the action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    /// Whether the user is from the parent site
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_is_from_parent_site: Option<bool>,
+
+    /// Type of action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<HistoryAction>,
+
+    /// Reason for a failed login
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_type: Option<String>,
+
+    /// Interface the action was performed through
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface: Option<HistoryInterface>,
+
+    /// Fields not covered above, kept for forward compatibility
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
 /// A History Export entity
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HistoryExportEntity {
@@ -20,11 +89,11 @@ pub struct HistoryExportEntity {
 
     /// Start date/time of export range
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_at: Option<String>,
+    pub start_at: Option<Timestamp>,
 
     /// End date/time of export range
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_at: Option<String>,
+    pub end_at: Option<Timestamp>,
 
     /// Status (building, ready, failed)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -123,7 +192,19 @@ pub struct HistoryExportResultEntity {
     pub result: Option<serde_json::Value>,
 }
 
+query_fields! {
+    /// Fields that history entries can be sorted and filtered by
+    pub enum HistoryField {
+        Path => "path",
+        Folder => "folder",
+        UserId => "user_id",
+        Action => "action",
+        CreatedAt => "created_at",
+    }
+}
+
 /// Handler for history operations
+#[derive(Debug, Clone)]
 pub struct HistoryHandler {
     client: FilesClient,
 }
@@ -140,6 +221,7 @@ impl HistoryHandler {
     /// * `path` - File path
     /// * `cursor` - Pagination cursor
     /// * `per_page` - Results per page
+    /// * `query` - Sorting and filtering options (optional)
     ///
     /// # Returns
     /// Tuple of (history_items, pagination_info)
@@ -148,7 +230,8 @@ impl HistoryHandler {
         path: &str,
         cursor: Option<&str>,
         per_page: Option<i64>,
-    ) -> Result<(Vec<serde_json::Value>, PaginationInfo)> {
+        query: Option<&ListQuery<HistoryField>>,
+    ) -> Result<(Vec<ActionEntity>, PaginationInfo)> {
         let mut params = vec![];
         if let Some(c) = cursor {
             params.push(("cursor", c.to_string()));
@@ -157,7 +240,7 @@ impl HistoryHandler {
             params.push(("per_page", pp.to_string()));
         }
 
-        let query = if params.is_empty() {
+        let query_string = if params.is_empty() {
             String::new()
         } else {
             format!(
@@ -170,32 +253,54 @@ impl HistoryHandler {
             )
         };
 
-        let response = self
-            .client
-            .get_raw(&format!("/history/files/{}{}", path, query))
-            .await?;
-        let history: Vec<serde_json::Value> = serde_json::from_value(response)?;
+        let mut endpoint = format!("/history/files/{}{}", path, query_string);
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
 
-        let pagination = PaginationInfo {
-            cursor_next: None,
-            cursor_prev: None,
-        };
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let history: Vec<ActionEntity> = serde_json::from_value(response)?;
 
         Ok((history, pagination))
     }
 
+    /// Stream history for a specific file path across all pages
+    ///
+    /// Takes the same arguments as [`list_for_file`](Self::list_for_file), without the cursor.
+    pub fn list_for_file_stream(
+        &self,
+        path: &str,
+        per_page: Option<i64>,
+        query: Option<ListQuery<HistoryField>>,
+    ) -> Paginator<ActionEntity> {
+        let handler = self.clone();
+        let path = path.to_string();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            let path = path.clone();
+            async move {
+                handler
+                    .list_for_file(&path, cursor.as_deref(), per_page, query.as_ref())
+                    .await
+            }
+        })
+    }
+
     /// List history for a specific folder path
     ///
     /// # Arguments
     /// * `path` - Folder path
     /// * `cursor` - Pagination cursor
     /// * `per_page` - Results per page
+    /// * `query` - Sorting and filtering options (optional)
     pub async fn list_for_folder(
         &self,
         path: &str,
         cursor: Option<&str>,
         per_page: Option<i64>,
-    ) -> Result<(Vec<serde_json::Value>, PaginationInfo)> {
+        query: Option<&ListQuery<HistoryField>>,
+    ) -> Result<(Vec<ActionEntity>, PaginationInfo)> {
         let mut params = vec![];
         if let Some(c) = cursor {
             params.push(("cursor", c.to_string()));
@@ -204,7 +309,7 @@ impl HistoryHandler {
             params.push(("per_page", pp.to_string()));
         }
 
-        let query = if params.is_empty() {
+        let query_string = if params.is_empty() {
             String::new()
         } else {
             format!(
@@ -217,32 +322,54 @@ impl HistoryHandler {
             )
         };
 
-        let response = self
-            .client
-            .get_raw(&format!("/history/folders/{}{}", path, query))
-            .await?;
-        let history: Vec<serde_json::Value> = serde_json::from_value(response)?;
+        let mut endpoint = format!("/history/folders/{}{}", path, query_string);
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
 
-        let pagination = PaginationInfo {
-            cursor_next: None,
-            cursor_prev: None,
-        };
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let history: Vec<ActionEntity> = serde_json::from_value(response)?;
 
         Ok((history, pagination))
     }
 
+    /// Stream history for a specific folder path across all pages
+    ///
+    /// Takes the same arguments as [`list_for_folder`](Self::list_for_folder), without the cursor.
+    pub fn list_for_folder_stream(
+        &self,
+        path: &str,
+        per_page: Option<i64>,
+        query: Option<ListQuery<HistoryField>>,
+    ) -> Paginator<ActionEntity> {
+        let handler = self.clone();
+        let path = path.to_string();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            let path = path.clone();
+            async move {
+                handler
+                    .list_for_folder(&path, cursor.as_deref(), per_page, query.as_ref())
+                    .await
+            }
+        })
+    }
+
     /// List history for a specific user
     ///
     /// # Arguments
     /// * `user_id` - User ID
     /// * `cursor` - Pagination cursor
     /// * `per_page` - Results per page
+    /// * `query` - Sorting and filtering options (optional)
     pub async fn list_for_user(
         &self,
         user_id: i64,
         cursor: Option<&str>,
         per_page: Option<i64>,
-    ) -> Result<(Vec<serde_json::Value>, PaginationInfo)> {
+        query: Option<&ListQuery<HistoryField>>,
+    ) -> Result<(Vec<ActionEntity>, PaginationInfo)> {
         let mut params = vec![];
         if let Some(c) = cursor {
             params.push(("cursor", c.to_string()));
@@ -251,7 +378,7 @@ impl HistoryHandler {
             params.push(("per_page", pp.to_string()));
         }
 
-        let query = if params.is_empty() {
+        let query_string = if params.is_empty() {
             String::new()
         } else {
             format!(
@@ -264,30 +391,50 @@ impl HistoryHandler {
             )
         };
 
-        let response = self
-            .client
-            .get_raw(&format!("/history/users/{}{}", user_id, query))
-            .await?;
-        let history: Vec<serde_json::Value> = serde_json::from_value(response)?;
+        let mut endpoint = format!("/history/users/{}{}", user_id, query_string);
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
 
-        let pagination = PaginationInfo {
-            cursor_next: None,
-            cursor_prev: None,
-        };
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let history: Vec<ActionEntity> = serde_json::from_value(response)?;
 
         Ok((history, pagination))
     }
 
+    /// Stream history for a specific user across all pages
+    ///
+    /// Takes the same arguments as [`list_for_user`](Self::list_for_user), without the cursor.
+    pub fn list_for_user_stream(
+        &self,
+        user_id: i64,
+        per_page: Option<i64>,
+        query: Option<ListQuery<HistoryField>>,
+    ) -> Paginator<ActionEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move {
+                handler
+                    .list_for_user(user_id, cursor.as_deref(), per_page, query.as_ref())
+                    .await
+            }
+        })
+    }
+
     /// List login history
     ///
     /// # Arguments
     /// * `cursor` - Pagination cursor
     /// * `per_page` - Results per page
+    /// * `query` - Sorting and filtering options (optional)
     pub async fn list_logins(
         &self,
         cursor: Option<&str>,
         per_page: Option<i64>,
-    ) -> Result<(Vec<serde_json::Value>, PaginationInfo)> {
+        query: Option<&ListQuery<HistoryField>>,
+    ) -> Result<(Vec<ActionEntity>, PaginationInfo)> {
         let mut params = vec![];
         if let Some(c) = cursor {
             params.push(("cursor", c.to_string()));
@@ -296,7 +443,7 @@ impl HistoryHandler {
             params.push(("per_page", pp.to_string()));
         }
 
-        let query = if params.is_empty() {
+        let query_string = if params.is_empty() {
             String::new()
         } else {
             format!(
@@ -309,22 +456,42 @@ impl HistoryHandler {
             )
         };
 
-        let response = self
-            .client
-            .get_raw(&format!("/history/login{}", query))
-            .await?;
-        let history: Vec<serde_json::Value> = serde_json::from_value(response)?;
+        let mut endpoint = format!("/history/login{}", query_string);
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
 
-        let pagination = PaginationInfo {
-            cursor_next: None,
-            cursor_prev: None,
-        };
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let history: Vec<ActionEntity> = serde_json::from_value(response)?;
 
         Ok((history, pagination))
     }
 
+    /// Stream login history across all pages
+    ///
+    /// Takes the same arguments as [`list_logins`](Self::list_logins), without the cursor.
+    pub fn list_logins_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<HistoryField>>,
+    ) -> Paginator<ActionEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move {
+                handler
+                    .list_logins(cursor.as_deref(), per_page, query.as_ref())
+                    .await
+            }
+        })
+    }
+
     /// Create a history export
     ///
+    /// Only covers the most common filters; see
+    /// [`create_export_with_query`](Self::create_export_with_query) for all of them.
+    ///
     /// # Arguments
     /// * `start_at` - Start date/time
     /// * `end_at` - End date/time
@@ -337,8 +504,8 @@ impl HistoryHandler {
     #[allow(clippy::too_many_arguments)]
     pub async fn create_export(
         &self,
-        start_at: Option<&str>,
-        end_at: Option<&str>,
+        start_at: Option<Timestamp>,
+        end_at: Option<Timestamp>,
         query_action: Option<&str>,
         query_user_id: Option<&str>,
         query_folder: Option<&str>,
@@ -368,6 +535,21 @@ impl HistoryHandler {
         Ok(serde_json::from_value(response)?)
     }
 
+    /// Create a history export filtered by a [`HistoryQuery`]
+    ///
+    /// # Arguments
+    /// * `query` - Date range and filters of the export
+    ///
+    /// # Returns
+    /// The created history export
+    pub async fn create_export_with_query(
+        &self,
+        query: &HistoryQuery,
+    ) -> Result<HistoryExportEntity> {
+        let response = self.client.post_raw("/history_exports", query).await?;
+        Ok(serde_json::from_value(response)?)
+    }
+
     /// Get a history export by ID
     ///
     /// # Arguments
@@ -416,19 +598,66 @@ impl HistoryHandler {
             )
         };
 
-        let response = self
+        let (response, pagination) = self
             .client
-            .get_raw(&format!("/history_export_results{}", query))
+            .get_paginated(&format!("/history_export_results{}", query))
             .await?;
         let results: Vec<HistoryExportResultEntity> = serde_json::from_value(response)?;
 
-        let pagination = PaginationInfo {
-            cursor_next: None,
-            cursor_prev: None,
-        };
-
         Ok((results, pagination))
     }
+
+    /// Stream history export results across all pages
+    ///
+    /// Takes the same arguments as [`get_export_results`](Self::get_export_results), without the cursor.
+    pub fn get_export_results_stream(
+        &self,
+        per_page: Option<i64>,
+        history_export_id: Option<i64>,
+    ) -> Paginator<HistoryExportResultEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            async move {
+                handler
+                    .get_export_results(cursor.as_deref(), per_page, history_export_id)
+                    .await
+            }
+        })
+    }
+
+    /// Create a history export, wait until it is ready and stream its results
+    ///
+    /// Replaces calling [`create_export`](Self::create_export), polling
+    /// [`get_export`](Self::get_export) and paging the results by hand. See
+    /// [`export`](crate::admin::export) for polling and cancellation.
+    ///
+    /// # Arguments
+    /// * `query` - Date range and filters of the export
+    /// * `options` - Polling, page size and cancellation options
+    pub fn run_export(
+        &self,
+        query: HistoryQuery,
+        options: ExportOptions,
+    ) -> ExportStream<HistoryExportResultEntity2> {
+        let creator = self.clone();
+        let poller = self.clone();
+        let results = HistoryExportResultHandler2::new(self.client.clone());
+        let per_page = options.per_page;
+
+        ExportStream::run(
+            options,
+            async move {
+                let export = creator.create_export_with_query(&query).await?;
+                created_id(export.id, "/history_exports")
+            },
+            move |id| {
+                let poller = poller.clone();
+                async move { Ok(poller.get_export(id).await?.status) }
+            },
+            move |id| results.list_stream(id, per_page),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -1,12 +1,116 @@
-use crate::{Result, client::FilesClient};
+use crate::pagination::Paginator;
+use crate::{PaginationInfo, Result, Timestamp, client::FilesClient};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// History export result entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HistoryExportResultEntity2 {
+    /// Action ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// When the action happened (Unix timestamp)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<i64>,
+
+    /// When the action happened (ISO 8601)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at_iso8601: Option<Timestamp>,
+
+    /// User ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
+
+    /// File ID related to the action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_id: Option<i64>,
+
+    /// ID of the parent folder
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<i64>,
+
+    /// Path of the related file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// Folder of the related file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub folder: Option<String>,
+
+    /// Source path for move and copy actions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src: Option<String>,
+
+    /// Destination path for move and copy actions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<String>,
+
+    /// Client IP address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+
+    /// Username
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    /// Whether the user is from the parent site
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_is_from_parent_site: Option<bool>,
+
+    /// Type of action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+
+    /// Reason for a failed login
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_type: Option<String>,
+
+    /// Interface the action was performed through
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface: Option<String>,
+
+    /// ID of the affected object
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_id: Option<i64>,
+
+    /// Name of the affected object
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_name: Option<String>,
+
+    /// Permission level of the affected object
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_permission: Option<String>,
+
+    /// Whether the permission is recursive
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_recursive: Option<bool>,
+
+    /// Expiration of the affected object (Unix timestamp)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_expires_at: Option<i64>,
+
+    /// Permission set of the affected API key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_permission_set: Option<String>,
+
+    /// Platform of the affected API key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_platform: Option<String>,
+
+    /// Username of the affected user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_username: Option<String>,
+
+    /// ID of the affected user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_user_id: Option<i64>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+#[derive(Debug, Clone)]
 pub struct HistoryExportResultHandler2 {
     client: FilesClient,
 }
@@ -21,4 +125,40 @@ impl HistoryExportResultHandler2 {
         let response = self.client.get_raw(&endpoint).await?;
         Ok(serde_json::from_value(response)?)
     }
+
+    /// List the results of a history export
+    pub async fn list(
+        &self,
+        history_export_id: i64,
+        cursor: Option<String>,
+        per_page: Option<i64>,
+    ) -> Result<(Vec<HistoryExportResultEntity2>, PaginationInfo)> {
+        let mut endpoint = format!(
+            "/history_export_results?history_export_id={}",
+            history_export_id
+        );
+        if let Some(c) = cursor {
+            endpoint.push_str(&format!("&cursor={}", c));
+        }
+        if let Some(pp) = per_page {
+            endpoint.push_str(&format!("&per_page={}", pp));
+        }
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let results: Vec<HistoryExportResultEntity2> = serde_json::from_value(response)?;
+        Ok((results, pagination))
+    }
+
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        history_export_id: i64,
+        per_page: Option<i64>,
+    ) -> Paginator<HistoryExportResultEntity2> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            async move { handler.list(history_export_id, cursor, per_page).await }
+        })
+    }
 }
@@ -1,10 +1,346 @@
-use crate::{Result, client::FilesClient};
+use crate::{Result, Timestamp, client::FilesClient};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// History export entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HistoryExportEntity2 {
+    /// History export ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Version of the underlying records for the export
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub history_version: Option<String>,
+
+    /// Start of the date range for the export
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_at: Option<Timestamp>,
+
+    /// End of the date range for the export
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_at: Option<Timestamp>,
+
+    /// Status of the export
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// Filter on action type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_action: Option<String>,
+
+    /// Filter on interface
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_interface: Option<String>,
+
+    /// Filter on user ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_user_id: Option<String>,
+
+    /// Filter on file ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_file_id: Option<String>,
+
+    /// Filter on parent folder ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_parent_id: Option<String>,
+
+    /// Filter on path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_path: Option<String>,
+
+    /// Filter on folder
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_folder: Option<String>,
+
+    /// Filter on move or copy source path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_src: Option<String>,
+
+    /// Filter on move or copy destination path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_destination: Option<String>,
+
+    /// Filter on IP address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_ip: Option<String>,
+
+    /// Filter on username
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_username: Option<String>,
+
+    /// Filter on login failure type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_failure_type: Option<String>,
+
+    /// Filter on target object ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_target_id: Option<String>,
+
+    /// Filter on target object name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_target_name: Option<String>,
+
+    /// Filter on target permission level
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_target_permission: Option<String>,
+
+    /// Filter on target user ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_target_user_id: Option<String>,
+
+    /// Filter on target username
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_target_username: Option<String>,
+
+    /// Filter on target API key platform
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_target_platform: Option<String>,
+
+    /// Filter on target API key permission set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_target_permission_set: Option<String>,
+
+    /// Download URL of the export results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results_url: Option<String>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parameters for creating a history export
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateHistoryExportParams {
+    /// User ID, when acting on behalf of another user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
+
+    /// Start of the date range for the export
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_at: Option<Timestamp>,
+
+    /// End of the date range for the export
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_at: Option<Timestamp>,
+
+    /// Filter on action type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_action: Option<String>,
+
+    /// Filter on interface
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_interface: Option<String>,
+
+    /// Filter on user ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_user_id: Option<String>,
+
+    /// Filter on file ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_file_id: Option<String>,
+
+    /// Filter on parent folder ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_parent_id: Option<String>,
+
+    /// Filter on path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_path: Option<String>,
+
+    /// Filter on folder
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_folder: Option<String>,
+
+    /// Filter on move or copy source path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_src: Option<String>,
+
+    /// Filter on move or copy destination path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_destination: Option<String>,
+
+    /// Filter on IP address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_ip: Option<String>,
+
+    /// Filter on username
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_username: Option<String>,
+
+    /// Filter on login failure type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_failure_type: Option<String>,
+
+    /// Filter on target object ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_target_id: Option<String>,
+
+    /// Filter on target object name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_target_name: Option<String>,
+
+    /// Filter on target permission level
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_target_permission: Option<String>,
+
+    /// Filter on target user ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_target_user_id: Option<String>,
+
+    /// Filter on target username
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_target_username: Option<String>,
+
+    /// Filter on target API key platform
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_target_platform: Option<String>,
+
+    /// Filter on target API key permission set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_target_permission_set: Option<String>,
+}
+
+impl CreateHistoryExportParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// User ID, when acting on behalf of another user
+    pub fn user_id(mut self, user_id: i64) -> Self {
+        self.user_id = Some(user_id);
+        self
+    }
+
+    /// Start of the date range for the export
+    pub fn start_at(mut self, start_at: impl Into<Timestamp>) -> Self {
+        self.start_at = Some(start_at.into());
+        self
+    }
+
+    /// End of the date range for the export
+    pub fn end_at(mut self, end_at: impl Into<Timestamp>) -> Self {
+        self.end_at = Some(end_at.into());
+        self
+    }
+
+    /// Filter on action type
+    pub fn query_action(mut self, query_action: impl Into<String>) -> Self {
+        self.query_action = Some(query_action.into());
+        self
+    }
+
+    /// Filter on interface
+    pub fn query_interface(mut self, query_interface: impl Into<String>) -> Self {
+        self.query_interface = Some(query_interface.into());
+        self
+    }
+
+    /// Filter on user ID
+    pub fn query_user_id(mut self, query_user_id: impl Into<String>) -> Self {
+        self.query_user_id = Some(query_user_id.into());
+        self
+    }
+
+    /// Filter on file ID
+    pub fn query_file_id(mut self, query_file_id: impl Into<String>) -> Self {
+        self.query_file_id = Some(query_file_id.into());
+        self
+    }
+
+    /// Filter on parent folder ID
+    pub fn query_parent_id(mut self, query_parent_id: impl Into<String>) -> Self {
+        self.query_parent_id = Some(query_parent_id.into());
+        self
+    }
+
+    /// Filter on path
+    pub fn query_path(mut self, query_path: impl Into<String>) -> Self {
+        self.query_path = Some(query_path.into());
+        self
+    }
+
+    /// Filter on folder
+    pub fn query_folder(mut self, query_folder: impl Into<String>) -> Self {
+        self.query_folder = Some(query_folder.into());
+        self
+    }
+
+    /// Filter on move or copy source path
+    pub fn query_src(mut self, query_src: impl Into<String>) -> Self {
+        self.query_src = Some(query_src.into());
+        self
+    }
+
+    /// Filter on move or copy destination path
+    pub fn query_destination(mut self, query_destination: impl Into<String>) -> Self {
+        self.query_destination = Some(query_destination.into());
+        self
+    }
+
+    /// Filter on IP address
+    pub fn query_ip(mut self, query_ip: impl Into<String>) -> Self {
+        self.query_ip = Some(query_ip.into());
+        self
+    }
+
+    /// Filter on username
+    pub fn query_username(mut self, query_username: impl Into<String>) -> Self {
+        self.query_username = Some(query_username.into());
+        self
+    }
+
+    /// Filter on login failure type
+    pub fn query_failure_type(mut self, query_failure_type: impl Into<String>) -> Self {
+        self.query_failure_type = Some(query_failure_type.into());
+        self
+    }
+
+    /// Filter on target object ID
+    pub fn query_target_id(mut self, query_target_id: impl Into<String>) -> Self {
+        self.query_target_id = Some(query_target_id.into());
+        self
+    }
+
+    /// Filter on target object name
+    pub fn query_target_name(mut self, query_target_name: impl Into<String>) -> Self {
+        self.query_target_name = Some(query_target_name.into());
+        self
+    }
+
+    /// Filter on target permission level
+    pub fn query_target_permission(mut self, query_target_permission: impl Into<String>) -> Self {
+        self.query_target_permission = Some(query_target_permission.into());
+        self
+    }
+
+    /// Filter on target user ID
+    pub fn query_target_user_id(mut self, query_target_user_id: impl Into<String>) -> Self {
+        self.query_target_user_id = Some(query_target_user_id.into());
+        self
+    }
+
+    /// Filter on target username
+    pub fn query_target_username(mut self, query_target_username: impl Into<String>) -> Self {
+        self.query_target_username = Some(query_target_username.into());
+        self
+    }
+
+    /// Filter on target API key platform
+    pub fn query_target_platform(mut self, query_target_platform: impl Into<String>) -> Self {
+        self.query_target_platform = Some(query_target_platform.into());
+        self
+    }
+
+    /// Filter on target API key permission set
+    pub fn query_target_permission_set(
+        mut self,
+        query_target_permission_set: impl Into<String>,
+    ) -> Self {
+        self.query_target_permission_set = Some(query_target_permission_set.into());
+        self
+    }
 }
 
 pub struct HistoryExportHandler2 {
@@ -22,7 +358,7 @@ impl HistoryExportHandler2 {
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn create(&self, params: serde_json::Value) -> Result<HistoryExportEntity2> {
+    pub async fn create(&self, params: CreateHistoryExportParams) -> Result<HistoryExportEntity2> {
         let response = self.client.post_raw("/history_exports", params).await?;
         Ok(serde_json::from_value(response)?)
     }
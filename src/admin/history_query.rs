@@ -0,0 +1,350 @@
+//! Typed filters for history exports
+//!
+//! [`HistoryQuery`] covers every filter a history export accepts. Actions
+//! and interfaces are the [`HistoryAction`] and [`HistoryInterface`] enums,
+//! and the date range takes [`Timestamp`]s.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use files_sdk::admin::{HistoryAction, HistoryInterface, HistoryQuery};
+//! use files_sdk::timestamp::timestamp;
+//! use files_sdk::{FilesClient, HistoryHandler};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = FilesClient::builder().api_key("your-api-key").build()?;
+//!
+//! // Failed SFTP logins from one address during an incident
+//! let query = HistoryQuery::new()
+//!     .start_at(timestamp("2024-06-01T00:00:00Z")?)
+//!     .end_at(timestamp("2024-06-02T00:00:00Z")?)
+//!     .action(HistoryAction::FailedLogin)
+//!     .interface(HistoryInterface::Sftp)
+//!     .ip("203.0.113.7");
+//!
+//! let export = HistoryHandler::new(client).create_export_with_query(&query).await?;
+//! println!("Export {:?} is {:?}", export.id, export.status);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::Timestamp;
+use crate::string_enum::string_enum;
+use serde::Serialize;
+
+string_enum! {
+    /// Type of action recorded in history
+    pub enum HistoryAction {
+        /// File or folder created
+        Create => "create",
+        /// File downloaded or viewed
+        Read => "read",
+        /// File or folder updated
+        Update => "update",
+        /// File or folder deleted
+        Destroy => "destroy",
+        /// File or folder moved
+        Move => "move",
+        /// File or folder copied
+        Copy => "copy",
+        /// Successful login
+        Login => "login",
+        /// Failed login
+        FailedLogin => "failedlogin",
+        /// User created
+        UserCreate => "user_create",
+        /// User updated
+        UserUpdate => "user_update",
+        /// User deleted
+        UserDestroy => "user_destroy",
+        /// Group created
+        GroupCreate => "group_create",
+        /// Group updated
+        GroupUpdate => "group_update",
+        /// Group deleted
+        GroupDestroy => "group_destroy",
+        /// Permission granted
+        PermissionCreate => "permission_create",
+        /// Permission revoked
+        PermissionDestroy => "permission_destroy",
+        /// API key created
+        ApiKeyCreate => "api_key_create",
+        /// API key updated
+        ApiKeyUpdate => "api_key_update",
+        /// API key deleted
+        ApiKeyDestroy => "api_key_destroy",
+    }
+}
+
+string_enum! {
+    /// Interface an action was performed through
+    pub enum HistoryInterface {
+        /// Web interface
+        Web => "web",
+        /// FTP
+        Ftp => "ftp",
+        /// Files.com automations and other internal processes
+        Robot => "robot",
+        /// JavaScript API
+        JsApi => "jsapi",
+        /// Desktop app through the web API
+        WebDesktopApi => "webdesktopapi",
+        /// SFTP
+        Sftp => "sftp",
+        /// WebDAV
+        Dav => "dav",
+        /// Desktop app
+        Desktop => "desktop",
+        /// REST API
+        RestApi => "restapi",
+        /// SCIM provisioning
+        Scim => "scim",
+        /// Office integration
+        Office => "office",
+        /// Mobile app
+        Mobile => "mobile",
+        /// AS2
+        As2 => "as2",
+        /// Inbound email
+        InboundEmail => "inbound_email",
+        /// Remote server sync
+        Remote => "remote",
+    }
+}
+
+/// Filters for a history export
+///
+/// Every filter is optional; an empty query exports all history Files.com
+/// retains.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct HistoryQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_at: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_at: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_action: Option<HistoryAction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_interface: Option<HistoryInterface>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_file_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_parent_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_folder: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_src: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_destination: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_ip: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_failure_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_target_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_target_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_target_permission: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_target_user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_target_username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_target_platform: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_target_permission_set: Option<String>,
+}
+
+impl HistoryQuery {
+    /// Creates a query without filters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only include actions at or after `start_at`
+    pub fn start_at(mut self, start_at: impl Into<Timestamp>) -> Self {
+        self.start_at = Some(start_at.into());
+        self
+    }
+
+    /// Only include actions before `end_at`
+    pub fn end_at(mut self, end_at: impl Into<Timestamp>) -> Self {
+        self.end_at = Some(end_at.into());
+        self
+    }
+
+    /// Only include actions of this type
+    pub fn action(mut self, action: HistoryAction) -> Self {
+        self.query_action = Some(action);
+        self
+    }
+
+    /// Only include actions performed through this interface
+    pub fn interface(mut self, interface: HistoryInterface) -> Self {
+        self.query_interface = Some(interface);
+        self
+    }
+
+    /// Only include actions performed by this user
+    pub fn user_id(mut self, user_id: i64) -> Self {
+        self.query_user_id = Some(user_id.to_string());
+        self
+    }
+
+    /// Only include actions on this file
+    pub fn file_id(mut self, file_id: i64) -> Self {
+        self.query_file_id = Some(file_id.to_string());
+        self
+    }
+
+    /// Only include actions in this parent folder
+    pub fn parent_id(mut self, parent_id: i64) -> Self {
+        self.query_parent_id = Some(parent_id.to_string());
+        self
+    }
+
+    /// Only include actions on this path
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.query_path = Some(path.into());
+        self
+    }
+
+    /// Only include actions in this folder
+    pub fn folder(mut self, folder: impl Into<String>) -> Self {
+        self.query_folder = Some(folder.into());
+        self
+    }
+
+    /// Only include moves and copies from this path
+    pub fn src(mut self, src: impl Into<String>) -> Self {
+        self.query_src = Some(src.into());
+        self
+    }
+
+    /// Only include moves and copies to this path
+    pub fn destination(mut self, destination: impl Into<String>) -> Self {
+        self.query_destination = Some(destination.into());
+        self
+    }
+
+    /// Only include actions from this IP address
+    pub fn ip(mut self, ip: impl Into<String>) -> Self {
+        self.query_ip = Some(ip.into());
+        self
+    }
+
+    /// Only include actions performed by this username
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.query_username = Some(username.into());
+        self
+    }
+
+    /// Only include failed logins with this failure type (e.g. `invalid_credentials`)
+    pub fn failure_type(mut self, failure_type: impl Into<String>) -> Self {
+        self.query_failure_type = Some(failure_type.into());
+        self
+    }
+
+    /// Only include actions on the object with this ID
+    pub fn target_id(mut self, target_id: i64) -> Self {
+        self.query_target_id = Some(target_id.to_string());
+        self
+    }
+
+    /// Only include actions on the object with this name
+    pub fn target_name(mut self, target_name: impl Into<String>) -> Self {
+        self.query_target_name = Some(target_name.into());
+        self
+    }
+
+    /// Only include permission changes with this permission level
+    pub fn target_permission(mut self, target_permission: impl Into<String>) -> Self {
+        self.query_target_permission = Some(target_permission.into());
+        self
+    }
+
+    /// Only include actions on the user with this ID
+    pub fn target_user_id(mut self, target_user_id: i64) -> Self {
+        self.query_target_user_id = Some(target_user_id.to_string());
+        self
+    }
+
+    /// Only include actions on the user with this username
+    pub fn target_username(mut self, target_username: impl Into<String>) -> Self {
+        self.query_target_username = Some(target_username.into());
+        self
+    }
+
+    /// Only include API key changes for this platform
+    pub fn target_platform(mut self, target_platform: impl Into<String>) -> Self {
+        self.query_target_platform = Some(target_platform.into());
+        self
+    }
+
+    /// Only include API key changes with this permission set
+    pub fn target_permission_set(mut self, target_permission_set: impl Into<String>) -> Self {
+        self.query_target_permission_set = Some(target_permission_set.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timestamp::timestamp;
+    use serde_json::json;
+
+    #[test]
+    fn test_enum_round_trip() {
+        assert_eq!(
+            HistoryAction::from("failedlogin"),
+            HistoryAction::FailedLogin
+        );
+        assert_eq!(HistoryInterface::RestApi.as_str(), "restapi");
+        assert_eq!(
+            HistoryAction::from("bundle_create"),
+            HistoryAction::Unknown("bundle_create".to_string())
+        );
+        assert_eq!(
+            serde_json::from_value::<HistoryInterface>(json!("sftp")).unwrap(),
+            HistoryInterface::Sftp
+        );
+        assert_eq!(
+            serde_json::to_value(HistoryAction::Unknown("x".to_string())).unwrap(),
+            json!("x")
+        );
+    }
+
+    #[test]
+    fn test_query_serialization() {
+        let query = HistoryQuery::new()
+            .start_at(timestamp("2024-06-01T00:00:00Z").unwrap())
+            .action(HistoryAction::Login)
+            .interface(HistoryInterface::Sftp)
+            .user_id(42)
+            .target_username("bob");
+
+        assert_eq!(
+            serde_json::to_value(&query).unwrap(),
+            json!({
+                "start_at": "2024-06-01T00:00:00Z",
+                "query_action": "login",
+                "query_interface": "sftp",
+                "query_user_id": "42",
+                "query_target_username": "bob"
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(HistoryQuery::new()).unwrap(),
+            json!({})
+        );
+    }
+}
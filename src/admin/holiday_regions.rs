@@ -1,12 +1,24 @@
-use crate::{Result, client::FilesClient};
+use crate::pagination::Paginator;
+use crate::{PaginationInfo, Result, client::FilesClient};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Holiday region entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HolidayRegionEntity {
+    /// Region code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+
+    /// Region name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+#[derive(Debug, Clone)]
 pub struct HolidayRegionHandler {
     client: FilesClient,
 }
@@ -16,9 +28,37 @@ impl HolidayRegionHandler {
         Self { client }
     }
 
-    pub async fn list(&self) -> Result<Vec<HolidayRegionEntity>> {
-        let response = self.client.get_raw("/holiday_regions").await?;
+    pub async fn list(
+        &self,
+        cursor: Option<String>,
+        per_page: Option<i64>,
+    ) -> Result<(Vec<HolidayRegionEntity>, PaginationInfo)> {
+        let mut endpoint = "/holiday_regions".to_string();
+        let mut params = Vec::new();
+
+        if let Some(c) = cursor {
+            params.push(format!("cursor={}", c));
+        }
+        if let Some(pp) = per_page {
+            params.push(format!("per_page={}", pp));
+        }
+
+        if !params.is_empty() {
+            endpoint.push('?');
+            endpoint.push_str(&params.join("&"));
+        }
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
         let entities: Vec<HolidayRegionEntity> = serde_json::from_value(response)?;
-        Ok(entities)
+        Ok((entities, pagination))
+    }
+
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(&self, per_page: Option<i64>) -> Paginator<HolidayRegionEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            async move { handler.list(cursor, per_page).await }
+        })
     }
 }
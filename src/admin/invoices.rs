@@ -2,7 +2,9 @@
 //!
 //! Invoices represent billing line items for your Files.com account.
 
-use crate::{FilesClient, PaginationInfo, Result};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{FilesClient, PaginationInfo, Result, Timestamp};
 use serde::{Deserialize, Serialize};
 
 /// An Invoice Line Item entity
@@ -18,7 +20,7 @@ pub struct InvoiceLineItemEntity {
 
     /// Created at
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
 
     /// Description
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -30,11 +32,11 @@ pub struct InvoiceLineItemEntity {
 
     /// Service end date
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub service_end_at: Option<String>,
+    pub service_end_at: Option<Timestamp>,
 
     /// Service start date
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub service_start_at: Option<String>,
+    pub service_start_at: Option<Timestamp>,
 
     /// Plan name
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -62,7 +64,7 @@ pub struct AccountLineItemEntity {
 
     /// Created at
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
 
     /// Currency (e.g., "USD")
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -77,7 +79,16 @@ pub struct AccountLineItemEntity {
     pub invoice_line_items: Option<Vec<InvoiceLineItemEntity>>,
 }
 
+query_fields! {
+    /// Fields that invoices can be sorted and filtered by
+    pub enum InvoiceField {
+        CreatedAt => "created_at",
+        Status => "status",
+    }
+}
+
 /// Handler for invoice operations
+#[derive(Debug, Clone)]
 pub struct InvoiceHandler {
     client: FilesClient,
 }
@@ -93,6 +104,7 @@ impl InvoiceHandler {
     /// # Arguments
     /// * `cursor` - Pagination cursor
     /// * `per_page` - Results per page
+    /// * `query` - Sorting and filtering options (optional)
     ///
     /// # Returns
     /// Tuple of (invoices, pagination_info)
@@ -104,7 +116,7 @@ impl InvoiceHandler {
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = FilesClient::builder().api_key("key").build()?;
     /// let handler = InvoiceHandler::new(client);
-    /// let (invoices, _) = handler.list(None, None).await?;
+    /// let (invoices, _) = handler.list(None, None, None).await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -112,6 +124,7 @@ impl InvoiceHandler {
         &self,
         cursor: Option<&str>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<InvoiceField>>,
     ) -> Result<(Vec<AccountLineItemEntity>, PaginationInfo)> {
         let mut params = vec![];
         if let Some(c) = cursor {
@@ -121,7 +134,7 @@ impl InvoiceHandler {
             params.push(("per_page", pp.to_string()));
         }
 
-        let query = if params.is_empty() {
+        let query_string = if params.is_empty() {
             String::new()
         } else {
             format!(
@@ -134,17 +147,37 @@ impl InvoiceHandler {
             )
         };
 
-        let response = self.client.get_raw(&format!("/invoices{}", query)).await?;
-        let invoices: Vec<AccountLineItemEntity> = serde_json::from_value(response)?;
+        let mut endpoint = format!("/invoices{}", query_string);
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
 
-        let pagination = PaginationInfo {
-            cursor_next: None,
-            cursor_prev: None,
-        };
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let invoices: Vec<AccountLineItemEntity> = serde_json::from_value(response)?;
 
         Ok((invoices, pagination))
     }
 
+    /// Stream invoices across all pages
+    ///
+    /// Takes the same arguments as [`list`](Self::list), without the cursor.
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<InvoiceField>>,
+    ) -> Paginator<AccountLineItemEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move {
+                handler
+                    .list(cursor.as_deref(), per_page, query.as_ref())
+                    .await
+            }
+        })
+    }
+
     /// Get a specific invoice
     ///
     /// # Arguments
@@ -2,8 +2,9 @@
 //!
 //! This module contains handlers for site administration, history, and billing:
 //! - Site settings and usage
-//! - History and history exports
+//! - History and history exports, with typed export filters
 //! - Action notification exports
+//! - Running exports to completion with [`export`]
 //! - Invoices and payments
 //! - DNS records and child site management
 //! - Holiday regions and styles
@@ -12,9 +13,11 @@ pub mod action_notification_export_results;
 pub mod action_notification_exports;
 pub mod child_site_management_policies;
 pub mod dns_records;
+pub mod export;
 pub mod history;
 pub mod history_export_results;
 pub mod history_exports;
+pub mod history_query;
 pub mod holiday_regions;
 pub mod invoices;
 pub mod payments;
@@ -26,6 +29,7 @@ pub use action_notification_export_results::ActionNotificationExportResultHandle
 pub use action_notification_exports::ActionNotificationExportHandler;
 pub use child_site_management_policies::ChildSiteManagementPolicyHandler;
 pub use dns_records::DnsRecordHandler;
+pub use export::{ExportOptions, ExportStream};
 pub use history::HistoryHandler;
 pub use history_export_results::HistoryExportResultHandler2;
 pub use history_exports::HistoryExportHandler2;
@@ -37,14 +41,26 @@ pub use styles::StyleHandler;
 
 // Re-export entities
 pub use action_notification_export_results::ActionNotificationExportResultEntity;
-pub use action_notification_exports::ActionNotificationExportEntity;
-pub use child_site_management_policies::ChildSiteManagementPolicyEntity;
+pub use action_notification_exports::{
+    ActionNotificationExportEntity, CreateActionNotificationExportParams,
+};
+pub use child_site_management_policies::{
+    ChildSiteManagementPolicyEntity, CreateChildSiteManagementPolicyParams,
+    UpdateChildSiteManagementPolicyParams,
+};
 pub use dns_records::DnsRecordEntity;
-pub use history::{HistoryExportEntity, HistoryExportResultEntity};
+pub use history::{ActionEntity, HistoryExportEntity, HistoryExportResultEntity};
 pub use history_export_results::HistoryExportResultEntity2;
-pub use history_exports::HistoryExportEntity2;
+pub use history_exports::{CreateHistoryExportParams, HistoryExportEntity2};
+pub use history_query::{HistoryAction, HistoryInterface, HistoryQuery};
 pub use holiday_regions::HolidayRegionEntity;
 pub use invoices::{AccountLineItemEntity, InvoiceLineItemEntity};
 pub use payments::{PaymentEntity, PaymentLineItemEntity};
 pub use site::{SiteEntity, SiteUsageEntity};
-pub use styles::StyleEntity;
+pub use styles::{StyleEntity, UpdateStyleParams};
+
+// Re-export query fields
+pub use child_site_management_policies::ChildSiteManagementPolicyField;
+pub use history::HistoryField;
+pub use invoices::InvoiceField;
+pub use payments::PaymentField;
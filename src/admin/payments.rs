@@ -2,7 +2,9 @@
 //!
 //! Payments represent payment transactions for your Files.com account.
 
-use crate::{FilesClient, PaginationInfo, Result};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{FilesClient, PaginationInfo, Result, Timestamp};
 use serde::{Deserialize, Serialize};
 
 /// A Payment Line Item entity
@@ -18,7 +20,7 @@ pub struct PaymentLineItemEntity {
 
     /// Created at
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
 
     /// Invoice ID
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -46,7 +48,7 @@ pub struct PaymentEntity {
 
     /// Created at
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
 
     /// Currency (e.g., "USD")
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -61,7 +63,16 @@ pub struct PaymentEntity {
     pub payment_line_items: Option<Vec<PaymentLineItemEntity>>,
 }
 
+query_fields! {
+    /// Fields that payments can be sorted and filtered by
+    pub enum PaymentField {
+        CreatedAt => "created_at",
+        Status => "status",
+    }
+}
+
 /// Handler for payment operations
+#[derive(Debug, Clone)]
 pub struct PaymentHandler {
     client: FilesClient,
 }
@@ -77,6 +88,7 @@ impl PaymentHandler {
     /// # Arguments
     /// * `cursor` - Pagination cursor
     /// * `per_page` - Results per page
+    /// * `query` - Sorting and filtering options (optional)
     ///
     /// # Returns
     /// Tuple of (payments, pagination_info)
@@ -88,7 +100,7 @@ impl PaymentHandler {
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = FilesClient::builder().api_key("key").build()?;
     /// let handler = PaymentHandler::new(client);
-    /// let (payments, _) = handler.list(None, None).await?;
+    /// let (payments, _) = handler.list(None, None, None).await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -96,6 +108,7 @@ impl PaymentHandler {
         &self,
         cursor: Option<&str>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<PaymentField>>,
     ) -> Result<(Vec<PaymentEntity>, PaginationInfo)> {
         let mut params = vec![];
         if let Some(c) = cursor {
@@ -105,7 +118,7 @@ impl PaymentHandler {
             params.push(("per_page", pp.to_string()));
         }
 
-        let query = if params.is_empty() {
+        let query_string = if params.is_empty() {
             String::new()
         } else {
             format!(
@@ -118,17 +131,37 @@ impl PaymentHandler {
             )
         };
 
-        let response = self.client.get_raw(&format!("/payments{}", query)).await?;
-        let payments: Vec<PaymentEntity> = serde_json::from_value(response)?;
+        let mut endpoint = format!("/payments{}", query_string);
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
 
-        let pagination = PaginationInfo {
-            cursor_next: None,
-            cursor_prev: None,
-        };
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let payments: Vec<PaymentEntity> = serde_json::from_value(response)?;
 
         Ok((payments, pagination))
     }
 
+    /// Stream payments across all pages
+    ///
+    /// Takes the same arguments as [`list`](Self::list), without the cursor.
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<PaymentField>>,
+    ) -> Paginator<PaymentEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move {
+                handler
+                    .list(cursor.as_deref(), per_page, query.as_ref())
+                    .await
+            }
+        })
+    }
+
     /// Get a specific payment
     ///
     /// # Arguments
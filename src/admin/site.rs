@@ -2,7 +2,7 @@
 //!
 //! Site represents site-wide settings and configuration for your Files.com account.
 
-use crate::{FilesClient, Result};
+use crate::{FilesClient, Result, Timestamp};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
@@ -148,7 +148,7 @@ pub struct SiteEntity {
 
     /// Site created at
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
 
     /// Custom namespace
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1,10 +1,53 @@
 use crate::{Result, client::FilesClient};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Style entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StyleEntity {
+    /// Style ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Folder path the style applies to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// Logo image
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo: Option<serde_json::Value>,
+
+    /// URL opened when the logo is clicked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo_click_href: Option<String>,
+
+    /// Logo thumbnail
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<serde_json::Value>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parameters for updating a style
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateStyleParams {
+    /// URL opened when the logo is clicked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo_click_href: Option<String>,
+}
+
+impl UpdateStyleParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// URL opened when the logo is clicked
+    pub fn logo_click_href(mut self, logo_click_href: impl Into<String>) -> Self {
+        self.logo_click_href = Some(logo_click_href.into());
+        self
+    }
 }
 
 pub struct StyleHandler {
@@ -22,7 +65,7 @@ impl StyleHandler {
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn update(&self, path: &str, params: serde_json::Value) -> Result<StyleEntity> {
+    pub async fn update(&self, path: &str, params: UpdateStyleParams) -> Result<StyleEntity> {
         let endpoint = format!("/styles/{}", path);
         let response = self.client.patch_raw(&endpoint, params).await?;
         Ok(serde_json::from_value(response)?)
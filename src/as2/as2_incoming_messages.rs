@@ -1,12 +1,161 @@
-use crate::{Result, client::FilesClient};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{PaginationInfo, Result, Timestamp, client::FilesClient};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// AS2 incoming message entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct As2IncomingMessageEntity {
+    /// Message ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// AS2 partner ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub as2_partner_id: Option<i64>,
+
+    /// AS2 station ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub as2_station_id: Option<i64>,
+
+    /// UUID assigned to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+
+    /// Content type header of the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+
+    /// HTTP headers of the request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_headers: Option<serde_json::Value>,
+
+    /// Result of processing the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub processing_result: Option<String>,
+
+    /// Description of the processing result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub processing_result_description: Option<String>,
+
+    /// Message integrity check
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mic: Option<String>,
+
+    /// Algorithm used for the message integrity check
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mic_algo: Option<String>,
+
+    /// AS2 TO header
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub as2_to: Option<String>,
+
+    /// AS2 FROM header
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub as2_from: Option<String>,
+
+    /// AS2 message ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+
+    /// AS2 subject header
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+
+    /// Date header
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+
+    /// Encrypted payload size in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_size: Option<String>,
+
+    /// Filename of the file being received
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachment_filename: Option<String>,
+
+    /// IP address of the sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+
+    /// Message creation time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+
+    /// HTTP response code sent to the sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_response_code: Option<String>,
+
+    /// HTTP headers sent to the sender
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_response_headers: Option<serde_json::Value>,
+
+    /// Serial of the certificate used to encrypt the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient_serial: Option<String>,
+
+    /// Certificate serial as a hex string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hex_recipient_serial: Option<String>,
+
+    /// Issuer of the certificate used to encrypt the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient_issuer: Option<String>,
+
+    /// Whether the message was received
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_received: Option<bool>,
+
+    /// Whether the message was decrypted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_decrypted: Option<bool>,
+
+    /// Whether the message signature was verified
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_signature_verified: Option<bool>,
+
+    /// Whether processing succeeded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_processing_success: Option<bool>,
+
+    /// Whether an MDN was returned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_mdn_returned: Option<bool>,
+
+    /// URL to download the encrypted payload
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encrypted_uri: Option<String>,
+
+    /// URL to download the signed S/MIME payload
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smime_signed_uri: Option<String>,
+
+    /// URL to download the S/MIME payload
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smime_uri: Option<String>,
+
+    /// URL to download the original file contents
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_uri: Option<String>,
+
+    /// URL to download the MDN response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mdn_response_uri: Option<String>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+query_fields! {
+    /// Fields that incoming AS2 messages can be sorted and filtered by
+    pub enum As2IncomingMessageField {
+        CreatedAt => "created_at",
+        As2PartnerId => "as2_partner_id",
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct As2IncomingMessageHandler {
     client: FilesClient,
 }
@@ -16,9 +165,47 @@ impl As2IncomingMessageHandler {
         Self { client }
     }
 
-    pub async fn list(&self) -> Result<Vec<As2IncomingMessageEntity>> {
-        let response = self.client.get_raw("/as2_incoming_messages").await?;
+    pub async fn list(
+        &self,
+        cursor: Option<String>,
+        per_page: Option<i64>,
+        query: Option<&ListQuery<As2IncomingMessageField>>,
+    ) -> Result<(Vec<As2IncomingMessageEntity>, PaginationInfo)> {
+        let mut endpoint = "/as2_incoming_messages".to_string();
+        let mut params = Vec::new();
+
+        if let Some(c) = cursor {
+            params.push(format!("cursor={}", c));
+        }
+        if let Some(pp) = per_page {
+            params.push(format!("per_page={}", pp));
+        }
+
+        if !params.is_empty() {
+            endpoint.push('?');
+            endpoint.push_str(&params.join("&"));
+        }
+
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
         let entities: Vec<As2IncomingMessageEntity> = serde_json::from_value(response)?;
-        Ok(entities)
+        Ok((entities, pagination))
+    }
+
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<As2IncomingMessageField>>,
+    ) -> Paginator<As2IncomingMessageEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
     }
 }
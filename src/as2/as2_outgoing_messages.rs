@@ -1,12 +1,149 @@
-use crate::{Result, client::FilesClient};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{PaginationInfo, Result, Timestamp, client::FilesClient};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// AS2 outgoing message entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct As2OutgoingMessageEntity {
+    /// Message ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// AS2 partner ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub as2_partner_id: Option<i64>,
+
+    /// AS2 station ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub as2_station_id: Option<i64>,
+
+    /// UUID assigned to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+
+    /// HTTP headers of the request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_headers: Option<serde_json::Value>,
+
+    /// Log of processing activity
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_log: Option<String>,
+
+    /// Result of processing the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub processing_result: Option<String>,
+
+    /// Description of the processing result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub processing_result_description: Option<String>,
+
+    /// Message integrity check
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mic: Option<String>,
+
+    /// SHA-256 message integrity check
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mic_sha_256: Option<String>,
+
+    /// AS2 TO header
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub as2_to: Option<String>,
+
+    /// AS2 FROM header
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub as2_from: Option<String>,
+
+    /// Date header
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+
+    /// AS2 message ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+
+    /// Encrypted payload size in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_size: Option<String>,
+
+    /// Filename of the file being sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachment_filename: Option<String>,
+
+    /// Message creation time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+
+    /// HTTP response code from the partner
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_response_code: Option<String>,
+
+    /// HTTP headers from the partner
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_response_headers: Option<serde_json::Value>,
+
+    /// Transmission duration in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_transmission_duration: Option<f64>,
+
+    /// Whether an MDN was received
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mdn_received: Option<bool>,
+
+    /// Whether the MDN was valid
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mdn_valid: Option<bool>,
+
+    /// Whether the MDN signature was verified
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mdn_signature_verified: Option<bool>,
+
+    /// Whether the MDN message ID matched
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mdn_message_id_matched: Option<bool>,
+
+    /// Whether the MDN integrity check matched
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mdn_mic_matched: Option<bool>,
+
+    /// Whether the MDN reported successful processing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mdn_processing_success: Option<bool>,
+
+    /// URL to download the original file contents
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_uri: Option<String>,
+
+    /// URL to download the S/MIME payload
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smime_uri: Option<String>,
+
+    /// URL to download the signed S/MIME payload
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smime_signed_uri: Option<String>,
+
+    /// URL to download the encrypted payload
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encrypted_uri: Option<String>,
+
+    /// URL to download the MDN response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mdn_response_uri: Option<String>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+query_fields! {
+    /// Fields that outgoing AS2 messages can be sorted and filtered by
+    pub enum As2OutgoingMessageField {
+        CreatedAt => "created_at",
+        As2PartnerId => "as2_partner_id",
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct As2OutgoingMessageHandler {
     client: FilesClient,
 }
@@ -16,9 +153,47 @@ impl As2OutgoingMessageHandler {
         Self { client }
     }
 
-    pub async fn list(&self) -> Result<Vec<As2OutgoingMessageEntity>> {
-        let response = self.client.get_raw("/as2_outgoing_messages").await?;
+    pub async fn list(
+        &self,
+        cursor: Option<String>,
+        per_page: Option<i64>,
+        query: Option<&ListQuery<As2OutgoingMessageField>>,
+    ) -> Result<(Vec<As2OutgoingMessageEntity>, PaginationInfo)> {
+        let mut endpoint = "/as2_outgoing_messages".to_string();
+        let mut params = Vec::new();
+
+        if let Some(c) = cursor {
+            params.push(format!("cursor={}", c));
+        }
+        if let Some(pp) = per_page {
+            params.push(format!("per_page={}", pp));
+        }
+
+        if !params.is_empty() {
+            endpoint.push('?');
+            endpoint.push_str(&params.join("&"));
+        }
+
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
         let entities: Vec<As2OutgoingMessageEntity> = serde_json::from_value(response)?;
-        Ok(entities)
+        Ok((entities, pagination))
+    }
+
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<As2OutgoingMessageField>>,
+    ) -> Paginator<As2OutgoingMessageEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
     }
 }
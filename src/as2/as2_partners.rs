@@ -1,13 +1,324 @@
 //! AS2 partner configuration
 
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
 use crate::{FilesClient, PaginationInfo, Result};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// AS2 partner entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct As2PartnerEntity {
+    /// Partner ID
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<i64>,
+
+    /// ID of the AS2 station the partner belongs to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub as2_station_id: Option<i64>,
+
+    /// Partner name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Public URI for sending AS2 messages to the partner
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+
+    /// Server certificate validation mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_certificate: Option<String>,
+
+    /// Username for HTTP authentication
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_auth_username: Option<String>,
+
+    /// Additional HTTP headers sent to the partner
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_http_headers: Option<serde_json::Value>,
+
+    /// Default MIME type of sent files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_mime_type: Option<String>,
+
+    /// How MDN responses are validated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mdn_validation_level: Option<String>,
+
+    /// Whether to send messages from dedicated IPs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_dedicated_ips: Option<bool>,
+
+    /// Public certificate serial as a hex string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hex_public_certificate_serial: Option<String>,
+
+    /// MD5 hash of the public certificate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_certificate_md5: Option<String>,
+
+    /// Subject of the public certificate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_certificate_subject: Option<String>,
+
+    /// Issuer of the public certificate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_certificate_issuer: Option<String>,
+
+    /// Serial of the public certificate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_certificate_serial: Option<String>,
+
+    /// Start of the public certificate's validity
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_certificate_not_before: Option<String>,
+
+    /// End of the public certificate's validity
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_certificate_not_after: Option<String>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parameters for creating an AS2 partner
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateAs2PartnerParams {
+    /// ID of the AS2 station the partner belongs to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub as2_station_id: Option<i64>,
+
+    /// Partner name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Public URI for sending AS2 messages to the partner
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+
+    /// Partner's public certificate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_certificate: Option<String>,
+
+    /// Server certificate validation mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_certificate: Option<String>,
+
+    /// Username for HTTP authentication
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_auth_username: Option<String>,
+
+    /// Password for HTTP authentication
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_auth_password: Option<String>,
+
+    /// Additional HTTP headers sent to the partner
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_http_headers: Option<serde_json::Value>,
+
+    /// Default MIME type of sent files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_mime_type: Option<String>,
+
+    /// How MDN responses are validated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mdn_validation_level: Option<String>,
+
+    /// Whether to send messages from dedicated IPs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_dedicated_ips: Option<bool>,
+}
+
+impl CreateAs2PartnerParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ID of the AS2 station the partner belongs to
+    pub fn as2_station_id(mut self, as2_station_id: i64) -> Self {
+        self.as2_station_id = Some(as2_station_id);
+        self
+    }
+
+    /// Partner name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Public URI for sending AS2 messages to the partner
+    pub fn uri(mut self, uri: impl Into<String>) -> Self {
+        self.uri = Some(uri.into());
+        self
+    }
+
+    /// Partner's public certificate
+    pub fn public_certificate(mut self, public_certificate: impl Into<String>) -> Self {
+        self.public_certificate = Some(public_certificate.into());
+        self
+    }
+
+    /// Server certificate validation mode
+    pub fn server_certificate(mut self, server_certificate: impl Into<String>) -> Self {
+        self.server_certificate = Some(server_certificate.into());
+        self
+    }
+
+    /// Username for HTTP authentication
+    pub fn http_auth_username(mut self, http_auth_username: impl Into<String>) -> Self {
+        self.http_auth_username = Some(http_auth_username.into());
+        self
+    }
+
+    /// Password for HTTP authentication
+    pub fn http_auth_password(mut self, http_auth_password: impl Into<String>) -> Self {
+        self.http_auth_password = Some(http_auth_password.into());
+        self
+    }
+
+    /// Additional HTTP headers sent to the partner
+    pub fn additional_http_headers(mut self, additional_http_headers: serde_json::Value) -> Self {
+        self.additional_http_headers = Some(additional_http_headers);
+        self
+    }
+
+    /// Default MIME type of sent files
+    pub fn default_mime_type(mut self, default_mime_type: impl Into<String>) -> Self {
+        self.default_mime_type = Some(default_mime_type.into());
+        self
+    }
+
+    /// How MDN responses are validated
+    pub fn mdn_validation_level(mut self, mdn_validation_level: impl Into<String>) -> Self {
+        self.mdn_validation_level = Some(mdn_validation_level.into());
+        self
+    }
+
+    /// Whether to send messages from dedicated IPs
+    pub fn enable_dedicated_ips(mut self, enable_dedicated_ips: bool) -> Self {
+        self.enable_dedicated_ips = Some(enable_dedicated_ips);
+        self
+    }
+}
+
+/// Parameters for updating an AS2 partner
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateAs2PartnerParams {
+    /// Partner name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Public URI for sending AS2 messages to the partner
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+
+    /// Partner's public certificate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_certificate: Option<String>,
+
+    /// Server certificate validation mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_certificate: Option<String>,
+
+    /// Username for HTTP authentication
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_auth_username: Option<String>,
+
+    /// Password for HTTP authentication
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_auth_password: Option<String>,
+
+    /// Additional HTTP headers sent to the partner
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_http_headers: Option<serde_json::Value>,
+
+    /// Default MIME type of sent files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_mime_type: Option<String>,
+
+    /// How MDN responses are validated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mdn_validation_level: Option<String>,
+
+    /// Whether to send messages from dedicated IPs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_dedicated_ips: Option<bool>,
+}
+
+impl UpdateAs2PartnerParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Partner name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Public URI for sending AS2 messages to the partner
+    pub fn uri(mut self, uri: impl Into<String>) -> Self {
+        self.uri = Some(uri.into());
+        self
+    }
+
+    /// Partner's public certificate
+    pub fn public_certificate(mut self, public_certificate: impl Into<String>) -> Self {
+        self.public_certificate = Some(public_certificate.into());
+        self
+    }
+
+    /// Server certificate validation mode
+    pub fn server_certificate(mut self, server_certificate: impl Into<String>) -> Self {
+        self.server_certificate = Some(server_certificate.into());
+        self
+    }
+
+    /// Username for HTTP authentication
+    pub fn http_auth_username(mut self, http_auth_username: impl Into<String>) -> Self {
+        self.http_auth_username = Some(http_auth_username.into());
+        self
+    }
+
+    /// Password for HTTP authentication
+    pub fn http_auth_password(mut self, http_auth_password: impl Into<String>) -> Self {
+        self.http_auth_password = Some(http_auth_password.into());
+        self
+    }
+
+    /// Additional HTTP headers sent to the partner
+    pub fn additional_http_headers(mut self, additional_http_headers: serde_json::Value) -> Self {
+        self.additional_http_headers = Some(additional_http_headers);
+        self
+    }
+
+    /// Default MIME type of sent files
+    pub fn default_mime_type(mut self, default_mime_type: impl Into<String>) -> Self {
+        self.default_mime_type = Some(default_mime_type.into());
+        self
+    }
+
+    /// How MDN responses are validated
+    pub fn mdn_validation_level(mut self, mdn_validation_level: impl Into<String>) -> Self {
+        self.mdn_validation_level = Some(mdn_validation_level.into());
+        self
+    }
+
+    /// Whether to send messages from dedicated IPs
+    pub fn enable_dedicated_ips(mut self, enable_dedicated_ips: bool) -> Self {
+        self.enable_dedicated_ips = Some(enable_dedicated_ips);
+        self
+    }
+}
+
+query_fields! {
+    /// Fields that AS2 partners can be sorted and filtered by
+    pub enum As2PartnerField {
+        Name => "name",
+        As2StationId => "as2_station_id",
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -24,6 +335,7 @@ impl As2PartnerHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<As2PartnerField>>,
     ) -> Result<(Vec<As2PartnerEntity>, PaginationInfo)> {
         let mut endpoint = "/as2_partners".to_string();
         let mut params = Vec::new();
@@ -38,39 +350,45 @@ impl As2PartnerHandler {
             endpoint.push_str(&params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
-        let items: Vec<As2PartnerEntity> = response.json().await?;
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let items: Vec<As2PartnerEntity> = serde_json::from_value(response)?;
         Ok((items, pagination))
     }
 
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<As2PartnerField>>,
+    ) -> Paginator<As2PartnerEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
+
     pub async fn get(&self, id: i64) -> Result<As2PartnerEntity> {
         let endpoint = format!("/as2_partners/{}", id);
         let response = self.client.get_raw(&endpoint).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn create(&self, params: serde_json::Value) -> Result<As2PartnerEntity> {
+    pub async fn create(&self, params: CreateAs2PartnerParams) -> Result<As2PartnerEntity> {
         let response = self.client.post_raw("/as2_partners", params).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn update(&self, id: i64, params: serde_json::Value) -> Result<As2PartnerEntity> {
+    pub async fn update(
+        &self,
+        id: i64,
+        params: UpdateAs2PartnerParams,
+    ) -> Result<As2PartnerEntity> {
         let endpoint = format!("/as2_partners/{}", id);
         let response = self.client.patch_raw(&endpoint, params).await?;
         Ok(serde_json::from_value(response)?)
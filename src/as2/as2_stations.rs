@@ -1,13 +1,177 @@
 //! AS2 station configuration
 
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
 use crate::{FilesClient, PaginationInfo, Result};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// AS2 station entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct As2StationEntity {
+    /// Station ID
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<i64>,
+
+    /// Station name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Public URI for sending AS2 messages to the station
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+
+    /// Station domain
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+
+    /// Public certificate serial as a hex string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hex_public_certificate_serial: Option<String>,
+
+    /// MD5 hash of the public certificate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_certificate_md5: Option<String>,
+
+    /// MD5 hash of the private key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_key_md5: Option<String>,
+
+    /// Subject of the public certificate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_certificate_subject: Option<String>,
+
+    /// Issuer of the public certificate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_certificate_issuer: Option<String>,
+
+    /// Serial of the public certificate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_certificate_serial: Option<String>,
+
+    /// Start of the public certificate's validity
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_certificate_not_before: Option<String>,
+
+    /// End of the public certificate's validity
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_certificate_not_after: Option<String>,
+
+    /// MD5 hash of the private key password
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_key_password_md5: Option<String>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parameters for creating an AS2 station
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateAs2StationParams {
+    /// Station name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Station's public certificate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_certificate: Option<String>,
+
+    /// Station's private key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_key: Option<String>,
+
+    /// Password for the private key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_key_password: Option<String>,
+}
+
+impl CreateAs2StationParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Station name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Station's public certificate
+    pub fn public_certificate(mut self, public_certificate: impl Into<String>) -> Self {
+        self.public_certificate = Some(public_certificate.into());
+        self
+    }
+
+    /// Station's private key
+    pub fn private_key(mut self, private_key: impl Into<String>) -> Self {
+        self.private_key = Some(private_key.into());
+        self
+    }
+
+    /// Password for the private key
+    pub fn private_key_password(mut self, private_key_password: impl Into<String>) -> Self {
+        self.private_key_password = Some(private_key_password.into());
+        self
+    }
+}
+
+/// Parameters for updating an AS2 station
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateAs2StationParams {
+    /// Station name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Station's public certificate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_certificate: Option<String>,
+
+    /// Station's private key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_key: Option<String>,
+
+    /// Password for the private key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_key_password: Option<String>,
+}
+
+impl UpdateAs2StationParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Station name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Station's public certificate
+    pub fn public_certificate(mut self, public_certificate: impl Into<String>) -> Self {
+        self.public_certificate = Some(public_certificate.into());
+        self
+    }
+
+    /// Station's private key
+    pub fn private_key(mut self, private_key: impl Into<String>) -> Self {
+        self.private_key = Some(private_key.into());
+        self
+    }
+
+    /// Password for the private key
+    pub fn private_key_password(mut self, private_key_password: impl Into<String>) -> Self {
+        self.private_key_password = Some(private_key_password.into());
+        self
+    }
+}
+
+query_fields! {
+    /// Fields that AS2 stations can be sorted and filtered by
+    pub enum As2StationField {
+        Name => "name",
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -24,6 +188,7 @@ impl As2StationHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<As2StationField>>,
     ) -> Result<(Vec<As2StationEntity>, PaginationInfo)> {
         let mut endpoint = "/as2_stations".to_string();
         let mut params = Vec::new();
@@ -38,39 +203,45 @@ impl As2StationHandler {
             endpoint.push_str(&params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
-        let items: Vec<As2StationEntity> = response.json().await?;
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let items: Vec<As2StationEntity> = serde_json::from_value(response)?;
         Ok((items, pagination))
     }
 
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<As2StationField>>,
+    ) -> Paginator<As2StationEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
+
     pub async fn get(&self, id: i64) -> Result<As2StationEntity> {
         let endpoint = format!("/as2_stations/{}", id);
         let response = self.client.get_raw(&endpoint).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn create(&self, params: serde_json::Value) -> Result<As2StationEntity> {
+    pub async fn create(&self, params: CreateAs2StationParams) -> Result<As2StationEntity> {
         let response = self.client.post_raw("/as2_stations", params).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn update(&self, id: i64, params: serde_json::Value) -> Result<As2StationEntity> {
+    pub async fn update(
+        &self,
+        id: i64,
+        params: UpdateAs2StationParams,
+    ) -> Result<As2StationEntity> {
         let endpoint = format!("/as2_stations/{}", id);
         let response = self.client.patch_raw(&endpoint, params).await?;
         Ok(serde_json::from_value(response)?)
@@ -18,5 +18,11 @@ pub use as2_stations::As2StationHandler;
 // Re-export entities
 pub use as2_incoming_messages::As2IncomingMessageEntity;
 pub use as2_outgoing_messages::As2OutgoingMessageEntity;
-pub use as2_partners::As2PartnerEntity;
-pub use as2_stations::As2StationEntity;
+pub use as2_partners::{As2PartnerEntity, CreateAs2PartnerParams, UpdateAs2PartnerParams};
+pub use as2_stations::{As2StationEntity, CreateAs2StationParams, UpdateAs2StationParams};
+
+// Re-export query fields
+pub use as2_incoming_messages::As2IncomingMessageField;
+pub use as2_outgoing_messages::As2OutgoingMessageField;
+pub use as2_partners::As2PartnerField;
+pub use as2_stations::As2StationField;
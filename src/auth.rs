@@ -0,0 +1,224 @@
+//! Authentication modes for the Files.com API
+//!
+//! Files.com accepts three kinds of credentials:
+//!
+//! - **API keys**, sent in the `X-FilesAPI-Key` header
+//! - **Session ids**, obtained by logging in with a username and password and
+//!   sent in the `X-FilesAPI-Auth` header
+//! - **Bearer tokens** (e.g. from OAuth), sent in the `Authorization` header
+//!   and supplied by a [`TokenProvider`]
+//!
+//! A [`CredentialRefresher`] can be configured on the client to obtain new
+//! credentials when a request fails with `401 Unauthorized`. The request is
+//! then sent once more with the new credentials.
+//!
+//! # Examples
+//!
+//! Logging in as an end user and using the resulting session:
+//!
+//! ```rust,no_run
+//! use files_sdk::{FilesClient, FolderHandler};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = FilesClient::builder()
+//!     .login("alice", "correct horse battery staple", None)
+//!     .await?;
+//!
+//! let folders = FolderHandler::new(client);
+//! let (files, _) = folders.list_folder("/", None, None, None).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{FilesClient, Result};
+use async_trait::async_trait;
+use std::fmt;
+use std::sync::Arc;
+
+/// Credentials used to authenticate requests
+#[derive(Clone)]
+pub enum Auth {
+    /// API key, sent as `X-FilesAPI-Key`
+    ApiKey(String),
+
+    /// Session id from a login, sent as `X-FilesAPI-Auth`
+    Session(String),
+
+    /// Bearer token from a provider, sent as `Authorization: Bearer ...`
+    Token(Arc<dyn TokenProvider>),
+}
+
+impl Auth {
+    /// Creates API key credentials
+    pub fn api_key(api_key: impl Into<String>) -> Self {
+        Auth::ApiKey(api_key.into())
+    }
+
+    /// Creates session credentials
+    pub fn session(session_id: impl Into<String>) -> Self {
+        Auth::Session(session_id.into())
+    }
+
+    /// Creates bearer token credentials backed by a provider
+    pub fn token(provider: impl TokenProvider + 'static) -> Self {
+        Auth::Token(Arc::new(provider))
+    }
+
+    /// Returns the header name and value to send with a request
+    pub(crate) async fn header(&self) -> Result<(&'static str, String)> {
+        match self {
+            Auth::ApiKey(key) => Ok(("X-FilesAPI-Key", key.clone())),
+            Auth::Session(id) => Ok(("X-FilesAPI-Auth", id.clone())),
+            Auth::Token(provider) => Ok((
+                "Authorization",
+                format!("Bearer {}", provider.token().await?),
+            )),
+        }
+    }
+}
+
+// Credentials are never printed
+impl fmt::Debug for Auth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Auth::ApiKey(_) => f.write_str("Auth::ApiKey(..)"),
+            Auth::Session(_) => f.write_str("Auth::Session(..)"),
+            Auth::Token(_) => f.write_str("Auth::Token(..)"),
+        }
+    }
+}
+
+/// Supplies bearer tokens for [`Auth::Token`]
+///
+/// The provider is asked for a token before every request, so it should
+/// cache tokens and only fetch a new one when the cached token expires.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_trait::async_trait;
+/// use files_sdk::auth::TokenProvider;
+///
+/// struct StaticToken(String);
+///
+/// #[async_trait]
+/// impl TokenProvider for StaticToken {
+///     async fn token(&self) -> files_sdk::Result<String> {
+///         Ok(self.0.clone())
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait TokenProvider: Send + Sync {
+    /// Returns the token to send with the next request
+    async fn token(&self) -> Result<String>;
+}
+
+/// Obtains new credentials after a request was rejected with `401`
+///
+/// # Examples
+///
+/// ```rust
+/// use async_trait::async_trait;
+/// use files_sdk::FilesClient;
+/// use files_sdk::auth::{Auth, CredentialRefresher};
+///
+/// struct FromVault;
+///
+/// #[async_trait]
+/// impl CredentialRefresher for FromVault {
+///     async fn refresh(&self, _client: &FilesClient) -> files_sdk::Result<Auth> {
+///         // Look up a fresh key in your secret store
+///         Ok(Auth::api_key("rotated-key"))
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait CredentialRefresher: Send + Sync {
+    /// Returns credentials to replace the rejected ones
+    ///
+    /// The client can be used to call the API, e.g. to log in again with
+    /// [`FilesClient::create_session`].
+    async fn refresh(&self, client: &FilesClient) -> Result<Auth>;
+}
+
+/// Refresher shared by a builder and the clients it creates
+#[derive(Clone)]
+pub(crate) struct SharedRefresher(pub(crate) Arc<dyn CredentialRefresher>);
+
+impl fmt::Debug for SharedRefresher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CredentialRefresher")
+    }
+}
+
+/// Refresher that logs in again with a username and password
+///
+/// Accounts that require a one-time password cannot be refreshed this way.
+#[derive(Clone)]
+pub struct PasswordLogin {
+    username: String,
+    password: String,
+}
+
+impl PasswordLogin {
+    /// Creates a refresher for the given credentials
+    pub fn new(username: impl Into<String>, password: impl Into<String>) -> Self {
+        Self {
+            username: username.into(),
+            password: password.into(),
+        }
+    }
+}
+
+impl fmt::Debug for PasswordLogin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PasswordLogin")
+            .field("username", &self.username)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl CredentialRefresher for PasswordLogin {
+    async fn refresh(&self, client: &FilesClient) -> Result<Auth> {
+        let session = client
+            .create_session(&self.username, &self.password, None)
+            .await?;
+        Ok(Auth::Session(session))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct StaticToken;
+
+    #[async_trait]
+    impl TokenProvider for StaticToken {
+        async fn token(&self) -> Result<String> {
+            Ok("abc".to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_auth_headers() {
+        let (name, value) = Auth::api_key("key").header().await.unwrap();
+        assert_eq!((name, value.as_str()), ("X-FilesAPI-Key", "key"));
+
+        let (name, value) = Auth::session("sid").header().await.unwrap();
+        assert_eq!((name, value.as_str()), ("X-FilesAPI-Auth", "sid"));
+
+        let (name, value) = Auth::token(StaticToken).header().await.unwrap();
+        assert_eq!((name, value.as_str()), ("Authorization", "Bearer abc"));
+    }
+
+    #[test]
+    fn test_debug_hides_secrets() {
+        assert_eq!(format!("{:?}", Auth::api_key("secret")), "Auth::ApiKey(..)");
+        let login = format!("{:?}", PasswordLogin::new("alice", "secret"));
+        assert!(!login.contains("secret"));
+    }
+}
@@ -1,12 +1,78 @@
-use crate::{Result, client::FilesClient, types::PaginationInfo};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{Result, Timestamp, client::FilesClient, types::PaginationInfo};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Automation run entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AutomationRunEntity {
+    /// Run ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// ID of the automation that ran
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automation_id: Option<i64>,
+
+    /// Completion time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<Timestamp>,
+
+    /// Start time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+
+    /// When the run will be retried
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_at: Option<Timestamp>,
+
+    /// When the run was retried
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retried_at: Option<Timestamp>,
+
+    /// ID of the run that retried this one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retried_in_run_id: Option<i64>,
+
+    /// ID of the run this one retried
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_of_run_id: Option<i64>,
+
+    /// Runtime in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runtime: Option<f64>,
+
+    /// Run status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// Number of successful operations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub successful_operations: Option<i64>,
+
+    /// Number of failed operations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failed_operations: Option<i64>,
+
+    /// URL of the status messages log
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_messages_url: Option<String>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+query_fields! {
+    /// Fields that automation runs can be sorted and filtered by
+    pub enum AutomationRunField {
+        AutomationId => "automation_id",
+        CreatedAt => "created_at",
+        Status => "status",
+    }
 }
 
+#[derive(Debug, Clone)]
 pub struct AutomationRunHandler {
     client: FilesClient,
 }
@@ -20,6 +86,7 @@ impl AutomationRunHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<AutomationRunField>>,
     ) -> Result<(Vec<AutomationRunEntity>, PaginationInfo)> {
         let mut endpoint = "/automation_runs".to_string();
         let mut query_params = Vec::new();
@@ -37,29 +104,29 @@ impl AutomationRunHandler {
             endpoint.push_str(&query_params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
 
-        let entities: Vec<AutomationRunEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let entities: Vec<AutomationRunEntity> = serde_json::from_value(response)?;
         Ok((entities, pagination))
     }
 
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<AutomationRunField>>,
+    ) -> Paginator<AutomationRunEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
+
     pub async fn get(&self, id: i64) -> Result<AutomationRunEntity> {
         let endpoint = format!("/automation_runs/{}", id);
         let response = self.client.get_raw(&endpoint).await?;
@@ -52,7 +52,9 @@
 //! # }
 //! ```
 
-use crate::{FilesClient, PaginationInfo, Result};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{FilesClient, PaginationInfo, Result, Timestamp};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
@@ -158,7 +160,7 @@ pub struct AutomationEntity {
 
     /// Last modification time
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_at: Option<String>,
+    pub last_modified_at: Option<Timestamp>,
 
     /// Use legacy folder matching
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -245,7 +247,18 @@ pub struct AutomationEntity {
     pub webhook_url: Option<String>,
 }
 
+query_fields! {
+    /// Fields that automations can be sorted and filtered by
+    pub enum AutomationField {
+        Automation => "automation",
+        Disabled => "disabled",
+        LastModifiedAt => "last_modified_at",
+        Name => "name",
+    }
+}
+
 /// Handler for automation operations
+#[derive(Debug, Clone)]
 pub struct AutomationHandler {
     client: FilesClient,
 }
@@ -266,6 +279,7 @@ impl AutomationHandler {
     /// * `cursor` - Pagination cursor from previous response
     /// * `per_page` - Number of results per page (max 10,000)
     /// * `automation` - Filter by automation type (e.g., "copy_file", "move_file")
+    /// * `query` - Sorting and filtering options (optional)
     ///
     /// # Returns
     ///
@@ -283,7 +297,7 @@ impl AutomationHandler {
     /// let handler = AutomationHandler::new(client);
     ///
     /// // List all automations
-    /// let (automations, pagination) = handler.list(None, Some(50), None).await?;
+    /// let (automations, pagination) = handler.list(None, Some(50), None, None).await?;
     ///
     /// for automation in automations {
     ///     println!("{}: {} - Disabled: {}",
@@ -293,7 +307,7 @@ impl AutomationHandler {
     /// }
     ///
     /// // Filter by type
-    /// let (copy_automations, _) = handler.list(None, None, Some("copy_file")).await?;
+    /// let (copy_automations, _) = handler.list(None, None, Some("copy_file"), None).await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -302,6 +316,7 @@ impl AutomationHandler {
         cursor: Option<&str>,
         per_page: Option<i64>,
         automation: Option<&str>,
+        query: Option<&ListQuery<AutomationField>>,
     ) -> Result<(Vec<AutomationEntity>, PaginationInfo)> {
         let mut params = vec![];
         if let Some(c) = cursor {
@@ -314,7 +329,7 @@ impl AutomationHandler {
             params.push(("automation", a.to_string()));
         }
 
-        let query = if params.is_empty() {
+        let query_string = if params.is_empty() {
             String::new()
         } else {
             format!(
@@ -327,20 +342,45 @@ impl AutomationHandler {
             )
         };
 
-        let response = self
-            .client
-            .get_raw(&format!("/automations{}", query))
-            .await?;
-        let automations: Vec<AutomationEntity> = serde_json::from_value(response)?;
+        let mut endpoint = format!("/automations{}", query_string);
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
 
-        let pagination = PaginationInfo {
-            cursor_next: None,
-            cursor_prev: None,
-        };
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let automations: Vec<AutomationEntity> = serde_json::from_value(response)?;
 
         Ok((automations, pagination))
     }
 
+    /// Stream all automations across all pages
+    ///
+    /// Takes the same arguments as [`list`](Self::list), without the cursor.
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        automation: Option<&str>,
+        query: Option<ListQuery<AutomationField>>,
+    ) -> Paginator<AutomationEntity> {
+        let handler = self.clone();
+        let automation = automation.map(String::from);
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            let automation = automation.clone();
+            async move {
+                handler
+                    .list(
+                        cursor.as_deref(),
+                        per_page,
+                        automation.as_deref(),
+                        query.as_ref(),
+                    )
+                    .await
+            }
+        })
+    }
+
     /// Get details of a specific automation
     ///
     /// # Arguments
@@ -3,6 +3,8 @@
 //! Behaviors are folder-level settings that automate actions like webhooks,
 //! file expiration, encryption, and more.
 
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
 use crate::{FilesClient, PaginationInfo, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -47,7 +49,16 @@ pub struct BehaviorEntity {
     pub recursive: Option<bool>,
 }
 
+query_fields! {
+    /// Fields that behaviors can be sorted and filtered by
+    pub enum BehaviorField {
+        Behavior => "behavior",
+        ImpactsUi => "impacts_ui",
+    }
+}
+
 /// Handler for behavior operations
+#[derive(Debug, Clone)]
 pub struct BehaviorHandler {
     client: FilesClient,
 }
@@ -63,32 +74,29 @@ impl BehaviorHandler {
     /// # Arguments
     /// * `cursor` - Pagination cursor
     /// * `per_page` - Results per page
-    /// * `sort_by` - Sort field and direction
-    /// * `filter` - Filter criteria
-    /// * `filter_prefix` - Filter by path prefix
+    /// * `query` - Sorting and filtering options (optional)
     ///
     /// # Returns
     /// Tuple of (behaviors, pagination_info)
     ///
     /// # Example
     /// ```no_run
-    /// use files_sdk::{FilesClient, BehaviorHandler};
+    /// use files_sdk::{FilesClient, BehaviorHandler, ListQuery};
+    /// use files_sdk::automation::BehaviorField;
     ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = FilesClient::builder().api_key("key").build()?;
     /// let handler = BehaviorHandler::new(client);
-    /// let (behaviors, _) = handler.list(None, None, None, None, None).await?;
+    /// let query = ListQuery::new().filter(BehaviorField::Behavior, "webhook");
+    /// let (behaviors, _) = handler.list(None, None, Some(&query)).await?;
     /// # Ok(())
     /// # }
     /// ```
-    #[allow(clippy::too_many_arguments)]
     pub async fn list(
         &self,
         cursor: Option<&str>,
         per_page: Option<i64>,
-        sort_by: Option<serde_json::Value>,
-        filter: Option<serde_json::Value>,
-        filter_prefix: Option<&str>,
+        query: Option<&ListQuery<BehaviorField>>,
     ) -> Result<(Vec<BehaviorEntity>, PaginationInfo)> {
         let mut params = vec![];
         if let Some(c) = cursor {
@@ -97,17 +105,8 @@ impl BehaviorHandler {
         if let Some(pp) = per_page {
             params.push(("per_page", pp.to_string()));
         }
-        if let Some(sb) = sort_by {
-            params.push(("sort_by", sb.to_string()));
-        }
-        if let Some(f) = filter {
-            params.push(("filter", f.to_string()));
-        }
-        if let Some(fp) = filter_prefix {
-            params.push(("filter_prefix", fp.to_string()));
-        }
 
-        let query = if params.is_empty() {
+        let query_string = if params.is_empty() {
             String::new()
         } else {
             format!(
@@ -120,23 +119,44 @@ impl BehaviorHandler {
             )
         };
 
-        let response = self.client.get_raw(&format!("/behaviors{}", query)).await?;
-        let behaviors: Vec<BehaviorEntity> = serde_json::from_value(response)?;
+        let mut endpoint = format!("/behaviors{}", query_string);
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
 
-        let pagination = PaginationInfo {
-            cursor_next: None,
-            cursor_prev: None,
-        };
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let behaviors: Vec<BehaviorEntity> = serde_json::from_value(response)?;
 
         Ok((behaviors, pagination))
     }
 
+    /// Stream behaviors across all pages
+    ///
+    /// Takes the same arguments as [`list`](Self::list), without the cursor.
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<BehaviorField>>,
+    ) -> Paginator<BehaviorEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move {
+                handler
+                    .list(cursor.as_deref(), per_page, query.as_ref())
+                    .await
+            }
+        })
+    }
+
     /// List behaviors for a specific folder path
     ///
     /// # Arguments
     /// * `path` - Folder path
     /// * `cursor` - Pagination cursor
     /// * `per_page` - Results per page
+    /// * `query` - Sorting and filtering options (optional)
     ///
     /// # Returns
     /// Tuple of (behaviors, pagination_info)
@@ -145,6 +165,7 @@ impl BehaviorHandler {
         path: &str,
         cursor: Option<&str>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<BehaviorField>>,
     ) -> Result<(Vec<BehaviorEntity>, PaginationInfo)> {
         let mut params = vec![];
         if let Some(c) = cursor {
@@ -154,7 +175,7 @@ impl BehaviorHandler {
             params.push(("per_page", pp.to_string()));
         }
 
-        let query = if params.is_empty() {
+        let query_string = if params.is_empty() {
             String::new()
         } else {
             format!(
@@ -167,20 +188,40 @@ impl BehaviorHandler {
             )
         };
 
-        let response = self
-            .client
-            .get_raw(&format!("/behaviors/folders/{}{}", path, query))
-            .await?;
-        let behaviors: Vec<BehaviorEntity> = serde_json::from_value(response)?;
+        let mut endpoint = format!("/behaviors/folders/{}{}", path, query_string);
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
 
-        let pagination = PaginationInfo {
-            cursor_next: None,
-            cursor_prev: None,
-        };
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let behaviors: Vec<BehaviorEntity> = serde_json::from_value(response)?;
 
         Ok((behaviors, pagination))
     }
 
+    /// Stream behaviors for a specific folder path across all pages
+    ///
+    /// Takes the same arguments as [`list_for_folder`](Self::list_for_folder), without the cursor.
+    pub fn list_for_folder_stream(
+        &self,
+        path: &str,
+        per_page: Option<i64>,
+        query: Option<ListQuery<BehaviorField>>,
+    ) -> Paginator<BehaviorEntity> {
+        let handler = self.clone();
+        let path = path.to_string();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            let path = path.clone();
+            async move {
+                handler
+                    .list_for_folder(&path, cursor.as_deref(), per_page, query.as_ref())
+                    .await
+            }
+        })
+    }
+
     /// Get a specific behavior
     ///
     /// # Arguments
@@ -27,7 +27,18 @@ pub use syncs::SyncHandler;
 pub use automation_runs::AutomationRunEntity;
 pub use automations::{AutomationEntity, AutomationTrigger, AutomationType};
 pub use behaviors::BehaviorEntity;
-pub use remote_mount_backends::RemoteMountBackendEntity;
+pub use remote_mount_backends::{
+    CreateRemoteMountBackendParams, RemoteMountBackendEntity, UpdateRemoteMountBackendParams,
+};
 pub use remote_servers::RemoteServerEntity;
 pub use sync_runs::SyncRunEntity;
-pub use syncs::SyncEntity;
+pub use syncs::{CreateSyncParams, SyncEntity, UpdateSyncParams};
+
+// Re-export query fields
+pub use automation_runs::AutomationRunField;
+pub use automations::AutomationField;
+pub use behaviors::BehaviorField;
+pub use remote_mount_backends::RemoteMountBackendField;
+pub use remote_servers::RemoteServerField;
+pub use sync_runs::SyncRunField;
+pub use syncs::SyncField;
@@ -1,12 +1,365 @@
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
 use crate::{Result, client::FilesClient, types::PaginationInfo};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Remote mount backend entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RemoteMountBackendEntity {
+    /// Backend ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Path of the canary file used for health checks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canary_file_path: Option<String>,
+
+    /// Whether the backend is enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// Failed health checks before the backend is marked down
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fall: Option<i64>,
+
+    /// Whether health checks are enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_check_enabled: Option<bool>,
+
+    /// Results of recent health checks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_check_results: Option<serde_json::Value>,
+
+    /// Type of health check
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_check_type: Option<String>,
+
+    /// Seconds between health checks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval: Option<i64>,
+
+    /// Minimum free CPU percentage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_free_cpu: Option<f64>,
+
+    /// Minimum free memory percentage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_free_mem: Option<f64>,
+
+    /// Backend priority
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+
+    /// Path on the remote server
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_path: Option<String>,
+
+    /// Remote server ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_server_id: Option<i64>,
+
+    /// Remote server mount ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_server_mount_id: Option<i64>,
+
+    /// Passed health checks before the backend is marked up
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rise: Option<i64>,
+
+    /// Backend status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// Whether the backend is undergoing maintenance
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub undergoing_maintenance: Option<bool>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parameters for creating a remote mount backend
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateRemoteMountBackendParams {
+    /// Remote server mount ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_server_mount_id: Option<i64>,
+
+    /// Remote server ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_server_id: Option<i64>,
+
+    /// Path of the canary file used for health checks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canary_file_path: Option<String>,
+
+    /// Whether the backend is enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// Failed health checks before the backend is marked down
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fall: Option<i64>,
+
+    /// Whether health checks are enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_check_enabled: Option<bool>,
+
+    /// Type of health check
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_check_type: Option<String>,
+
+    /// Seconds between health checks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval: Option<i64>,
+
+    /// Minimum free CPU percentage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_free_cpu: Option<f64>,
+
+    /// Minimum free memory percentage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_free_mem: Option<f64>,
+
+    /// Backend priority
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+
+    /// Path on the remote server
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_path: Option<String>,
+
+    /// Passed health checks before the backend is marked up
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rise: Option<i64>,
+}
+
+impl CreateRemoteMountBackendParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remote server mount ID
+    pub fn remote_server_mount_id(mut self, remote_server_mount_id: i64) -> Self {
+        self.remote_server_mount_id = Some(remote_server_mount_id);
+        self
+    }
+
+    /// Remote server ID
+    pub fn remote_server_id(mut self, remote_server_id: i64) -> Self {
+        self.remote_server_id = Some(remote_server_id);
+        self
+    }
+
+    /// Path of the canary file used for health checks
+    pub fn canary_file_path(mut self, canary_file_path: impl Into<String>) -> Self {
+        self.canary_file_path = Some(canary_file_path.into());
+        self
+    }
+
+    /// Whether the backend is enabled
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// Failed health checks before the backend is marked down
+    pub fn fall(mut self, fall: i64) -> Self {
+        self.fall = Some(fall);
+        self
+    }
+
+    /// Whether health checks are enabled
+    pub fn health_check_enabled(mut self, health_check_enabled: bool) -> Self {
+        self.health_check_enabled = Some(health_check_enabled);
+        self
+    }
+
+    /// Type of health check
+    pub fn health_check_type(mut self, health_check_type: impl Into<String>) -> Self {
+        self.health_check_type = Some(health_check_type.into());
+        self
+    }
+
+    /// Seconds between health checks
+    pub fn interval(mut self, interval: i64) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /// Minimum free CPU percentage
+    pub fn min_free_cpu(mut self, min_free_cpu: f64) -> Self {
+        self.min_free_cpu = Some(min_free_cpu);
+        self
+    }
+
+    /// Minimum free memory percentage
+    pub fn min_free_mem(mut self, min_free_mem: f64) -> Self {
+        self.min_free_mem = Some(min_free_mem);
+        self
+    }
+
+    /// Backend priority
+    pub fn priority(mut self, priority: i64) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Path on the remote server
+    pub fn remote_path(mut self, remote_path: impl Into<String>) -> Self {
+        self.remote_path = Some(remote_path.into());
+        self
+    }
+
+    /// Passed health checks before the backend is marked up
+    pub fn rise(mut self, rise: i64) -> Self {
+        self.rise = Some(rise);
+        self
+    }
+}
+
+/// Parameters for updating a remote mount backend
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateRemoteMountBackendParams {
+    /// Remote server ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_server_id: Option<i64>,
+
+    /// Path of the canary file used for health checks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canary_file_path: Option<String>,
+
+    /// Whether the backend is enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// Failed health checks before the backend is marked down
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fall: Option<i64>,
+
+    /// Whether health checks are enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_check_enabled: Option<bool>,
+
+    /// Type of health check
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_check_type: Option<String>,
+
+    /// Seconds between health checks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval: Option<i64>,
+
+    /// Minimum free CPU percentage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_free_cpu: Option<f64>,
+
+    /// Minimum free memory percentage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_free_mem: Option<f64>,
+
+    /// Backend priority
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+
+    /// Path on the remote server
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_path: Option<String>,
+
+    /// Passed health checks before the backend is marked up
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rise: Option<i64>,
+}
+
+impl UpdateRemoteMountBackendParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remote server ID
+    pub fn remote_server_id(mut self, remote_server_id: i64) -> Self {
+        self.remote_server_id = Some(remote_server_id);
+        self
+    }
+
+    /// Path of the canary file used for health checks
+    pub fn canary_file_path(mut self, canary_file_path: impl Into<String>) -> Self {
+        self.canary_file_path = Some(canary_file_path.into());
+        self
+    }
+
+    /// Whether the backend is enabled
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// Failed health checks before the backend is marked down
+    pub fn fall(mut self, fall: i64) -> Self {
+        self.fall = Some(fall);
+        self
+    }
+
+    /// Whether health checks are enabled
+    pub fn health_check_enabled(mut self, health_check_enabled: bool) -> Self {
+        self.health_check_enabled = Some(health_check_enabled);
+        self
+    }
+
+    /// Type of health check
+    pub fn health_check_type(mut self, health_check_type: impl Into<String>) -> Self {
+        self.health_check_type = Some(health_check_type.into());
+        self
+    }
+
+    /// Seconds between health checks
+    pub fn interval(mut self, interval: i64) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /// Minimum free CPU percentage
+    pub fn min_free_cpu(mut self, min_free_cpu: f64) -> Self {
+        self.min_free_cpu = Some(min_free_cpu);
+        self
+    }
+
+    /// Minimum free memory percentage
+    pub fn min_free_mem(mut self, min_free_mem: f64) -> Self {
+        self.min_free_mem = Some(min_free_mem);
+        self
+    }
+
+    /// Backend priority
+    pub fn priority(mut self, priority: i64) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Path on the remote server
+    pub fn remote_path(mut self, remote_path: impl Into<String>) -> Self {
+        self.remote_path = Some(remote_path.into());
+        self
+    }
+
+    /// Passed health checks before the backend is marked up
+    pub fn rise(mut self, rise: i64) -> Self {
+        self.rise = Some(rise);
+        self
+    }
 }
 
+query_fields! {
+    /// Fields that remote mount backends can be sorted and filtered by
+    pub enum RemoteMountBackendField {
+        RemoteServerId => "remote_server_id",
+        CanaryFilePath => "canary_file_path",
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct RemoteMountBackendHandler {
     client: FilesClient,
 }
@@ -20,6 +373,7 @@ impl RemoteMountBackendHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<RemoteMountBackendField>>,
     ) -> Result<(Vec<RemoteMountBackendEntity>, PaginationInfo)> {
         let mut endpoint = "/remote_mount_backends".to_string();
         let mut query_params = Vec::new();
@@ -37,36 +391,39 @@ impl RemoteMountBackendHandler {
             endpoint.push_str(&query_params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
 
-        let entities: Vec<RemoteMountBackendEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let entities: Vec<RemoteMountBackendEntity> = serde_json::from_value(response)?;
         Ok((entities, pagination))
     }
 
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<RemoteMountBackendField>>,
+    ) -> Paginator<RemoteMountBackendEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
+
     pub async fn get(&self, id: i64) -> Result<RemoteMountBackendEntity> {
         let endpoint = format!("/remote_mount_backends/{}", id);
         let response = self.client.get_raw(&endpoint).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn create(&self, params: serde_json::Value) -> Result<RemoteMountBackendEntity> {
+    pub async fn create(
+        &self,
+        params: CreateRemoteMountBackendParams,
+    ) -> Result<RemoteMountBackendEntity> {
         let response = self
             .client
             .post_raw("/remote_mount_backends", params)
@@ -77,7 +434,7 @@ impl RemoteMountBackendHandler {
     pub async fn update(
         &self,
         id: i64,
-        params: serde_json::Value,
+        params: UpdateRemoteMountBackendParams,
     ) -> Result<RemoteMountBackendEntity> {
         let endpoint = format!("/remote_mount_backends/{}", id);
         let response = self.client.patch_raw(&endpoint, params).await?;
@@ -90,10 +447,11 @@ impl RemoteMountBackendHandler {
         Ok(())
     }
 
+    /// Tests the backend with `params` applied on top of its saved settings
     pub async fn test_configuration(
         &self,
         id: i64,
-        params: serde_json::Value,
+        params: UpdateRemoteMountBackendParams,
     ) -> Result<RemoteMountBackendEntity> {
         let endpoint = format!("/remote_mount_backends/{}/test_configuration", id);
         let response = self.client.post_raw(&endpoint, params).await?;
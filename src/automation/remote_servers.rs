@@ -3,6 +3,8 @@
 //! Remote Servers represent connections to external storage providers like S3, Azure,
 //! FTP/SFTP servers, and more for syncing or mounting.
 
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
 use crate::{FilesClient, PaginationInfo, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -231,7 +233,16 @@ pub struct RemoteServerEntity {
     pub google_cloud_storage_s3_compatible_access_key: Option<String>,
 }
 
+query_fields! {
+    /// Fields that remote servers can be sorted and filtered by
+    pub enum RemoteServerField {
+        Name => "name",
+        ServerType => "server_type",
+    }
+}
+
 /// Handler for remote server operations
+#[derive(Debug, Clone)]
 pub struct RemoteServerHandler {
     client: FilesClient,
 }
@@ -247,6 +258,7 @@ impl RemoteServerHandler {
     /// # Arguments
     /// * `cursor` - Pagination cursor
     /// * `per_page` - Results per page
+    /// * `query` - Sorting and filtering options (optional)
     ///
     /// # Returns
     /// Tuple of (remote_servers, pagination_info)
@@ -258,7 +270,7 @@ impl RemoteServerHandler {
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = FilesClient::builder().api_key("key").build()?;
     /// let handler = RemoteServerHandler::new(client);
-    /// let (servers, _) = handler.list(None, None).await?;
+    /// let (servers, _) = handler.list(None, None, None).await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -266,6 +278,7 @@ impl RemoteServerHandler {
         &self,
         cursor: Option<&str>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<RemoteServerField>>,
     ) -> Result<(Vec<RemoteServerEntity>, PaginationInfo)> {
         let mut params = vec![];
         if let Some(c) = cursor {
@@ -275,7 +288,7 @@ impl RemoteServerHandler {
             params.push(("per_page", pp.to_string()));
         }
 
-        let query = if params.is_empty() {
+        let query_string = if params.is_empty() {
             String::new()
         } else {
             format!(
@@ -288,20 +301,37 @@ impl RemoteServerHandler {
             )
         };
 
-        let response = self
-            .client
-            .get_raw(&format!("/remote_servers{}", query))
-            .await?;
-        let servers: Vec<RemoteServerEntity> = serde_json::from_value(response)?;
+        let mut endpoint = format!("/remote_servers{}", query_string);
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
 
-        let pagination = PaginationInfo {
-            cursor_next: None,
-            cursor_prev: None,
-        };
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let servers: Vec<RemoteServerEntity> = serde_json::from_value(response)?;
 
         Ok((servers, pagination))
     }
 
+    /// Stream remote servers across all pages
+    ///
+    /// Takes the same arguments as [`list`](Self::list), without the cursor.
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<RemoteServerField>>,
+    ) -> Paginator<RemoteServerEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move {
+                handler
+                    .list(cursor.as_deref(), per_page, query.as_ref())
+                    .await
+            }
+        })
+    }
+
     /// Get a specific remote server
     ///
     /// # Arguments
@@ -1,12 +1,91 @@
-use crate::{Result, client::FilesClient, types::PaginationInfo};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{Result, Timestamp, client::FilesClient, types::PaginationInfo};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Sync run entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SyncRunEntity {
+    /// Run ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// ID of the sync that ran
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_id: Option<i64>,
+
+    /// Site ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub site_id: Option<i64>,
+
+    /// Name of the sync
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_name: Option<String>,
+
+    /// Run status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// Whether this was a dry run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<bool>,
+
+    /// Total bytes synced
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bytes_synced: Option<i64>,
+
+    /// Number of files compared
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compared_files: Option<i64>,
+
+    /// Number of folders compared
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compared_folders: Option<i64>,
+
+    /// Number of files that failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errored_files: Option<i64>,
+
+    /// Number of files synced successfully
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub successful_files: Option<i64>,
+
+    /// Runtime in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runtime: Option<f64>,
+
+    /// URL of the run log
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_url: Option<String>,
+
+    /// Completion time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<Timestamp>,
+
+    /// Start time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+
+    /// Last update time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Timestamp>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+query_fields! {
+    /// Fields that sync runs can be sorted and filtered by
+    pub enum SyncRunField {
+        SyncId => "sync_id",
+        CreatedAt => "created_at",
+        Status => "status",
+        DryRun => "dry_run",
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct SyncRunHandler {
     client: FilesClient,
 }
@@ -20,6 +99,7 @@ impl SyncRunHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<SyncRunField>>,
     ) -> Result<(Vec<SyncRunEntity>, PaginationInfo)> {
         let mut endpoint = "/sync_runs".to_string();
         let mut query_params = Vec::new();
@@ -37,29 +117,29 @@ impl SyncRunHandler {
             endpoint.push_str(&query_params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
 
-        let entities: Vec<SyncRunEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let entities: Vec<SyncRunEntity> = serde_json::from_value(response)?;
         Ok((entities, pagination))
     }
 
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<SyncRunField>>,
+    ) -> Paginator<SyncRunEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
+
     pub async fn get(&self, id: i64) -> Result<SyncRunEntity> {
         let endpoint = format!("/sync_runs/{}", id);
         let response = self.client.get_raw(&endpoint).await?;
@@ -1,12 +1,534 @@
-use crate::{Result, client::FilesClient, types::PaginationInfo};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{Result, Timestamp, client::FilesClient, types::PaginationInfo};
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Sync entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SyncEntity {
+    /// Sync ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Sync name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Sync description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Site ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub site_id: Option<i64>,
+
+    /// ID of the user who created the sync
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
+
+    /// Source path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_path: Option<String>,
+
+    /// Destination path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dest_path: Option<String>,
+
+    /// Source remote server ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_remote_server_id: Option<i64>,
+
+    /// Destination remote server ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dest_remote_server_id: Option<i64>,
+
+    /// Whether the sync is two-way
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub two_way: Option<bool>,
+
+    /// Whether to keep files on the source after copying
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keep_after_copy: Option<bool>,
+
+    /// Whether to delete empty folders after syncing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete_empty_folders: Option<bool>,
+
+    /// Whether the sync is disabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+
+    /// Schedule interval
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval: Option<String>,
+
+    /// Trigger type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger: Option<String>,
+
+    /// Name of the file that triggers the sync
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_file: Option<String>,
+
+    /// Glob patterns of files to include
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_patterns: Option<Vec<String>>,
+
+    /// Glob patterns of files to exclude
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_patterns: Option<Vec<String>>,
+
+    /// Creation time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+
+    /// Last update time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Timestamp>,
+
+    /// Minutes between scheduled runs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_interval_minutes: Option<i64>,
+
+    /// Days of the week the sync runs (0 is Sunday)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_days_of_week: Option<Vec<i64>>,
+
+    /// Times of day the sync runs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_times_of_day: Option<Vec<String>>,
+
+    /// Time zone of the schedule
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_time_zone: Option<String>,
+
+    /// Holiday region whose holidays are skipped
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub holiday_region: Option<String>,
+
+    /// Most recent sync run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_sync_run: Option<serde_json::Value>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parameters for creating a sync
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateSyncParams {
+    /// Sync name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Sync description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Source path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_path: Option<String>,
+
+    /// Destination path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dest_path: Option<String>,
+
+    /// Source remote server ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_remote_server_id: Option<i64>,
+
+    /// Destination remote server ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dest_remote_server_id: Option<i64>,
+
+    /// Whether the sync is two-way
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub two_way: Option<bool>,
+
+    /// Whether to keep files on the source after copying
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keep_after_copy: Option<bool>,
+
+    /// Whether to delete empty folders after syncing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete_empty_folders: Option<bool>,
+
+    /// Whether the sync is disabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+
+    /// Schedule interval
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval: Option<String>,
+
+    /// Trigger type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger: Option<String>,
+
+    /// Name of the file that triggers the sync
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_file: Option<String>,
+
+    /// Glob patterns of files to include
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_patterns: Option<Vec<String>>,
+
+    /// Glob patterns of files to exclude
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_patterns: Option<Vec<String>>,
+
+    /// Days of the week the sync runs (0 is Sunday)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_days_of_week: Option<Vec<i64>>,
+
+    /// Times of day the sync runs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_times_of_day: Option<Vec<String>>,
+
+    /// Time zone of the schedule
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_time_zone: Option<String>,
+
+    /// Holiday region whose holidays are skipped
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub holiday_region: Option<String>,
 }
 
+impl CreateSyncParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sync name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sync description
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Source path
+    pub fn src_path(mut self, src_path: impl Into<String>) -> Self {
+        self.src_path = Some(src_path.into());
+        self
+    }
+
+    /// Destination path
+    pub fn dest_path(mut self, dest_path: impl Into<String>) -> Self {
+        self.dest_path = Some(dest_path.into());
+        self
+    }
+
+    /// Source remote server ID
+    pub fn src_remote_server_id(mut self, src_remote_server_id: i64) -> Self {
+        self.src_remote_server_id = Some(src_remote_server_id);
+        self
+    }
+
+    /// Destination remote server ID
+    pub fn dest_remote_server_id(mut self, dest_remote_server_id: i64) -> Self {
+        self.dest_remote_server_id = Some(dest_remote_server_id);
+        self
+    }
+
+    /// Whether the sync is two-way
+    pub fn two_way(mut self, two_way: bool) -> Self {
+        self.two_way = Some(two_way);
+        self
+    }
+
+    /// Whether to keep files on the source after copying
+    pub fn keep_after_copy(mut self, keep_after_copy: bool) -> Self {
+        self.keep_after_copy = Some(keep_after_copy);
+        self
+    }
+
+    /// Whether to delete empty folders after syncing
+    pub fn delete_empty_folders(mut self, delete_empty_folders: bool) -> Self {
+        self.delete_empty_folders = Some(delete_empty_folders);
+        self
+    }
+
+    /// Whether the sync is disabled
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = Some(disabled);
+        self
+    }
+
+    /// Schedule interval
+    pub fn interval(mut self, interval: impl Into<String>) -> Self {
+        self.interval = Some(interval.into());
+        self
+    }
+
+    /// Trigger type
+    pub fn trigger(mut self, trigger: impl Into<String>) -> Self {
+        self.trigger = Some(trigger.into());
+        self
+    }
+
+    /// Name of the file that triggers the sync
+    pub fn trigger_file(mut self, trigger_file: impl Into<String>) -> Self {
+        self.trigger_file = Some(trigger_file.into());
+        self
+    }
+
+    /// Glob patterns of files to include
+    pub fn include_patterns(mut self, include_patterns: Vec<String>) -> Self {
+        self.include_patterns = Some(include_patterns);
+        self
+    }
+
+    /// Glob patterns of files to exclude
+    pub fn exclude_patterns(mut self, exclude_patterns: Vec<String>) -> Self {
+        self.exclude_patterns = Some(exclude_patterns);
+        self
+    }
+
+    /// Days of the week the sync runs (0 is Sunday)
+    pub fn schedule_days_of_week(mut self, schedule_days_of_week: Vec<i64>) -> Self {
+        self.schedule_days_of_week = Some(schedule_days_of_week);
+        self
+    }
+
+    /// Times of day the sync runs
+    pub fn schedule_times_of_day(mut self, schedule_times_of_day: Vec<String>) -> Self {
+        self.schedule_times_of_day = Some(schedule_times_of_day);
+        self
+    }
+
+    /// Time zone of the schedule
+    pub fn schedule_time_zone(mut self, schedule_time_zone: impl Into<String>) -> Self {
+        self.schedule_time_zone = Some(schedule_time_zone.into());
+        self
+    }
+
+    /// Holiday region whose holidays are skipped
+    pub fn holiday_region(mut self, holiday_region: impl Into<String>) -> Self {
+        self.holiday_region = Some(holiday_region.into());
+        self
+    }
+}
+
+/// Parameters for updating a sync
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateSyncParams {
+    /// Sync name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Sync description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Source path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_path: Option<String>,
+
+    /// Destination path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dest_path: Option<String>,
+
+    /// Source remote server ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_remote_server_id: Option<i64>,
+
+    /// Destination remote server ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dest_remote_server_id: Option<i64>,
+
+    /// Whether the sync is two-way
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub two_way: Option<bool>,
+
+    /// Whether to keep files on the source after copying
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keep_after_copy: Option<bool>,
+
+    /// Whether to delete empty folders after syncing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete_empty_folders: Option<bool>,
+
+    /// Whether the sync is disabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+
+    /// Schedule interval
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval: Option<String>,
+
+    /// Trigger type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger: Option<String>,
+
+    /// Name of the file that triggers the sync
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_file: Option<String>,
+
+    /// Glob patterns of files to include
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_patterns: Option<Vec<String>>,
+
+    /// Glob patterns of files to exclude
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_patterns: Option<Vec<String>>,
+
+    /// Days of the week the sync runs (0 is Sunday)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_days_of_week: Option<Vec<i64>>,
+
+    /// Times of day the sync runs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_times_of_day: Option<Vec<String>>,
+
+    /// Time zone of the schedule
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_time_zone: Option<String>,
+
+    /// Holiday region whose holidays are skipped
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub holiday_region: Option<String>,
+}
+
+impl UpdateSyncParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sync name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sync description
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Source path
+    pub fn src_path(mut self, src_path: impl Into<String>) -> Self {
+        self.src_path = Some(src_path.into());
+        self
+    }
+
+    /// Destination path
+    pub fn dest_path(mut self, dest_path: impl Into<String>) -> Self {
+        self.dest_path = Some(dest_path.into());
+        self
+    }
+
+    /// Source remote server ID
+    pub fn src_remote_server_id(mut self, src_remote_server_id: i64) -> Self {
+        self.src_remote_server_id = Some(src_remote_server_id);
+        self
+    }
+
+    /// Destination remote server ID
+    pub fn dest_remote_server_id(mut self, dest_remote_server_id: i64) -> Self {
+        self.dest_remote_server_id = Some(dest_remote_server_id);
+        self
+    }
+
+    /// Whether the sync is two-way
+    pub fn two_way(mut self, two_way: bool) -> Self {
+        self.two_way = Some(two_way);
+        self
+    }
+
+    /// Whether to keep files on the source after copying
+    pub fn keep_after_copy(mut self, keep_after_copy: bool) -> Self {
+        self.keep_after_copy = Some(keep_after_copy);
+        self
+    }
+
+    /// Whether to delete empty folders after syncing
+    pub fn delete_empty_folders(mut self, delete_empty_folders: bool) -> Self {
+        self.delete_empty_folders = Some(delete_empty_folders);
+        self
+    }
+
+    /// Whether the sync is disabled
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = Some(disabled);
+        self
+    }
+
+    /// Schedule interval
+    pub fn interval(mut self, interval: impl Into<String>) -> Self {
+        self.interval = Some(interval.into());
+        self
+    }
+
+    /// Trigger type
+    pub fn trigger(mut self, trigger: impl Into<String>) -> Self {
+        self.trigger = Some(trigger.into());
+        self
+    }
+
+    /// Name of the file that triggers the sync
+    pub fn trigger_file(mut self, trigger_file: impl Into<String>) -> Self {
+        self.trigger_file = Some(trigger_file.into());
+        self
+    }
+
+    /// Glob patterns of files to include
+    pub fn include_patterns(mut self, include_patterns: Vec<String>) -> Self {
+        self.include_patterns = Some(include_patterns);
+        self
+    }
+
+    /// Glob patterns of files to exclude
+    pub fn exclude_patterns(mut self, exclude_patterns: Vec<String>) -> Self {
+        self.exclude_patterns = Some(exclude_patterns);
+        self
+    }
+
+    /// Days of the week the sync runs (0 is Sunday)
+    pub fn schedule_days_of_week(mut self, schedule_days_of_week: Vec<i64>) -> Self {
+        self.schedule_days_of_week = Some(schedule_days_of_week);
+        self
+    }
+
+    /// Times of day the sync runs
+    pub fn schedule_times_of_day(mut self, schedule_times_of_day: Vec<String>) -> Self {
+        self.schedule_times_of_day = Some(schedule_times_of_day);
+        self
+    }
+
+    /// Time zone of the schedule
+    pub fn schedule_time_zone(mut self, schedule_time_zone: impl Into<String>) -> Self {
+        self.schedule_time_zone = Some(schedule_time_zone.into());
+        self
+    }
+
+    /// Holiday region whose holidays are skipped
+    pub fn holiday_region(mut self, holiday_region: impl Into<String>) -> Self {
+        self.holiday_region = Some(holiday_region.into());
+        self
+    }
+}
+
+query_fields! {
+    /// Fields that syncs can be sorted and filtered by
+    pub enum SyncField {
+        Name => "name",
+        Disabled => "disabled",
+        SrcRemoteServerId => "src_remote_server_id",
+        DestRemoteServerId => "dest_remote_server_id",
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct SyncHandler {
     client: FilesClient,
 }
@@ -20,6 +542,7 @@ impl SyncHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<SyncField>>,
     ) -> Result<(Vec<SyncEntity>, PaginationInfo)> {
         let mut endpoint = "/syncs".to_string();
         let mut query_params = Vec::new();
@@ -37,41 +560,41 @@ impl SyncHandler {
             endpoint.push_str(&query_params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
 
-        let entities: Vec<SyncEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let entities: Vec<SyncEntity> = serde_json::from_value(response)?;
         Ok((entities, pagination))
     }
 
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<SyncField>>,
+    ) -> Paginator<SyncEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
+
     pub async fn get(&self, id: i64) -> Result<SyncEntity> {
         let endpoint = format!("/syncs/{}", id);
         let response = self.client.get_raw(&endpoint).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn create(&self, params: serde_json::Value) -> Result<SyncEntity> {
+    pub async fn create(&self, params: CreateSyncParams) -> Result<SyncEntity> {
         let response = self.client.post_raw("/syncs", params).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn update(&self, id: i64, params: serde_json::Value) -> Result<SyncEntity> {
+    pub async fn update(&self, id: i64, params: UpdateSyncParams) -> Result<SyncEntity> {
         let endpoint = format!("/syncs/{}", id);
         let response = self.client.patch_raw(&endpoint, params).await?;
         Ok(serde_json::from_value(response)?)
@@ -83,15 +606,15 @@ impl SyncHandler {
         Ok(())
     }
 
-    pub async fn manual_run(&self, id: i64, params: serde_json::Value) -> Result<()> {
+    pub async fn manual_run(&self, id: i64) -> Result<()> {
         let endpoint = format!("/syncs/{}/manual_run", id);
-        self.client.post_raw(&endpoint, params).await?;
+        self.client.post_raw(&endpoint, json!({})).await?;
         Ok(())
     }
 
-    pub async fn dry_run(&self, id: i64, params: serde_json::Value) -> Result<SyncEntity> {
+    pub async fn dry_run(&self, id: i64) -> Result<SyncEntity> {
         let endpoint = format!("/syncs/{}/dry_run", id);
-        let response = self.client.post_raw(&endpoint, params).await?;
+        let response = self.client.post_raw(&endpoint, json!({})).await?;
         Ok(serde_json::from_value(response)?)
     }
 }
@@ -6,10 +6,13 @@
 //! The client is designed around a builder pattern for flexible configuration and supports
 //! both typed and untyped API interactions.
 
-use crate::{FilesError, Result};
-use reqwest::Client;
+use crate::auth::{Auth, CredentialRefresher, SharedRefresher, TokenProvider};
+use crate::error::ApiErrorDetail;
+use crate::rate_limit::{RateLimit, RateLimitStats, RateLimiter};
+use crate::{FilesError, PaginationInfo, Result, RetryPolicy};
+use reqwest::{Client, Method, RequestBuilder};
 use serde::Serialize;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 use std::time::Duration;
 
 #[cfg(feature = "tracing")]
@@ -34,6 +37,11 @@ const USER_AGENT: &str = concat!("Files.com Rust SDK ", env!("CARGO_PKG_VERSION"
 ///     .api_key("your-api-key")
 ///     .build()?;
 ///
+/// // Session-based configuration
+/// let client = FilesClient::builder()
+///     .session_id("session-id-from-login")
+///     .build()?;
+///
 /// // Advanced configuration
 /// let client = FilesClient::builder()
 ///     .api_key("your-api-key")
@@ -44,17 +52,27 @@ const USER_AGENT: &str = concat!("Files.com Rust SDK ", env!("CARGO_PKG_VERSION"
 /// ```
 #[derive(Debug, Clone)]
 pub struct FilesClientBuilder {
-    api_key: Option<String>,
+    auth: Option<Auth>,
+    refresher: Option<SharedRefresher>,
     base_url: String,
     timeout: Duration,
+    retry_policy: RetryPolicy,
+    rate_limit: Option<RateLimit>,
+    #[cfg(feature = "tower")]
+    layers: crate::middleware::LayerStack,
 }
 
 impl Default for FilesClientBuilder {
     fn default() -> Self {
         Self {
-            api_key: None,
+            auth: None,
+            refresher: None,
             base_url: "https://app.files.com/api/rest/v1".to_string(),
             timeout: Duration::from_secs(60),
+            retry_policy: RetryPolicy::none(),
+            rate_limit: None,
+            #[cfg(feature = "tower")]
+            layers: crate::middleware::LayerStack::default(),
         }
     }
 }
@@ -66,7 +84,50 @@ impl FilesClientBuilder {
     ///
     /// * `api_key` - Your Files.com API key
     pub fn api_key<S: Into<String>>(mut self, api_key: S) -> Self {
-        self.api_key = Some(api_key.into());
+        self.auth = Some(Auth::ApiKey(api_key.into()));
+        self
+    }
+
+    /// Sets a session id for authentication
+    ///
+    /// # Arguments
+    ///
+    /// * `session_id` - Id of a session created by logging in
+    pub fn session_id<S: Into<String>>(mut self, session_id: S) -> Self {
+        self.auth = Some(Auth::Session(session_id.into()));
+        self
+    }
+
+    /// Sets a provider of bearer tokens for authentication
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - Source of tokens, asked before every request
+    pub fn token_provider(mut self, provider: impl TokenProvider + 'static) -> Self {
+        self.auth = Some(Auth::token(provider));
+        self
+    }
+
+    /// Sets the credentials for authentication
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` - API key, session or token provider credentials
+    pub fn auth(mut self, auth: Auth) -> Self {
+        self.auth = Some(auth);
+        self
+    }
+
+    /// Sets a refresher to obtain new credentials when a request returns 401
+    ///
+    /// The failed request is sent once more with the refreshed credentials.
+    ///
+    /// # Arguments
+    ///
+    /// * `refresher` - Source of replacement credentials, e.g.
+    ///   [`PasswordLogin`](crate::auth::PasswordLogin)
+    pub fn credential_refresher(mut self, refresher: impl CredentialRefresher + 'static) -> Self {
+        self.refresher = Some(SharedRefresher(Arc::new(refresher)));
         self
     }
 
@@ -90,18 +151,103 @@ impl FilesClientBuilder {
         self
     }
 
+    /// Sets the policy for retrying failed requests
+    ///
+    /// Requests are not retried unless a policy is set. See [`RetryPolicy`]
+    /// for the defaults it applies.
+    ///
+    /// # Arguments
+    ///
+    /// * `retry_policy` - Retry configuration applied to every request
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets a client-side rate limit for API requests
+    ///
+    /// The limit is shared by all clones of the client and the handlers
+    /// created from them. See [`RateLimit`] for how it adapts to `429`
+    /// responses.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate_limit` - Requests per second and burst size
+    pub fn rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// Adds a tower middleware layer around the client's HTTP transport
+    ///
+    /// Layers apply to every request the client sends. The first layer added
+    /// is the outermost. A [`tower::ServiceBuilder`] can be passed to add
+    /// several layers at once. See [`middleware`](crate::middleware) for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `layer` - Layer wrapping an [`HttpService`](crate::middleware::HttpService)
+    #[cfg(feature = "tower")]
+    pub fn layer<L>(mut self, layer: L) -> Self
+    where
+        L: tower::Layer<crate::middleware::HttpService> + Send + Sync + 'static,
+        L::Service: tower::Service<
+                crate::middleware::HttpRequest,
+                Response = crate::middleware::HttpResponse,
+            > + Clone
+            + Send
+            + Sync
+            + 'static,
+        <L::Service as tower::Service<crate::middleware::HttpRequest>>::Error:
+            Into<crate::middleware::BoxError>,
+        <L::Service as tower::Service<crate::middleware::HttpRequest>>::Future: Send + 'static,
+    {
+        self.layers.push(layer);
+        self
+    }
+
     /// Builds the FilesClient instance
     ///
     /// # Errors
     ///
     /// Returns an error if:
-    /// - API key is not set
+    /// - No credentials are set
     /// - HTTP client cannot be constructed
     pub fn build(self) -> Result<FilesClient> {
-        let api_key = self
-            .api_key
-            .ok_or_else(|| FilesError::ConfigError("API key is required".to_string()))?;
+        if self.auth.is_none() {
+            return Err(FilesError::ConfigError(
+                "API key or other credentials are required".to_string(),
+            ));
+        }
+
+        self.build_client()
+    }
 
+    /// Builds a client by logging in with a username and password
+    ///
+    /// The client authenticates with the session created by the login.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - Username to sign in as
+    /// * `password` - Password for sign in
+    /// * `otp` - One-time password for 2FA (optional)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be constructed or the login fails
+    pub async fn login(
+        self,
+        username: &str,
+        password: &str,
+        otp: Option<&str>,
+    ) -> Result<FilesClient> {
+        let client = self.build_client()?;
+        client.login(username, password, otp).await?;
+        Ok(client)
+    }
+
+    fn build_client(self) -> Result<FilesClient> {
         let client = Client::builder()
             .timeout(self.timeout)
             .build()
@@ -109,9 +255,18 @@ impl FilesClientBuilder {
 
         Ok(FilesClient {
             inner: Arc::new(FilesClientInner {
-                api_key,
+                auth: RwLock::new(AuthState {
+                    auth: self.auth,
+                    generation: 0,
+                }),
+                refresher: self.refresher,
+                refresh_lock: tokio::sync::Mutex::new(()),
                 base_url: self.base_url,
+                #[cfg(feature = "tower")]
+                service: self.layers.build(client.clone()),
                 client,
+                retry_policy: self.retry_policy,
+                rate_limiter: RateLimiter::new(self.rate_limit),
             }),
         })
     }
@@ -120,9 +275,22 @@ impl FilesClientBuilder {
 /// Internal client state
 #[derive(Debug)]
 pub(crate) struct FilesClientInner {
-    pub(crate) api_key: String,
+    pub(crate) auth: RwLock<AuthState>,
+    pub(crate) refresher: Option<SharedRefresher>,
+    pub(crate) refresh_lock: tokio::sync::Mutex<()>,
     pub(crate) base_url: String,
     pub(crate) client: Client,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) rate_limiter: RateLimiter,
+    #[cfg(feature = "tower")]
+    pub(crate) service: Option<crate::middleware::HttpService>,
+}
+
+/// Current credentials and how often they have been replaced
+#[derive(Debug)]
+pub(crate) struct AuthState {
+    pub(crate) auth: Option<Auth>,
+    pub(crate) generation: u64,
 }
 
 /// Files.com API client
@@ -157,6 +325,99 @@ impl FilesClient {
         FilesClientBuilder::default()
     }
 
+    /// Replaces the credentials used for subsequent requests
+    ///
+    /// All clones of this client and the handlers created from them share
+    /// the new credentials.
+    pub fn set_auth(&self, auth: Auth) {
+        let mut state = self.inner.auth.write().unwrap_or_else(|e| e.into_inner());
+        state.auth = Some(auth);
+        state.generation += 1;
+    }
+
+    /// Returns counters describing how API requests were throttled
+    ///
+    /// Counts are shared by all clones of this client. `429` responses are
+    /// counted even when no [`RateLimit`] is configured.
+    pub fn rate_limit_stats(&self) -> RateLimitStats {
+        self.inner.rate_limiter.stats()
+    }
+
+    /// Logs in with a username and password and uses the new session
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - Username to sign in as
+    /// * `password` - Password for sign in
+    /// * `otp` - One-time password for 2FA (optional)
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use files_sdk::FilesClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = FilesClient::builder().api_key("key").build()?;
+    /// client.login("username", "password", Some("123456")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the login is rejected or the request fails
+    pub async fn login(&self, username: &str, password: &str, otp: Option<&str>) -> Result<()> {
+        let session_id = self.create_session(username, password, otp).await?;
+        self.set_auth(Auth::Session(session_id));
+        Ok(())
+    }
+
+    /// Creates a session and returns its id without using it
+    ///
+    /// The request is sent without the client's current credentials.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - Username to sign in as
+    /// * `password` - Password for sign in
+    /// * `otp` - One-time password for 2FA (optional)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the login is rejected or the request fails
+    pub async fn create_session(
+        &self,
+        username: &str,
+        password: &str,
+        otp: Option<&str>,
+    ) -> Result<String> {
+        let url = format!("{}/sessions", self.inner.base_url);
+
+        let mut body = serde_json::json!({
+            "username": username,
+            "password": password,
+        });
+        if let Some(otp) = otp {
+            body["otp"] = serde_json::json!(otp);
+        }
+
+        let request = self
+            .inner
+            .client
+            .post(&url)
+            .header("User-Agent", USER_AGENT)
+            .json(&body)
+            .build()?;
+
+        let (response, _) = self.execute_request(request, false).await?;
+        let session: crate::users::SessionEntity = serde_json::from_value(response)?;
+        session.id.ok_or_else(|| FilesError::AuthenticationFailed {
+            message: "Login response did not include a session id".to_string(),
+            auth_type: Some("session".to_string()),
+            detail: None,
+        })
+    }
+
     /// Performs a GET request to the Files.com API
     ///
     /// # Arguments
@@ -173,19 +434,30 @@ impl FilesClient {
         #[cfg(feature = "tracing")]
         debug!("Making GET request to {}", path);
 
-        let response = self
-            .inner
-            .client
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.inner.api_key)
-            .header("User-Agent", USER_AGENT)
-            .send()
-            .await?;
+        self.execute(self.request(Method::GET, &url)).await
+    }
+
+    /// Performs a GET request and returns the body with pagination cursors
+    ///
+    /// List endpoints return their cursors in the `X-Files-Cursor-Next` and
+    /// `X-Files-Cursor-Prev` response headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - API endpoint path (without base URL), including any query string
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or returns a non-success status code
+    #[cfg_attr(feature = "tracing", instrument(skip(self), fields(method = "GET")))]
+    pub async fn get_paginated(&self, path: &str) -> Result<(serde_json::Value, PaginationInfo)> {
+        let url = format!("{}{}", self.inner.base_url, path);
 
         #[cfg(feature = "tracing")]
-        debug!("GET response status: {}", response.status());
+        debug!("Making paginated GET request to {}", path);
 
-        self.handle_response(response).await
+        self.execute_paginated(self.request(Method::GET, &url))
+            .await
     }
 
     /// Performs a POST request to the Files.com API
@@ -210,21 +482,12 @@ impl FilesClient {
 
         let json_body = serde_json::to_string(&body).map_err(FilesError::JsonError)?;
 
-        let response = self
-            .inner
-            .client
-            .post(&url)
-            .header("X-FilesAPI-Key", &self.inner.api_key)
-            .header("User-Agent", USER_AGENT)
+        let request = self
+            .request(Method::POST, &url)
             .header("Content-Type", "application/json")
-            .body(json_body)
-            .send()
-            .await?;
-
-        #[cfg(feature = "tracing")]
-        debug!("POST response status: {}", response.status());
+            .body(json_body);
 
-        self.handle_response(response).await
+        self.execute(request).await
     }
 
     /// Performs a PATCH request to the Files.com API
@@ -249,21 +512,12 @@ impl FilesClient {
 
         let json_body = serde_json::to_string(&body).map_err(FilesError::JsonError)?;
 
-        let response = self
-            .inner
-            .client
-            .patch(&url)
-            .header("X-FilesAPI-Key", &self.inner.api_key)
-            .header("User-Agent", USER_AGENT)
+        let request = self
+            .request(Method::PATCH, &url)
             .header("Content-Type", "application/json")
-            .body(json_body)
-            .send()
-            .await?;
+            .body(json_body);
 
-        #[cfg(feature = "tracing")]
-        debug!("PATCH response status: {}", response.status());
-
-        self.handle_response(response).await
+        self.execute(request).await
     }
 
     /// Performs a DELETE request to the Files.com API
@@ -282,19 +536,7 @@ impl FilesClient {
         #[cfg(feature = "tracing")]
         debug!("Making DELETE request to {}", path);
 
-        let response = self
-            .inner
-            .client
-            .delete(&url)
-            .header("X-FilesAPI-Key", &self.inner.api_key)
-            .header("User-Agent", USER_AGENT)
-            .send()
-            .await?;
-
-        #[cfg(feature = "tracing")]
-        debug!("DELETE response status: {}", response.status());
-
-        self.handle_response(response).await
+        self.execute(self.request(Method::DELETE, &url)).await
     }
 
     /// Performs a POST request with form data to the Files.com API
@@ -310,126 +552,382 @@ impl FilesClient {
     pub async fn post_form<T: Serialize>(&self, path: &str, form: T) -> Result<serde_json::Value> {
         let url = format!("{}{}", self.inner.base_url, path);
 
-        let response = self
-            .inner
+        self.execute(self.request(Method::POST, &url).form(&form))
+            .await
+    }
+
+    /// Creates a request with the User-Agent header set
+    ///
+    /// Credentials are added when the request is sent.
+    fn request(&self, method: Method, url: &str) -> RequestBuilder {
+        self.inner
             .client
-            .post(&url)
-            .header("X-FilesAPI-Key", &self.inner.api_key)
+            .request(method, url)
             .header("User-Agent", USER_AGENT)
-            .form(&form)
-            .send()
-            .await?;
+    }
+
+    /// Adds the current credentials to a request
+    ///
+    /// Returns the generation of the credentials that were used.
+    async fn authorize(&self, request: &mut reqwest::Request) -> Result<u64> {
+        let (auth, generation) = {
+            let state = self.inner.auth.read().unwrap_or_else(|e| e.into_inner());
+            (state.auth.clone(), state.generation)
+        };
+
+        if let Some(auth) = auth {
+            let (name, value) = auth.header().await?;
+            let mut value = reqwest::header::HeaderValue::from_str(&value).map_err(|_| {
+                FilesError::ConfigError("Credentials contain invalid characters".to_string())
+            })?;
+            value.set_sensitive(true);
+            request.headers_mut().insert(name, value);
+        }
+
+        Ok(generation)
+    }
+
+    /// Replaces the credentials after a 401, unless another request already did
+    async fn refresh_auth(&self, refresher: &SharedRefresher, generation: u64) -> Result<()> {
+        let _guard = self.inner.refresh_lock.lock().await;
+
+        let current = self
+            .inner
+            .auth
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .generation;
+        if current != generation {
+            return Ok(());
+        }
+
+        #[cfg(feature = "tracing")]
+        debug!("Refreshing credentials after authentication failure");
 
-        self.handle_response(response).await
+        let auth = refresher.0.refresh(self).await?;
+        self.set_auth(auth);
+        Ok(())
     }
 
-    /// Handles HTTP response and converts to Result
+    /// Sends a request, retrying it according to the client's [`RetryPolicy`]
     ///
-    /// Processes status codes and extracts error information when applicable
-    async fn handle_response(&self, response: reqwest::Response) -> Result<serde_json::Value> {
-        let status = response.status();
+    /// Every API call goes through here, so the retry policy applies
+    /// uniformly to all handlers.
+    async fn execute(&self, request: RequestBuilder) -> Result<serde_json::Value> {
+        self.execute_paginated(request)
+            .await
+            .map(|(value, _)| value)
+    }
 
-        if status.is_success() {
-            // Handle 204 No Content
-            if status.as_u16() == 204 {
-                #[cfg(feature = "tracing")]
-                debug!("Received 204 No Content response");
-                return Ok(serde_json::Value::Null);
-            }
+    /// Like [`execute`](Self::execute), also returning the pagination cursors
+    async fn execute_paginated(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<(serde_json::Value, PaginationInfo)> {
+        self.execute_request(request.build()?, true).await
+    }
 
-            // Use serde_path_to_error for better error messages
-            let text = response.text().await?;
-            let deserializer = &mut serde_json::Deserializer::from_str(&text);
-            let value: serde_json::Value =
-                serde_path_to_error::deserialize(deserializer).map_err(|e| {
-                    FilesError::JsonPathError {
-                        path: e.path().to_string(),
-                        source: e.into_inner(),
-                    }
-                })?;
-            Ok(value)
-        } else {
-            let status_code = status.as_u16();
-            let error_body = response.text().await.unwrap_or_default();
+    /// Sends a built request with retries and parses its JSON body
+    ///
+    /// With `authenticate` set, the client's credentials are added and
+    /// refreshed once on a 401 if a refresher is configured.
+    async fn execute_request(
+        &self,
+        request: reqwest::Request,
+        authenticate: bool,
+    ) -> Result<(serde_json::Value, PaginationInfo)> {
+        let response = self.send_with_retry(request, authenticate).await?;
+        let pagination = PaginationInfo::from_headers(response.headers());
+        self.parse_body(response)
+            .await
+            .map(|value| (value, pagination))
+    }
 
-            #[cfg(feature = "tracing")]
-            warn!(
-                status_code = status_code,
-                error_body = %error_body,
-                "API request failed"
-            );
-
-            // Try to parse error message from JSON
-            let message = if let Ok(json) = serde_json::from_str::<serde_json::Value>(&error_body) {
-                json.get("error")
-                    .or_else(|| json.get("message"))
-                    .and_then(|v| v.as_str())
-                    .unwrap_or(&error_body)
-                    .to_string()
+    /// Sends a built request with retries and returns the successful response unread
+    ///
+    /// Requests pass through the middleware stack, the rate limiter and the
+    /// retry policy. Error statuses are turned into errors as for API calls.
+    /// Download URIs are sent through here without `authenticate`, so no
+    /// credentials leave for the storage backend.
+    pub(crate) async fn send_with_retry(
+        &self,
+        request: reqwest::Request,
+        authenticate: bool,
+    ) -> Result<reqwest::Response> {
+        let policy = &self.inner.retry_policy;
+        let endpoint = self.endpoint_of(request.url());
+        let mut refresher = self.inner.refresher.as_ref().filter(|_| authenticate);
+        let mut attempt = 1;
+
+        loop {
+            let mut attempt_request = request.try_clone().ok_or_else(|| {
+                FilesError::ConfigError("Request body cannot be cloned for retry".to_string())
+            })?;
+            let generation = if authenticate {
+                self.authorize(&mut attempt_request).await?
             } else {
-                error_body
+                0
             };
 
-            let error = match status_code {
-                400 => FilesError::BadRequest {
-                    message,
-                    field: None,
-                },
-                401 => FilesError::AuthenticationFailed {
-                    message,
-                    auth_type: None,
-                },
-                403 => FilesError::Forbidden {
-                    message,
-                    resource: None,
-                },
-                404 => FilesError::NotFound {
-                    message,
-                    resource_type: None,
-                    path: None,
-                },
-                409 => FilesError::Conflict {
-                    message,
-                    resource: None,
-                },
-                412 => FilesError::PreconditionFailed {
-                    message,
-                    condition: None,
-                },
-                422 => FilesError::UnprocessableEntity {
-                    message,
-                    field: None,
-                    value: None,
-                },
-                423 => FilesError::Locked {
-                    message,
-                    resource: None,
-                },
-                429 => FilesError::RateLimited {
-                    message,
-                    retry_after: None, // TODO: Parse Retry-After header
-                },
-                500 => FilesError::InternalServerError {
-                    message,
-                    request_id: None, // TODO: Parse request ID from headers
-                },
-                503 => FilesError::ServiceUnavailable {
-                    message,
-                    retry_after: None, // TODO: Parse Retry-After header
-                },
-                _ => FilesError::ApiError {
-                    code: status_code,
-                    message,
-                    endpoint: None,
-                },
+            self.inner.rate_limiter.acquire().await;
+            let result = match self.send(attempt_request).await {
+                Ok(response) => {
+                    #[cfg(feature = "tracing")]
+                    debug!(
+                        "{} response status: {}",
+                        request.method(),
+                        response.status()
+                    );
+
+                    if response.status().is_success() {
+                        Ok(response)
+                    } else {
+                        Err(self.error_from(request.method(), &endpoint, response).await)
+                    }
+                }
+                Err(e) => Err(e),
             };
 
-            #[cfg(feature = "tracing")]
-            error!(error = ?error, "Returning error to caller");
+            match &result {
+                Err(FilesError::RateLimited { retry_after, .. }) => {
+                    self.inner.rate_limiter.on_rate_limited(*retry_after)
+                }
+                Ok(_) => self.inner.rate_limiter.on_success(),
+                Err(_) => {}
+            }
 
-            Err(error)
+            // Refresh the credentials once and resend, without counting a retry
+            if let (Err(FilesError::AuthenticationFailed { .. }), Some(active)) =
+                (&result, refresher)
+            {
+                refresher = None;
+                self.refresh_auth(active, generation).await?;
+                continue;
+            }
+
+            match result {
+                Err(error) if policy.should_retry(request.method(), &error, attempt) => {
+                    let delay = policy.delay_for(&error, attempt);
+
+                    #[cfg(feature = "tracing")]
+                    warn!(
+                        attempt = attempt,
+                        delay_ms = delay.as_millis() as u64,
+                        error = %error,
+                        "Retrying request"
+                    );
+
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
         }
     }
+
+    /// Sends a single request over the client's transport
+    ///
+    /// With the `tower` feature, requests pass through the configured
+    /// middleware stack.
+    pub(crate) async fn send(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        #[cfg(feature = "tower")]
+        if let Some(service) = &self.inner.service {
+            return crate::middleware::dispatch(service, request).await;
+        }
+
+        Ok(self.inner.client.execute(request).await?)
+    }
+
+    /// The endpoint of a request URL, relative to the base URL
+    fn endpoint_of(&self, url: &reqwest::Url) -> String {
+        let base = self.inner.base_url.trim_end_matches('/');
+        match url.as_str().strip_prefix(base) {
+            Some(endpoint) if endpoint.starts_with('/') => endpoint.to_string(),
+            _ => url.path().to_string(),
+        }
+    }
+
+    /// Parses the JSON body of a successful response
+    async fn parse_body(&self, response: reqwest::Response) -> Result<serde_json::Value> {
+        // Handle 204 No Content
+        if response.status().as_u16() == 204 {
+            #[cfg(feature = "tracing")]
+            debug!("Received 204 No Content response");
+            return Ok(serde_json::Value::Null);
+        }
+
+        // Use serde_path_to_error for better error messages
+        let text = response.text().await?;
+        let deserializer = &mut serde_json::Deserializer::from_str(&text);
+        let value: serde_json::Value =
+            serde_path_to_error::deserialize(deserializer).map_err(|e| {
+                FilesError::JsonPathError {
+                    path: e.path().to_string(),
+                    source: e.into_inner(),
+                }
+            })?;
+        Ok(value)
+    }
+
+    /// Converts an error response into the matching [`FilesError`]
+    ///
+    /// Errors carry an [`ApiErrorDetail`] with the parsed error body and the
+    /// request context. `endpoint` is taken from the outgoing request, since
+    /// responses that pass through middleware no longer carry their URL.
+    async fn error_from(
+        &self,
+        method: &Method,
+        endpoint: &str,
+        response: reqwest::Response,
+    ) -> FilesError {
+        let status = response.status();
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(parse_retry_after);
+
+        let status_code = status.as_u16();
+        let endpoint = endpoint.to_string();
+        let headers = response.headers().clone();
+        let error_body = response.text().await.unwrap_or_default();
+
+        #[cfg(feature = "tracing")]
+        warn!(
+            status_code = status_code,
+            error_body = %error_body,
+            "API request failed"
+        );
+
+        let detail = ApiErrorDetail::parse(
+            method.as_str(),
+            endpoint.clone(),
+            status_code,
+            &headers,
+            error_body,
+        );
+        let message = detail.best_message();
+        let field = detail.field().map(str::to_string);
+        let request_id = detail.request_id.clone();
+        let detail = Some(Box::new(detail));
+
+        let error = match status_code {
+            400 => FilesError::BadRequest {
+                message,
+                field,
+                detail,
+            },
+            401 => FilesError::AuthenticationFailed {
+                message,
+                auth_type: None,
+                detail,
+            },
+            403 => FilesError::Forbidden {
+                message,
+                resource: None,
+                detail,
+            },
+            404 => FilesError::NotFound {
+                message,
+                resource_type: None,
+                path: None,
+                detail,
+            },
+            409 => FilesError::Conflict {
+                message,
+                resource: None,
+                detail,
+            },
+            412 => FilesError::PreconditionFailed {
+                message,
+                condition: None,
+                detail,
+            },
+            422 => FilesError::UnprocessableEntity {
+                message,
+                field,
+                value: None,
+                detail,
+            },
+            423 => FilesError::Locked {
+                message,
+                resource: None,
+                detail,
+            },
+            429 => FilesError::RateLimited {
+                message,
+                retry_after,
+                detail,
+            },
+            500 => FilesError::InternalServerError {
+                message,
+                request_id,
+                detail,
+            },
+            503 => FilesError::ServiceUnavailable {
+                message,
+                retry_after,
+                detail,
+            },
+            _ => FilesError::ApiError {
+                code: status_code,
+                message,
+                endpoint: Some(endpoint),
+                detail,
+            },
+        };
+
+        #[cfg(feature = "tracing")]
+        error!(error = ?error, "Returning error to caller");
+
+        error
+    }
+}
+
+/// Parses a `Retry-After` header value into seconds
+///
+/// Accepts both forms allowed by RFC 9110: a number of seconds, or an
+/// HTTP date such as `Wed, 21 Oct 2015 07:28:00 GMT`. Dates in the past
+/// yield zero.
+fn parse_retry_after(value: &str) -> Option<u64> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(seconds);
+    }
+
+    // IMF-fixdate: "Wed, 21 Oct 2015 07:28:00 GMT"
+    let (_, date) = value.split_once(", ")?;
+    let mut parts = date.split_whitespace();
+    let day = parts.next()?;
+    let month = match parts.next()? {
+        "Jan" => 1,
+        "Feb" => 2,
+        "Mar" => 3,
+        "Apr" => 4,
+        "May" => 5,
+        "Jun" => 6,
+        "Jul" => 7,
+        "Aug" => 8,
+        "Sep" => 9,
+        "Oct" => 10,
+        "Nov" => 11,
+        "Dec" => 12,
+        _ => return None,
+    };
+    let year = parts.next()?;
+    let time = parts.next()?;
+    if parts.next()? != "GMT" {
+        return None;
+    }
+
+    let at = crate::timestamp::parse_system_time(&format!(
+        "{}-{:02}-{:0>2}T{}Z",
+        year, month, day, time
+    ))?;
+    Some(
+        at.duration_since(std::time::SystemTime::now())
+            .map_or(0, |d| d.as_secs()),
+    )
 }
 
 #[cfg(test)]
@@ -453,7 +951,7 @@ mod tests {
             .base_url("https://custom.example.com")
             .timeout(Duration::from_secs(120));
 
-        assert_eq!(builder.api_key, Some("test-key".to_string()));
+        assert!(matches!(builder.auth, Some(Auth::ApiKey(ref key)) if key == "test-key"));
         assert_eq!(builder.base_url, "https://custom.example.com");
         assert_eq!(builder.timeout, Duration::from_secs(120));
     }
@@ -465,6 +963,15 @@ mod tests {
         assert!(matches!(result.unwrap_err(), FilesError::ConfigError(_)));
     }
 
+    #[test]
+    fn test_parse_retry_after() {
+        assert_eq!(parse_retry_after("120"), Some(120));
+        assert_eq!(parse_retry_after(" 0 "), Some(0));
+        assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"), Some(0));
+        assert!(parse_retry_after("Fri, 01 Jan 2100 00:00:00 GMT").unwrap() > 0);
+        assert_eq!(parse_retry_after("soon"), None);
+    }
+
     #[test]
     fn test_builder_success() {
         let result = FilesClientBuilder::default().api_key("test-key").build();
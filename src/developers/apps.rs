@@ -1,12 +1,89 @@
-use crate::{Result, client::FilesClient};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{PaginationInfo, Result, client::FilesClient};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// App entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppEntity {
+    /// App name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Long description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extended_description: Option<String>,
+
+    /// Short description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub short_description: Option<String>,
+
+    /// Links to documentation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub documentation_links: Option<serde_json::Value>,
+
+    /// Icon URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+
+    /// Logo URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+
+    /// Logo thumbnail URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo_thumbnail_url: Option<String>,
+
+    /// Screenshot URLs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub screenshot_list_urls: Option<Vec<String>>,
+
+    /// Associated SSO strategy type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sso_strategy_type: Option<String>,
+
+    /// Associated remote server type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_server_type: Option<String>,
+
+    /// Associated folder behavior type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub folder_behavior_type: Option<String>,
+
+    /// Homepage URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_homepage_url: Option<String>,
+
+    /// Marketing video URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub marketing_youtube_url: Option<String>,
+
+    /// Tutorial video URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tutorial_youtube_url: Option<String>,
+
+    /// App type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_type: Option<String>,
+
+    /// Whether the app is featured
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub featured: Option<bool>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+query_fields! {
+    /// Fields that apps can be sorted and filtered by
+    pub enum AppField {
+        Name => "name",
+        AppType => "app_type",
+    }
 }
 
+#[derive(Debug, Clone)]
 pub struct AppHandler {
     client: FilesClient,
 }
@@ -16,9 +93,47 @@ impl AppHandler {
         Self { client }
     }
 
-    pub async fn list(&self) -> Result<Vec<AppEntity>> {
-        let response = self.client.get_raw("/apps").await?;
+    pub async fn list(
+        &self,
+        cursor: Option<String>,
+        per_page: Option<i64>,
+        query: Option<&ListQuery<AppField>>,
+    ) -> Result<(Vec<AppEntity>, PaginationInfo)> {
+        let mut endpoint = "/apps".to_string();
+        let mut params = Vec::new();
+
+        if let Some(c) = cursor {
+            params.push(format!("cursor={}", c));
+        }
+        if let Some(pp) = per_page {
+            params.push(format!("per_page={}", pp));
+        }
+
+        if !params.is_empty() {
+            endpoint.push('?');
+            endpoint.push_str(&params.join("&"));
+        }
+
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
         let entities: Vec<AppEntity> = serde_json::from_value(response)?;
-        Ok(entities)
+        Ok((entities, pagination))
+    }
+
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<AppField>>,
+    ) -> Paginator<AppEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
     }
 }
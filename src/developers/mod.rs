@@ -10,3 +10,6 @@ pub use apps::AppHandler;
 
 // Re-export entities
 pub use apps::AppEntity;
+
+// Re-export query fields
+pub use apps::AppField;
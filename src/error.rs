@@ -3,6 +3,9 @@
 //! This module provides comprehensive error handling with contextual information
 //! to make debugging and error handling easier.
 
+use crate::error_kind::{ErrorCategory, ErrorKind};
+use serde_json::Value;
+use std::fmt;
 use thiserror::Error;
 
 /// Errors that can occur when using the Files.com API
@@ -21,6 +24,8 @@ pub enum FilesError {
         message: String,
         /// Optional field that caused the error
         field: Option<String>,
+        /// Details parsed from the Files.com error response
+        detail: Option<Box<ApiErrorDetail>>,
     },
 
     /// Authentication failed (401)
@@ -29,6 +34,8 @@ pub enum FilesError {
         message: String,
         /// The authentication method that failed
         auth_type: Option<String>,
+        /// Details parsed from the Files.com error response
+        detail: Option<Box<ApiErrorDetail>>,
     },
 
     /// Forbidden (403) - Valid credentials but insufficient permissions
@@ -37,6 +44,8 @@ pub enum FilesError {
         message: String,
         /// The resource that was forbidden
         resource: Option<String>,
+        /// Details parsed from the Files.com error response
+        detail: Option<Box<ApiErrorDetail>>,
     },
 
     /// Not Found (404) - Resource does not exist
@@ -47,6 +56,8 @@ pub enum FilesError {
         resource_type: Option<String>,
         /// Path or identifier of the resource
         path: Option<String>,
+        /// Details parsed from the Files.com error response
+        detail: Option<Box<ApiErrorDetail>>,
     },
 
     /// Conflict (409) - Resource already exists or state conflict
@@ -55,6 +66,8 @@ pub enum FilesError {
         message: String,
         /// The conflicting resource path or identifier
         resource: Option<String>,
+        /// Details parsed from the Files.com error response
+        detail: Option<Box<ApiErrorDetail>>,
     },
 
     /// Precondition Failed (412) - Conditional request failed
@@ -63,6 +76,8 @@ pub enum FilesError {
         message: String,
         /// The condition that failed
         condition: Option<String>,
+        /// Details parsed from the Files.com error response
+        detail: Option<Box<ApiErrorDetail>>,
     },
 
     /// Unprocessable Entity (422) - Validation error
@@ -73,6 +88,8 @@ pub enum FilesError {
         field: Option<String>,
         /// The invalid value provided
         value: Option<String>,
+        /// Details parsed from the Files.com error response
+        detail: Option<Box<ApiErrorDetail>>,
     },
 
     /// Locked (423) - Resource is locked
@@ -81,6 +98,8 @@ pub enum FilesError {
         message: String,
         /// The locked resource
         resource: Option<String>,
+        /// Details parsed from the Files.com error response
+        detail: Option<Box<ApiErrorDetail>>,
     },
 
     /// Rate Limited (429) - Too many requests
@@ -89,6 +108,8 @@ pub enum FilesError {
         message: String,
         /// Seconds until retry is allowed
         retry_after: Option<u64>,
+        /// Details parsed from the Files.com error response
+        detail: Option<Box<ApiErrorDetail>>,
     },
 
     /// Internal Server Error (500)
@@ -97,6 +118,8 @@ pub enum FilesError {
         message: String,
         /// Request ID for support purposes
         request_id: Option<String>,
+        /// Details parsed from the Files.com error response
+        detail: Option<Box<ApiErrorDetail>>,
     },
 
     /// Service Unavailable (503)
@@ -105,6 +128,8 @@ pub enum FilesError {
         message: String,
         /// Seconds until service might be available
         retry_after: Option<u64>,
+        /// Details parsed from the Files.com error response
+        detail: Option<Box<ApiErrorDetail>>,
     },
 
     /// Generic API error with status code
@@ -114,6 +139,8 @@ pub enum FilesError {
         message: String,
         /// The endpoint that returned the error
         endpoint: Option<String>,
+        /// Details parsed from the Files.com error response
+        detail: Option<Box<ApiErrorDetail>>,
     },
 
     /// Configuration error
@@ -138,6 +165,71 @@ pub enum FilesError {
     /// URL parsing error
     #[error("URL parse error: {0}")]
     UrlParseError(#[from] url::ParseError),
+
+    /// Transferred content does not match the checksum reported by Files.com
+    #[error("Checksum mismatch ({algorithm}): expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        /// Checksum reported by Files.com
+        expected: String,
+        /// Checksum of the transferred bytes
+        actual: String,
+        /// Algorithm both checksums were computed with
+        algorithm: crate::files::ChecksumAlgorithm,
+    },
+
+    /// Checksum verification was requested, but Files.com reports no checksum
+    #[error("No checksum reported for {path}; the transfer could not be verified")]
+    ChecksumUnavailable {
+        /// Remote path of the transferred file
+        path: String,
+    },
+
+    /// A copy or move ran as a file migration that did not complete
+    #[error("File migration {migration_id} ended with status '{status}'")]
+    MigrationFailed {
+        /// Id of the file migration
+        migration_id: i64,
+        /// Final status reported by Files.com (e.g. `failed`)
+        status: String,
+    },
+
+    /// Waiting for a file migration took longer than the configured timeout
+    #[error("File migration {migration_id} did not finish within {elapsed:?}")]
+    MigrationTimedOut {
+        /// Id of the file migration
+        migration_id: i64,
+        /// Time spent waiting before giving up
+        elapsed: std::time::Duration,
+    },
+
+    /// A history or action notification export could not be built
+    #[error("Export {export_id} ended with status '{status}'")]
+    ExportFailed {
+        /// Id of the export
+        export_id: i64,
+        /// Final status reported by Files.com
+        status: String,
+    },
+
+    /// Waiting for an export took longer than the configured timeout
+    #[error("Export {export_id} was not ready within {elapsed:?}")]
+    ExportTimedOut {
+        /// Id of the export
+        export_id: i64,
+        /// Time spent waiting before giving up
+        elapsed: std::time::Duration,
+    },
+
+    /// The operation was stopped through its cancellation token
+    #[error("Operation cancelled")]
+    Cancelled,
+
+    /// Error raised by a tower middleware layer (e.g. a timeout)
+    ///
+    /// Only produced with the `tower` feature, but always present so that
+    /// enabling the feature does not change this enum.
+    #[error("Middleware error: {0}")]
+    Middleware(#[source] Box<dyn std::error::Error + Send + Sync>),
 }
 
 impl FilesError {
@@ -147,6 +239,7 @@ impl FilesError {
             message: message.into(),
             resource_type: None,
             path: None,
+            detail: None,
         }
     }
 
@@ -160,6 +253,7 @@ impl FilesError {
             message: message.into(),
             resource_type: Some(resource_type.into()),
             path: Some(path.into()),
+            detail: None,
         }
     }
 
@@ -168,6 +262,7 @@ impl FilesError {
         FilesError::BadRequest {
             message: message.into(),
             field: None,
+            detail: None,
         }
     }
 
@@ -176,6 +271,7 @@ impl FilesError {
         FilesError::BadRequest {
             message: message.into(),
             field: Some(field.into()),
+            detail: None,
         }
     }
 
@@ -189,6 +285,7 @@ impl FilesError {
             message: message.into(),
             field: Some(field.into()),
             value: Some(value.into()),
+            detail: None,
         }
     }
 
@@ -197,6 +294,7 @@ impl FilesError {
         FilesError::RateLimited {
             message: message.into(),
             retry_after,
+            detail: None,
         }
     }
 
@@ -256,6 +354,53 @@ impl FilesError {
         )
     }
 
+    /// Details parsed from the Files.com error response, if any
+    ///
+    /// Present on errors created from an API response.
+    pub fn detail(&self) -> Option<&ApiErrorDetail> {
+        match self {
+            FilesError::BadRequest { detail, .. }
+            | FilesError::AuthenticationFailed { detail, .. }
+            | FilesError::Forbidden { detail, .. }
+            | FilesError::NotFound { detail, .. }
+            | FilesError::Conflict { detail, .. }
+            | FilesError::PreconditionFailed { detail, .. }
+            | FilesError::UnprocessableEntity { detail, .. }
+            | FilesError::Locked { detail, .. }
+            | FilesError::RateLimited { detail, .. }
+            | FilesError::InternalServerError { detail, .. }
+            | FilesError::ServiceUnavailable { detail, .. }
+            | FilesError::ApiError { detail, .. } => detail.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The Files.com error type of an API error
+    ///
+    /// Returns [`ErrorKind::Unknown`] when the response had no error type,
+    /// an error type missing from the catalog, or the error did not come
+    /// from an API response.
+    pub fn kind(&self) -> ErrorKind {
+        self.detail()
+            .and_then(|d| d.error_type.as_deref())
+            .map_or(ErrorKind::Unknown, ErrorKind::from_type)
+    }
+
+    /// The category of the Files.com error type of an API error
+    ///
+    /// Unlike [`kind`](Self::kind), this also classifies error types that
+    /// are missing from the catalog.
+    pub fn category(&self) -> ErrorCategory {
+        self.detail()
+            .and_then(|d| d.error_type.as_deref())
+            .map_or(ErrorCategory::Unknown, ErrorCategory::from_type)
+    }
+
+    /// Request id of the failed request, to quote in support tickets
+    pub fn request_id(&self) -> Option<&str> {
+        self.detail().and_then(|d| d.request_id.as_deref())
+    }
+
     /// Get retry-after duration if available
     pub fn retry_after(&self) -> Option<u64> {
         match self {
@@ -266,6 +411,209 @@ impl FilesError {
     }
 }
 
+/// Structured error returned by the Files.com API, with request context
+///
+/// Files.com error bodies look like:
+///
+/// ```json
+/// {
+///   "type": "not-authorized/insufficient-permission-for-params",
+///   "http-code": 403,
+///   "error": "You do not have permission to set these parameters.",
+///   "title": "Insufficient Permission For Params",
+///   "detail": "Ask a site administrator for access.",
+///   "errors": [{"type": "...", "message": "...", "field": "group_ids"}],
+///   "instance": "bc14a6a9-...",
+///   "model": "User"
+/// }
+/// ```
+///
+/// Every field is optional, since bodies vary between endpoints and
+/// proxies may return plain text. The request context (method, endpoint,
+/// status and request id) is always recorded.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use files_sdk::{FilesClient, UserHandler};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = FilesClient::builder().api_key("key").build()?;
+/// if let Err(e) = UserHandler::new(client).get(42).await {
+///     if let Some(detail) = e.detail() {
+///         eprintln!(
+///             "{} {} failed with {} ({:?}), request id {:?}",
+///             detail.method, detail.endpoint, detail.status, detail.error_type, detail.request_id
+///         );
+///         for sub in &detail.errors {
+///             eprintln!("  {:?}: {:?}", sub.field, sub.message);
+///         }
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ApiErrorDetail {
+    /// Error type slug, e.g. `not-found/file-not-found`
+    pub error_type: Option<String>,
+
+    /// HTTP status code reported in the body (`http-code`)
+    pub http_code: Option<u16>,
+
+    /// Error message (`error`, or `message` on some endpoints)
+    pub message: Option<String>,
+
+    /// Short human-readable title of the error type
+    pub title: Option<String>,
+
+    /// Longer explanation of the error
+    pub detail: Option<String>,
+
+    /// Individual errors, e.g. one per invalid field
+    pub errors: Vec<ApiSubError>,
+
+    /// Model the error relates to, e.g. `User`
+    pub model: Option<Value>,
+
+    /// Identifier of this error occurrence (`instance`)
+    pub instance: Option<String>,
+
+    /// HTTP method of the failed request
+    pub method: String,
+
+    /// Endpoint of the failed request, relative to the base URL
+    pub endpoint: String,
+
+    /// HTTP status of the response
+    pub status: u16,
+
+    /// Request id from the response headers, for Files.com support
+    pub request_id: Option<String>,
+
+    /// Raw response body
+    pub body: String,
+}
+
+/// One entry of the `errors` list in a Files.com error response
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ApiSubError {
+    /// Error type slug
+    pub error_type: Option<String>,
+
+    /// Error message
+    pub message: Option<String>,
+
+    /// Field the error relates to
+    pub field: Option<String>,
+}
+
+/// Response headers that may carry the request id, in order of preference
+const REQUEST_ID_HEADERS: &[&str] = &["x-request-id", "x-files-request-id", "x-amzn-requestid"];
+
+impl ApiErrorDetail {
+    /// Parses an error response
+    ///
+    /// Non-JSON bodies are kept in [`body`](Self::body) and leave the parsed
+    /// fields empty.
+    pub fn parse(
+        method: impl Into<String>,
+        endpoint: impl Into<String>,
+        status: u16,
+        headers: &reqwest::header::HeaderMap,
+        body: impl Into<String>,
+    ) -> Self {
+        let body = body.into();
+        let request_id = REQUEST_ID_HEADERS.iter().find_map(|name| {
+            headers
+                .get(*name)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string)
+        });
+
+        let mut detail = Self {
+            method: method.into(),
+            endpoint: endpoint.into(),
+            status,
+            request_id,
+            ..Self::default()
+        };
+
+        if let Ok(Value::Object(json)) = serde_json::from_str::<Value>(&body) {
+            let text = |key: &str| json.get(key).and_then(Value::as_str).map(str::to_string);
+            detail.error_type = text("type");
+            detail.http_code = json.get("http-code").and_then(|v| match v {
+                Value::Number(n) => n.as_u64().and_then(|n| u16::try_from(n).ok()),
+                Value::String(s) => s.parse().ok(),
+                _ => None,
+            });
+            detail.message = text("error").or_else(|| text("message"));
+            detail.title = text("title");
+            detail.detail = text("detail");
+            detail.instance = text("instance");
+            detail.model = json.get("model").filter(|v| !v.is_null()).cloned();
+            detail.errors = match json.get("errors") {
+                Some(Value::Array(errors)) => errors.iter().map(ApiSubError::from_json).collect(),
+                _ => Vec::new(),
+            };
+        }
+        detail.body = body;
+        detail
+    }
+
+    /// The most specific message available
+    ///
+    /// Falls back to the title, the detail and finally the raw body.
+    pub fn best_message(&self) -> String {
+        self.message
+            .as_ref()
+            .or(self.title.as_ref())
+            .or(self.detail.as_ref())
+            .cloned()
+            .unwrap_or_else(|| self.body.clone())
+    }
+
+    /// The first field named by a sub-error
+    pub fn field(&self) -> Option<&str> {
+        self.errors.iter().find_map(|e| e.field.as_deref())
+    }
+}
+
+impl fmt::Display for ApiErrorDetail {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} returned {}",
+            self.method, self.endpoint, self.status
+        )?;
+        if let Some(error_type) = &self.error_type {
+            write!(f, " ({})", error_type)?;
+        }
+        if let Some(request_id) = &self.request_id {
+            write!(f, ", request id {}", request_id)?;
+        }
+        Ok(())
+    }
+}
+
+impl ApiSubError {
+    fn from_json(value: &Value) -> Self {
+        let text = |key: &str| value.get(key).and_then(Value::as_str).map(str::to_string);
+        match value {
+            Value::String(message) => Self {
+                message: Some(message.clone()),
+                ..Self::default()
+            },
+            _ => Self {
+                error_type: text("type"),
+                message: text("message").or_else(|| text("error")),
+                field: text("field"),
+            },
+        }
+    }
+}
+
 /// Result type for Files.com operations
 pub type Result<T> = std::result::Result<T, FilesError>;
 
@@ -324,13 +672,69 @@ mod tests {
         assert!(
             FilesError::InternalServerError {
                 message: "test".to_string(),
-                request_id: None
+                request_id: None,
+                detail: None,
             }
             .is_retryable()
         );
         assert!(!FilesError::not_found("test").is_retryable());
     }
 
+    #[test]
+    fn test_middleware_error_without_feature() {
+        // Available regardless of the `tower` feature, so matches stay exhaustive
+        let error = FilesError::Middleware("request timed out".into());
+        assert_eq!(error.to_string(), "Middleware error: request timed out");
+        assert!(std::error::Error::source(&error).is_some());
+    }
+
+    #[test]
+    fn test_checksum_mismatch_display() {
+        let error = FilesError::ChecksumMismatch {
+            expected: "abc".to_string(),
+            actual: "def".to_string(),
+            algorithm: crate::files::ChecksumAlgorithm::Md5,
+        };
+        assert_eq!(
+            error.to_string(),
+            "Checksum mismatch (md5): expected abc, got def"
+        );
+        assert!(!error.is_retryable());
+        assert_eq!(error.status_code(), None);
+    }
+
+    #[test]
+    fn test_api_error_detail_parse() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("x-request-id", "abc".parse().unwrap());
+        let detail = ApiErrorDetail::parse(
+            "GET",
+            "/folders/x",
+            404,
+            &headers,
+            r#"{"type":"not-found/folder-not-found","http-code":404,"title":"Folder Not Found"}"#,
+        );
+
+        assert_eq!(
+            detail.error_type.as_deref(),
+            Some("not-found/folder-not-found")
+        );
+        assert_eq!(detail.http_code, Some(404));
+        assert_eq!(detail.request_id.as_deref(), Some("abc"));
+        assert_eq!(detail.best_message(), "Folder Not Found");
+        assert!(detail.errors.is_empty());
+    }
+
+    #[test]
+    fn test_api_error_detail_plain_body() {
+        let headers = reqwest::header::HeaderMap::new();
+        let detail = ApiErrorDetail::parse("POST", "/users", 502, &headers, "Bad Gateway");
+
+        assert_eq!(detail.message, None);
+        assert_eq!(detail.best_message(), "Bad Gateway");
+        assert_eq!(detail.to_string(), "POST /users returned 502");
+    }
+
     #[test]
     fn test_builder_pattern() {
         let error = FilesError::not_found("File not found")
@@ -0,0 +1,377 @@
+//! Classification of Files.com API errors by error type
+//!
+//! Files.com error responses carry a machine-readable `type` such as
+//! `processing-failure/destination-exists`: a category, a slash and the
+//! specific error. [`FilesError::kind`](crate::FilesError::kind) maps it to
+//! an [`ErrorKind`], so callers can tell apart errors that share an HTTP
+//! status without matching on message strings.
+//!
+//! The variants follow the error catalog in the Files.com API documentation.
+//! Types missing from the catalog map to [`ErrorKind::Unknown`]; their
+//! category is still available from [`ErrorCategory::from_type`] and the raw
+//! type from [`ApiErrorDetail::error_type`](crate::ApiErrorDetail::error_type).
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use files_sdk::{ErrorKind, FileHandler, FilesClient};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let client = FilesClient::builder().api_key("key").build()?;
+//! let files = FileHandler::new(client);
+//! match files.move_file("/inbox/report.pdf", "/archive/report.pdf").await {
+//!     Ok(_) => {}
+//!     Err(e) if e.kind() == ErrorKind::DestinationExists => {
+//!         files.delete_file("/inbox/report.pdf", false).await?;
+//!     }
+//!     Err(e) if e.kind() == ErrorKind::InvalidFilename => {
+//!         eprintln!("Rename the file first: {}", e);
+//!     }
+//!     Err(e) => return Err(e.into()),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+/// Category of a Files.com error type, the part before the slash
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCategory {
+    /// `bad-request`: the request was malformed or had invalid parameters
+    BadRequest,
+    /// `not-authenticated`: credentials were missing or invalid
+    NotAuthenticated,
+    /// `not-authorized`: the credentials lack a required permission
+    NotAuthorized,
+    /// `not-found`: the resource does not exist
+    NotFound,
+    /// `processing-failure`: the request was valid but could not be carried out
+    ProcessingFailure,
+    /// `rate-limited`: too many requests or attempts
+    RateLimited,
+    /// `service-unavailable`: a service is temporarily unavailable
+    ServiceUnavailable,
+    /// `site-configuration`: the site's account or plan prevents the request
+    SiteConfiguration,
+    /// A category missing from the catalog, or no error type at all
+    Unknown,
+}
+
+impl ErrorCategory {
+    /// Parses the category of an error type such as `not-found/file-not-found`
+    pub fn from_type(error_type: &str) -> Self {
+        let category = error_type.split('/').next().unwrap_or_default();
+        match category {
+            "bad-request" => Self::BadRequest,
+            "not-authenticated" => Self::NotAuthenticated,
+            "not-authorized" => Self::NotAuthorized,
+            "not-found" => Self::NotFound,
+            "processing-failure" => Self::ProcessingFailure,
+            "rate-limited" => Self::RateLimited,
+            "service-unavailable" => Self::ServiceUnavailable,
+            "site-configuration" => Self::SiteConfiguration,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// Generates [`ErrorKind`] from the error catalog, grouped by category
+macro_rules! error_kinds {
+    ($($category:ident => $prefix:literal { $($kind:ident => $slug:literal,)* })*) => {
+        /// Specific Files.com error type
+        ///
+        /// Each variant documents the error type it is parsed from.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum ErrorKind {
+            $($(
+                #[doc = concat!("`", $prefix, "/", $slug, "`")]
+                $kind,
+            )*)*
+            /// An error type missing from the catalog, or no error type at all
+            Unknown,
+        }
+
+        impl ErrorKind {
+            /// Parses an error type such as `processing-failure/file-locked`
+            pub fn from_type(error_type: &str) -> Self {
+                match error_type {
+                    $($(concat!($prefix, "/", $slug) => Self::$kind,)*)*
+                    _ => Self::Unknown,
+                }
+            }
+
+            /// The error type this kind is parsed from, or `None` for [`Unknown`](Self::Unknown)
+            pub fn as_type(&self) -> Option<&'static str> {
+                match self {
+                    $($(Self::$kind => Some(concat!($prefix, "/", $slug)),)*)*
+                    Self::Unknown => None,
+                }
+            }
+
+            /// The category this kind belongs to
+            pub fn category(&self) -> ErrorCategory {
+                match self {
+                    $($(Self::$kind => ErrorCategory::$category,)*)*
+                    Self::Unknown => ErrorCategory::Unknown,
+                }
+            }
+        }
+    };
+}
+
+error_kinds! {
+    BadRequest => "bad-request" {
+        AgentUpgradeRequired => "agent-upgrade-required",
+        AttachmentTooLarge => "attachment-too-large",
+        CannotDownloadDirectory => "cannot-download-directory",
+        CantMoveWithMultipleLocations => "cant-move-with-multiple-locations",
+        DatetimeParse => "datetime-parse",
+        DestinationSame => "destination-same",
+        DoesNotSupportSorting => "does-not-support-sorting",
+        FolderMustNotBeAFile => "folder-must-not-be-a-file",
+        FoldersNotAllowed => "folders-not-allowed",
+        InvalidBody => "invalid-body",
+        InvalidCursor => "invalid-cursor",
+        InvalidCursorTypeForSort => "invalid-cursor-type-for-sort",
+        InvalidEtags => "invalid-etags",
+        InvalidFilterAliasCombination => "invalid-filter-alias-combination",
+        InvalidFilterField => "invalid-filter-field",
+        InvalidFilterParam => "invalid-filter-param",
+        InvalidFilterParamFormat => "invalid-filter-param-format",
+        InvalidFilterParamValue => "invalid-filter-param-value",
+        InvalidInputEncoding => "invalid-input-encoding",
+        InvalidInterface => "invalid-interface",
+        InvalidOauthProvider => "invalid-oauth-provider",
+        InvalidPath => "invalid-path",
+        InvalidReturnToUrl => "invalid-return-to-url",
+        InvalidSortField => "invalid-sort-field",
+        InvalidSortFilterCombination => "invalid-sort-filter-combination",
+        InvalidUploadOffset => "invalid-upload-offset",
+        InvalidUploadPartGap => "invalid-upload-part-gap",
+        InvalidUploadPartSize => "invalid-upload-part-size",
+        MethodNotAllowed => "method-not-allowed",
+        MultipleSortParamsNotAllowed => "multiple-sort-params-not-allowed",
+        NoValidInputParams => "no-valid-input-params",
+        PartNumberTooLarge => "part-number-too-large",
+        PathCannotHaveTrailingWhitespace => "path-cannot-have-trailing-whitespace",
+        ReauthenticationNeededFields => "reauthentication-needed-fields",
+        RequestParamsContainInvalidCharacter => "request-params-contain-invalid-character",
+        RequestParamsInvalid => "request-params-invalid",
+        RequestParamsRequired => "request-params-required",
+        SearchAllOnChildPath => "search-all-on-child-path",
+        UnrecognizedSortIndex => "unrecognized-sort-index",
+        UnsupportedCurrency => "unsupported-currency",
+        UnsupportedHttpResponseFormat => "unsupported-http-response-format",
+        UnsupportedMediaType => "unsupported-media-type",
+        UserIdInvalid => "user-id-invalid",
+        UserIdOnUserEndpoint => "user-id-on-user-endpoint",
+        UserRequired => "user-required",
+    }
+    NotAuthenticated => "not-authenticated" {
+        AdditionalAuthenticationRequired => "additional-authentication-required",
+        AuthenticationRequired => "authentication-required",
+        BundleRegistrationCodeFailed => "bundle-registration-code-failed",
+        FilesAgentTokenFailed => "files-agent-token-failed",
+        InboxRegistrationCodeFailed => "inbox-registration-code-failed",
+        InvalidCredentials => "invalid-credentials",
+        InvalidOauth => "invalid-oauth",
+        InvalidOrExpiredCode => "invalid-or-expired-code",
+        InvalidSession => "invalid-session",
+        InvalidUsernameOrPassword => "invalid-username-or-password",
+        LockedOut => "locked-out",
+        LockoutRegionMismatch => "lockout-region-mismatch",
+        OneTimePasswordIncorrect => "one-time-password-incorrect",
+        TwoFactorAuthenticationError => "two-factor-authentication-error",
+        TwoFactorAuthenticationSetupExpired => "two-factor-authentication-setup-expired",
+    }
+    NotAuthorized => "not-authorized" {
+        ApiKeyIsDisabled => "api-key-is-disabled",
+        ApiKeyIsPathRestricted => "api-key-is-path-restricted",
+        ApiKeyOnlyForDesktopApp => "api-key-only-for-desktop-app",
+        ApiKeyOnlyForMobileApp => "api-key-only-for-mobile-app",
+        ApiKeyOnlyForOfficeIntegration => "api-key-only-for-office-integration",
+        BillingOrSiteAdminPermissionRequired => "billing-or-site-admin-permission-required",
+        BillingPermissionRequired => "billing-permission-required",
+        BundleMaximumUsesReached => "bundle-maximum-uses-reached",
+        BundlePermissionRequired => "bundle-permission-required",
+        CannotLoginWhileUsingKey => "cannot-login-while-using-key",
+        CantActForOtherUser => "cant-act-for-other-user",
+        ContactAdminForPasswordChangeHelp => "contact-admin-for-password-change-help",
+        FilesAgentFailedAuthorization => "files-agent-failed-authorization",
+        FolderAdminOrBillingPermissionRequired => "folder-admin-or-billing-permission-required",
+        FolderAdminPermissionRequired => "folder-admin-permission-required",
+        FullPermissionRequired => "full-permission-required",
+        HistoryPermissionRequired => "history-permission-required",
+        InsufficientPermissionForParams => "insufficient-permission-for-params",
+        InsufficientPermissionForSite => "insufficient-permission-for-site",
+        MustAuthenticateWithApiKey => "must-authenticate-with-api-key",
+        NeedAdminPermissionForInbox => "need-admin-permission-for-inbox",
+        NonAdminsMustQueryByFolderOrPath => "non-admins-must-query-by-folder-or-path",
+        NotAllowedToCreateBundle => "not-allowed-to-create-bundle",
+        PasswordChangeNotRequired => "password-change-not-required",
+        PasswordChangeRequired => "password-change-required",
+        ReadOnlySession => "read-only-session",
+        ReadPermissionRequired => "read-permission-required",
+        ReauthenticationFailed => "reauthentication-failed",
+        ReauthenticationFailedFinal => "reauthentication-failed-final",
+        ReauthenticationNeededAction => "reauthentication-needed-action",
+        RecaptchaFailed => "recaptcha-failed",
+        SelfManagedRequired => "self-managed-required",
+        SiteAdminRequired => "site-admin-required",
+        SiteFilesAreImmutable => "site-files-are-immutable",
+        TwoFactorAuthenticationRequired => "two-factor-authentication-required",
+        UserIdWithoutSiteAdmin => "user-id-without-site-admin",
+        WriteAndBundlePermissionRequired => "write-and-bundle-permission-required",
+        WritePermissionRequired => "write-permission-required",
+    }
+    NotFound => "not-found" {
+        ApiKeyNotFound => "api-key-not-found",
+        BundlePathNotFound => "bundle-path-not-found",
+        BundleRegistrationNotFound => "bundle-registration-not-found",
+        CodeNotFound => "code-not-found",
+        FileNotFound => "file-not-found",
+        FileUploadNotFound => "file-upload-not-found",
+        FolderNotFound => "folder-not-found",
+        GroupNotFound => "group-not-found",
+        InboxNotFound => "inbox-not-found",
+        NestedNotFound => "nested-not-found",
+        PlanNotFound => "plan-not-found",
+        SiteNotFound => "site-not-found",
+        UserNotFound => "user-not-found",
+    }
+    ProcessingFailure => "processing-failure" {
+        AlreadyCompleted => "already-completed",
+        AutomationCannotBeRunManually => "automation-cannot-be-run-manually",
+        BehaviorNotFound => "behavior-not-found",
+        BundleOnlyAllowsPreviews => "bundle-only-allows-previews",
+        BundleOperationRequiresSubfolder => "bundle-operation-requires-subfolder",
+        CouldNotCreateParent => "could-not-create-parent",
+        DestinationExists => "destination-exists",
+        DestinationFolderLimited => "destination-folder-limited",
+        DestinationParentConflict => "destination-parent-conflict",
+        DestinationParentDoesNotExist => "destination-parent-does-not-exist",
+        ExceededRuntimeLimit => "exceeded-runtime-limit",
+        ExpiredPrivateKey => "expired-private-key",
+        ExpiredPublicKey => "expired-public-key",
+        ExportFailure => "export-failure",
+        ExportNotReady => "export-not-ready",
+        FailedToChangePassword => "failed-to-change-password",
+        FileLocked => "file-locked",
+        FileNotUploaded => "file-not-uploaded",
+        FilePendingProcessing => "file-pending-processing",
+        FileProcessingError => "file-processing-error",
+        FileTooBigToDecrypt => "file-too-big-to-decrypt",
+        FileTooBigToEncrypt => "file-too-big-to-encrypt",
+        FileUploadMaxSizeExceeded => "file-upload-max-size-exceeded",
+        FolderLocked => "folder-locked",
+        FolderNotEmpty => "folder-not-empty",
+        HistoryUnavailable => "history-unavailable",
+        InvalidBundleCode => "invalid-bundle-code",
+        InvalidFileType => "invalid-file-type",
+        InvalidFilename => "invalid-filename",
+        InvalidPriorityColor => "invalid-priority-color",
+        InvalidRange => "invalid-range",
+        InvalidSite => "invalid-site",
+        ModelSaveError => "model-save-error",
+        MultipleProcessingErrors => "multiple-processing-errors",
+        PathTooLong => "path-too-long",
+        RecipientAlreadyShared => "recipient-already-shared",
+        RemoteServerError => "remote-server-error",
+        ResourceBelongsToParentSite => "resource-belongs-to-parent-site",
+        ResourceLocked => "resource-locked",
+        SubfolderLocked => "subfolder-locked",
+        TwoFactorAuthenticationCodeAlreadySent => "two-factor-authentication-code-already-sent",
+        TwoFactorAuthenticationCountryBlacklisted => "two-factor-authentication-country-blacklisted",
+        TwoFactorAuthenticationGeneralError => "two-factor-authentication-general-error",
+        TwoFactorAuthenticationMethodUnsupportedError => "two-factor-authentication-method-unsupported-error",
+        TwoFactorAuthenticationUnsubscribedRecipient => "two-factor-authentication-unsubscribed-recipient",
+        UpdatesNotAllowedForRemotes => "updates-not-allowed-for-remotes",
+    }
+    RateLimited => "rate-limited" {
+        DuplicateShareRecipient => "duplicate-share-recipient",
+        ReauthenticationRateLimited => "reauthentication-rate-limited",
+        TooManyConcurrentLogins => "too-many-concurrent-logins",
+        TooManyConcurrentRequests => "too-many-concurrent-requests",
+        TooManyLoginAttempts => "too-many-login-attempts",
+        TooManyRequests => "too-many-requests",
+        TooManyShares => "too-many-shares",
+    }
+    ServiceUnavailable => "service-unavailable" {
+        AgentUnavailable => "agent-unavailable",
+        AutomationsUnavailable => "automations-unavailable",
+        MigrationInProgress => "migration-in-progress",
+        SiteDisabled => "site-disabled",
+        UploadsUnavailable => "uploads-unavailable",
+    }
+    SiteConfiguration => "site-configuration" {
+        AccountAlreadyExists => "account-already-exists",
+        AccountOverdue => "account-overdue",
+        NoAccountForSite => "no-account-for-site",
+        SiteWasRemoved => "site-was-removed",
+        TrialExpired => "trial-expired",
+        TrialLocked => "trial-locked",
+        UserRequestsEnabledRequired => "user-requests-enabled-required",
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_type().unwrap_or("unknown"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_type() {
+        assert_eq!(
+            ErrorKind::from_type("processing-failure/destination-exists"),
+            ErrorKind::DestinationExists
+        );
+        assert_eq!(
+            ErrorKind::from_type("not-authorized/insufficient-permission-for-params"),
+            ErrorKind::InsufficientPermissionForParams
+        );
+        assert_eq!(
+            ErrorKind::from_type("bad-request/invalid-path"),
+            ErrorKind::InvalidPath
+        );
+        // The category prefix is part of the type
+        assert_eq!(ErrorKind::from_type("invalid-path"), ErrorKind::Unknown);
+        assert_eq!(
+            ErrorKind::from_type("not-found/widget-not-found"),
+            ErrorKind::Unknown
+        );
+    }
+
+    #[test]
+    fn test_round_trip_and_category() {
+        let kind = ErrorKind::FolderNotFound;
+        assert_eq!(kind.as_type(), Some("not-found/folder-not-found"));
+        assert_eq!(ErrorKind::from_type(kind.as_type().unwrap()), kind);
+        assert_eq!(kind.category(), ErrorCategory::NotFound);
+        assert_eq!(kind.to_string(), "not-found/folder-not-found");
+
+        assert_eq!(ErrorKind::Unknown.as_type(), None);
+        assert_eq!(ErrorKind::Unknown.category(), ErrorCategory::Unknown);
+    }
+
+    #[test]
+    fn test_category_from_type() {
+        assert_eq!(
+            ErrorCategory::from_type("not-found/widget-not-found"),
+            ErrorCategory::NotFound
+        );
+        assert_eq!(
+            ErrorCategory::from_type("rate-limited/too-many-requests"),
+            ErrorCategory::RateLimited
+        );
+        assert_eq!(ErrorCategory::from_type("teapot"), ErrorCategory::Unknown);
+        assert_eq!(ErrorCategory::from_type(""), ErrorCategory::Unknown);
+    }
+}
@@ -0,0 +1,229 @@
+//! Upload checkpoints for resumable uploads
+//!
+//! A checkpoint records everything needed to continue an interrupted
+//! multipart upload: the upload `ref`, the parts that were stored together
+//! with their ETags, and how many bytes of the source have been uploaded.
+//! A fingerprint of the source guards against resuming with a file that
+//! changed in the meantime.
+//!
+//! Checkpoints are plain serde types, so they can be stored anywhere. The
+//! [`save`](UploadCheckpoint::save) and [`load`](UploadCheckpoint::load)
+//! helpers persist them as JSON files.
+
+use crate::files::multipart::CompletedPart;
+use crate::{FilesError, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+/// Progress of a resumable upload
+///
+/// # Examples
+///
+/// ```rust
+/// use files_sdk::files::UploadCheckpoint;
+///
+/// let checkpoint = UploadCheckpoint::new("/backups/db.tar", "1048576:1700000000.0");
+/// assert!(!checkpoint.is_started());
+/// assert_eq!(checkpoint.next_part_number(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UploadCheckpoint {
+    /// Destination path on Files.com
+    pub path: String,
+
+    /// Upload reference returned by the first `begin_upload` call
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    pub ref_: Option<String>,
+
+    /// Parts stored so far, ordered by part number
+    #[serde(default)]
+    pub completed_parts: Vec<CompletedPart>,
+
+    /// Number of source bytes covered by the contiguous run of completed parts
+    #[serde(default)]
+    pub offset: u64,
+
+    /// Fingerprint identifying the source data
+    pub fingerprint: String,
+}
+
+impl UploadCheckpoint {
+    /// Creates an empty checkpoint for a new upload
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Destination path on Files.com
+    /// * `fingerprint` - Value identifying the source, e.g. from
+    ///   [`fingerprint_file`](Self::fingerprint_file)
+    pub fn new(path: impl Into<String>, fingerprint: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            ref_: None,
+            completed_parts: Vec::new(),
+            offset: 0,
+            fingerprint: fingerprint.into(),
+        }
+    }
+
+    /// Whether the upload has been started on the server
+    pub fn is_started(&self) -> bool {
+        self.ref_.is_some()
+    }
+
+    /// Whether this checkpoint belongs to the given destination and source
+    pub fn matches(&self, path: &str, fingerprint: &str) -> bool {
+        self.path == path && self.fingerprint == fingerprint
+    }
+
+    /// The part number to continue the upload with
+    pub fn next_part_number(&self) -> i32 {
+        self.contiguous_parts()
+            .last()
+            .map_or(1, |p| p.part_number + 1)
+    }
+
+    /// Completed parts starting at part 1 without gaps
+    ///
+    /// Only this run of parts is kept on resume: with concurrent uploads a
+    /// later part can finish before an earlier one, and bytes after the first
+    /// gap are uploaded again.
+    pub fn contiguous_parts(&self) -> &[CompletedPart] {
+        let run = self
+            .completed_parts
+            .iter()
+            .enumerate()
+            .take_while(|(i, p)| p.part_number == *i as i32 + 1)
+            .count();
+        &self.completed_parts[..run]
+    }
+
+    /// Records a completed part and advances the contiguous offset
+    pub(crate) fn record_part(&mut self, part: CompletedPart) {
+        self.completed_parts
+            .retain(|p| p.part_number != part.part_number);
+        self.completed_parts.push(part);
+        self.completed_parts.sort_by_key(|p| p.part_number);
+        self.offset = self.contiguous_parts().iter().map(|p| p.size).sum();
+    }
+
+    /// Drops parts after the first gap so the upload can continue at `offset`
+    pub(crate) fn truncate_to_contiguous(&mut self) {
+        let run = self.contiguous_parts().len();
+        self.completed_parts.truncate(run);
+        self.offset = self.completed_parts.iter().map(|p| p.size).sum();
+    }
+
+    /// Computes a fingerprint for a local file from its size and modification time
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file metadata cannot be read
+    pub fn fingerprint_file(path: &Path) -> Result<String> {
+        let metadata = std::fs::metadata(path).map_err(|e| {
+            FilesError::IoError(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|m| m.duration_since(UNIX_EPOCH).ok())
+            .unwrap_or_default();
+
+        Ok(format!(
+            "{}:{}.{:09}",
+            metadata.len(),
+            modified.as_secs(),
+            modified.subsec_nanos()
+        ))
+    }
+
+    /// Loads a checkpoint from a JSON file
+    ///
+    /// Returns `Ok(None)` if the file does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a valid checkpoint
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        match std::fs::read(path) {
+            Ok(data) => Ok(Some(serde_json::from_slice(&data)?)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(FilesError::IoError(format!(
+                "Failed to read checkpoint {}: {}",
+                path.display(),
+                e
+            ))),
+        }
+    }
+
+    /// Saves the checkpoint as JSON
+    ///
+    /// The file is written to a temporary sibling first and then renamed, so
+    /// an interrupted save never leaves a truncated checkpoint behind.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let data = serde_json::to_vec_pretty(self)?;
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+
+        std::fs::write(&tmp, data)
+            .and_then(|_| std::fs::rename(&tmp, path))
+            .map_err(|e| {
+                FilesError::IoError(format!(
+                    "Failed to write checkpoint {}: {}",
+                    path.display(),
+                    e
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part(part_number: i32, size: u64) -> CompletedPart {
+        CompletedPart {
+            part_number,
+            etag: format!("etag-{}", part_number),
+            size,
+        }
+    }
+
+    #[test]
+    fn test_record_part_out_of_order() {
+        let mut checkpoint = UploadCheckpoint::new("/file.bin", "fp");
+        checkpoint.ref_ = Some("ref".to_string());
+
+        checkpoint.record_part(part(2, 10));
+        assert_eq!(checkpoint.offset, 0);
+        assert_eq!(checkpoint.next_part_number(), 1);
+
+        checkpoint.record_part(part(1, 10));
+        assert_eq!(checkpoint.offset, 20);
+        assert_eq!(checkpoint.next_part_number(), 3);
+
+        checkpoint.record_part(part(4, 10));
+        checkpoint.truncate_to_contiguous();
+        assert_eq!(checkpoint.completed_parts.len(), 2);
+        assert_eq!(checkpoint.offset, 20);
+    }
+
+    #[test]
+    fn test_checkpoint_roundtrip() {
+        let mut checkpoint = UploadCheckpoint::new("/file.bin", "fp");
+        checkpoint.ref_ = Some("ref".to_string());
+        checkpoint.record_part(part(1, 5));
+
+        let json = serde_json::to_value(&checkpoint).unwrap();
+        assert_eq!(json["ref"], "ref");
+
+        let decoded: UploadCheckpoint = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded, checkpoint);
+        assert!(decoded.matches("/file.bin", "fp"));
+        assert!(!decoded.matches("/file.bin", "other"));
+    }
+}
@@ -0,0 +1,287 @@
+//! Checksums for verifying transfers
+//!
+//! Files.com reports CRC32, MD5, SHA-1 and SHA-256 checksums for stored
+//! files (not every checksum is available for every file). When checksum
+//! verification is enabled in [`UploadOptions`](crate::files::UploadOptions)
+//! or [`DownloadOptions`](crate::files::DownloadOptions), the transferred
+//! bytes are hashed on the fly and compared with the strongest checksum the
+//! server reports. A difference is returned as
+//! [`FilesError::ChecksumMismatch`], and a file without any checksum to
+//! compare with as [`FilesError::ChecksumUnavailable`].
+
+use crate::files::files::STREAM_CHUNK_SIZE;
+use crate::types::FileEntity;
+use crate::{FilesError, Result};
+use md5::Md5;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::path::Path;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// Checksum algorithms reported by Files.com
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChecksumAlgorithm {
+    /// CRC-32 (IEEE)
+    Crc32,
+
+    /// MD5
+    Md5,
+
+    /// SHA-1
+    Sha1,
+
+    /// SHA-256
+    Sha256,
+}
+
+impl ChecksumAlgorithm {
+    /// Name of the algorithm as used in `FileEntity` fields
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::Crc32 => "crc32",
+            ChecksumAlgorithm::Md5 => "md5",
+            ChecksumAlgorithm::Sha1 => "sha1",
+            ChecksumAlgorithm::Sha256 => "sha256",
+        }
+    }
+}
+
+impl fmt::Display for ChecksumAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Returns the strongest checksum reported for `file`
+pub(crate) fn strongest(file: &FileEntity) -> Option<(ChecksumAlgorithm, &str)> {
+    [
+        (ChecksumAlgorithm::Sha256, &file.sha256),
+        (ChecksumAlgorithm::Sha1, &file.sha1),
+        (ChecksumAlgorithm::Md5, &file.md5),
+        (ChecksumAlgorithm::Crc32, &file.crc32),
+    ]
+    .into_iter()
+    .find_map(|(algorithm, value)| {
+        value
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(|value| (algorithm, value))
+    })
+}
+
+/// Incrementally computes one or more checksums over the same bytes
+#[derive(Clone, Default)]
+pub(crate) struct Hasher {
+    crc32: Option<crc32fast::Hasher>,
+    md5: Option<Md5>,
+    sha1: Option<Sha1>,
+    sha256: Option<Sha256>,
+}
+
+impl Hasher {
+    /// Creates a hasher computing only `algorithm`
+    pub(crate) fn new(algorithm: ChecksumAlgorithm) -> Self {
+        let mut hasher = Self::default();
+        match algorithm {
+            ChecksumAlgorithm::Crc32 => hasher.crc32 = Some(crc32fast::Hasher::new()),
+            ChecksumAlgorithm::Md5 => hasher.md5 = Some(Md5::new()),
+            ChecksumAlgorithm::Sha1 => hasher.sha1 = Some(Sha1::new()),
+            ChecksumAlgorithm::Sha256 => hasher.sha256 = Some(Sha256::new()),
+        }
+        hasher
+    }
+
+    /// Creates a hasher computing every algorithm
+    ///
+    /// Used when the checksum to compare with is only known once the
+    /// transfer is complete.
+    pub(crate) fn all() -> Self {
+        Self {
+            crc32: Some(crc32fast::Hasher::new()),
+            md5: Some(Md5::new()),
+            sha1: Some(Sha1::new()),
+            sha256: Some(Sha256::new()),
+        }
+    }
+
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        if let Some(hasher) = self.crc32.as_mut() {
+            hasher.update(data);
+        }
+        if let Some(hasher) = self.md5.as_mut() {
+            hasher.update(data);
+        }
+        if let Some(hasher) = self.sha1.as_mut() {
+            hasher.update(data);
+        }
+        if let Some(hasher) = self.sha256.as_mut() {
+            hasher.update(data);
+        }
+    }
+
+    /// Returns the hex-encoded checksum, if `algorithm` is being computed
+    pub(crate) fn finish(&self, algorithm: ChecksumAlgorithm) -> Option<String> {
+        match algorithm {
+            ChecksumAlgorithm::Crc32 => self
+                .crc32
+                .clone()
+                .map(|hasher| format!("{:08x}", hasher.finalize())),
+            ChecksumAlgorithm::Md5 => self.md5.clone().map(|h| to_hex(&h.finalize())),
+            ChecksumAlgorithm::Sha1 => self.sha1.clone().map(|h| to_hex(&h.finalize())),
+            ChecksumAlgorithm::Sha256 => self.sha256.clone().map(|h| to_hex(&h.finalize())),
+        }
+    }
+
+    /// Compares the hashed bytes with the strongest checksum reported for `file`
+    ///
+    /// Fails with [`FilesError::ChecksumUnavailable`] if the server reports
+    /// no checksum this hasher computes, since nothing was verified.
+    pub(crate) fn verify(&self, path: &str, file: &FileEntity) -> Result<()> {
+        let unavailable = || FilesError::ChecksumUnavailable {
+            path: path.to_string(),
+        };
+        let (algorithm, expected) = strongest(file).ok_or_else(unavailable)?;
+        let actual = self.finish(algorithm).ok_or_else(unavailable)?;
+
+        if actual.eq_ignore_ascii_case(expected) {
+            Ok(())
+        } else {
+            Err(FilesError::ChecksumMismatch {
+                expected: expected.to_string(),
+                actual,
+                algorithm,
+            })
+        }
+    }
+}
+
+/// Feeds the first `limit` bytes of a local file (all if `None`) to `hasher`
+pub(crate) async fn hash_file(hasher: &mut Hasher, path: &Path, limit: Option<u64>) -> Result<()> {
+    let file = tokio::fs::File::open(path)
+        .await
+        .map_err(|e| FilesError::IoError(format!("Failed to open {}: {}", path.display(), e)))?;
+    hash_reader(hasher, file, limit)
+        .await
+        .map_err(|e| FilesError::IoError(format!("Failed to read {}: {}", path.display(), e)))
+}
+
+/// Feeds the first `limit` bytes of `reader` (all if `None`) to `hasher`
+pub(crate) async fn hash_reader<R>(
+    hasher: &mut Hasher,
+    reader: R,
+    limit: Option<u64>,
+) -> std::io::Result<()>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut reader = reader.take(limit.unwrap_or(u64::MAX));
+
+    let mut buffer = vec![0u8; STREAM_CHUNK_SIZE];
+    loop {
+        let read = reader.read(&mut buffer).await?;
+        if read == 0 {
+            return Ok(());
+        }
+        hasher.update(&buffer[..read]);
+    }
+}
+
+/// Computes the hex-encoded MD5 digest of a local file
+pub(crate) async fn md5_file(path: &Path) -> Result<String> {
+    let mut hasher = Hasher::new(ChecksumAlgorithm::Md5);
+    hash_file(&mut hasher, path, None).await?;
+    Ok(hasher
+        .finish(ChecksumAlgorithm::Md5)
+        .expect("MD5 is being computed"))
+}
+
+/// Lowercase hex encoding of a digest
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entity(value: serde_json::Value) -> FileEntity {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_all_algorithms() {
+        let mut hasher = Hasher::all();
+        hasher.update(b"hello ");
+        hasher.update(b"world");
+
+        assert_eq!(hasher.finish(ChecksumAlgorithm::Crc32).unwrap(), "0d4a1185");
+        assert_eq!(
+            hasher.finish(ChecksumAlgorithm::Md5).unwrap(),
+            "5eb63bbbe01eeed093cb22bb8f5acdc3"
+        );
+        assert_eq!(
+            hasher.finish(ChecksumAlgorithm::Sha1).unwrap(),
+            "2aae6c35c94fcfb415dbe95f408b9ce91ee846ed"
+        );
+        assert_eq!(
+            hasher.finish(ChecksumAlgorithm::Sha256).unwrap(),
+            "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"
+        );
+    }
+
+    #[test]
+    fn test_verify_uses_strongest_checksum() {
+        let mut hasher = Hasher::all();
+        hasher.update(b"hello world");
+
+        // The MD5 is wrong but SHA-256 takes precedence
+        let file = entity(serde_json::json!({
+            "md5": "00000000000000000000000000000000",
+            "sha256": "B94D27B9934D3E08A52E52D7DA7DABFAC484EFE37A5380EE9088F7ACE2EFCDE9"
+        }));
+        assert!(hasher.verify("/a.txt", &file).is_ok());
+
+        let file = entity(serde_json::json!({"crc32": "deadbeef"}));
+        match hasher.verify("/a.txt", &file) {
+            Err(FilesError::ChecksumMismatch {
+                expected,
+                actual,
+                algorithm,
+            }) => {
+                assert_eq!(expected, "deadbeef");
+                assert_eq!(actual, "0d4a1185");
+                assert_eq!(algorithm, ChecksumAlgorithm::Crc32);
+            }
+            other => panic!("Expected ChecksumMismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_verify_without_server_checksum() {
+        let hasher = Hasher::new(ChecksumAlgorithm::Md5);
+        assert!(matches!(
+            hasher.verify("/a.txt", &entity(serde_json::json!({}))),
+            Err(FilesError::ChecksumUnavailable { path }) if path == "/a.txt"
+        ));
+
+        // A checksum the hasher does not compute cannot be verified either
+        let file = entity(serde_json::json!({"sha256": "abc"}));
+        assert!(matches!(
+            hasher.verify("/a.txt", &file),
+            Err(FilesError::ChecksumUnavailable { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_md5_file() {
+        let path = std::env::temp_dir().join(format!("files-sdk-md5-{}", std::process::id()));
+        std::fs::write(&path, b"hello world").unwrap();
+
+        let digest = md5_file(&path).await.unwrap();
+        assert_eq!(digest, "5eb63bbbe01eeed093cb22bb8f5acdc3");
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
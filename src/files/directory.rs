@@ -0,0 +1,832 @@
+//! Directory upload and download engines
+//!
+//! Uploads every file below a local directory, preserving the directory
+//! structure. Files are streamed through the multipart upload engine, so
+//! each one is read part by part and finalized with `action=end`. Up to
+//! [`DirectoryUploadOptions::concurrency`] files are uploaded at once, which
+//! bounds memory use to roughly
+//! `concurrency * part size * max_concurrent_parts`.
+//!
+//! Downloads mirror a remote folder tree into a local directory. Folders are
+//! listed recursively, every folder is recreated locally (including empty
+//! ones) and up to [`DirectoryDownloadOptions::concurrency`] files are
+//! streamed to disk at once. Each file is written to a `.part` file first and
+//! renamed into place once complete, so an interrupted download never leaves
+//! a truncated file under the final name, and running the download again
+//! continues the partial files.
+//!
+//! In both directions a failure on one file does not stop the others. Every
+//! file ends up in exactly one list of the returned report.
+
+use crate::files::checksum;
+use crate::files::download::{self, DownloadOptions};
+use crate::files::multipart::{self, UploadOptions};
+use crate::files::{FileHandler, FolderHandler};
+use crate::timestamp::to_system_time;
+use crate::types::FileEntity;
+use crate::{FilesClient, FilesError, Result};
+use futures::stream::{self, StreamExt};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Default number of files uploaded concurrently
+pub const DEFAULT_DIRECTORY_CONCURRENCY: usize = 4;
+
+/// How symbolic links found while walking the directory are handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SymlinkPolicy {
+    /// Do not upload links; they are reported as skipped
+    #[default]
+    Skip,
+
+    /// Upload the files links point to and descend into linked directories
+    Follow,
+}
+
+/// Options controlling a directory upload
+///
+/// # Examples
+///
+/// ```rust
+/// use files_sdk::files::{DirectoryUploadOptions, SymlinkPolicy};
+///
+/// let options = DirectoryUploadOptions::default()
+///     .concurrency(8)
+///     .include("**/*.csv")
+///     .exclude("archive/**")
+///     .symlinks(SymlinkPolicy::Follow);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DirectoryUploadOptions {
+    /// Maximum number of files uploaded at the same time
+    pub concurrency: usize,
+
+    /// Glob patterns a file's relative path must match (all files if empty)
+    pub include: Vec<String>,
+
+    /// Glob patterns of relative paths that are skipped
+    pub exclude: Vec<String>,
+
+    /// How symbolic links are handled
+    pub symlinks: SymlinkPolicy,
+
+    /// Options for each file's multipart upload
+    pub upload_options: UploadOptions,
+}
+
+impl Default for DirectoryUploadOptions {
+    fn default() -> Self {
+        Self {
+            concurrency: DEFAULT_DIRECTORY_CONCURRENCY,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            symlinks: SymlinkPolicy::default(),
+            upload_options: UploadOptions::default(),
+        }
+    }
+}
+
+impl DirectoryUploadOptions {
+    /// Sets the maximum number of files uploaded concurrently
+    ///
+    /// Values below 1 are treated as 1.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Adds a glob pattern that files must match to be uploaded
+    ///
+    /// Patterns are matched against the path relative to the uploaded
+    /// directory, using `/` as separator.
+    pub fn include(mut self, pattern: impl Into<String>) -> Self {
+        self.include.push(pattern.into());
+        self
+    }
+
+    /// Adds a glob pattern for files that are skipped
+    ///
+    /// Exclusions take precedence over inclusions.
+    pub fn exclude(mut self, pattern: impl Into<String>) -> Self {
+        self.exclude.push(pattern.into());
+        self
+    }
+
+    /// Sets how symbolic links are handled
+    pub fn symlinks(mut self, policy: SymlinkPolicy) -> Self {
+        self.symlinks = policy;
+        self
+    }
+
+    /// Sets whether parent directories are created
+    pub fn mkdir_parents(mut self, mkdir_parents: bool) -> Self {
+        self.upload_options.mkdir_parents = mkdir_parents;
+        self
+    }
+
+    /// Sets whether each uploaded file is verified against its checksum
+    pub fn verify_checksum(mut self, verify: bool) -> Self {
+        self.upload_options.verify_checksum = verify;
+        self
+    }
+
+    /// Sets the options used for each file's multipart upload
+    pub fn upload_options(mut self, options: UploadOptions) -> Self {
+        self.upload_options = options;
+        self
+    }
+}
+
+/// A file that was uploaded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UploadedFile {
+    /// Local path of the file
+    pub local_path: PathBuf,
+
+    /// Path the file was uploaded to
+    pub remote_path: String,
+
+    /// Size of the file in bytes
+    pub size: u64,
+}
+
+/// Why a file was not transferred
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipReason {
+    /// The path did not match any include pattern
+    NotIncluded,
+
+    /// The path matched an exclude pattern
+    Excluded,
+
+    /// The entry is a symbolic link and links are not followed
+    Symlink,
+
+    /// The local copy already has the remote file's size and checksum
+    Unchanged,
+}
+
+/// A file that was not transferred on purpose
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedFile {
+    /// Local path of the file
+    pub local_path: PathBuf,
+
+    /// Why the file was skipped
+    pub reason: SkipReason,
+}
+
+/// A file or directory entry that could not be transferred
+#[derive(Debug)]
+pub struct FailedFile {
+    /// Local path of the entry
+    pub local_path: PathBuf,
+
+    /// Remote path of the entry, if one could be derived
+    pub remote_path: Option<String>,
+
+    /// What went wrong
+    pub error: FilesError,
+}
+
+/// Outcome of a directory upload
+///
+/// Each list is sorted by local path.
+#[derive(Debug, Default)]
+pub struct DirectoryUploadReport {
+    /// Files that were uploaded
+    pub uploaded: Vec<UploadedFile>,
+
+    /// Files that were left out by the filters or the symlink policy
+    pub skipped: Vec<SkippedFile>,
+
+    /// Entries that could not be read or uploaded
+    pub failed: Vec<FailedFile>,
+}
+
+impl DirectoryUploadReport {
+    /// Returns true if no entry failed
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    /// Total bytes uploaded
+    pub fn bytes_uploaded(&self) -> u64 {
+        self.uploaded.iter().map(|file| file.size).sum()
+    }
+
+    /// Converts the report into the uploaded remote paths, or the first error
+    pub fn into_result(self) -> Result<Vec<String>> {
+        match self.failed.into_iter().next() {
+            Some(failed) => Err(failed.error),
+            None => Ok(self
+                .uploaded
+                .into_iter()
+                .map(|file| file.remote_path)
+                .collect()),
+        }
+    }
+}
+
+/// Options controlling a directory download
+///
+/// # Examples
+///
+/// ```rust
+/// use files_sdk::files::DirectoryDownloadOptions;
+///
+/// let options = DirectoryDownloadOptions::default()
+///     .concurrency(8)
+///     .skip_unchanged(true);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DirectoryDownloadOptions {
+    /// Maximum number of files downloaded at the same time
+    pub concurrency: usize,
+
+    /// Skip files whose local copy already has the remote size and MD5
+    pub skip_unchanged: bool,
+
+    /// Set each local file's modification time from the remote file
+    pub preserve_mtime: bool,
+
+    /// Options for each file's download
+    pub download_options: DownloadOptions,
+}
+
+impl Default for DirectoryDownloadOptions {
+    fn default() -> Self {
+        Self {
+            concurrency: DEFAULT_DIRECTORY_CONCURRENCY,
+            skip_unchanged: false,
+            preserve_mtime: true,
+            download_options: DownloadOptions::default(),
+        }
+    }
+}
+
+impl DirectoryDownloadOptions {
+    /// Sets the maximum number of files downloaded concurrently
+    ///
+    /// Values below 1 are treated as 1.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Sets whether files that already match locally are skipped
+    ///
+    /// A file is only skipped when Files.com reports an MD5 for it and both
+    /// the size and the MD5 of the local file match.
+    pub fn skip_unchanged(mut self, skip_unchanged: bool) -> Self {
+        self.skip_unchanged = skip_unchanged;
+        self
+    }
+
+    /// Sets whether local modification times are set from the remote files
+    ///
+    /// `provided_mtime` is used when present, `mtime` otherwise.
+    pub fn preserve_mtime(mut self, preserve_mtime: bool) -> Self {
+        self.preserve_mtime = preserve_mtime;
+        self
+    }
+
+    /// Sets whether each downloaded file is verified against its checksum
+    pub fn verify_checksum(mut self, verify: bool) -> Self {
+        self.download_options.verify_checksum = verify;
+        self
+    }
+
+    /// Sets the options used for each file's download
+    pub fn download_options(mut self, options: DownloadOptions) -> Self {
+        self.download_options = options;
+        self
+    }
+}
+
+/// A file that was downloaded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadedFile {
+    /// Remote path of the file
+    pub remote_path: String,
+
+    /// Local path the file was written to
+    pub local_path: PathBuf,
+
+    /// Size of the file in bytes
+    pub size: u64,
+}
+
+/// Outcome of a directory download
+///
+/// Each list is sorted by local path.
+#[derive(Debug, Default)]
+pub struct DirectoryDownloadReport {
+    /// Files that were downloaded
+    pub downloaded: Vec<DownloadedFile>,
+
+    /// Files that already matched locally
+    pub skipped: Vec<SkippedFile>,
+
+    /// Files and folders that could not be listed or downloaded
+    pub failed: Vec<FailedFile>,
+}
+
+impl DirectoryDownloadReport {
+    /// Returns true if no entry failed
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    /// Total bytes downloaded
+    pub fn bytes_downloaded(&self) -> u64 {
+        self.downloaded.iter().map(|file| file.size).sum()
+    }
+}
+
+/// A file selected for upload
+struct UploadJob {
+    local_path: PathBuf,
+    remote_path: String,
+}
+
+/// Uploads `local_dir` to `remote_path`, calling `on_file` with
+/// `(files finished, files selected)` after each upload completes or fails
+pub(crate) async fn upload_directory<F>(
+    client: &FilesClient,
+    local_dir: &Path,
+    remote_path: &str,
+    options: &DirectoryUploadOptions,
+    on_file: F,
+) -> Result<DirectoryUploadReport>
+where
+    F: Fn(usize, usize),
+{
+    if !local_dir.is_dir() {
+        return Err(FilesError::IoError(format!(
+            "Not a directory: {}",
+            local_dir.display()
+        )));
+    }
+
+    let include = build_globset(&options.include)?;
+    let exclude = build_globset(&options.exclude)?;
+
+    let mut report = DirectoryUploadReport::default();
+    let mut jobs = Vec::new();
+
+    let walker = WalkDir::new(local_dir).follow_links(options.symlinks == SymlinkPolicy::Follow);
+    for entry in walker {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                report.failed.push(FailedFile {
+                    local_path: e.path().unwrap_or(local_dir).to_path_buf(),
+                    remote_path: None,
+                    error: FilesError::IoError(e.to_string()),
+                });
+                continue;
+            }
+        };
+
+        let file_type = entry.file_type();
+        if file_type.is_dir() {
+            continue;
+        }
+
+        let local_path = entry.into_path();
+        if file_type.is_symlink() {
+            report.skipped.push(SkippedFile {
+                local_path,
+                reason: SkipReason::Symlink,
+            });
+            continue;
+        }
+
+        let relative = match relative_path(local_dir, &local_path) {
+            Ok(relative) => relative,
+            Err(error) => {
+                report.failed.push(FailedFile {
+                    local_path,
+                    remote_path: None,
+                    error,
+                });
+                continue;
+            }
+        };
+
+        if exclude.as_ref().is_some_and(|set| set.is_match(&relative)) {
+            report.skipped.push(SkippedFile {
+                local_path,
+                reason: SkipReason::Excluded,
+            });
+            continue;
+        }
+        if include.as_ref().is_some_and(|set| !set.is_match(&relative)) {
+            report.skipped.push(SkippedFile {
+                local_path,
+                reason: SkipReason::NotIncluded,
+            });
+            continue;
+        }
+
+        let remote_path = format!("{}/{}", remote_path.trim_end_matches('/'), relative);
+        jobs.push(UploadJob {
+            local_path,
+            remote_path,
+        });
+    }
+
+    let total = jobs.len();
+    let mut uploads = stream::iter(jobs)
+        .map(|job| async move {
+            let result = upload_one(client, &job, &options.upload_options).await;
+            (job, result)
+        })
+        .buffer_unordered(options.concurrency.max(1));
+
+    let mut finished = 0;
+    while let Some((job, result)) = uploads.next().await {
+        match result {
+            Ok(size) => report.uploaded.push(UploadedFile {
+                local_path: job.local_path,
+                remote_path: job.remote_path,
+                size,
+            }),
+            Err(error) => report.failed.push(FailedFile {
+                local_path: job.local_path,
+                remote_path: Some(job.remote_path),
+                error,
+            }),
+        }
+        finished += 1;
+        on_file(finished, total);
+    }
+
+    report
+        .uploaded
+        .sort_by(|a, b| a.local_path.cmp(&b.local_path));
+    report
+        .skipped
+        .sort_by(|a, b| a.local_path.cmp(&b.local_path));
+    report
+        .failed
+        .sort_by(|a, b| a.local_path.cmp(&b.local_path));
+
+    Ok(report)
+}
+
+/// Streams one file through the multipart engine and returns its size
+async fn upload_one(client: &FilesClient, job: &UploadJob, options: &UploadOptions) -> Result<u64> {
+    let file = tokio::fs::File::open(&job.local_path).await.map_err(|e| {
+        FilesError::IoError(format!(
+            "Failed to open {}: {}",
+            job.local_path.display(),
+            e
+        ))
+    })?;
+    let size = file
+        .metadata()
+        .await
+        .map_err(|e| FilesError::IoError(e.to_string()))?
+        .len();
+
+    multipart::upload(
+        client,
+        &job.remote_path,
+        file,
+        Some(size as i64),
+        None,
+        options,
+    )
+    .await?;
+
+    Ok(size)
+}
+
+/// A remote file selected for download
+struct DownloadJob {
+    remote_path: String,
+    local_path: PathBuf,
+    entry: FileEntity,
+}
+
+/// Mirrors the remote folder `remote_path` into `local_dir`
+pub(crate) async fn download_directory(
+    client: &FilesClient,
+    remote_path: &str,
+    local_dir: &Path,
+    options: &DirectoryDownloadOptions,
+) -> Result<DirectoryDownloadReport> {
+    let root = remote_path.trim_matches('/');
+    let folders = FolderHandler::new(client.clone());
+    let mut report = DirectoryDownloadReport::default();
+    let mut jobs = Vec::new();
+
+    create_local_dir(local_dir)?;
+
+    let mut pending = VecDeque::from([(root.to_string(), local_dir.to_path_buf())]);
+    let mut listed_root = false;
+    while let Some((folder, local_folder)) = pending.pop_front() {
+        let folder_path = format!("/{}", folder);
+        let entries = match folders
+            .list_stream(&folder_path, None, None)
+            .collect_all()
+            .await
+        {
+            Ok(entries) => entries,
+            // The root folder itself must be listable
+            Err(error) if !listed_root => return Err(error),
+            Err(error) => {
+                report.failed.push(FailedFile {
+                    local_path: local_folder,
+                    remote_path: Some(folder_path),
+                    error,
+                });
+                continue;
+            }
+        };
+        listed_root = true;
+
+        for entry in entries {
+            let Some(entry_path) = entry.path.as_deref() else {
+                continue;
+            };
+            let entry_path = entry_path.trim_matches('/').to_string();
+            let remote_path = format!("/{}", entry_path);
+
+            let local_path = match local_path_for(local_dir, root, &entry_path) {
+                Ok(local_path) => local_path,
+                Err(error) => {
+                    report.failed.push(FailedFile {
+                        local_path: local_folder.clone(),
+                        remote_path: Some(remote_path),
+                        error,
+                    });
+                    continue;
+                }
+            };
+
+            if entry.file_type.as_deref() == Some("directory") {
+                match create_local_dir(&local_path) {
+                    Ok(()) => pending.push_back((entry_path, local_path)),
+                    Err(error) => report.failed.push(FailedFile {
+                        local_path,
+                        remote_path: Some(remote_path),
+                        error,
+                    }),
+                }
+            } else {
+                jobs.push(DownloadJob {
+                    remote_path,
+                    local_path,
+                    entry,
+                });
+            }
+        }
+    }
+
+    let files = FileHandler::new(client.clone());
+    let mut downloads = stream::iter(jobs)
+        .map(|job| {
+            let files = &files;
+            async move {
+                let result = download_one(files, &job, options).await;
+                (job, result)
+            }
+        })
+        .buffer_unordered(options.concurrency.max(1));
+
+    while let Some((job, result)) = downloads.next().await {
+        match result {
+            Ok(Some(size)) => report.downloaded.push(DownloadedFile {
+                remote_path: job.remote_path,
+                local_path: job.local_path,
+                size,
+            }),
+            Ok(None) => report.skipped.push(SkippedFile {
+                local_path: job.local_path,
+                reason: SkipReason::Unchanged,
+            }),
+            Err(error) => report.failed.push(FailedFile {
+                local_path: job.local_path,
+                remote_path: Some(job.remote_path),
+                error,
+            }),
+        }
+    }
+
+    report
+        .downloaded
+        .sort_by(|a, b| a.local_path.cmp(&b.local_path));
+    report
+        .skipped
+        .sort_by(|a, b| a.local_path.cmp(&b.local_path));
+    report
+        .failed
+        .sort_by(|a, b| a.local_path.cmp(&b.local_path));
+
+    Ok(report)
+}
+
+/// Downloads one file and returns its size, or `None` if it was unchanged
+async fn download_one(
+    files: &FileHandler,
+    job: &DownloadJob,
+    options: &DirectoryDownloadOptions,
+) -> Result<Option<u64>> {
+    if options.skip_unchanged && is_unchanged(&job.local_path, &job.entry).await {
+        return Ok(None);
+    }
+
+    download::download_to_file(
+        files,
+        &job.remote_path,
+        &job.local_path,
+        &options.download_options,
+    )
+    .await?;
+
+    let file = std::fs::File::options()
+        .write(true)
+        .open(&job.local_path)
+        .map_err(|e| {
+            FilesError::IoError(format!(
+                "Failed to open {}: {}",
+                job.local_path.display(),
+                e
+            ))
+        })?;
+    let size = file
+        .metadata()
+        .map_err(|e| FilesError::IoError(e.to_string()))?
+        .len();
+
+    let mtime = job
+        .entry
+        .provided_mtime
+        .as_ref()
+        .or(job.entry.mtime.as_ref())
+        .and_then(to_system_time);
+    if let (true, Some(mtime)) = (options.preserve_mtime, mtime) {
+        file.set_modified(mtime).map_err(|e| {
+            FilesError::IoError(format!(
+                "Failed to set modification time of {}: {}",
+                job.local_path.display(),
+                e
+            ))
+        })?;
+    }
+
+    Ok(Some(size))
+}
+
+/// Returns true if the local file has the remote file's size and MD5
+async fn is_unchanged(local_path: &Path, entry: &FileEntity) -> bool {
+    let Some(expected) = entry.md5.as_deref() else {
+        return false;
+    };
+    let Ok(metadata) = tokio::fs::metadata(local_path).await else {
+        return false;
+    };
+    if entry.size != Some(metadata.len() as i64) {
+        return false;
+    }
+
+    checksum::md5_file(local_path)
+        .await
+        .is_ok_and(|actual| actual.eq_ignore_ascii_case(expected))
+}
+
+fn create_local_dir(path: &Path) -> Result<()> {
+    std::fs::create_dir_all(path)
+        .map_err(|e| FilesError::IoError(format!("Failed to create {}: {}", path.display(), e)))
+}
+
+/// Maps a remote entry below `root` to a path below `local_dir`
+///
+/// Remote paths are compared case-insensitively, like Files.com does. Paths
+/// with empty, `.` or `..` segments are rejected so a listing can never
+/// write outside `local_dir`.
+fn local_path_for(local_dir: &Path, root: &str, entry_path: &str) -> Result<PathBuf> {
+    let relative = if root.is_empty() {
+        Some(entry_path)
+    } else {
+        entry_path
+            .get(..root.len())
+            .filter(|prefix| prefix.eq_ignore_ascii_case(root))
+            .and_then(|_| entry_path[root.len()..].strip_prefix('/'))
+    };
+    let relative = relative
+        .ok_or_else(|| FilesError::IoError(format!("{} is not inside /{}", entry_path, root)))?;
+
+    let mut local = local_dir.to_path_buf();
+    for part in relative.split('/') {
+        if part.is_empty() || part == "." || part == ".." {
+            return Err(FilesError::IoError(format!(
+                "Refusing to download unsafe path: {}",
+                entry_path
+            )));
+        }
+        local.push(part);
+    }
+    Ok(local)
+}
+
+/// Returns `path` relative to `base` with `/` separators
+fn relative_path(base: &Path, path: &Path) -> Result<String> {
+    let relative = path
+        .strip_prefix(base)
+        .map_err(|e| FilesError::IoError(format!("Failed to strip prefix: {}", e)))?;
+
+    let mut parts = Vec::new();
+    for component in relative.components() {
+        let part = component.as_os_str().to_str().ok_or_else(|| {
+            FilesError::IoError(format!("Invalid UTF-8 in path: {}", path.display()))
+        })?;
+        parts.push(part);
+    }
+    Ok(parts.join("/"))
+}
+
+fn build_globset(patterns: &[String]) -> Result<Option<GlobSet>> {
+    if patterns.is_empty() {
+        return Ok(None);
+    }
+
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let glob = Glob::new(pattern).map_err(|e| {
+            FilesError::ConfigError(format!("Invalid glob pattern '{}': {}", pattern, e))
+        })?;
+        builder.add(glob);
+    }
+    builder
+        .build()
+        .map(Some)
+        .map_err(|e| FilesError::ConfigError(format!("Invalid glob patterns: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relative_path_uses_forward_slashes() {
+        let base = Path::new("/data");
+        let path = Path::new("/data").join("a").join("b.txt");
+        assert_eq!(relative_path(base, &path).unwrap(), "a/b.txt");
+    }
+
+    #[test]
+    fn test_invalid_glob_is_config_error() {
+        let result = build_globset(&["a[".to_string()]);
+        assert!(matches!(result, Err(FilesError::ConfigError(_))));
+    }
+
+    #[test]
+    fn test_globs_match_nested_paths() {
+        let set = build_globset(&["**/*.csv".to_string()]).unwrap().unwrap();
+        assert!(set.is_match("report.csv"));
+        assert!(set.is_match("2024/01/report.csv"));
+        assert!(!set.is_match("report.txt"));
+    }
+
+    #[test]
+    fn test_local_path_for_strips_root() {
+        let local = Path::new("/mirror");
+        assert_eq!(
+            local_path_for(local, "Reports", "reports/2024/q1.csv").unwrap(),
+            local.join("2024").join("q1.csv")
+        );
+        assert_eq!(
+            local_path_for(local, "", "a.txt").unwrap(),
+            local.join("a.txt")
+        );
+    }
+
+    #[test]
+    fn test_local_path_for_rejects_unsafe_paths() {
+        let local = Path::new("/mirror");
+        assert!(local_path_for(local, "reports", "reports/../etc/passwd").is_err());
+        assert!(local_path_for(local, "reports", "reportsx/a.txt").is_err());
+        assert!(local_path_for(local, "reports", "other/a.txt").is_err());
+    }
+
+    #[test]
+    fn test_report_into_result() {
+        let report = DirectoryUploadReport {
+            uploaded: vec![UploadedFile {
+                local_path: PathBuf::from("a.txt"),
+                remote_path: "/r/a.txt".to_string(),
+                size: 3,
+            }],
+            ..Default::default()
+        };
+        assert!(report.is_success());
+        assert_eq!(report.bytes_uploaded(), 3);
+        assert_eq!(report.into_result().unwrap(), vec!["/r/a.txt".to_string()]);
+    }
+}
@@ -0,0 +1,467 @@
+//! Streaming download engine
+//!
+//! Downloads first request the file's metadata, which carries a short-lived
+//! `download_uri`, then stream the content from that URI in chunks. If the
+//! URI has expired by the time it is used (the storage backend answers 403
+//! or 410), fresh metadata is requested once and the request is repeated.
+//! Content requests are sent by the [`FilesClient`](crate::FilesClient)
+//! itself, so its timeout, middleware, rate limiter and retry policy apply.
+//!
+//! Downloads to a local path are resumable: bytes are written to
+//! `<path>.part`, and a later attempt that finds that file continues from its
+//! length with a `Range` request. The `.part` file is renamed into place
+//! once the download is complete. Large files can instead be fetched as
+//! several ranges in parallel into a pre-allocated file; see
+//! [`DownloadOptions::segments`].
+//!
+//! With [`DownloadOptions::verify_checksum`] the downloaded bytes are hashed
+//! and compared with the checksum Files.com reports.
+
+use crate::files::FileHandler;
+use crate::files::checksum::{self, Hasher};
+use crate::progress::{Progress, ProgressCallback};
+use crate::types::FileEntity;
+use crate::{FilesError, Result};
+use futures::future::try_join_all;
+use std::io::SeekFrom;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::io::{AsyncSeekExt, AsyncWrite, AsyncWriteExt};
+
+/// Default smallest range fetched by one segment of a segmented download (8MB)
+pub const DEFAULT_MIN_SEGMENT_SIZE: u64 = 8 * 1024 * 1024;
+
+/// Options controlling how a file is downloaded
+///
+/// # Examples
+///
+/// ```rust
+/// use files_sdk::files::DownloadOptions;
+///
+/// let options = DownloadOptions::default()
+///     .verify_checksum(true)
+///     .segments(4);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DownloadOptions {
+    /// Compare the downloaded bytes with the checksum reported by Files.com
+    pub verify_checksum: bool,
+
+    /// Maximum number of ranges of one file downloaded in parallel
+    pub segments: usize,
+
+    /// Smallest range a segment is given
+    pub min_segment_size: u64,
+}
+
+impl Default for DownloadOptions {
+    fn default() -> Self {
+        Self {
+            verify_checksum: false,
+            segments: 1,
+            min_segment_size: DEFAULT_MIN_SEGMENT_SIZE,
+        }
+    }
+}
+
+impl DownloadOptions {
+    /// Sets whether the downloaded bytes are verified
+    ///
+    /// The strongest checksum the server reports (SHA-256, SHA-1, MD5, then
+    /// CRC32) is used. A mismatch fails the download with
+    /// [`FilesError::ChecksumMismatch`] after all bytes have been written.
+    /// Files without any reported checksum fail with
+    /// [`FilesError::ChecksumUnavailable`] before any bytes are transferred.
+    pub fn verify_checksum(mut self, verify: bool) -> Self {
+        self.verify_checksum = verify;
+        self
+    }
+
+    /// Sets the maximum number of ranges downloaded in parallel
+    ///
+    /// Only applies to downloads to a local path. A file is split into at
+    /// most `segments` ranges of at least
+    /// [`min_segment_size`](Self::min_segment_size) bytes each, so small
+    /// files are still downloaded in one request. Segmented downloads write
+    /// into a pre-allocated `<path>.segments` file and always start over;
+    /// they do not resume a `.part` file. Values below 1 are treated as 1.
+    pub fn segments(mut self, segments: usize) -> Self {
+        self.segments = segments;
+        self
+    }
+
+    /// Sets the smallest range a segment is given
+    pub fn min_segment_size(mut self, size: u64) -> Self {
+        self.min_segment_size = size;
+        self
+    }
+
+    /// Number of segments to split a file of `size` bytes into
+    fn segment_count(&self, size: u64) -> u64 {
+        let by_size = size / self.min_segment_size.max(1);
+        by_size.clamp(1, self.segments.max(1) as u64)
+    }
+}
+
+/// An open response for a range of a file
+struct RangeResponse {
+    response: reqwest::Response,
+    /// Leading bytes to drop because the server ignored the `Range` header
+    skip: u64,
+}
+
+/// Requests bytes `start..=end` (to the end of the file if `end` is `None`)
+///
+/// The request goes through the client's transport, rate limiter and retry
+/// policy, without credentials. Re-requests `file` once if its download URI
+/// has expired.
+async fn open_range(
+    files: &FileHandler,
+    remote_path: &str,
+    file: &mut FileEntity,
+    start: u64,
+    end: Option<u64>,
+) -> Result<RangeResponse> {
+    let mut refreshed = false;
+    loop {
+        let download_uri = file.download_uri.as_deref().ok_or_else(|| {
+            FilesError::not_found_resource("No download URI available", "file", remote_path)
+        })?;
+
+        let mut request = files.client.inner.client.get(download_uri);
+        if start > 0 || end.is_some() {
+            let end = end.map(|end| end.to_string()).unwrap_or_default();
+            request = request.header(reqwest::header::RANGE, format!("bytes={}-{}", start, end));
+        }
+
+        match files.client.send_with_retry(request.build()?, false).await {
+            Ok(response) if response.status().as_u16() == 206 => {
+                return Ok(RangeResponse { response, skip: 0 });
+            }
+            Ok(response) => {
+                return Ok(RangeResponse {
+                    response,
+                    skip: start,
+                });
+            }
+            Err(e) if !refreshed && matches!(e.status_code(), Some(403 | 410)) => {
+                refreshed = true;
+                *file = files.download_file(remote_path).await?;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Writes the body of `range` to `writer`, up to `limit` bytes
+///
+/// `on_chunk` is called with every chunk written. Returns the bytes written.
+async fn copy_range<W, F>(
+    range: &mut RangeResponse,
+    limit: Option<u64>,
+    writer: &mut W,
+    mut on_chunk: F,
+) -> Result<u64>
+where
+    W: AsyncWrite + Unpin,
+    F: FnMut(&[u8]),
+{
+    let mut written = 0u64;
+    while let Some(chunk) = range.response.chunk().await.map_err(FilesError::Request)? {
+        let mut chunk = &chunk[..];
+
+        if range.skip > 0 {
+            let skipped = range.skip.min(chunk.len() as u64);
+            range.skip -= skipped;
+            chunk = &chunk[skipped as usize..];
+        }
+        if let Some(limit) = limit {
+            let remaining = limit - written;
+            if (chunk.len() as u64) > remaining {
+                chunk = &chunk[..remaining as usize];
+            }
+        }
+        if chunk.is_empty() {
+            continue;
+        }
+
+        writer
+            .write_all(chunk)
+            .await
+            .map_err(|e| FilesError::IoError(format!("Failed to write to stream: {}", e)))?;
+        written += chunk.len() as u64;
+        on_chunk(chunk);
+
+        if limit.is_some_and(|limit| written >= limit) {
+            break;
+        }
+    }
+
+    writer
+        .flush()
+        .await
+        .map_err(|e| FilesError::IoError(format!("Failed to flush stream: {}", e)))?;
+    Ok(written)
+}
+
+/// Returns a hasher for the strongest checksum of `file`, if verification is on
+///
+/// Fails if verification is on but the server reports no checksum for `file`.
+fn hasher_for(
+    remote_path: &str,
+    file: &FileEntity,
+    options: &DownloadOptions,
+) -> Result<Option<Hasher>> {
+    if !options.verify_checksum {
+        return Ok(None);
+    }
+    match checksum::strongest(file) {
+        Some((algorithm, _)) => Ok(Some(Hasher::new(algorithm))),
+        None => Err(FilesError::ChecksumUnavailable {
+            path: remote_path.to_string(),
+        }),
+    }
+}
+
+/// Returns `path` with `suffix` appended to its file name
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(suffix);
+    PathBuf::from(path)
+}
+
+fn io_error(action: &str, path: &Path, e: std::io::Error) -> FilesError {
+    FilesError::IoError(format!("Failed to {} {}: {}", action, path.display(), e))
+}
+
+/// Streams `remote_path` into `writer`
+pub(crate) async fn download<W>(
+    files: &FileHandler,
+    remote_path: &str,
+    writer: &mut W,
+    progress_callback: Option<Arc<dyn ProgressCallback>>,
+    options: &DownloadOptions,
+) -> Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    // First, get the file metadata to obtain the download URI and size
+    let mut file = files.download_file(remote_path).await?;
+
+    // Get the total file size for progress tracking
+    let total_bytes = file
+        .size
+        .and_then(|s| if s > 0 { Some(s as u64) } else { None });
+
+    let mut hasher = hasher_for(remote_path, &file, options)?;
+    let mut range = open_range(files, remote_path, &mut file, 0, None).await?;
+
+    let mut bytes_transferred = 0u64;
+    copy_range(&mut range, None, writer, |chunk| {
+        if let Some(hasher) = hasher.as_mut() {
+            hasher.update(chunk);
+        }
+        bytes_transferred += chunk.len() as u64;
+
+        if let Some(ref callback) = progress_callback {
+            callback.on_progress(&Progress::new(bytes_transferred, total_bytes));
+        }
+    })
+    .await?;
+
+    match hasher {
+        Some(hasher) => hasher.verify(remote_path, &file),
+        None => Ok(()),
+    }
+}
+
+/// Downloads bytes `start..=end` of `remote_path` (to the end if `end` is `None`)
+pub(crate) async fn download_range(
+    files: &FileHandler,
+    remote_path: &str,
+    start: u64,
+    end: Option<u64>,
+) -> Result<Vec<u8>> {
+    let mut file = files.download_file(remote_path).await?;
+    let mut range = open_range(files, remote_path, &mut file, start, end).await?;
+
+    let limit = end.map(|end| end + 1 - start);
+    let mut buffer = Vec::new();
+    copy_range(&mut range, limit, &mut buffer, |_| {}).await?;
+    Ok(buffer)
+}
+
+/// Downloads `remote_path` to `local_path`, resuming a previous attempt
+pub(crate) async fn download_to_file(
+    files: &FileHandler,
+    remote_path: &str,
+    local_path: &Path,
+    options: &DownloadOptions,
+) -> Result<()> {
+    let mut file = files.download_file(remote_path).await?;
+    let size = file.size.and_then(|s| u64::try_from(s).ok());
+
+    if let Some(size) = size {
+        let segments = options.segment_count(size);
+        if segments > 1 {
+            return download_segmented(
+                files,
+                remote_path,
+                local_path,
+                file,
+                size,
+                segments,
+                options,
+            )
+            .await;
+        }
+    }
+
+    let part_path = with_suffix(local_path, ".part");
+    let mut hasher = hasher_for(remote_path, &file, options)?;
+
+    // A partial file can only be continued if the expected size is known
+    let mut offset = match (size, tokio::fs::metadata(&part_path).await) {
+        (Some(size), Ok(metadata)) if metadata.len() <= size => metadata.len(),
+        _ => 0,
+    };
+    if let (Some(hasher), true) = (hasher.as_mut(), offset > 0) {
+        checksum::hash_file(hasher, &part_path, Some(offset)).await?;
+    }
+
+    let mut part = tokio::fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(false)
+        .open(&part_path)
+        .await
+        .map_err(|e| io_error("open", &part_path, e))?;
+    part.set_len(offset)
+        .await
+        .map_err(|e| io_error("truncate", &part_path, e))?;
+    part.seek(SeekFrom::Start(offset))
+        .await
+        .map_err(|e| io_error("seek", &part_path, e))?;
+
+    if size != Some(offset) {
+        let mut range = open_range(files, remote_path, &mut file, offset, None).await?;
+        offset += copy_range(&mut range, None, &mut part, |chunk| {
+            if let Some(hasher) = hasher.as_mut() {
+                hasher.update(chunk);
+            }
+        })
+        .await?;
+    }
+    drop(part);
+
+    if let Some(size) = size.filter(|size| offset < *size) {
+        // Keep the partial file so the next attempt can continue it
+        return Err(FilesError::IoError(format!(
+            "Download of {} ended after {} of {} bytes",
+            remote_path, offset, size
+        )));
+    }
+
+    if let Some(hasher) = hasher {
+        if let Err(e) = hasher.verify(remote_path, &file) {
+            let _ = tokio::fs::remove_file(&part_path).await;
+            return Err(e);
+        }
+    }
+
+    tokio::fs::rename(&part_path, local_path)
+        .await
+        .map_err(|e| io_error("rename", &part_path, e))
+}
+
+/// Downloads `remote_path` as `segments` parallel ranges into a pre-allocated file
+async fn download_segmented(
+    files: &FileHandler,
+    remote_path: &str,
+    local_path: &Path,
+    file: FileEntity,
+    size: u64,
+    segments: u64,
+    options: &DownloadOptions,
+) -> Result<()> {
+    let hasher = hasher_for(remote_path, &file, options)?;
+    let temp_path = with_suffix(local_path, ".segments");
+    let temp = tokio::fs::File::create(&temp_path)
+        .await
+        .map_err(|e| io_error("create", &temp_path, e))?;
+    temp.set_len(size)
+        .await
+        .map_err(|e| io_error("allocate", &temp_path, e))?;
+    drop(temp);
+
+    let segment_size = size.div_ceil(segments);
+    let downloads = (0..segments).map(|index| {
+        let start = index * segment_size;
+        let end = (start + segment_size).min(size) - 1;
+        let mut file = file.clone();
+        let temp_path = &temp_path;
+        async move {
+            let mut range = open_range(files, remote_path, &mut file, start, Some(end)).await?;
+
+            let mut out = tokio::fs::OpenOptions::new()
+                .write(true)
+                .open(temp_path)
+                .await
+                .map_err(|e| io_error("open", temp_path, e))?;
+            out.seek(SeekFrom::Start(start))
+                .await
+                .map_err(|e| io_error("seek", temp_path, e))?;
+
+            let expected = end + 1 - start;
+            let written = copy_range(&mut range, Some(expected), &mut out, |_| {}).await?;
+            if written < expected {
+                return Err(FilesError::IoError(format!(
+                    "Segment {}-{} of {} ended after {} of {} bytes",
+                    start, end, remote_path, written, expected
+                )));
+            }
+            Ok(())
+        }
+    });
+
+    let result = match try_join_all(downloads).await {
+        Ok(_) => match hasher {
+            Some(mut hasher) => match checksum::hash_file(&mut hasher, &temp_path, None).await {
+                Ok(()) => hasher.verify(remote_path, &file),
+                Err(e) => Err(e),
+            },
+            None => Ok(()),
+        },
+        Err(e) => Err(e),
+    };
+    if let Err(e) = result {
+        let _ = tokio::fs::remove_file(&temp_path).await;
+        return Err(e);
+    }
+
+    tokio::fs::rename(&temp_path, local_path)
+        .await
+        .map_err(|e| io_error("rename", &temp_path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segment_count() {
+        let options = DownloadOptions::default().segments(4).min_segment_size(100);
+        assert_eq!(options.segment_count(0), 1);
+        assert_eq!(options.segment_count(250), 2);
+        assert_eq!(options.segment_count(10_000), 4);
+        assert_eq!(DownloadOptions::default().segment_count(1 << 40), 1);
+    }
+
+    #[test]
+    fn test_with_suffix() {
+        assert_eq!(
+            with_suffix(Path::new("/tmp/report.csv"), ".part"),
+            PathBuf::from("/tmp/report.csv.part")
+        );
+    }
+}
@@ -2,13 +2,14 @@
 //!
 //! This module provides specialized file operations including:
 //! - Begin upload (first stage of file upload process)
-//! - Copy files
-//! - Move files
+//! - Copy files and folders
+//! - Move files and folders
 //! - Get metadata
 //!
 //! The most important operation here is `begin_upload`, which must be called
 //! before uploading any file to Files.com.
 
+use crate::files::MigrationHandle;
 use crate::utils::encode_path;
 use crate::{FileUploadPartEntity, FilesClient, Result};
 use serde_json::json;
@@ -138,7 +139,59 @@ impl FileActionHandler {
         }
     }
 
-    /// Copy a file to a new location
+    /// Request the upload URL for a subsequent part of an upload
+    ///
+    /// Files.com hands out upload URLs one part at a time. After the first
+    /// `begin_upload` call, each additional part is requested with the `ref`
+    /// returned by the first call and the 1-based part number.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Destination path for the file
+    /// * `upload_ref` - The `ref` value returned by the initial `begin_upload`
+    /// * `part` - Part number to request (1-based)
+    ///
+    /// # Returns
+    ///
+    /// Returns the `FileUploadPartEntity` for the requested part
+    pub async fn begin_upload_part(
+        &self,
+        path: &str,
+        upload_ref: &str,
+        part: i32,
+    ) -> Result<FileUploadPartEntity> {
+        let body = json!({
+            "ref": upload_ref,
+            "part": part,
+        });
+
+        let encoded_path = encode_path(path);
+        let endpoint = format!("/file_actions/begin_upload{}", encoded_path);
+        let response = self.client.post_raw(&endpoint, body).await?;
+
+        // The API may wrap the part in an array even when only one is requested
+        let mut parts: Vec<FileUploadPartEntity> = if response.is_array() {
+            serde_json::from_value(response)?
+        } else {
+            vec![serde_json::from_value(response)?]
+        };
+
+        if parts.is_empty() {
+            return Err(crate::FilesError::ApiError {
+                endpoint: Some(endpoint),
+                code: 500,
+                message: format!("No upload part returned for part {}", part),
+                detail: None,
+            });
+        }
+
+        Ok(parts.remove(0))
+    }
+
+    /// Copy a file or folder to a new location
+    ///
+    /// Folders are copyd in the background by a file migration. The returned
+    /// [`MigrationHandle`] can be waited on until the copy has finished.
     ///
     /// # Arguments
     ///
@@ -153,22 +206,32 @@ impl FileActionHandler {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let client = FilesClient::builder().api_key("key").build()?;
     /// let handler = FileActionHandler::new(client);
-    /// handler.copy_file("/source/file.txt", "/dest/file.txt").await?;
+    /// handler
+    ///     .copy_file("/source/folder", "/dest/folder")
+    ///     .await?
+    ///     .wait()
+    ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn copy_file(&self, path: &str, destination: &str) -> Result<()> {
+    pub async fn copy_file(&self, path: &str, destination: &str) -> Result<MigrationHandle> {
         let body = json!({
             "destination": destination,
         });
 
         let encoded_path = encode_path(path);
         let endpoint = format!("/file_actions/copy{}", encoded_path);
-        self.client.post_raw(&endpoint, body).await?;
-        Ok(())
+        let response = self.client.post_raw(&endpoint, body).await?;
+        Ok(MigrationHandle::from_response(
+            self.client.clone(),
+            &response,
+        ))
     }
 
-    /// Move a file to a new location
+    /// Move a file or folder to a new location
+    ///
+    /// Folders are moved in the background by a file migration. The returned
+    /// [`MigrationHandle`] can be waited on until the move has finished.
     ///
     /// # Arguments
     ///
@@ -183,19 +246,26 @@ impl FileActionHandler {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let client = FilesClient::builder().api_key("key").build()?;
     /// let handler = FileActionHandler::new(client);
-    /// handler.move_file("/source/file.txt", "/dest/file.txt").await?;
+    /// handler
+    ///     .move_file("/source/folder", "/dest/folder")
+    ///     .await?
+    ///     .wait()
+    ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn move_file(&self, path: &str, destination: &str) -> Result<()> {
+    pub async fn move_file(&self, path: &str, destination: &str) -> Result<MigrationHandle> {
         let body = json!({
             "destination": destination,
         });
 
         let encoded_path = encode_path(path);
         let endpoint = format!("/file_actions/move{}", encoded_path);
-        self.client.post_raw(&endpoint, body).await?;
-        Ok(())
+        let response = self.client.post_raw(&endpoint, body).await?;
+        Ok(MigrationHandle::from_response(
+            self.client.clone(),
+            &response,
+        ))
     }
 
     /// Get file metadata without downloading
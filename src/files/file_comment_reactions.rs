@@ -1,10 +1,61 @@
 use crate::{Result, client::FilesClient};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// File comment reaction entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FileCommentReactionEntity {
+    /// Reaction ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Emoji used in the reaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parameters for creating a file comment reaction
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateFileCommentReactionParams {
+    /// User ID, when acting on behalf of another user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
+
+    /// ID of the file comment to react to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_comment_id: Option<i64>,
+
+    /// Emoji to react with
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+}
+
+impl CreateFileCommentReactionParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// User ID, when acting on behalf of another user
+    pub fn user_id(mut self, user_id: i64) -> Self {
+        self.user_id = Some(user_id);
+        self
+    }
+
+    /// ID of the file comment to react to
+    pub fn file_comment_id(mut self, file_comment_id: i64) -> Self {
+        self.file_comment_id = Some(file_comment_id);
+        self
+    }
+
+    /// Emoji to react with
+    pub fn emoji(mut self, emoji: impl Into<String>) -> Self {
+        self.emoji = Some(emoji.into());
+        self
+    }
 }
 
 pub struct FileCommentReactionHandler {
@@ -16,7 +67,10 @@ impl FileCommentReactionHandler {
         Self { client }
     }
 
-    pub async fn create(&self, params: serde_json::Value) -> Result<FileCommentReactionEntity> {
+    pub async fn create(
+        &self,
+        params: CreateFileCommentReactionParams,
+    ) -> Result<FileCommentReactionEntity> {
         let response = self
             .client
             .post_raw("/file_comment_reactions", params)
@@ -7,8 +7,9 @@
 //! - Delete comments
 //! - React to comments
 
+use crate::pagination::Paginator;
 use crate::utils::encode_path;
-use crate::{FilesClient, Result};
+use crate::{FilesClient, PaginationInfo, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
@@ -52,6 +53,8 @@ impl FileCommentHandler {
     /// # Arguments
     ///
     /// * `path` - Path to the file
+    /// * `cursor` - Pagination cursor (optional)
+    /// * `per_page` - Results per page (optional)
     ///
     /// # Examples
     ///
@@ -62,18 +65,51 @@ impl FileCommentHandler {
     /// let client = FilesClient::builder().api_key("key").build()?;
     /// let handler = FileCommentHandler::new(client);
     ///
-    /// let comments = handler.list("/path/to/file.txt").await?;
+    /// let (comments, _) = handler.list("/path/to/file.txt", None, None).await?;
     /// for comment in comments {
     ///     println!("{}", comment.body.unwrap_or_default());
     /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn list(&self, path: &str) -> Result<Vec<FileCommentEntity>> {
+    pub async fn list(
+        &self,
+        path: &str,
+        cursor: Option<String>,
+        per_page: Option<i64>,
+    ) -> Result<(Vec<FileCommentEntity>, PaginationInfo)> {
         let encoded_path = encode_path(path);
-        let endpoint = format!("/file_comments/files{}", encoded_path);
-        let response = self.client.get_raw(&endpoint).await?;
-        Ok(serde_json::from_value(response)?)
+        let mut endpoint = format!("/file_comments/files{}", encoded_path);
+        let mut params = Vec::new();
+
+        if let Some(c) = cursor {
+            params.push(format!("cursor={}", c));
+        }
+        if let Some(pp) = per_page {
+            params.push(format!("per_page={}", pp));
+        }
+
+        if !params.is_empty() {
+            endpoint.push('?');
+            endpoint.push_str(&params.join("&"));
+        }
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let comments: Vec<FileCommentEntity> = serde_json::from_value(response)?;
+        Ok((comments, pagination))
+    }
+
+    /// Stream the results of [`list`](Self::list) across all pages
+    ///
+    /// Takes the same arguments as [`list`](Self::list), without the cursor.
+    pub fn list_stream(&self, path: &str, per_page: Option<i64>) -> Paginator<FileCommentEntity> {
+        let handler = self.clone();
+        let path = path.to_string();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let path = path.clone();
+            async move { handler.list(&path, cursor, per_page).await }
+        })
     }
 
     /// Create a new file comment
@@ -1,12 +1,59 @@
 use crate::{Result, client::FilesClient};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// File migration entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FileMigrationEntity {
+    /// Migration ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Source path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// Destination path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dest_path: Option<String>,
+
+    /// Number of files processed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files_moved: Option<i64>,
+
+    /// Total number of files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files_total: Option<i64>,
+
+    /// Number of bytes processed, when reported
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bytes_moved: Option<i64>,
+
+    /// Total number of bytes, when reported
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bytes_total: Option<i64>,
+
+    /// Operation type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation: Option<String>,
+
+    /// Destination region
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+
+    /// Migration status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// URL of the migration log
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_url: Option<String>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+#[derive(Debug, Clone)]
 pub struct FileMigrationHandler {
     client: FilesClient,
 }
@@ -10,16 +10,24 @@
 //! 1. Call `FileActionHandler::begin_upload()` to get upload URLs
 //! 2. Use this handler's `upload_file()` to complete the upload
 
-use crate::files::FileActionHandler;
-use crate::progress::{Progress, ProgressCallback};
+use crate::files::UploadCheckpoint;
+use crate::files::checksum::{self, Hasher};
+use crate::files::directory::{
+    self, DirectoryDownloadOptions, DirectoryDownloadReport, DirectoryUploadOptions,
+    DirectoryUploadReport,
+};
+use crate::files::download::{self, DownloadOptions};
+use crate::files::multipart::{self, UploadOptions};
+use crate::files::{FileActionHandler, MigrationHandle};
+use crate::progress::ProgressCallback;
 use crate::types::FileEntity;
 use crate::utils::encode_path;
-use crate::{FilesClient, FilesError, Result};
+use crate::{FilesClient, FilesError, Result, Timestamp};
 use serde_json::json;
 use std::collections::HashMap;
+use std::ops::{Bound, RangeBounds};
 use std::path::Path;
 use std::sync::Arc;
-use walkdir::WalkDir;
 
 /// Default chunk size for streaming operations (64KB)
 ///
@@ -27,14 +35,14 @@ use walkdir::WalkDir;
 /// - Memory usage per read operation
 /// - Number of syscalls
 /// - Progress update granularity
-const STREAM_CHUNK_SIZE: usize = 65536; // 64KB
+pub(crate) const STREAM_CHUNK_SIZE: usize = 65536; // 64KB
 
 /// Handler for file operations
 ///
 /// Provides methods for downloading, uploading, updating, and deleting files.
 #[derive(Debug, Clone)]
 pub struct FileHandler {
-    client: FilesClient,
+    pub(crate) client: FilesClient,
 }
 
 impl FileHandler {
@@ -116,28 +124,16 @@ impl FileHandler {
     /// # }
     /// ```
     pub async fn download_content(&self, path: &str) -> Result<Vec<u8>> {
-        // First, get the file metadata to obtain the download URI
-        let file = self.download_file(path).await?;
-
-        // Extract the download URI
-        let download_uri = file.download_uri.ok_or_else(|| {
-            FilesError::not_found_resource("No download URI available", "file", path)
-        })?;
-
-        // Fetch the actual file content from the download URI
-        let response = reqwest::get(&download_uri)
-            .await
-            .map_err(FilesError::Request)?;
-
-        let bytes = response.bytes().await.map_err(FilesError::Request)?;
-
-        Ok(bytes.to_vec())
+        download::download_range(self, path, 0, None).await
     }
 
     /// Download file content and save to a local file
     ///
-    /// This is a convenience method that downloads the file content and
-    /// writes it to the specified local path.
+    /// The content is streamed to `<local_path>.part`, which is renamed to
+    /// `local_path` once complete. If a `.part` file from an interrupted
+    /// attempt exists, the download continues from its length using a
+    /// `Range` request. An expired download URI is replaced by a fresh one
+    /// automatically.
     ///
     /// # Arguments
     ///
@@ -169,10 +165,107 @@ impl FileHandler {
         remote_path: &str,
         local_path: &std::path::Path,
     ) -> Result<()> {
-        let content = self.download_content(remote_path).await?;
-        std::fs::write(local_path, content)
-            .map_err(|e| FilesError::IoError(format!("Failed to write file: {}", e)))?;
-        Ok(())
+        download::download_to_file(self, remote_path, local_path, &DownloadOptions::default()).await
+    }
+
+    /// Download a file to a local path with custom download options
+    ///
+    /// Same as [`download_to_file()`](Self::download_to_file), with optional
+    /// checksum verification and parallel segmented downloads of large
+    /// files (see [`DownloadOptions::segments`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `remote_path` - Path to the file on Files.com
+    /// * `local_path` - Local filesystem path where the file should be saved
+    /// * `options` - Download options
+    ///
+    /// # Errors
+    ///
+    /// With checksum verification enabled, returns
+    /// [`FilesError::ChecksumMismatch`] if the downloaded file does not
+    /// match; the downloaded data is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use files_sdk::files::DownloadOptions;
+    /// use files_sdk::{FilesClient, FileHandler};
+    /// use std::path::Path;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = FilesClient::builder().api_key("key").build()?;
+    /// let handler = FileHandler::new(client);
+    ///
+    /// let options = DownloadOptions::default().verify_checksum(true).segments(8);
+    /// handler
+    ///     .download_to_file_with_options("/backups/disk.img", Path::new("disk.img"), &options)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_to_file_with_options(
+        &self,
+        remote_path: &str,
+        local_path: &Path,
+        options: &DownloadOptions,
+    ) -> Result<()> {
+        download::download_to_file(self, remote_path, local_path, options).await
+    }
+
+    /// Download a byte range of a file
+    ///
+    /// The range is sent as an HTTP `Range` header, so only the requested
+    /// bytes are transferred. Open ranges such as `1024..` read to the end
+    /// of the file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the file on Files.com
+    /// * `range` - Byte range to download, e.g. `0..512` or `1024..`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the range starts past the end of the file
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use files_sdk::{FilesClient, FileHandler};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = FilesClient::builder().api_key("key").build()?;
+    /// let handler = FileHandler::new(client);
+    ///
+    /// // Read a 512-byte header without downloading the whole file
+    /// let header = handler.download_range("/data/archive.tar", 0..512).await?;
+    /// println!("Read {} bytes", header.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_range(
+        &self,
+        path: &str,
+        range: impl RangeBounds<u64>,
+    ) -> Result<Vec<u8>> {
+        let start = match range.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => Some(*end),
+            Bound::Excluded(0) => return Ok(Vec::new()),
+            Bound::Excluded(end) => Some(end - 1),
+            Bound::Unbounded => None,
+        };
+        if end.is_some_and(|end| end < start) {
+            return Ok(Vec::new());
+        }
+
+        download::download_range(self, path, start, end).await
     }
 
     /// Download file content to an async stream
@@ -261,51 +354,63 @@ impl FileHandler {
     where
         W: tokio::io::AsyncWrite + Unpin,
     {
-        use tokio::io::AsyncWriteExt;
-
-        // First, get the file metadata to obtain the download URI and size
-        let file = self.download_file(remote_path).await?;
-
-        // Extract the download URI
-        let download_uri = file.download_uri.ok_or_else(|| {
-            FilesError::not_found_resource("No download URI available", "file", remote_path)
-        })?;
-
-        // Get the total file size for progress tracking
-        let total_bytes = file
-            .size
-            .and_then(|s| if s > 0 { Some(s as u64) } else { None });
-
-        // Stream the file content from the download URI
-        let mut response = reqwest::get(&download_uri)
-            .await
-            .map_err(FilesError::Request)?;
-
-        let mut bytes_transferred = 0u64;
-
-        // Stream chunks to the writer with progress tracking
-        while let Some(chunk) = response.chunk().await.map_err(FilesError::Request)? {
-            writer
-                .write_all(&chunk)
-                .await
-                .map_err(|e| FilesError::IoError(format!("Failed to write to stream: {}", e)))?;
-
-            bytes_transferred += chunk.len() as u64;
-
-            // Report progress
-            if let Some(ref callback) = progress_callback {
-                let progress = Progress::new(bytes_transferred, total_bytes);
-                callback.on_progress(&progress);
-            }
-        }
-
-        // Flush the writer to ensure all data is written
-        writer
-            .flush()
-            .await
-            .map_err(|e| FilesError::IoError(format!("Failed to flush stream: {}", e)))?;
+        self.download_stream_with_options(
+            remote_path,
+            writer,
+            progress_callback,
+            &DownloadOptions::default(),
+        )
+        .await
+    }
 
-        Ok(())
+    /// Download file content to an async stream with custom download options
+    ///
+    /// Same as [`download_stream()`](Self::download_stream), but allows
+    /// enabling checksum verification through [`DownloadOptions`].
+    ///
+    /// # Arguments
+    ///
+    /// * `remote_path` - Path to the file on Files.com
+    /// * `writer` - An async writer implementing [`tokio::io::AsyncWrite`]
+    /// * `progress_callback` - Optional callback for progress updates
+    /// * `options` - Download options
+    ///
+    /// # Errors
+    ///
+    /// With checksum verification enabled, returns
+    /// [`FilesError::ChecksumMismatch`] if the bytes written do not match the
+    /// checksum reported by Files.com. The bytes have already been written
+    /// to `writer` at that point.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use files_sdk::{FilesClient, files::{DownloadOptions, FileHandler}};
+    /// # use tokio::fs::File;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = FilesClient::builder().api_key("key").build()?;
+    /// let handler = FileHandler::new(client);
+    ///
+    /// let mut file = File::create("ledger.csv").await?;
+    /// let options = DownloadOptions::default().verify_checksum(true);
+    /// handler
+    ///     .download_stream_with_options("/finance/ledger.csv", &mut file, None, &options)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_stream_with_options<W>(
+        &self,
+        remote_path: &str,
+        writer: &mut W,
+        progress_callback: Option<Arc<dyn ProgressCallback>>,
+        options: &DownloadOptions,
+    ) -> Result<()>
+    where
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        download::download(self, remote_path, writer, progress_callback, options).await
     }
 
     /// Get file metadata only (no download URL, no logging)
@@ -324,8 +429,8 @@ impl FileHandler {
     ///
     /// This method handles the complete upload process:
     /// 1. Calls begin_upload to get upload URLs
-    /// 2. Uploads the file data
-    /// 3. Finalizes the upload
+    /// 2. Uploads the file data, one part at a time
+    /// 3. Finalizes the upload with the part ETags
     ///
     /// # Arguments
     ///
@@ -348,90 +453,15 @@ impl FileHandler {
     /// # }
     /// ```
     pub async fn upload_file(&self, path: &str, data: &[u8]) -> Result<FileEntity> {
-        // Stage 1: Begin upload
-        let file_action = FileActionHandler::new(self.client.clone());
-        let upload_parts = file_action
-            .begin_upload(path, Some(data.len() as i64), true)
-            .await?;
-
-        if upload_parts.is_empty() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: 500,
-                message: "No upload parts returned from begin_upload".to_string(),
-            });
-        }
-
-        let upload_part = &upload_parts[0];
-
-        // Stage 2: Upload file data to the provided URL
-        // This is an external URL (not Files.com API), typically to cloud storage
-        let _etag = if let Some(upload_uri) = &upload_part.upload_uri {
-            let http_client = reqwest::Client::new();
-            let http_method = upload_part
-                .http_method
-                .as_deref()
-                .unwrap_or("PUT")
-                .to_uppercase();
-
-            let mut request = match http_method.as_str() {
-                "PUT" => http_client.put(upload_uri),
-                "POST" => http_client.post(upload_uri),
-                _ => http_client.put(upload_uri),
-            };
-
-            // Add any custom headers
-            if let Some(headers) = &upload_part.headers {
-                for (key, value) in headers {
-                    request = request.header(key, value);
-                }
-            }
-
-            // Set Content-Length header (required by S3, even for empty files)
-            let upload_response = request
-                .header("Content-Length", data.len().to_string())
-                .body(data.to_vec())
-                .send()
-                .await?;
-
-            // Extract ETag from response headers
-            upload_response
-                .headers()
-                .get("etag")
-                .and_then(|v| v.to_str().ok())
-                .map(|s| s.trim_matches('"').to_string())
-        } else {
-            None
-        };
-
-        // Stage 3: Finalize upload with Files.com
-        let encoded_path = encode_path(path);
-        let endpoint = format!("/files{}", encoded_path);
-
-        // Build the finalization request as form data
-        let mut form = vec![("action", "end".to_string())];
-
-        // Add ref (upload reference) - this is required to identify the upload
-        if let Some(ref_value) = &upload_part.ref_ {
-            form.push(("ref", ref_value.clone()));
-        }
-
-        // Note: etags might not be needed when ref is provided
-        // Commenting out for now to test
-        // if let Some(etag_value) = etag {
-        //     let part_number = upload_part.part_number.unwrap_or(1);
-        //     form.push(("etags[etag]", etag_value));
-        //     form.push(("etags[part]", part_number.to_string()));
-        // }
-
-        let response = self.client.post_form(&endpoint, &form).await?;
-        Ok(serde_json::from_value(response)?)
+        self.upload_stream(path, data, Some(data.len() as i64), None)
+            .await
     }
 
     /// Upload a file from an async stream
     ///
-    /// This method is more memory-efficient than [`upload_file()`](Self::upload_file) for large files
-    /// as it reads the data in chunks (8KB) instead of loading it entirely into memory.
+    /// This method is more memory-efficient than [`upload_file()`](Self::upload_file) for large files.
+    /// The data is read one part at a time and parts are uploaded concurrently, so memory use
+    /// is bounded by the part size times the number of parts in flight (see [`UploadOptions`]).
     ///
     /// # Arguments
     ///
@@ -522,117 +552,319 @@ impl FileHandler {
     pub async fn upload_stream<R>(
         &self,
         path: &str,
-        mut reader: R,
+        reader: R,
         size: Option<i64>,
         progress_callback: Option<Arc<dyn ProgressCallback>>,
     ) -> Result<FileEntity>
     where
         R: tokio::io::AsyncRead + Unpin,
     {
-        use tokio::io::AsyncReadExt;
+        self.upload_stream_with_options(
+            path,
+            reader,
+            size,
+            progress_callback,
+            &UploadOptions::default(),
+        )
+        .await
+    }
 
-        // Stage 1: Begin upload
-        let file_action = FileActionHandler::new(self.client.clone());
-        let upload_parts = file_action.begin_upload(path, size, true).await?;
-
-        if upload_parts.is_empty() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: 500,
-                message: "No upload parts returned from begin_upload".to_string(),
-            });
+    /// Upload a file from an async stream with custom upload options
+    ///
+    /// Same as [`upload_stream()`](Self::upload_stream), but allows tuning the
+    /// multipart upload through [`UploadOptions`], e.g. the number of parts
+    /// uploaded concurrently.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Destination path for the file on Files.com
+    /// * `reader` - An async reader implementing [`tokio::io::AsyncRead`]
+    /// * `size` - Optional size of the file in bytes
+    /// * `progress_callback` - Optional callback for progress updates
+    /// * `options` - Upload options
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use files_sdk::{FilesClient, files::{FileHandler, UploadOptions}};
+    /// # use tokio::fs::File;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = FilesClient::builder().api_key("key").build()?;
+    /// let handler = FileHandler::new(client);
+    ///
+    /// let file = File::open("backup.tar").await?;
+    /// let size = file.metadata().await?.len() as i64;
+    ///
+    /// let options = UploadOptions::default().max_concurrent_parts(8);
+    /// handler
+    ///     .upload_stream_with_options("/backups/backup.tar", file, Some(size), None, &options)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn upload_stream_with_options<R>(
+        &self,
+        path: &str,
+        reader: R,
+        size: Option<i64>,
+        progress_callback: Option<Arc<dyn ProgressCallback>>,
+        options: &UploadOptions,
+    ) -> Result<FileEntity>
+    where
+        R: tokio::io::AsyncRead + Unpin,
+    {
+        multipart::upload(&self.client, path, reader, size, progress_callback, options).await
+    }
+
+    /// Upload from a seekable stream, resuming from a checkpoint
+    ///
+    /// Continues the upload described by `checkpoint`, or starts a new one if
+    /// the checkpoint has not been started yet. The reader is positioned at the
+    /// checkpoint's offset before reading. `on_checkpoint` is called with the
+    /// updated checkpoint after every completed part, so it can be persisted and
+    /// handed back to a later call if this one is interrupted.
+    ///
+    /// Part URLs that have expired are re-requested automatically.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Destination path for the file on Files.com
+    /// * `reader` - Source data, seekable so the upload can resume mid-file
+    /// * `size` - Size of the source in bytes
+    /// * `checkpoint` - Checkpoint to resume from (see [`UploadCheckpoint::new`])
+    /// * `on_checkpoint` - Called after each completed part
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the checkpoint belongs to a different path, if any
+    /// request fails, or if `on_checkpoint` returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use files_sdk::{FilesClient, files::{FileHandler, UploadCheckpoint}};
+    /// # use std::path::Path;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = FilesClient::builder().api_key("key").build()?;
+    /// let handler = FileHandler::new(client);
+    ///
+    /// let file = tokio::fs::File::open("db.tar").await?;
+    /// let size = file.metadata().await?.len() as i64;
+    /// let fingerprint = UploadCheckpoint::fingerprint_file(Path::new("db.tar"))?;
+    /// let checkpoint = UploadCheckpoint::new("/backups/db.tar", fingerprint);
+    ///
+    /// handler
+    ///     .upload_stream_resumable("/backups/db.tar", file, size, checkpoint, |cp| {
+    ///         cp.save(Path::new("db.tar.checkpoint"))
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn upload_stream_resumable<R, F>(
+        &self,
+        path: &str,
+        reader: R,
+        size: i64,
+        checkpoint: UploadCheckpoint,
+        on_checkpoint: F,
+    ) -> Result<FileEntity>
+    where
+        R: tokio::io::AsyncRead + tokio::io::AsyncSeek + Unpin,
+        F: FnMut(&UploadCheckpoint) -> Result<()>,
+    {
+        self.upload_stream_resumable_with_options(
+            path,
+            reader,
+            size,
+            checkpoint,
+            &UploadOptions::default(),
+            on_checkpoint,
+        )
+        .await
+    }
+
+    /// Upload from a seekable stream, resuming from a checkpoint, with custom options
+    ///
+    /// Same as [`upload_stream_resumable`](Self::upload_stream_resumable).
+    /// With [`UploadOptions::verify_checksum`], the bytes uploaded before the
+    /// interruption are read again from the start of `reader` and hashed, so
+    /// the whole file is verified.
+    pub async fn upload_stream_resumable_with_options<R, F>(
+        &self,
+        path: &str,
+        mut reader: R,
+        size: i64,
+        mut checkpoint: UploadCheckpoint,
+        options: &UploadOptions,
+        on_checkpoint: F,
+    ) -> Result<FileEntity>
+    where
+        R: tokio::io::AsyncRead + tokio::io::AsyncSeek + Unpin,
+        F: FnMut(&UploadCheckpoint) -> Result<()>,
+    {
+        use tokio::io::AsyncSeekExt;
+
+        if checkpoint.path != path {
+            return Err(FilesError::ConfigError(format!(
+                "Checkpoint was created for {}, not {}",
+                checkpoint.path, path
+            )));
         }
 
-        let upload_part = &upload_parts[0];
-
-        // Stage 2: Stream file data to the provided URL with progress tracking
-        // Note: Even for empty files (size=0), we must perform the upload stage.
-        // S3 requires the Content-Length header, and the API tracks whether the upload occurred.
-        let _etag = if upload_part.upload_uri.is_some() {
-            let upload_uri = upload_part.upload_uri.as_ref().unwrap();
-            // Read the stream into a buffer with progress tracking
-            // Note: We read in chunks to provide progress updates, but still buffer
-            // the entire file before upload. This is required by the Files.com API
-            // which expects the full file in a single PUT/POST request.
-
-            // Pre-allocate buffer if size is known for better performance
-            let mut buffer = if let Some(s) = size {
-                Vec::with_capacity(s as usize)
-            } else {
-                Vec::new()
-            };
-
-            let mut temp_buffer = vec![0u8; STREAM_CHUNK_SIZE];
-            let total_bytes = size.map(|s| s as u64);
-
-            loop {
-                let bytes_read = reader.read(&mut temp_buffer).await.map_err(|e| {
-                    FilesError::IoError(format!("Failed to read from stream: {}", e))
-                })?;
-
-                if bytes_read == 0 {
-                    break;
-                }
-
-                buffer.extend_from_slice(&temp_buffer[..bytes_read]);
-
-                // Report progress
-                if let Some(ref callback) = progress_callback {
-                    let progress = Progress::new(buffer.len() as u64, total_bytes);
-                    callback.on_progress(&progress);
-                }
-            }
-
-            let http_client = reqwest::Client::new();
-            let http_method = upload_part
-                .http_method
-                .as_deref()
-                .unwrap_or("PUT")
-                .to_uppercase();
-
-            let mut request = match http_method.as_str() {
-                "PUT" => http_client.put(upload_uri),
-                "POST" => http_client.post(upload_uri),
-                _ => http_client.put(upload_uri),
-            };
-
-            // Add any custom headers
-            if let Some(headers) = &upload_part.headers {
-                for (key, value) in headers {
-                    request = request.header(key, value);
-                }
-            }
-
-            // Set Content-Length header (required by S3, even for empty files)
-            let content_length = buffer.len();
-            let upload_response = request
-                .header("Content-Length", content_length.to_string())
-                .body(buffer)
-                .send()
-                .await?;
-
-            // Extract ETag from response headers
-            upload_response
-                .headers()
-                .get("etag")
-                .and_then(|v| v.to_str().ok())
-                .map(|s| s.trim_matches('"').to_string())
-        } else {
-            None
-        };
+        checkpoint.truncate_to_contiguous();
+        let seek_error =
+            |e: std::io::Error| FilesError::IoError(format!("Failed to seek source: {}", e));
 
-        // Stage 3: Finalize upload with Files.com
-        let encoded_path = encode_path(path);
-        let endpoint = format!("/files{}", encoded_path);
+        let mut hasher = None;
+        if options.verify_checksum {
+            let mut prefix = Hasher::all();
+            reader
+                .seek(std::io::SeekFrom::Start(0))
+                .await
+                .map_err(seek_error)?;
+            checksum::hash_reader(&mut prefix, &mut reader, Some(checkpoint.offset))
+                .await
+                .map_err(|e| FilesError::IoError(format!("Failed to read source: {}", e)))?;
+            hasher = Some(prefix);
+        }
+        reader
+            .seek(std::io::SeekFrom::Start(checkpoint.offset))
+            .await
+            .map_err(seek_error)?;
+
+        multipart::upload_with_checkpoint(
+            &self.client,
+            path,
+            reader,
+            Some(size),
+            None,
+            options,
+            checkpoint,
+            hasher,
+            on_checkpoint,
+        )
+        .await
+    }
 
-        let mut form = vec![("action", "end".to_string())];
+    /// Upload a local file, resuming from a checkpoint file if one exists
+    ///
+    /// The checkpoint is stored as JSON at `checkpoint_path` after every
+    /// completed part and removed once the upload is finalized. If the local
+    /// file changed since the checkpoint was written (different size or
+    /// modification time), the upload starts over.
+    ///
+    /// # Arguments
+    ///
+    /// * `local_path` - Local file to upload
+    /// * `remote_path` - Destination path on Files.com
+    /// * `checkpoint_path` - Where to keep the checkpoint
+    /// * `progress_callback` - Optional callback for progress updates
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use files_sdk::{FilesClient, FileHandler};
+    /// # use std::path::Path;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = FilesClient::builder().api_key("key").build()?;
+    /// let handler = FileHandler::new(client);
+    ///
+    /// // Safe to re-run after a crash: completed parts are not uploaded again
+    /// handler
+    ///     .upload_file_resumable(
+    ///         Path::new("video.mp4"),
+    ///         "/media/video.mp4",
+    ///         Path::new("video.mp4.checkpoint"),
+    ///         None,
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn upload_file_resumable(
+        &self,
+        local_path: &Path,
+        remote_path: &str,
+        checkpoint_path: &Path,
+        progress_callback: Option<Arc<dyn ProgressCallback>>,
+    ) -> Result<FileEntity> {
+        self.upload_file_resumable_with_options(
+            local_path,
+            remote_path,
+            checkpoint_path,
+            progress_callback,
+            &UploadOptions::default(),
+        )
+        .await
+    }
+
+    /// Upload a local file, resuming from a checkpoint file, with custom options
+    ///
+    /// Same as [`upload_file_resumable`](Self::upload_file_resumable). With
+    /// [`UploadOptions::verify_checksum`], the part of the local file uploaded
+    /// before the interruption is hashed again, so the whole file is verified.
+    pub async fn upload_file_resumable_with_options(
+        &self,
+        local_path: &Path,
+        remote_path: &str,
+        checkpoint_path: &Path,
+        progress_callback: Option<Arc<dyn ProgressCallback>>,
+        options: &UploadOptions,
+    ) -> Result<FileEntity> {
+        use tokio::io::AsyncSeekExt;
+
+        let fingerprint = UploadCheckpoint::fingerprint_file(local_path)?;
+        let mut checkpoint = match UploadCheckpoint::load(checkpoint_path)? {
+            Some(checkpoint) if checkpoint.matches(remote_path, &fingerprint) => checkpoint,
+            _ => UploadCheckpoint::new(remote_path, fingerprint),
+        };
+        checkpoint.truncate_to_contiguous();
 
-        if let Some(ref_value) = &upload_part.ref_ {
-            form.push(("ref", ref_value.clone()));
+        let mut hasher = None;
+        if options.verify_checksum {
+            let mut prefix = Hasher::all();
+            checksum::hash_file(&mut prefix, local_path, Some(checkpoint.offset)).await?;
+            hasher = Some(prefix);
         }
 
-        let response = self.client.post_form(&endpoint, &form).await?;
-        Ok(serde_json::from_value(response)?)
+        let mut file = tokio::fs::File::open(local_path).await.map_err(|e| {
+            FilesError::IoError(format!("Failed to open {}: {}", local_path.display(), e))
+        })?;
+        let size = file
+            .metadata()
+            .await
+            .map_err(|e| FilesError::IoError(e.to_string()))?
+            .len();
+        file.seek(std::io::SeekFrom::Start(checkpoint.offset))
+            .await
+            .map_err(|e| FilesError::IoError(format!("Failed to seek source: {}", e)))?;
+
+        let entity = multipart::upload_with_checkpoint(
+            &self.client,
+            remote_path,
+            file,
+            Some(size as i64),
+            progress_callback,
+            options,
+            checkpoint,
+            hasher,
+            |checkpoint| checkpoint.save(checkpoint_path),
+        )
+        .await?;
+
+        match std::fs::remove_file(checkpoint_path) {
+            Ok(()) => Ok(entity),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(entity),
+            Err(e) => Err(FilesError::IoError(format!(
+                "Failed to remove checkpoint {}: {}",
+                checkpoint_path.display(),
+                e
+            ))),
+        }
     }
 
     /// Update file metadata
@@ -665,7 +897,7 @@ impl FileHandler {
         &self,
         path: &str,
         custom_metadata: Option<HashMap<String, String>>,
-        provided_mtime: Option<String>,
+        provided_mtime: Option<Timestamp>,
         priority_color: Option<String>,
     ) -> Result<FileEntity> {
         let mut body = json!({});
@@ -719,7 +951,7 @@ impl FileHandler {
         Ok(())
     }
 
-    /// Copy a file
+    /// Copy a file or folder
     ///
     /// This is a convenience method that calls `FileActionHandler::copy_file()`
     ///
@@ -727,12 +959,12 @@ impl FileHandler {
     ///
     /// * `source` - Source file path
     /// * `destination` - Destination path
-    pub async fn copy_file(&self, source: &str, destination: &str) -> Result<()> {
+    pub async fn copy_file(&self, source: &str, destination: &str) -> Result<MigrationHandle> {
         let file_action = FileActionHandler::new(self.client.clone());
         file_action.copy_file(source, destination).await
     }
 
-    /// Move a file
+    /// Move a file or folder
     ///
     /// This is a convenience method that calls `FileActionHandler::move_file()`
     ///
@@ -740,7 +972,7 @@ impl FileHandler {
     ///
     /// * `source` - Source file path
     /// * `destination` - Destination path
-    pub async fn move_file(&self, source: &str, destination: &str) -> Result<()> {
+    pub async fn move_file(&self, source: &str, destination: &str) -> Result<MigrationHandle> {
         let file_action = FileActionHandler::new(self.client.clone());
         file_action.move_file(source, destination).await
     }
@@ -748,7 +980,8 @@ impl FileHandler {
     /// Upload an entire directory recursively
     ///
     /// Walks through a local directory and uploads all files to Files.com,
-    /// preserving the directory structure.
+    /// preserving the directory structure. Files are streamed and uploaded
+    /// concurrently (see [`upload_directory_with_options()`](Self::upload_directory_with_options)).
     ///
     /// # Arguments
     ///
@@ -764,8 +997,9 @@ impl FileHandler {
     ///
     /// Returns an error if:
     /// - Local directory doesn't exist or isn't readable
-    /// - Path contains invalid UTF-8
-    /// - Any file upload fails
+    /// - A file cannot be read or its path contains invalid UTF-8
+    /// - Any file upload fails (the first error is returned after all
+    ///   other files have been attempted)
     ///
     /// # Examples
     ///
@@ -794,72 +1028,10 @@ impl FileHandler {
         remote_path: &str,
         mkdir_parents: bool,
     ) -> Result<Vec<String>> {
-        let mut uploaded = Vec::new();
-
-        for entry in WalkDir::new(local_dir).into_iter().filter_map(|e| e.ok()) {
-            if entry.file_type().is_file() {
-                let local_file = entry.path();
-
-                // Calculate relative path
-                let relative = local_file
-                    .strip_prefix(local_dir)
-                    .map_err(|e| FilesError::IoError(format!("Failed to strip prefix: {}", e)))?
-                    .to_str()
-                    .ok_or_else(|| {
-                        FilesError::IoError(format!(
-                            "Invalid UTF-8 in path: {}",
-                            local_file.display()
-                        ))
-                    })?;
-
-                // Construct remote path (use forward slashes for Files.com)
-                let remote_file = format!(
-                    "{}/{}",
-                    remote_path.trim_end_matches('/'),
-                    relative.replace('\\', "/")
-                );
-
-                // Read and upload
-                let data =
-                    std::fs::read(local_file).map_err(|e| FilesError::IoError(e.to_string()))?;
-
-                // Upload using the same two-stage process as upload_file
-                let file_action = FileActionHandler::new(self.client.clone());
-                let upload_parts = file_action
-                    .begin_upload(&remote_file, Some(data.len() as i64), mkdir_parents)
-                    .await?;
-
-                if !upload_parts.is_empty() {
-                    let upload_part = &upload_parts[0];
-                    if let Some(upload_uri) = &upload_part.upload_uri {
-                        let http_client = reqwest::Client::new();
-                        let http_method = upload_part
-                            .http_method
-                            .as_deref()
-                            .unwrap_or("PUT")
-                            .to_uppercase();
-
-                        let mut request = match http_method.as_str() {
-                            "PUT" => http_client.put(upload_uri),
-                            "POST" => http_client.post(upload_uri),
-                            _ => http_client.put(upload_uri),
-                        };
-
-                        if let Some(headers) = &upload_part.headers {
-                            for (key, value) in headers {
-                                request = request.header(key, value);
-                            }
-                        }
-
-                        request.body(data.to_vec()).send().await?;
-                    }
-                }
-
-                uploaded.push(remote_file);
-            }
-        }
-
-        Ok(uploaded)
+        let options = DirectoryUploadOptions::default().mkdir_parents(mkdir_parents);
+        directory::upload_directory(&self.client, local_dir, remote_path, &options, |_, _| {})
+            .await?
+            .into_result()
     }
 
     /// Upload directory with progress callback
@@ -907,82 +1079,155 @@ impl FileHandler {
     where
         F: Fn(usize, usize),
     {
-        // Count files first
-        let total_files = WalkDir::new(local_dir)
-            .into_iter()
-            .filter_map(|e| e.ok())
-            .filter(|e| e.file_type().is_file())
-            .count();
-
-        let mut uploaded = Vec::new();
-        let mut current = 0;
-
-        for entry in WalkDir::new(local_dir).into_iter().filter_map(|e| e.ok()) {
-            if entry.file_type().is_file() {
-                let local_file = entry.path();
-
-                // Calculate relative path
-                let relative = local_file
-                    .strip_prefix(local_dir)
-                    .map_err(|e| FilesError::IoError(format!("Failed to strip prefix: {}", e)))?
-                    .to_str()
-                    .ok_or_else(|| {
-                        FilesError::IoError(format!(
-                            "Invalid UTF-8 in path: {}",
-                            local_file.display()
-                        ))
-                    })?;
-
-                // Construct remote path
-                let remote_file = format!(
-                    "{}/{}",
-                    remote_path.trim_end_matches('/'),
-                    relative.replace('\\', "/")
-                );
-
-                // Read and upload
-                let data =
-                    std::fs::read(local_file).map_err(|e| FilesError::IoError(e.to_string()))?;
-
-                // Upload using the same two-stage process as upload_file
-                let file_action = FileActionHandler::new(self.client.clone());
-                let upload_parts = file_action
-                    .begin_upload(&remote_file, Some(data.len() as i64), mkdir_parents)
-                    .await?;
-
-                if !upload_parts.is_empty() {
-                    let upload_part = &upload_parts[0];
-                    if let Some(upload_uri) = &upload_part.upload_uri {
-                        let http_client = reqwest::Client::new();
-                        let http_method = upload_part
-                            .http_method
-                            .as_deref()
-                            .unwrap_or("PUT")
-                            .to_uppercase();
-
-                        let mut request = match http_method.as_str() {
-                            "PUT" => http_client.put(upload_uri),
-                            "POST" => http_client.post(upload_uri),
-                            _ => http_client.put(upload_uri),
-                        };
-
-                        if let Some(headers) = &upload_part.headers {
-                            for (key, value) in headers {
-                                request = request.header(key, value);
-                            }
-                        }
-
-                        request.body(data.to_vec()).send().await?;
-                    }
-                }
-
-                uploaded.push(remote_file);
-                current += 1;
-                progress(current, total_files);
-            }
-        }
+        let options = DirectoryUploadOptions::default().mkdir_parents(mkdir_parents);
+        directory::upload_directory(&self.client, local_dir, remote_path, &options, progress)
+            .await?
+            .into_result()
+    }
 
-        Ok(uploaded)
+    /// Upload a directory and report the outcome for every file
+    ///
+    /// Files are streamed and uploaded concurrently, filtered by the include
+    /// and exclude globs in `options`. Unlike
+    /// [`upload_directory()`](Self::upload_directory), a failed file does not
+    /// stop the upload; it is recorded in the report together with its error.
+    ///
+    /// # Arguments
+    ///
+    /// * `local_dir` - Local directory path to upload
+    /// * `remote_path` - Remote destination path on Files.com
+    /// * `options` - Concurrency, filters and symlink handling
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if `local_dir` is not a directory or a glob
+    /// pattern is invalid
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use files_sdk::files::DirectoryUploadOptions;
+    /// use files_sdk::{FilesClient, FileHandler};
+    /// use std::path::Path;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = FilesClient::builder().api_key("key").build()?;
+    /// let handler = FileHandler::new(client);
+    ///
+    /// let options = DirectoryUploadOptions::default()
+    ///     .concurrency(8)
+    ///     .exclude("**/.git/**");
+    /// let report = handler
+    ///     .upload_directory_with_options(Path::new("./site"), "/www", &options)
+    ///     .await?;
+    ///
+    /// println!("Uploaded {} files", report.uploaded.len());
+    /// for failed in &report.failed {
+    ///     eprintln!("{}: {}", failed.local_path.display(), failed.error);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn upload_directory_with_options(
+        &self,
+        local_dir: &Path,
+        remote_path: &str,
+        options: &DirectoryUploadOptions,
+    ) -> Result<DirectoryUploadReport> {
+        directory::upload_directory(&self.client, local_dir, remote_path, options, |_, _| {}).await
+    }
+
+    /// Download a folder and everything below it into a local directory
+    ///
+    /// The remote folder tree is recreated under `local_dir`, including empty
+    /// folders. Files are streamed to disk concurrently and keep their remote
+    /// modification time. A failed file does not stop the download; it is
+    /// recorded in the report together with its error.
+    ///
+    /// # Arguments
+    ///
+    /// * `remote_path` - Folder path on Files.com
+    /// * `local_dir` - Local directory to download into (created if missing)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if `local_dir` cannot be created or
+    /// `remote_path` cannot be listed
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use files_sdk::{FilesClient, FileHandler};
+    /// use std::path::Path;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = FilesClient::builder().api_key("key").build()?;
+    /// let handler = FileHandler::new(client);
+    ///
+    /// let report = handler
+    ///     .download_directory("/reports", Path::new("./reports"))
+    ///     .await?;
+    ///
+    /// println!("Downloaded {} bytes", report.bytes_downloaded());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_directory(
+        &self,
+        remote_path: &str,
+        local_dir: &Path,
+    ) -> Result<DirectoryDownloadReport> {
+        let options = DirectoryDownloadOptions::default();
+        directory::download_directory(&self.client, remote_path, local_dir, &options).await
+    }
+
+    /// Download a folder with custom options
+    ///
+    /// Same as [`download_directory()`](Self::download_directory), with
+    /// control over concurrency, modification times and whether files that
+    /// already match locally are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `remote_path` - Folder path on Files.com
+    /// * `local_dir` - Local directory to download into (created if missing)
+    /// * `options` - Concurrency, skipping and modification time handling
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use files_sdk::files::DirectoryDownloadOptions;
+    /// use files_sdk::{FilesClient, FileHandler};
+    /// use std::path::Path;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = FilesClient::builder().api_key("key").build()?;
+    /// let handler = FileHandler::new(client);
+    ///
+    /// let options = DirectoryDownloadOptions::default().skip_unchanged(true);
+    /// let report = handler
+    ///     .download_directory_with_options("/reports", Path::new("./reports"), &options)
+    ///     .await?;
+    ///
+    /// println!(
+    ///     "{} downloaded, {} unchanged, {} failed",
+    ///     report.downloaded.len(),
+    ///     report.skipped.len(),
+    ///     report.failed.len()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_directory_with_options(
+        &self,
+        remote_path: &str,
+        local_dir: &Path,
+        options: &DirectoryDownloadOptions,
+    ) -> Result<DirectoryDownloadReport> {
+        directory::download_directory(&self.client, remote_path, local_dir, options).await
     }
 }
 
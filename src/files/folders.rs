@@ -10,6 +10,8 @@
 //! - Delete folders (recursive or non-recursive)
 //! - Search files within folders
 //! - Automatic pagination for large directories
+//! - Walk folder trees recursively
+//! - Mirror a folder tree to a local directory
 //!
 //! # Example
 //!
@@ -24,7 +26,7 @@
 //! let handler = FolderHandler::new(client);
 //!
 //! // List root directory
-//! let (files, pagination) = handler.list_folder("/", None, None).await?;
+//! let (files, pagination) = handler.list_folder("/", None, None, None).await?;
 //! for file in files {
 //!     println!("{}: {}",
 //!         file.file_type.unwrap_or_default(),
@@ -35,16 +37,30 @@
 //! handler.create_folder("/projects/2024/q4", true).await?;
 //!
 //! // Search for files
-//! let (results, _) = handler.search_folder("/", "report", None).await?;
+//! let (results, _) = handler.search_folder("/", "report", None, None).await?;
 //! println!("Found {} matching files", results.len());
 //! # Ok(())
 //! # }
 //! ```
 
+use crate::files::directory::{self, DirectoryDownloadOptions, DirectoryDownloadReport};
+use crate::files::walk::Walk;
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
 use crate::utils::encode_path;
 use crate::{FileEntity, FilesClient, PaginationInfo, Result};
-use futures::stream::Stream;
 use serde_json::json;
+use std::path::Path;
+
+query_fields! {
+    /// Fields that folder contents can be sorted and filtered by
+    pub enum FolderListField {
+        Path => "path",
+        Size => "size",
+        Mtime => "mtime",
+        ProvidedMtime => "provided_mtime",
+    }
+}
 
 /// Handler for folder operations
 ///
@@ -74,6 +90,7 @@ impl FolderHandler {
     /// * `path` - Folder path to list (empty string for root)
     /// * `per_page` - Number of items per page (optional, max 10,000)
     /// * `cursor` - Pagination cursor (optional)
+    /// * `query` - Sorting and filtering options (optional)
     ///
     /// # Returns
     ///
@@ -91,7 +108,7 @@ impl FolderHandler {
     ///     .build()?;
     ///
     /// let handler = FolderHandler::new(client);
-    /// let (files, pagination) = handler.list_folder("/", None, None).await?;
+    /// let (files, pagination) = handler.list_folder("/", None, None, None).await?;
     ///
     /// for file in files {
     ///     println!("{}: {}", file.file_type.unwrap_or_default(), file.path.unwrap_or_default());
@@ -108,6 +125,7 @@ impl FolderHandler {
         path: &str,
         per_page: Option<i32>,
         cursor: Option<String>,
+        query: Option<&ListQuery<FolderListField>>,
     ) -> Result<(Vec<FileEntity>, PaginationInfo)> {
         let encoded_path = encode_path(path);
         let mut endpoint = format!("/folders{}", encoded_path);
@@ -126,27 +144,12 @@ impl FolderHandler {
             endpoint.push_str(&query_params.join("&"));
         }
 
-        // Need to get the raw response to access headers
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
 
-        let files: Vec<FileEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let files: Vec<FileEntity> = serde_json::from_value(response)?;
 
         Ok((files, pagination))
     }
@@ -177,7 +180,7 @@ impl FolderHandler {
         let mut cursor = None;
 
         loop {
-            let (mut files, pagination) = self.list_folder(path, Some(1000), cursor).await?;
+            let (mut files, pagination) = self.list_folder(path, Some(1000), cursor, None).await?;
             all_files.append(&mut files);
 
             if pagination.has_next() {
@@ -200,6 +203,7 @@ impl FolderHandler {
     ///
     /// * `path` - Folder path to list
     /// * `per_page` - Number of items per page (optional, default 1000)
+    /// * `query` - Sorting and filtering options (optional)
     ///
     /// # Examples
     ///
@@ -210,7 +214,7 @@ impl FolderHandler {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let client = FilesClient::builder().api_key("key").build()?;
     /// let handler = FolderHandler::new(client);
-    /// let stream = handler.list_stream("/uploads", Some(100));
+    /// let stream = handler.list_stream("/uploads", Some(100), None);
     ///
     /// tokio::pin!(stream);
     ///
@@ -225,28 +229,58 @@ impl FolderHandler {
         &self,
         path: &str,
         per_page: Option<i32>,
-    ) -> impl Stream<Item = Result<FileEntity>> + '_ {
+        query: Option<ListQuery<FolderListField>>,
+    ) -> Paginator<FileEntity> {
+        let handler = self.clone();
         let path = path.to_string();
         let per_page = per_page.unwrap_or(1000);
 
-        async_stream::try_stream! {
-            let mut cursor: Option<String> = None;
-
-            loop {
-                let (files, pagination) = self
-                    .list_folder(&path, Some(per_page), cursor.clone())
-                    .await?;
-
-                for file in files {
-                    yield file;
-                }
-
-                match pagination.cursor_next {
-                    Some(next) => cursor = Some(next),
-                    None => break,
-                }
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let path = path.clone();
+            let query = query.clone();
+            async move {
+                handler
+                    .list_folder(&path, Some(per_page), cursor, query.as_ref())
+                    .await
             }
-        }
+        })
+    }
+
+    /// Walk a folder tree recursively
+    ///
+    /// Returns a stream of `(depth, entry)` pairs for every file and folder
+    /// below `path`. Entries directly inside `path` have depth 1. The walk
+    /// is breadth-first by default; see [`Walk`] for the max depth, pruning,
+    /// order and concurrency options.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Folder path to walk
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use files_sdk::{FilesClient, FolderHandler};
+    /// # use files_sdk::files::WalkOrder;
+    /// # use futures::TryStreamExt;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = FilesClient::builder().api_key("key").build()?;
+    /// let handler = FolderHandler::new(client);
+    /// let mut walk = handler
+    ///     .walk("/projects")
+    ///     .max_depth(3)
+    ///     .order(WalkOrder::DepthFirst);
+    ///
+    /// while let Some((depth, entry)) = walk.try_next().await? {
+    ///     println!("{}{}", "  ".repeat(depth - 1), entry.path.unwrap_or_default());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn walk(&self, path: &str) -> Walk {
+        Walk::new(self.clone(), path)
     }
 
     /// Create a new folder
@@ -321,6 +355,7 @@ impl FolderHandler {
     /// * `path` - Folder path to search in
     /// * `search` - Search query string
     /// * `per_page` - Number of results per page (optional)
+    /// * `cursor` - Pagination cursor from a previous response (optional)
     ///
     /// # Examples
     ///
@@ -330,7 +365,7 @@ impl FolderHandler {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let client = FilesClient::builder().api_key("key").build()?;
     /// let handler = FolderHandler::new(client);
-    /// let (results, _) = handler.search_folder("/", "report", None).await?;
+    /// let (results, _) = handler.search_folder("/", "report", None, None).await?;
     /// println!("Found {} files", results.len());
     /// # Ok(())
     /// # }
@@ -340,37 +375,87 @@ impl FolderHandler {
         path: &str,
         search: &str,
         per_page: Option<i32>,
+        cursor: Option<String>,
     ) -> Result<(Vec<FileEntity>, PaginationInfo)> {
         let encoded_path = encode_path(path);
+        let search: String = url::form_urlencoded::byte_serialize(search.as_bytes()).collect();
         let mut endpoint = format!("/folders{}?search={}", encoded_path, search);
 
         if let Some(per_page) = per_page {
             endpoint.push_str(&format!("&per_page={}", per_page));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(cursor) = cursor {
+            endpoint.push_str(&format!("&cursor={}", cursor));
         }
 
-        let files: Vec<FileEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let files: Vec<FileEntity> = serde_json::from_value(response)?;
 
         Ok((files, pagination))
     }
+
+    /// Stream search results within a folder across all pages
+    ///
+    /// Takes the same arguments as [`search_folder`](Self::search_folder), without the cursor.
+    pub fn search_folder_stream(
+        &self,
+        path: &str,
+        search: &str,
+        per_page: Option<i32>,
+    ) -> Paginator<FileEntity> {
+        let handler = self.clone();
+        let path = path.to_string();
+        let search = search.to_string();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let path = path.clone();
+            let search = search.clone();
+            async move {
+                handler
+                    .search_folder(&path, &search, per_page, cursor)
+                    .await
+            }
+        })
+    }
+
+    /// Download a folder and everything below it into a local directory
+    ///
+    /// Equivalent to
+    /// [`FileHandler::download_directory_with_options()`](crate::FileHandler::download_directory_with_options).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Folder path on Files.com
+    /// * `local_dir` - Local directory to download into (created if missing)
+    /// * `options` - Concurrency, skipping and modification time handling
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use files_sdk::{FilesClient, FolderHandler};
+    /// # use files_sdk::files::DirectoryDownloadOptions;
+    /// # use std::path::Path;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = FilesClient::builder().api_key("key").build()?;
+    /// let handler = FolderHandler::new(client);
+    /// let options = DirectoryDownloadOptions::default().concurrency(8);
+    /// let report = handler
+    ///     .download_directory("/projects", Path::new("./projects"), &options)
+    ///     .await?;
+    /// assert!(report.is_success());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_directory(
+        &self,
+        path: &str,
+        local_dir: &Path,
+        options: &DirectoryDownloadOptions,
+    ) -> Result<DirectoryDownloadReport> {
+        directory::download_directory(&self.client, path, local_dir, options).await
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,320 @@
+//! Tracking of copy and move operations
+//!
+//! Copying or moving a single file completes before Files.com responds. For
+//! folders, Files.com starts a file migration in the background and responds
+//! with its id instead. [`FileActionHandler::copy_file`] and
+//! [`FileActionHandler::move_file`] return a [`MigrationHandle`] in both cases,
+//! which can be waited on until the operation has actually finished.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use files_sdk::{FileHandler, FilesClient};
+//! use std::time::Duration;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = FilesClient::builder().api_key("your-api-key").build()?;
+//! let files = FileHandler::new(client);
+//!
+//! let migration = files
+//!     .move_file("/incoming/2024", "/archive/2024")
+//!     .await?
+//!     .poll_interval(Duration::from_secs(10))
+//!     .timeout(Duration::from_secs(6 * 60 * 60));
+//!
+//! match migration
+//!     .wait_with_progress(|p| println!("{}/{:?} files", p.files_moved, p.files_total))
+//!     .await
+//! {
+//!     Ok(progress) => println!("Moved {} files", progress.files_moved),
+//!     Err(e) => {
+//!         for log in migration.logs().await? {
+//!             println!("{:?}: {:?}", log.path, log.message);
+//!         }
+//!         return Err(e.into());
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`FileActionHandler::copy_file`]: crate::FileActionHandler::copy_file
+//! [`FileActionHandler::move_file`]: crate::FileActionHandler::move_file
+
+use crate::files::file_migration_logs::{FileMigrationLogEntity, FileMigrationLogField};
+use crate::files::{FileMigrationEntity, FileMigrationHandler, FileMigrationLogHandler};
+use crate::query::ListQuery;
+use crate::{FilesClient, FilesError, Result};
+use serde_json::Value;
+use std::time::Duration;
+use tokio::time::Instant;
+
+#[cfg(feature = "tracing")]
+use tracing::debug;
+
+/// Default time between two status checks of a running migration
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Status of a migration that finished successfully
+const STATUS_COMPLETED: &str = "completed";
+
+/// Statuses of migrations that finished without completing
+const FAILED_STATUSES: &[&str] = &["failed", "canceled", "cancelled"];
+
+/// Progress of a file migration
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MigrationProgress {
+    /// Migration status reported by Files.com (e.g. `pending`, `processing`, `completed`)
+    pub status: String,
+
+    /// Number of files processed so far
+    pub files_moved: i64,
+
+    /// Total number of files, once Files.com has counted them
+    pub files_total: Option<i64>,
+
+    /// Number of bytes processed so far, when reported
+    pub bytes_moved: Option<i64>,
+
+    /// Total number of bytes, when reported
+    pub bytes_total: Option<i64>,
+}
+
+impl MigrationProgress {
+    fn from_entity(entity: &FileMigrationEntity) -> Self {
+        Self {
+            status: entity.status.clone().unwrap_or_default(),
+            files_moved: entity.files_moved.unwrap_or(0),
+            files_total: entity.files_total,
+            bytes_moved: entity.bytes_moved,
+            bytes_total: entity.bytes_total,
+        }
+    }
+
+    /// Returns true once the migration has completed successfully
+    pub fn is_completed(&self) -> bool {
+        self.status == STATUS_COMPLETED
+    }
+
+    /// Returns true if the migration failed or was canceled
+    pub fn is_failed(&self) -> bool {
+        FAILED_STATUSES.contains(&self.status.as_str())
+    }
+
+    /// Returns true once the migration will make no further progress
+    pub fn is_finished(&self) -> bool {
+        self.is_completed() || self.is_failed()
+    }
+
+    /// Fraction of files processed, between 0.0 and 1.0
+    ///
+    /// `None` until the total number of files is known.
+    pub fn fraction(&self) -> Option<f64> {
+        match self.files_total {
+            Some(0) => Some(1.0),
+            Some(total) => Some((self.files_moved as f64 / total as f64).min(1.0)),
+            None => None,
+        }
+    }
+}
+
+/// Handle to a copy or move operation
+///
+/// Operations that Files.com completed immediately have no
+/// [`migration_id`](Self::migration_id); waiting on them returns at once.
+#[derive(Debug, Clone)]
+pub struct MigrationHandle {
+    client: FilesClient,
+    migration_id: Option<i64>,
+    status: Option<String>,
+    poll_interval: Duration,
+    timeout: Option<Duration>,
+}
+
+impl MigrationHandle {
+    /// Creates a handle from the response of a copy or move request
+    pub(crate) fn from_response(client: FilesClient, response: &Value) -> Self {
+        Self {
+            client,
+            migration_id: response.get("file_migration_id").and_then(Value::as_i64),
+            status: response
+                .get("status")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            timeout: None,
+        }
+    }
+
+    /// Sets the time between two status checks (default: 2 seconds)
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Sets how long [`wait`](Self::wait) waits before giving up (default: no limit)
+    ///
+    /// Giving up does not cancel the migration on Files.com.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Id of the file migration, if the operation runs in the background
+    pub fn migration_id(&self) -> Option<i64> {
+        self.migration_id
+    }
+
+    /// Status returned with the copy or move response
+    pub fn status(&self) -> Option<&str> {
+        self.status.as_deref()
+    }
+
+    /// Returns true if the operation runs in the background
+    pub fn is_async(&self) -> bool {
+        self.migration_id.is_some()
+    }
+
+    /// Fetches the current progress of the migration
+    ///
+    /// Operations that completed immediately report a `completed` status
+    /// without any request being made.
+    pub async fn progress(&self) -> Result<MigrationProgress> {
+        let Some(id) = self.migration_id else {
+            return Ok(MigrationProgress {
+                status: STATUS_COMPLETED.to_string(),
+                ..Default::default()
+            });
+        };
+
+        let entity = FileMigrationHandler::new(self.client.clone())
+            .get(id)
+            .await?;
+        Ok(MigrationProgress::from_entity(&entity))
+    }
+
+    /// Waits until the migration has finished
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FilesError::MigrationFailed`] if the migration failed or was
+    /// canceled, and [`FilesError::MigrationTimedOut`] if it did not finish
+    /// within the configured [`timeout`](Self::timeout).
+    pub async fn wait(&self) -> Result<MigrationProgress> {
+        self.wait_with_progress(|_| {}).await
+    }
+
+    /// Waits until the migration has finished, reporting progress after each poll
+    ///
+    /// # Errors
+    ///
+    /// Same as [`wait`](Self::wait).
+    pub async fn wait_with_progress<F>(&self, mut on_progress: F) -> Result<MigrationProgress>
+    where
+        F: FnMut(&MigrationProgress),
+    {
+        let started = Instant::now();
+        loop {
+            let progress = self.progress().await?;
+            on_progress(&progress);
+
+            #[cfg(feature = "tracing")]
+            debug!(
+                migration_id = ?self.migration_id,
+                status = %progress.status,
+                files_moved = progress.files_moved,
+                files_total = ?progress.files_total,
+                "Polled file migration"
+            );
+
+            if progress.is_completed() {
+                return Ok(progress);
+            }
+            // Only reachable with a migration id, since immediate operations complete
+            let migration_id = self.migration_id.unwrap_or_default();
+            if progress.is_failed() {
+                return Err(FilesError::MigrationFailed {
+                    migration_id,
+                    status: progress.status,
+                });
+            }
+
+            let mut wait = self.poll_interval;
+            if let Some(timeout) = self.timeout {
+                let elapsed = started.elapsed();
+                if elapsed >= timeout {
+                    return Err(FilesError::MigrationTimedOut {
+                        migration_id,
+                        elapsed,
+                    });
+                }
+                wait = wait.min(timeout - elapsed);
+            }
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Fetches all log entries of the migration
+    ///
+    /// Useful to find out which files failed. Operations that completed
+    /// immediately have no logs.
+    pub async fn logs(&self) -> Result<Vec<FileMigrationLogEntity>> {
+        let Some(id) = self.migration_id else {
+            return Ok(Vec::new());
+        };
+
+        let query = ListQuery::new().filter(FileMigrationLogField::FileMigrationId, id);
+        FileMigrationLogHandler::new(self.client.clone())
+            .list_stream(None, Some(query))
+            .collect_all()
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn handle(response: Value) -> MigrationHandle {
+        let client = FilesClient::builder().api_key("test-key").build().unwrap();
+        MigrationHandle::from_response(client, &response)
+    }
+
+    #[test]
+    fn test_from_response() {
+        let migration = handle(json!({"status": "pending", "file_migration_id": 42}));
+        assert_eq!(migration.migration_id(), Some(42));
+        assert_eq!(migration.status(), Some("pending"));
+        assert!(migration.is_async());
+
+        let immediate = handle(json!({}));
+        assert_eq!(immediate.migration_id(), None);
+        assert!(!immediate.is_async());
+    }
+
+    #[tokio::test]
+    async fn test_immediate_operation_completes_without_requests() {
+        let progress = handle(json!({"status": "completed"})).wait().await.unwrap();
+        assert!(progress.is_completed());
+        assert!(handle(json!({})).logs().await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_progress_fraction() {
+        let mut progress = MigrationProgress {
+            status: "processing".to_string(),
+            files_moved: 25,
+            ..Default::default()
+        };
+        assert_eq!(progress.fraction(), None);
+        progress.files_total = Some(100);
+        assert_eq!(progress.fraction(), Some(0.25));
+        assert!(!progress.is_finished());
+
+        progress.status = "canceled".to_string();
+        assert!(progress.is_failed());
+        assert!(progress.is_finished());
+    }
+}
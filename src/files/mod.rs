@@ -2,11 +2,19 @@
 //!
 //! This module contains handlers for file and folder operations including:
 //! - File upload, download, and management
-//! - Folder listing and manipulation
+//! - Multipart uploads with concurrent parts
+//! - Resumable uploads with persisted checkpoints
+//! - Opt-in checksum verification of transfers
+//! - Concurrent directory uploads and downloads with per-file reports
+//! - Folder listing, recursive walks and manipulation
 //! - File actions (copy, move, metadata)
 //! - File comments and reactions
-//! - File migrations
+//! - File migrations and tracking of background copies and moves
 
+pub mod checkpoint;
+pub mod checksum;
+pub mod directory;
+pub mod download;
 #[allow(clippy::module_inception)]
 pub mod file_actions;
 pub mod file_comment_reactions;
@@ -16,6 +24,9 @@ pub mod file_migrations;
 #[allow(clippy::module_inception)]
 pub mod files;
 pub mod folders;
+pub mod migration;
+pub mod multipart;
+pub mod walk;
 
 // Re-export handlers
 pub use file_actions::FileActionHandler;
@@ -26,10 +37,27 @@ pub use file_migrations::FileMigrationHandler;
 pub use files::FileHandler;
 pub use folders::FolderHandler;
 
+// Re-export transfer types
+pub use checkpoint::UploadCheckpoint;
+pub use checksum::ChecksumAlgorithm;
+pub use directory::{
+    DirectoryDownloadOptions, DirectoryDownloadReport, DirectoryUploadOptions,
+    DirectoryUploadReport, DownloadedFile, FailedFile, SkipReason, SkippedFile, SymlinkPolicy,
+    UploadedFile,
+};
+pub use download::DownloadOptions;
+pub use migration::{MigrationHandle, MigrationProgress};
+pub use multipart::{CompletedPart, UploadOptions};
+pub use walk::{Walk, WalkOrder};
+
 // Re-export entities
-pub use file_comment_reactions::FileCommentReactionEntity;
+pub use file_comment_reactions::{CreateFileCommentReactionParams, FileCommentReactionEntity};
 pub use file_comments::{
     FileCommentEntity, FileCommentReactionEntity as FileCommentReactionEntity2,
 };
 pub use file_migration_logs::FileMigrationLogEntity;
 pub use file_migrations::FileMigrationEntity;
+
+// Re-export query fields
+pub use file_migration_logs::FileMigrationLogField;
+pub use folders::FolderListField;
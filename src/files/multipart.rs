@@ -0,0 +1,563 @@
+//! Multipart upload engine
+//!
+//! Files.com uploads are split into parts. The first call to
+//! `begin_upload` returns the URL for part 1 together with a `ref` that
+//! identifies the upload; every further part is requested with that `ref`
+//! and its part number. Once all parts are stored, the upload is finalized
+//! with `action=end` and the list of part ETags.
+//!
+//! The engine in this module reads the source one part at a time, so memory
+//! use is bounded by `part size * max_concurrent_parts` regardless of the
+//! total file size, and uploads up to `max_concurrent_parts` parts at once.
+
+use crate::files::FileActionHandler;
+use crate::files::checkpoint::UploadCheckpoint;
+use crate::files::checksum::{self, Hasher};
+use crate::files::files::STREAM_CHUNK_SIZE;
+use crate::progress::{Progress, ProgressCallback};
+use crate::types::{FileEntity, FileUploadPartEntity};
+use crate::utils::encode_path;
+use crate::{FilesClient, FilesError, Result};
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::task::JoinSet;
+use tokio::time::Instant;
+
+/// Default number of parts uploaded concurrently
+pub const DEFAULT_MAX_CONCURRENT_PARTS: usize = 4;
+
+/// Part size used when the API does not report one (5MB, the S3 minimum)
+const DEFAULT_PART_SIZE: i64 = 5 * 1024 * 1024;
+
+/// Default time to wait for Files.com to compute the checksum of an upload
+const DEFAULT_CHECKSUM_WAIT: Duration = Duration::from_secs(30);
+
+/// Delay before the first metadata check when an upload has no checksum yet
+const CHECKSUM_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Longest delay between two metadata checks
+const MAX_CHECKSUM_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Options controlling how a file is uploaded
+///
+/// # Examples
+///
+/// ```rust
+/// use files_sdk::files::UploadOptions;
+///
+/// let options = UploadOptions::default()
+///     .max_concurrent_parts(8)
+///     .mkdir_parents(false);
+/// ```
+#[derive(Debug, Clone)]
+pub struct UploadOptions {
+    /// Maximum number of parts uploaded at the same time
+    pub max_concurrent_parts: usize,
+
+    /// Create parent directories if they don't exist
+    pub mkdir_parents: bool,
+
+    /// Compare the uploaded bytes with the checksum of the finalized file
+    pub verify_checksum: bool,
+
+    /// How long to wait for Files.com to report a checksum after finalizing
+    pub checksum_wait: Duration,
+}
+
+impl Default for UploadOptions {
+    fn default() -> Self {
+        Self {
+            max_concurrent_parts: DEFAULT_MAX_CONCURRENT_PARTS,
+            mkdir_parents: true,
+            verify_checksum: false,
+            checksum_wait: DEFAULT_CHECKSUM_WAIT,
+        }
+    }
+}
+
+impl UploadOptions {
+    /// Sets the maximum number of parts uploaded concurrently
+    ///
+    /// Values below 1 are treated as 1. The limit is ignored when the API
+    /// reports that parts cannot be uploaded in parallel.
+    pub fn max_concurrent_parts(mut self, max: usize) -> Self {
+        self.max_concurrent_parts = max;
+        self
+    }
+
+    /// Sets whether parent directories are created
+    pub fn mkdir_parents(mut self, mkdir_parents: bool) -> Self {
+        self.mkdir_parents = mkdir_parents;
+        self
+    }
+
+    /// Sets whether the uploaded bytes are verified
+    ///
+    /// The source is hashed while it is read and compared with the
+    /// strongest checksum reported for the finalized file. If the finalize
+    /// response carries none, the file's metadata is polled until it does,
+    /// for up to [`checksum_wait`](Self::checksum_wait). Resumed uploads hash
+    /// the already uploaded bytes from the source before continuing.
+    ///
+    /// A mismatch returns [`FilesError::ChecksumMismatch`], and a file still
+    /// without checksums [`FilesError::ChecksumUnavailable`]; in both cases
+    /// the uploaded file is left in place.
+    pub fn verify_checksum(mut self, verify: bool) -> Self {
+        self.verify_checksum = verify;
+        self
+    }
+
+    /// Sets how long to wait for the checksum of a finalized upload (default: 30 seconds)
+    ///
+    /// Only used with [`verify_checksum`](Self::verify_checksum). Files.com
+    /// may compute checksums some time after the upload completes.
+    pub fn checksum_wait(mut self, wait: Duration) -> Self {
+        self.checksum_wait = wait;
+        self
+    }
+}
+
+/// A part that has been stored by the upload target
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompletedPart {
+    /// Part number (1-based)
+    pub part_number: i32,
+
+    /// ETag returned when the part was stored (empty if the target sent none)
+    pub etag: String,
+
+    /// Size of the part in bytes
+    #[serde(default)]
+    pub size: u64,
+}
+
+/// Uploads `reader` to `path` using the multipart protocol
+pub(crate) async fn upload<R>(
+    client: &FilesClient,
+    path: &str,
+    reader: R,
+    size: Option<i64>,
+    progress_callback: Option<Arc<dyn ProgressCallback>>,
+    options: &UploadOptions,
+) -> Result<FileEntity>
+where
+    R: AsyncRead + Unpin,
+{
+    upload_with_checkpoint(
+        client,
+        path,
+        reader,
+        size,
+        progress_callback,
+        options,
+        UploadCheckpoint::new(path, ""),
+        options.verify_checksum.then(Hasher::all),
+        |_| Ok(()),
+    )
+    .await
+}
+
+/// Uploads `reader` to `path`, continuing from `checkpoint` if it was started
+///
+/// `reader` must be positioned at `checkpoint.offset`. `on_checkpoint` is
+/// called with the updated checkpoint every time a part completes.
+///
+/// With checksum verification on, `hasher` must already have been fed the
+/// first `checkpoint.offset` bytes of the source; without it the upload
+/// fails before anything is sent rather than going unverified.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn upload_with_checkpoint<R, F>(
+    client: &FilesClient,
+    path: &str,
+    mut reader: R,
+    size: Option<i64>,
+    progress_callback: Option<Arc<dyn ProgressCallback>>,
+    options: &UploadOptions,
+    mut checkpoint: UploadCheckpoint,
+    hasher: Option<Hasher>,
+    mut on_checkpoint: F,
+) -> Result<FileEntity>
+where
+    R: AsyncRead + Unpin,
+    F: FnMut(&UploadCheckpoint) -> Result<()>,
+{
+    let file_action = FileActionHandler::new(client.clone());
+    checkpoint.truncate_to_contiguous();
+
+    let mut hasher = match (options.verify_checksum, hasher) {
+        (false, _) => None,
+        (true, Some(hasher)) => Some(hasher),
+        (true, None) => {
+            return Err(FilesError::ConfigError(format!(
+                "Cannot verify the upload of {}: the {} bytes uploaded before resuming were not hashed",
+                path, checkpoint.offset
+            )));
+        }
+    };
+
+    let first_part = match checkpoint.ref_.clone() {
+        Some(upload_ref) => {
+            // Every part was stored before the interruption; only the
+            // finalization is missing
+            let complete = size.is_some_and(|s| checkpoint.offset >= s as u64);
+            if complete && !checkpoint.completed_parts.is_empty() {
+                let file =
+                    finalize(client, path, Some(&upload_ref), checkpoint.completed_parts).await?;
+                return match hasher {
+                    Some(hasher) => verify_upload(client, path, file, &hasher, options).await,
+                    None => Ok(file),
+                };
+            }
+            file_action
+                .begin_upload_part(path, &upload_ref, checkpoint.next_part_number())
+                .await?
+        }
+        None => {
+            let part = file_action
+                .begin_upload(path, size, options.mkdir_parents)
+                .await?
+                .into_iter()
+                .next()
+                .ok_or_else(|| FilesError::ApiError {
+                    endpoint: None,
+                    code: 500,
+                    message: "No upload parts returned from begin_upload".to_string(),
+                    detail: None,
+                })?;
+            checkpoint.ref_ = part.ref_.clone();
+            part
+        }
+    };
+
+    let upload_ref = checkpoint.ref_.clone();
+    let max_in_flight = if first_part.parallel_parts == Some(false) {
+        1
+    } else {
+        options.max_concurrent_parts.max(1)
+    };
+    let available_parts = first_part.available_parts;
+    let total_bytes = size.map(|s| s as u64);
+
+    let mut in_flight: JoinSet<Result<CompletedPart>> = JoinSet::new();
+    let mut part_number = checkpoint.next_part_number();
+    let mut read_size = first_part
+        .partsize
+        .filter(|s| *s > 0)
+        .unwrap_or(DEFAULT_PART_SIZE);
+    let mut next_part = Some(first_part);
+    let mut bytes_read = checkpoint.offset;
+
+    loop {
+        let data = read_part(
+            &mut reader,
+            read_size as usize,
+            &mut bytes_read,
+            total_bytes,
+            progress_callback.as_ref(),
+        )
+        .await?;
+        if let Some(hasher) = hasher.as_mut() {
+            hasher.update(&data);
+        }
+
+        // Part 1 is always sent, even for empty files: the API tracks
+        // whether the upload happened and S3 needs the Content-Length header.
+        if data.is_empty() && (next_part.is_none() || part_number > 1) {
+            break;
+        }
+
+        let part = match next_part.take() {
+            Some(part) => part,
+            None => {
+                if let Some(max) = available_parts.filter(|max| part_number > *max) {
+                    return Err(FilesError::ApiError {
+                        endpoint: None,
+                        code: 400,
+                        message: format!(
+                            "Upload of {} exceeds the {} parts allowed by the server",
+                            path, max
+                        ),
+                        detail: None,
+                    });
+                }
+                let upload_ref = upload_ref.as_deref().ok_or_else(|| FilesError::ApiError {
+                    endpoint: None,
+                    code: 500,
+                    message: "begin_upload did not return an upload ref".to_string(),
+                    detail: None,
+                })?;
+                file_action
+                    .begin_upload_part(path, upload_ref, part_number)
+                    .await?
+            }
+        };
+
+        let is_last = (data.len() as i64) < read_size;
+        read_size = part
+            .next_partsize
+            .or(part.partsize)
+            .filter(|s| *s > 0)
+            .unwrap_or(read_size);
+
+        in_flight.spawn(upload_part(
+            client.clone(),
+            path.to_string(),
+            upload_ref.clone(),
+            part,
+            part_number,
+            Bytes::from(data),
+        ));
+
+        while in_flight.len() >= max_in_flight {
+            if let Some(result) = in_flight.join_next().await {
+                checkpoint.record_part(task_result(result)?);
+                on_checkpoint(&checkpoint)?;
+            }
+        }
+
+        if is_last {
+            break;
+        }
+        part_number += 1;
+    }
+
+    while let Some(result) = in_flight.join_next().await {
+        checkpoint.record_part(task_result(result)?);
+        on_checkpoint(&checkpoint)?;
+    }
+
+    let file = finalize(
+        client,
+        path,
+        upload_ref.as_deref(),
+        checkpoint.completed_parts,
+    )
+    .await?;
+
+    match hasher {
+        Some(hasher) => verify_upload(client, path, file, &hasher, options).await,
+        None => Ok(file),
+    }
+}
+
+/// Compares the uploaded bytes with the checksum of the finalized `file`
+///
+/// Checksums are often computed after the upload has been finalized, so
+/// without one the file's metadata is polled with a growing interval until
+/// it reports a checksum or `options.checksum_wait` has passed.
+async fn verify_upload(
+    client: &FilesClient,
+    path: &str,
+    file: FileEntity,
+    hasher: &Hasher,
+    options: &UploadOptions,
+) -> Result<FileEntity> {
+    if checksum::strongest(&file).is_some() {
+        hasher.verify(path, &file)?;
+        return Ok(file);
+    }
+
+    let file_action = FileActionHandler::new(client.clone());
+    let started = Instant::now();
+    let mut interval = CHECKSUM_POLL_INTERVAL;
+    loop {
+        let metadata = file_action.get_metadata(path).await?;
+        let elapsed = started.elapsed();
+        if checksum::strongest(&metadata).is_some() || elapsed >= options.checksum_wait {
+            hasher.verify(path, &metadata)?;
+            return Ok(metadata);
+        }
+
+        tokio::time::sleep(interval.min(options.checksum_wait - elapsed)).await;
+        interval = (interval * 2).min(MAX_CHECKSUM_POLL_INTERVAL);
+    }
+}
+
+/// Reads up to `part_size` bytes from `reader`, reporting progress per chunk
+async fn read_part<R>(
+    reader: &mut R,
+    part_size: usize,
+    bytes_read: &mut u64,
+    total_bytes: Option<u64>,
+    progress_callback: Option<&Arc<dyn ProgressCallback>>,
+) -> Result<Vec<u8>>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut buffer = Vec::with_capacity(part_size.min(64 * 1024 * 1024));
+    let mut chunk = vec![0u8; STREAM_CHUNK_SIZE.min(part_size.max(1))];
+
+    while buffer.len() < part_size {
+        let want = (part_size - buffer.len()).min(chunk.len());
+        let n = reader
+            .read(&mut chunk[..want])
+            .await
+            .map_err(|e| FilesError::IoError(format!("Failed to read from stream: {}", e)))?;
+
+        if n == 0 {
+            break;
+        }
+
+        buffer.extend_from_slice(&chunk[..n]);
+        *bytes_read += n as u64;
+
+        if let Some(callback) = progress_callback {
+            callback.on_progress(&Progress::new(*bytes_read, total_bytes));
+        }
+    }
+
+    Ok(buffer)
+}
+
+/// Unwraps the result of a part upload task
+fn task_result(
+    result: std::result::Result<Result<CompletedPart>, tokio::task::JoinError>,
+) -> Result<CompletedPart> {
+    result.map_err(|e| FilesError::IoError(format!("Upload task failed: {}", e)))?
+}
+
+/// Uploads one part, requesting a fresh URL if the current one has expired
+async fn upload_part(
+    client: FilesClient,
+    path: String,
+    upload_ref: Option<String>,
+    mut part: FileUploadPartEntity,
+    part_number: i32,
+    data: Bytes,
+) -> Result<CompletedPart> {
+    let file_action = FileActionHandler::new(client.clone());
+
+    if let (true, Some(upload_ref)) = (part.is_expired(), upload_ref.as_deref()) {
+        part = file_action
+            .begin_upload_part(&path, upload_ref, part_number)
+            .await?;
+    }
+
+    match (
+        put_part(&client, &part, part_number, data.clone()).await,
+        upload_ref.as_deref(),
+    ) {
+        // Storage backends answer 403 once a presigned URL has expired
+        (Err(e), Some(upload_ref)) if e.status_code() == Some(403) => {
+            let part = file_action
+                .begin_upload_part(&path, upload_ref, part_number)
+                .await?;
+            put_part(&client, &part, part_number, data).await
+        }
+        (result, _) => result,
+    }
+}
+
+/// Sends a single part to its upload URL and returns the stored ETag
+///
+/// Parts without an `upload_uri` have nothing to send and complete immediately.
+/// The request goes through the client's retry policy and rate limiter, without
+/// the API key, since the URL is presigned.
+pub(crate) async fn put_part(
+    client: &FilesClient,
+    part: &FileUploadPartEntity,
+    part_number: i32,
+    data: Bytes,
+) -> Result<CompletedPart> {
+    let size = data.len() as u64;
+    let Some(upload_uri) = &part.upload_uri else {
+        return Ok(CompletedPart {
+            part_number,
+            etag: String::new(),
+            size,
+        });
+    };
+
+    let http_method = part.http_method.as_deref().unwrap_or("PUT").to_uppercase();
+    let mut request = match http_method.as_str() {
+        "POST" => client.inner.client.post(upload_uri),
+        _ => client.inner.client.put(upload_uri),
+    };
+
+    if let Some(headers) = &part.headers {
+        for (key, value) in headers {
+            request = request.header(key, value);
+        }
+    }
+
+    // Content-Length is required by S3, even for empty parts
+    let request = request
+        .header("Content-Length", size.to_string())
+        .body(data)
+        .build()?;
+    let response = client.send_with_retry(request, false).await?;
+
+    let etag = response
+        .headers()
+        .get("etag")
+        .and_then(|v| v.to_str().ok())
+        .map(|s| s.trim_matches('"').to_string())
+        .unwrap_or_default();
+
+    Ok(CompletedPart {
+        part_number,
+        etag,
+        size,
+    })
+}
+
+/// Completes the upload with `action=end`, the upload ref and all part ETags
+pub(crate) async fn finalize(
+    client: &FilesClient,
+    path: &str,
+    upload_ref: Option<&str>,
+    mut parts: Vec<CompletedPart>,
+) -> Result<FileEntity> {
+    parts.sort_by_key(|p| p.part_number);
+
+    let mut form = vec![("action", "end".to_string())];
+    if let Some(upload_ref) = upload_ref {
+        form.push(("ref", upload_ref.to_string()));
+    }
+    for part in parts.into_iter().filter(|p| !p.etag.is_empty()) {
+        form.push(("etags[][etag]", part.etag));
+        form.push(("etags[][part]", part.part_number.to_string()));
+    }
+
+    let endpoint = format!("/files{}", encode_path(path));
+    let response = client.post_form(&endpoint, &form).await?;
+    Ok(serde_json::from_value(response)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upload_options_default() {
+        let options = UploadOptions::default();
+        assert_eq!(options.max_concurrent_parts, DEFAULT_MAX_CONCURRENT_PARTS);
+        assert!(options.mkdir_parents);
+    }
+
+    #[tokio::test]
+    async fn test_read_part_bounded() {
+        let data = [7u8; 10];
+        let mut reader = &data[..];
+        let mut bytes_read = 0;
+
+        let first = read_part(&mut reader, 4, &mut bytes_read, Some(10), None)
+            .await
+            .unwrap();
+        assert_eq!(first.len(), 4);
+
+        let second = read_part(&mut reader, 4, &mut bytes_read, Some(10), None)
+            .await
+            .unwrap();
+        assert_eq!(second.len(), 4);
+
+        let third = read_part(&mut reader, 4, &mut bytes_read, Some(10), None)
+            .await
+            .unwrap();
+        assert_eq!(third.len(), 2);
+        assert_eq!(bytes_read, 10);
+    }
+}
@@ -0,0 +1,303 @@
+//! Recursive walks over remote folder trees
+//!
+//! [`FolderHandler::list_stream`] lists a single folder. [`Walk`] follows the
+//! subfolders it finds and yields every entry below a folder together with
+//! its depth, as a [`Stream`]. Created with [`FolderHandler::walk`].
+//!
+//! Entries directly inside the walked folder have depth 1, their children
+//! depth 2, and so on. Folder listings run on background tasks: while the
+//! caller consumes one folder, up to [`Walk::concurrency`] of the folders it
+//! will need next are already being listed.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use files_sdk::{FilesClient, FolderHandler};
+//! use futures::TryStreamExt;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = FilesClient::builder().api_key("your-api-key").build()?;
+//! let folders = FolderHandler::new(client);
+//!
+//! // Total size of everything below /projects, ignoring .git folders
+//! let mut walk = folders
+//!     .walk("/projects")
+//!     .prune(|entry| entry.path.as_deref().is_some_and(|p| p.ends_with("/.git")));
+//!
+//! let mut total = 0;
+//! while let Some((_depth, entry)) = walk.try_next().await? {
+//!     total += entry.size.unwrap_or(0);
+//! }
+//! println!("{} bytes", total);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::files::FolderHandler;
+use crate::types::FileEntity;
+use crate::{FilesError, Result};
+use futures::Stream;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::task::JoinHandle;
+
+/// Default number of folders listed concurrently
+pub const DEFAULT_WALK_CONCURRENCY: usize = 4;
+
+type Prune = Arc<dyn Fn(&FileEntity) -> bool + Send + Sync>;
+
+/// Order in which a [`Walk`] yields entries
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WalkOrder {
+    /// All entries of a depth before any entry of the next depth
+    #[default]
+    BreadthFirst,
+
+    /// Each folder is followed by its contents, before its next sibling
+    DepthFirst,
+}
+
+/// Work left in a walk, in the order it is yielded
+enum Pending {
+    /// An entry to yield; `descend` holds the path of folders to list
+    Entry {
+        depth: usize,
+        entry: Box<FileEntity>,
+        descend: Option<String>,
+    },
+    /// A folder whose contents are yielded next, at `depth + 1`
+    Folder { depth: usize, path: String },
+}
+
+/// Stream of `(depth, entry)` pairs for everything below a remote folder
+///
+/// A folder that cannot be listed yields one error; the walk then goes on
+/// with the remaining folders.
+pub struct Walk {
+    folders: FolderHandler,
+    root: String,
+    max_depth: Option<usize>,
+    prune: Option<Prune>,
+    order: WalkOrder,
+    concurrency: usize,
+    started: bool,
+    pending: VecDeque<Pending>,
+    /// Folders still to be listed, in the order their listings are needed
+    unlisted: VecDeque<String>,
+    /// Listings in flight or finished but not consumed yet, by folder path
+    listings: HashMap<String, JoinHandle<Result<Vec<FileEntity>>>>,
+}
+
+// Entries are only moved in and out of the queues, never pinned
+impl Unpin for Walk {}
+
+impl Walk {
+    pub(crate) fn new(folders: FolderHandler, path: &str) -> Self {
+        Self {
+            folders,
+            root: path.trim_matches('/').to_string(),
+            max_depth: None,
+            prune: None,
+            order: WalkOrder::default(),
+            concurrency: DEFAULT_WALK_CONCURRENCY,
+            started: false,
+            pending: VecDeque::new(),
+            unlisted: VecDeque::new(),
+            listings: HashMap::new(),
+        }
+    }
+
+    /// Does not descend below `depth`
+    ///
+    /// With a max depth of 1 only the walked folder's own entries are
+    /// yielded. Folders at the max depth are yielded but not listed.
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Skips folders for which `predicate` returns true
+    ///
+    /// A pruned folder is neither yielded nor listed, so nothing below it is
+    /// visited. The predicate is only called for folders.
+    pub fn prune<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&FileEntity) -> bool + Send + Sync + 'static,
+    {
+        self.prune = Some(Arc::new(predicate));
+        self
+    }
+
+    /// Sets the order entries are yielded in
+    pub fn order(mut self, order: WalkOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Sets the maximum number of folders listed at the same time
+    ///
+    /// Values below 1 are treated as 1.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Starts listing `path` on a background task
+    fn spawn_listing(&mut self, path: String) {
+        let folders = self.folders.clone();
+        let folder_path = format!("/{}", path);
+        let handle = tokio::spawn(async move {
+            folders
+                .list_stream(&folder_path, None, None)
+                .collect_all()
+                .await
+        });
+        self.listings.insert(path, handle);
+    }
+
+    /// Starts the next needed listings until `concurrency` are in flight
+    fn fill(&mut self) {
+        while self.listings.len() < self.concurrency.max(1) {
+            match self.unlisted.pop_front() {
+                Some(path) => self.spawn_listing(path),
+                None => break,
+            }
+        }
+    }
+
+    /// Queues the entries of a folder listed at `depth`
+    fn receive(&mut self, depth: usize, entries: Vec<FileEntity>) {
+        let depth = depth + 1;
+        let mut queued = Vec::with_capacity(entries.len());
+        let mut folders = Vec::new();
+
+        for entry in entries {
+            let is_folder = entry.file_type.as_deref() == Some("directory");
+            if is_folder && self.prune.as_ref().is_some_and(|prune| prune(&entry)) {
+                continue;
+            }
+
+            let descend = entry
+                .path
+                .as_deref()
+                .filter(|_| is_folder && self.max_depth.is_none_or(|max| depth < max))
+                .map(|path| path.trim_matches('/').to_string());
+            folders.extend(descend.clone());
+            queued.push(Pending::Entry {
+                depth,
+                entry: Box::new(entry),
+                descend,
+            });
+        }
+
+        match self.order {
+            WalkOrder::BreadthFirst => {
+                self.pending.extend(queued);
+                self.unlisted.extend(folders);
+            }
+            WalkOrder::DepthFirst => {
+                for item in queued.into_iter().rev() {
+                    self.pending.push_front(item);
+                }
+                for path in folders.into_iter().rev() {
+                    self.unlisted.push_front(path);
+                }
+            }
+        }
+    }
+}
+
+impl Stream for Walk {
+    type Item = Result<(usize, FileEntity)>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        if !this.started {
+            this.started = true;
+            this.pending.push_back(Pending::Folder {
+                depth: 0,
+                path: this.root.clone(),
+            });
+            this.unlisted.push_back(this.root.clone());
+            this.fill();
+        }
+
+        loop {
+            let (depth, path) = match this.pending.pop_front() {
+                None => return Poll::Ready(None),
+                Some(Pending::Entry {
+                    depth,
+                    entry,
+                    descend,
+                }) => {
+                    if let Some(path) = descend {
+                        let folder = Pending::Folder { depth, path };
+                        match this.order {
+                            WalkOrder::BreadthFirst => this.pending.push_back(folder),
+                            WalkOrder::DepthFirst => this.pending.push_front(folder),
+                        }
+                    }
+                    return Poll::Ready(Some(Ok((depth, *entry))));
+                }
+                Some(Pending::Folder { depth, path }) => (depth, path),
+            };
+
+            if !this.listings.contains_key(&path) {
+                // Listings are started in the order they are needed, so this
+                // only happens if the folder was never queued for listing
+                this.unlisted.retain(|p| p != &path);
+                this.spawn_listing(path.clone());
+            }
+
+            let handle = this
+                .listings
+                .get_mut(&path)
+                .expect("listing was just started");
+            let listing = match Pin::new(handle).poll(cx) {
+                Poll::Ready(Ok(listing)) => listing,
+                Poll::Ready(Err(e)) => {
+                    Err(FilesError::IoError(format!("Folder listing failed: {}", e)))
+                }
+                Poll::Pending => {
+                    this.pending.push_front(Pending::Folder { depth, path });
+                    return Poll::Pending;
+                }
+            };
+
+            this.listings.remove(&path);
+            this.fill();
+
+            match listing {
+                Ok(entries) => this.receive(depth, entries),
+                Err(e) => return Poll::Ready(Some(Err(e))),
+            }
+        }
+    }
+}
+
+impl Drop for Walk {
+    fn drop(&mut self) {
+        for handle in self.listings.values() {
+            handle.abort();
+        }
+    }
+}
+
+impl fmt::Debug for Walk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Walk")
+            .field("root", &self.root)
+            .field("max_depth", &self.max_depth)
+            .field("order", &self.order)
+            .field("concurrency", &self.concurrency)
+            .field("pending", &self.pending.len())
+            .field("listings", &self.listings.len())
+            .finish_non_exhaustive()
+    }
+}
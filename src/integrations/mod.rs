@@ -9,4 +9,9 @@ pub mod siem_http_destinations;
 pub use siem_http_destinations::SiemHttpDestinationHandler;
 
 // Re-export entities
-pub use siem_http_destinations::SiemHttpDestinationEntity;
+pub use siem_http_destinations::{
+    CreateSiemHttpDestinationParams, SiemHttpDestinationEntity, UpdateSiemHttpDestinationParams,
+};
+
+// Re-export query fields
+pub use siem_http_destinations::SiemHttpDestinationField;
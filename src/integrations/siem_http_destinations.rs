@@ -1,13 +1,844 @@
 //! SIEM HTTP destination configuration
 
-use crate::{FilesClient, PaginationInfo, Result};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{FilesClient, PaginationInfo, Result, Timestamp};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// SIEM HTTP destination entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SiemHttpDestinationEntity {
+    /// Destination ID
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<i64>,
+
+    /// Destination name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Destination type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_type: Option<String>,
+
+    /// Destination URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_url: Option<String>,
+
+    /// Additional HTTP headers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_headers: Option<serde_json::Value>,
+
+    /// Whether sending is active
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sending_active: Option<bool>,
+
+    /// Payload format for generic destinations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generic_payload_type: Option<String>,
+
+    /// Masked Splunk token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub splunk_token_masked: Option<String>,
+
+    /// Azure data collection rule immutable ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub azure_dcr_immutable_id: Option<String>,
+
+    /// Azure stream name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub azure_stream_name: Option<String>,
+
+    /// Azure OAuth tenant ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub azure_oauth_client_credentials_tenant_id: Option<String>,
+
+    /// Azure OAuth client ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub azure_oauth_client_credentials_client_id: Option<String>,
+
+    /// Masked Azure OAuth client secret
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub azure_oauth_client_credentials_client_secret_masked: Option<String>,
+
+    /// QRadar username
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qradar_username: Option<String>,
+
+    /// Masked QRadar password
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qradar_password_masked: Option<String>,
+
+    /// Masked SolarWinds token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solar_winds_token_masked: Option<String>,
+
+    /// Masked New Relic API key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_relic_api_key_masked: Option<String>,
+
+    /// Masked Datadog API key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub datadog_api_key_masked: Option<String>,
+
+    /// Whether SFTP action logs are sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sftp_action_send_enabled: Option<bool>,
+
+    /// Number of SFTP action log entries sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sftp_action_entries_sent: Option<i64>,
+
+    /// Whether FTP action logs are sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ftp_action_send_enabled: Option<bool>,
+
+    /// Number of FTP action log entries sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ftp_action_entries_sent: Option<i64>,
+
+    /// Whether WebDAV action logs are sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_dav_action_send_enabled: Option<bool>,
+
+    /// Number of WebDAV action log entries sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_dav_action_entries_sent: Option<i64>,
+
+    /// Whether sync logs are sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_send_enabled: Option<bool>,
+
+    /// Number of sync log entries sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_entries_sent: Option<i64>,
+
+    /// Whether outbound connection logs are sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outbound_connection_send_enabled: Option<bool>,
+
+    /// Number of outbound connection log entries sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outbound_connection_entries_sent: Option<i64>,
+
+    /// Whether automation logs are sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automation_send_enabled: Option<bool>,
+
+    /// Number of automation log entries sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automation_entries_sent: Option<i64>,
+
+    /// Whether API request logs are sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_request_send_enabled: Option<bool>,
+
+    /// Number of API request log entries sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_request_entries_sent: Option<i64>,
+
+    /// Whether public hosting request logs are sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_hosting_request_send_enabled: Option<bool>,
+
+    /// Number of public hosting request log entries sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_hosting_request_entries_sent: Option<i64>,
+
+    /// Whether email logs are sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_send_enabled: Option<bool>,
+
+    /// Number of email log entries sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_entries_sent: Option<i64>,
+
+    /// Whether ExaVault API request logs are sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exavault_api_request_send_enabled: Option<bool>,
+
+    /// Number of ExaVault API request log entries sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exavault_api_request_entries_sent: Option<i64>,
+
+    /// Whether settings change logs are sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings_change_send_enabled: Option<bool>,
+
+    /// Number of settings change log entries sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings_change_entries_sent: Option<i64>,
+
+    /// Log type of the last HTTP call
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_http_call_target_type: Option<String>,
+
+    /// Whether the last HTTP call succeeded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_http_call_success: Option<bool>,
+
+    /// Response code of the last HTTP call
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_http_call_response_code: Option<i64>,
+
+    /// Response body of the last HTTP call
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_http_call_response_body: Option<String>,
+
+    /// Error message of the last HTTP call
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_http_call_error_message: Option<String>,
+
+    /// Time of the last HTTP call
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_http_call_time: Option<Timestamp>,
+
+    /// Duration of the last HTTP call in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_http_call_duration_ms: Option<i64>,
+
+    /// Time of the most recent successful HTTP call
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub most_recent_http_call_success_time: Option<Timestamp>,
+
+    /// Result of the last connection test
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_test_entry: Option<String>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parameters for creating a SIEM HTTP destination
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateSiemHttpDestinationParams {
+    /// Destination name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Destination type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_type: Option<String>,
+
+    /// Destination URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_url: Option<String>,
+
+    /// Additional HTTP headers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_headers: Option<serde_json::Value>,
+
+    /// Whether sending is active
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sending_active: Option<bool>,
+
+    /// Payload format for generic destinations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generic_payload_type: Option<String>,
+
+    /// Splunk token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub splunk_token: Option<String>,
+
+    /// Azure data collection rule immutable ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub azure_dcr_immutable_id: Option<String>,
+
+    /// Azure stream name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub azure_stream_name: Option<String>,
+
+    /// Azure OAuth tenant ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub azure_oauth_client_credentials_tenant_id: Option<String>,
+
+    /// Azure OAuth client ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub azure_oauth_client_credentials_client_id: Option<String>,
+
+    /// Azure OAuth client secret
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub azure_oauth_client_credentials_client_secret: Option<String>,
+
+    /// QRadar username
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qradar_username: Option<String>,
+
+    /// QRadar password
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qradar_password: Option<String>,
+
+    /// SolarWinds token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solar_winds_token: Option<String>,
+
+    /// New Relic API key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_relic_api_key: Option<String>,
+
+    /// Datadog API key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub datadog_api_key: Option<String>,
+
+    /// Whether to send SFTP action logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sftp_action_send_enabled: Option<bool>,
+
+    /// Whether to send FTP action logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ftp_action_send_enabled: Option<bool>,
+
+    /// Whether to send WebDAV action logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_dav_action_send_enabled: Option<bool>,
+
+    /// Whether to send sync logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_send_enabled: Option<bool>,
+
+    /// Whether to send outbound connection logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outbound_connection_send_enabled: Option<bool>,
+
+    /// Whether to send automation logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automation_send_enabled: Option<bool>,
+
+    /// Whether to send API request logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_request_send_enabled: Option<bool>,
+
+    /// Whether to send public hosting request logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_hosting_request_send_enabled: Option<bool>,
+
+    /// Whether to send email logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_send_enabled: Option<bool>,
+
+    /// Whether to send ExaVault API request logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exavault_api_request_send_enabled: Option<bool>,
+
+    /// Whether to send settings change logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings_change_send_enabled: Option<bool>,
+}
+
+impl CreateSiemHttpDestinationParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Destination name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Destination type
+    pub fn destination_type(mut self, destination_type: impl Into<String>) -> Self {
+        self.destination_type = Some(destination_type.into());
+        self
+    }
+
+    /// Destination URL
+    pub fn destination_url(mut self, destination_url: impl Into<String>) -> Self {
+        self.destination_url = Some(destination_url.into());
+        self
+    }
+
+    /// Additional HTTP headers
+    pub fn additional_headers(mut self, additional_headers: serde_json::Value) -> Self {
+        self.additional_headers = Some(additional_headers);
+        self
+    }
+
+    /// Whether sending is active
+    pub fn sending_active(mut self, sending_active: bool) -> Self {
+        self.sending_active = Some(sending_active);
+        self
+    }
+
+    /// Payload format for generic destinations
+    pub fn generic_payload_type(mut self, generic_payload_type: impl Into<String>) -> Self {
+        self.generic_payload_type = Some(generic_payload_type.into());
+        self
+    }
+
+    /// Splunk token
+    pub fn splunk_token(mut self, splunk_token: impl Into<String>) -> Self {
+        self.splunk_token = Some(splunk_token.into());
+        self
+    }
+
+    /// Azure data collection rule immutable ID
+    pub fn azure_dcr_immutable_id(mut self, azure_dcr_immutable_id: impl Into<String>) -> Self {
+        self.azure_dcr_immutable_id = Some(azure_dcr_immutable_id.into());
+        self
+    }
+
+    /// Azure stream name
+    pub fn azure_stream_name(mut self, azure_stream_name: impl Into<String>) -> Self {
+        self.azure_stream_name = Some(azure_stream_name.into());
+        self
+    }
+
+    /// Azure OAuth tenant ID
+    pub fn azure_oauth_client_credentials_tenant_id(
+        mut self,
+        azure_oauth_client_credentials_tenant_id: impl Into<String>,
+    ) -> Self {
+        self.azure_oauth_client_credentials_tenant_id =
+            Some(azure_oauth_client_credentials_tenant_id.into());
+        self
+    }
+
+    /// Azure OAuth client ID
+    pub fn azure_oauth_client_credentials_client_id(
+        mut self,
+        azure_oauth_client_credentials_client_id: impl Into<String>,
+    ) -> Self {
+        self.azure_oauth_client_credentials_client_id =
+            Some(azure_oauth_client_credentials_client_id.into());
+        self
+    }
+
+    /// Azure OAuth client secret
+    pub fn azure_oauth_client_credentials_client_secret(
+        mut self,
+        azure_oauth_client_credentials_client_secret: impl Into<String>,
+    ) -> Self {
+        self.azure_oauth_client_credentials_client_secret =
+            Some(azure_oauth_client_credentials_client_secret.into());
+        self
+    }
+
+    /// QRadar username
+    pub fn qradar_username(mut self, qradar_username: impl Into<String>) -> Self {
+        self.qradar_username = Some(qradar_username.into());
+        self
+    }
+
+    /// QRadar password
+    pub fn qradar_password(mut self, qradar_password: impl Into<String>) -> Self {
+        self.qradar_password = Some(qradar_password.into());
+        self
+    }
+
+    /// SolarWinds token
+    pub fn solar_winds_token(mut self, solar_winds_token: impl Into<String>) -> Self {
+        self.solar_winds_token = Some(solar_winds_token.into());
+        self
+    }
+
+    /// New Relic API key
+    pub fn new_relic_api_key(mut self, new_relic_api_key: impl Into<String>) -> Self {
+        self.new_relic_api_key = Some(new_relic_api_key.into());
+        self
+    }
+
+    /// Datadog API key
+    pub fn datadog_api_key(mut self, datadog_api_key: impl Into<String>) -> Self {
+        self.datadog_api_key = Some(datadog_api_key.into());
+        self
+    }
+
+    /// Whether to send SFTP action logs
+    pub fn sftp_action_send_enabled(mut self, sftp_action_send_enabled: bool) -> Self {
+        self.sftp_action_send_enabled = Some(sftp_action_send_enabled);
+        self
+    }
+
+    /// Whether to send FTP action logs
+    pub fn ftp_action_send_enabled(mut self, ftp_action_send_enabled: bool) -> Self {
+        self.ftp_action_send_enabled = Some(ftp_action_send_enabled);
+        self
+    }
+
+    /// Whether to send WebDAV action logs
+    pub fn web_dav_action_send_enabled(mut self, web_dav_action_send_enabled: bool) -> Self {
+        self.web_dav_action_send_enabled = Some(web_dav_action_send_enabled);
+        self
+    }
+
+    /// Whether to send sync logs
+    pub fn sync_send_enabled(mut self, sync_send_enabled: bool) -> Self {
+        self.sync_send_enabled = Some(sync_send_enabled);
+        self
+    }
+
+    /// Whether to send outbound connection logs
+    pub fn outbound_connection_send_enabled(
+        mut self,
+        outbound_connection_send_enabled: bool,
+    ) -> Self {
+        self.outbound_connection_send_enabled = Some(outbound_connection_send_enabled);
+        self
+    }
+
+    /// Whether to send automation logs
+    pub fn automation_send_enabled(mut self, automation_send_enabled: bool) -> Self {
+        self.automation_send_enabled = Some(automation_send_enabled);
+        self
+    }
+
+    /// Whether to send API request logs
+    pub fn api_request_send_enabled(mut self, api_request_send_enabled: bool) -> Self {
+        self.api_request_send_enabled = Some(api_request_send_enabled);
+        self
+    }
+
+    /// Whether to send public hosting request logs
+    pub fn public_hosting_request_send_enabled(
+        mut self,
+        public_hosting_request_send_enabled: bool,
+    ) -> Self {
+        self.public_hosting_request_send_enabled = Some(public_hosting_request_send_enabled);
+        self
+    }
+
+    /// Whether to send email logs
+    pub fn email_send_enabled(mut self, email_send_enabled: bool) -> Self {
+        self.email_send_enabled = Some(email_send_enabled);
+        self
+    }
+
+    /// Whether to send ExaVault API request logs
+    pub fn exavault_api_request_send_enabled(
+        mut self,
+        exavault_api_request_send_enabled: bool,
+    ) -> Self {
+        self.exavault_api_request_send_enabled = Some(exavault_api_request_send_enabled);
+        self
+    }
+
+    /// Whether to send settings change logs
+    pub fn settings_change_send_enabled(mut self, settings_change_send_enabled: bool) -> Self {
+        self.settings_change_send_enabled = Some(settings_change_send_enabled);
+        self
+    }
+}
+
+/// Parameters for updating a SIEM HTTP destination
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateSiemHttpDestinationParams {
+    /// Destination name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Destination type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_type: Option<String>,
+
+    /// Destination URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_url: Option<String>,
+
+    /// Additional HTTP headers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_headers: Option<serde_json::Value>,
+
+    /// Whether sending is active
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sending_active: Option<bool>,
+
+    /// Payload format for generic destinations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generic_payload_type: Option<String>,
+
+    /// Splunk token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub splunk_token: Option<String>,
+
+    /// Azure data collection rule immutable ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub azure_dcr_immutable_id: Option<String>,
+
+    /// Azure stream name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub azure_stream_name: Option<String>,
+
+    /// Azure OAuth tenant ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub azure_oauth_client_credentials_tenant_id: Option<String>,
+
+    /// Azure OAuth client ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub azure_oauth_client_credentials_client_id: Option<String>,
+
+    /// Azure OAuth client secret
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub azure_oauth_client_credentials_client_secret: Option<String>,
+
+    /// QRadar username
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qradar_username: Option<String>,
+
+    /// QRadar password
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qradar_password: Option<String>,
+
+    /// SolarWinds token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solar_winds_token: Option<String>,
+
+    /// New Relic API key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_relic_api_key: Option<String>,
+
+    /// Datadog API key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub datadog_api_key: Option<String>,
+
+    /// Whether to send SFTP action logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sftp_action_send_enabled: Option<bool>,
+
+    /// Whether to send FTP action logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ftp_action_send_enabled: Option<bool>,
+
+    /// Whether to send WebDAV action logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_dav_action_send_enabled: Option<bool>,
+
+    /// Whether to send sync logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_send_enabled: Option<bool>,
+
+    /// Whether to send outbound connection logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outbound_connection_send_enabled: Option<bool>,
+
+    /// Whether to send automation logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automation_send_enabled: Option<bool>,
+
+    /// Whether to send API request logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_request_send_enabled: Option<bool>,
+
+    /// Whether to send public hosting request logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_hosting_request_send_enabled: Option<bool>,
+
+    /// Whether to send email logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_send_enabled: Option<bool>,
+
+    /// Whether to send ExaVault API request logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exavault_api_request_send_enabled: Option<bool>,
+
+    /// Whether to send settings change logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings_change_send_enabled: Option<bool>,
+}
+
+impl UpdateSiemHttpDestinationParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Destination name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Destination type
+    pub fn destination_type(mut self, destination_type: impl Into<String>) -> Self {
+        self.destination_type = Some(destination_type.into());
+        self
+    }
+
+    /// Destination URL
+    pub fn destination_url(mut self, destination_url: impl Into<String>) -> Self {
+        self.destination_url = Some(destination_url.into());
+        self
+    }
+
+    /// Additional HTTP headers
+    pub fn additional_headers(mut self, additional_headers: serde_json::Value) -> Self {
+        self.additional_headers = Some(additional_headers);
+        self
+    }
+
+    /// Whether sending is active
+    pub fn sending_active(mut self, sending_active: bool) -> Self {
+        self.sending_active = Some(sending_active);
+        self
+    }
+
+    /// Payload format for generic destinations
+    pub fn generic_payload_type(mut self, generic_payload_type: impl Into<String>) -> Self {
+        self.generic_payload_type = Some(generic_payload_type.into());
+        self
+    }
+
+    /// Splunk token
+    pub fn splunk_token(mut self, splunk_token: impl Into<String>) -> Self {
+        self.splunk_token = Some(splunk_token.into());
+        self
+    }
+
+    /// Azure data collection rule immutable ID
+    pub fn azure_dcr_immutable_id(mut self, azure_dcr_immutable_id: impl Into<String>) -> Self {
+        self.azure_dcr_immutable_id = Some(azure_dcr_immutable_id.into());
+        self
+    }
+
+    /// Azure stream name
+    pub fn azure_stream_name(mut self, azure_stream_name: impl Into<String>) -> Self {
+        self.azure_stream_name = Some(azure_stream_name.into());
+        self
+    }
+
+    /// Azure OAuth tenant ID
+    pub fn azure_oauth_client_credentials_tenant_id(
+        mut self,
+        azure_oauth_client_credentials_tenant_id: impl Into<String>,
+    ) -> Self {
+        self.azure_oauth_client_credentials_tenant_id =
+            Some(azure_oauth_client_credentials_tenant_id.into());
+        self
+    }
+
+    /// Azure OAuth client ID
+    pub fn azure_oauth_client_credentials_client_id(
+        mut self,
+        azure_oauth_client_credentials_client_id: impl Into<String>,
+    ) -> Self {
+        self.azure_oauth_client_credentials_client_id =
+            Some(azure_oauth_client_credentials_client_id.into());
+        self
+    }
+
+    /// Azure OAuth client secret
+    pub fn azure_oauth_client_credentials_client_secret(
+        mut self,
+        azure_oauth_client_credentials_client_secret: impl Into<String>,
+    ) -> Self {
+        self.azure_oauth_client_credentials_client_secret =
+            Some(azure_oauth_client_credentials_client_secret.into());
+        self
+    }
+
+    /// QRadar username
+    pub fn qradar_username(mut self, qradar_username: impl Into<String>) -> Self {
+        self.qradar_username = Some(qradar_username.into());
+        self
+    }
+
+    /// QRadar password
+    pub fn qradar_password(mut self, qradar_password: impl Into<String>) -> Self {
+        self.qradar_password = Some(qradar_password.into());
+        self
+    }
+
+    /// SolarWinds token
+    pub fn solar_winds_token(mut self, solar_winds_token: impl Into<String>) -> Self {
+        self.solar_winds_token = Some(solar_winds_token.into());
+        self
+    }
+
+    /// New Relic API key
+    pub fn new_relic_api_key(mut self, new_relic_api_key: impl Into<String>) -> Self {
+        self.new_relic_api_key = Some(new_relic_api_key.into());
+        self
+    }
+
+    /// Datadog API key
+    pub fn datadog_api_key(mut self, datadog_api_key: impl Into<String>) -> Self {
+        self.datadog_api_key = Some(datadog_api_key.into());
+        self
+    }
+
+    /// Whether to send SFTP action logs
+    pub fn sftp_action_send_enabled(mut self, sftp_action_send_enabled: bool) -> Self {
+        self.sftp_action_send_enabled = Some(sftp_action_send_enabled);
+        self
+    }
+
+    /// Whether to send FTP action logs
+    pub fn ftp_action_send_enabled(mut self, ftp_action_send_enabled: bool) -> Self {
+        self.ftp_action_send_enabled = Some(ftp_action_send_enabled);
+        self
+    }
+
+    /// Whether to send WebDAV action logs
+    pub fn web_dav_action_send_enabled(mut self, web_dav_action_send_enabled: bool) -> Self {
+        self.web_dav_action_send_enabled = Some(web_dav_action_send_enabled);
+        self
+    }
+
+    /// Whether to send sync logs
+    pub fn sync_send_enabled(mut self, sync_send_enabled: bool) -> Self {
+        self.sync_send_enabled = Some(sync_send_enabled);
+        self
+    }
+
+    /// Whether to send outbound connection logs
+    pub fn outbound_connection_send_enabled(
+        mut self,
+        outbound_connection_send_enabled: bool,
+    ) -> Self {
+        self.outbound_connection_send_enabled = Some(outbound_connection_send_enabled);
+        self
+    }
+
+    /// Whether to send automation logs
+    pub fn automation_send_enabled(mut self, automation_send_enabled: bool) -> Self {
+        self.automation_send_enabled = Some(automation_send_enabled);
+        self
+    }
+
+    /// Whether to send API request logs
+    pub fn api_request_send_enabled(mut self, api_request_send_enabled: bool) -> Self {
+        self.api_request_send_enabled = Some(api_request_send_enabled);
+        self
+    }
+
+    /// Whether to send public hosting request logs
+    pub fn public_hosting_request_send_enabled(
+        mut self,
+        public_hosting_request_send_enabled: bool,
+    ) -> Self {
+        self.public_hosting_request_send_enabled = Some(public_hosting_request_send_enabled);
+        self
+    }
+
+    /// Whether to send email logs
+    pub fn email_send_enabled(mut self, email_send_enabled: bool) -> Self {
+        self.email_send_enabled = Some(email_send_enabled);
+        self
+    }
+
+    /// Whether to send ExaVault API request logs
+    pub fn exavault_api_request_send_enabled(
+        mut self,
+        exavault_api_request_send_enabled: bool,
+    ) -> Self {
+        self.exavault_api_request_send_enabled = Some(exavault_api_request_send_enabled);
+        self
+    }
+
+    /// Whether to send settings change logs
+    pub fn settings_change_send_enabled(mut self, settings_change_send_enabled: bool) -> Self {
+        self.settings_change_send_enabled = Some(settings_change_send_enabled);
+        self
+    }
+}
+
+query_fields! {
+    /// Fields that SIEM HTTP destinations can be sorted and filtered by
+    pub enum SiemHttpDestinationField {
+        Name => "name",
+        DestinationType => "destination_type",
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -24,6 +855,7 @@ impl SiemHttpDestinationHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<SiemHttpDestinationField>>,
     ) -> Result<(Vec<SiemHttpDestinationEntity>, PaginationInfo)> {
         let mut endpoint = "/siem_http_destinations".to_string();
         let mut params = Vec::new();
@@ -38,34 +870,39 @@ impl SiemHttpDestinationHandler {
             endpoint.push_str(&params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
-        let items: Vec<SiemHttpDestinationEntity> = response.json().await?;
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let items: Vec<SiemHttpDestinationEntity> = serde_json::from_value(response)?;
         Ok((items, pagination))
     }
 
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<SiemHttpDestinationField>>,
+    ) -> Paginator<SiemHttpDestinationEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
+
     pub async fn get(&self, id: i64) -> Result<SiemHttpDestinationEntity> {
         let endpoint = format!("/siem_http_destinations/{}", id);
         let response = self.client.get_raw(&endpoint).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn create(&self, params: serde_json::Value) -> Result<SiemHttpDestinationEntity> {
+    pub async fn create(
+        &self,
+        params: CreateSiemHttpDestinationParams,
+    ) -> Result<SiemHttpDestinationEntity> {
         let response = self
             .client
             .post_raw("/siem_http_destinations", params)
@@ -76,7 +913,7 @@ impl SiemHttpDestinationHandler {
     pub async fn update(
         &self,
         id: i64,
-        params: serde_json::Value,
+        params: UpdateSiemHttpDestinationParams,
     ) -> Result<SiemHttpDestinationEntity> {
         let endpoint = format!("/siem_http_destinations/{}", id);
         let response = self.client.patch_raw(&endpoint, params).await?;
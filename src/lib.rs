@@ -61,13 +61,13 @@
 //!
 //! ### Middleware with Tower (Optional)
 //!
-//! For retry logic, rate limiting, and observability, use the optional `tower` feature:
+//! For retry logic, rate limiting, and observability, use the optional `tower` feature
+//! together with the layers you need from `tower`:
 //!
 //! ```toml
 //! [dependencies]
 //! files-sdk = { version = "0.3", features = ["tower"] }
-//! tower = "0.5"
-//! tower-http = { version = "0.6", features = ["retry", "trace"] }
+//! tower = { version = "0.5", features = ["limit", "retry", "timeout"] }
 //! ```
 //!
 //! See the `tower_*` examples in the examples directory for complete working code.
@@ -140,10 +140,23 @@
 //! API keys can be obtained from the Files.com web interface under Account Settings.
 
 // Core modules
+pub mod auth;
 pub mod client;
 pub mod error;
+pub mod error_kind;
+#[cfg(feature = "tower")]
+pub mod middleware;
+pub mod ops;
+pub mod pagination;
 pub mod prelude;
 pub mod progress;
+pub mod query;
+pub mod rate_limit;
+pub mod retry;
+mod string_enum;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod timestamp;
 pub mod types;
 pub mod utils;
 
@@ -160,6 +173,7 @@ pub mod security;
 pub mod sharing;
 pub mod storage;
 pub mod users;
+pub mod webhooks;
 
 // Misc (to be moved or removed)
 pub mod webhook_tests;
@@ -167,8 +181,30 @@ pub mod webhook_tests;
 // Re-export client types
 pub use client::{FilesClient, FilesClientBuilder};
 
+// Re-export authentication types
+pub use auth::Auth;
+
 // Re-export error types
-pub use error::{FilesError, Result};
+pub use error::{ApiErrorDetail, ApiSubError, FilesError, Result};
+pub use error_kind::{ErrorCategory, ErrorKind};
+
+// Re-export handler traits
+pub use ops::{FileOps, FolderOps, UserOps};
+
+// Re-export pagination
+pub use pagination::Paginator;
+
+// Re-export list query builder
+pub use query::{FilterValue, ListQuery, QueryField, SortDirection};
+
+// Re-export retry configuration
+pub use retry::RetryPolicy;
+
+// Re-export rate limiting
+pub use rate_limit::{RateLimit, RateLimitStats};
+
+// Re-export timestamp type
+pub use timestamp::Timestamp;
 
 // Re-export common types
 pub use types::{FileEntity, FileUploadPartEntity, FolderEntity, PaginationInfo};
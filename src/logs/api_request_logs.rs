@@ -1,12 +1,116 @@
 //! API request logging
 
-use crate::{FilesClient, PaginationInfo, Result};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{FilesClient, PaginationInfo, Result, Timestamp};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// API request log entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiRequestLogEntity {
+    /// Start time of the request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<Timestamp>,
+
+    /// API key ID, if used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key_id: Option<i64>,
+
+    /// API key prefix, if used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key_prefix: Option<String>,
+
+    /// User ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
+
+    /// Username
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    /// Whether the user is from the parent site
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_is_from_parent_site: Option<bool>,
+
+    /// Interface of the request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface: Option<String>,
+
+    /// HTTP method
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_method: Option<String>,
+
+    /// Request path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_path: Option<String>,
+
+    /// Client IP address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_ip: Option<String>,
+
+    /// Request hostname
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_host: Option<String>,
+
+    /// Unique request ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+
+    /// Name of the integration that made the request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_integration: Option<String>,
+
+    /// User agent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+
+    /// Error type, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_type: Option<String>,
+
+    /// Error message, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+
+    /// HTTP response code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_code: Option<i64>,
+
+    /// Whether the request succeeded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+
+    /// Duration in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<i64>,
+
+    /// Whether the entry was imported from another system
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub imported: Option<bool>,
+
+    /// Log entry time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+query_fields! {
+    /// Fields that API request log entries can be sorted and filtered by
+    pub enum ApiRequestLogField {
+        StartDate => "start_date",
+        EndDate => "end_date",
+        ApiKeyId => "api_key_id",
+        UserId => "user_id",
+        Interface => "interface",
+        RequestIp => "request_ip",
+        RequestMethod => "request_method",
+        ResponseCode => "response_code",
+        Success => "success",
+        CreatedAt => "created_at",
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -23,6 +127,7 @@ impl ApiRequestLogHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<ApiRequestLogField>>,
     ) -> Result<(Vec<ApiRequestLogEntity>, PaginationInfo)> {
         let mut endpoint = "/api_request_logs".to_string();
         let mut params = Vec::new();
@@ -39,28 +144,28 @@ impl ApiRequestLogHandler {
             endpoint.push_str(&params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-        let status = response.status();
-
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
 
-        let logs: Vec<ApiRequestLogEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let logs: Vec<ApiRequestLogEntity> = serde_json::from_value(response)?;
         Ok((logs, pagination))
     }
+
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<ApiRequestLogField>>,
+    ) -> Paginator<ApiRequestLogEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
 }
 
 #[cfg(test)]
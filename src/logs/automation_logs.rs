@@ -1,12 +1,70 @@
 //! Automation execution logging
 
-use crate::{FilesClient, PaginationInfo, Result};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{FilesClient, PaginationInfo, Result, Timestamp};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Automation log entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AutomationLogEntity {
+    /// Start time of the action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<Timestamp>,
+
+    /// Automation ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automation_id: Option<i64>,
+
+    /// Automation run ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automation_run_id: Option<i64>,
+
+    /// Destination path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dest_path: Option<String>,
+
+    /// Type of error, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_type: Option<String>,
+
+    /// Message describing the action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+
+    /// Operation type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation: Option<String>,
+
+    /// File path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// Status of the operation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// Log entry time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+query_fields! {
+    /// Fields that automation log entries can be sorted and filtered by
+    pub enum AutomationLogField {
+        StartDate => "start_date",
+        EndDate => "end_date",
+        AutomationId => "automation_id",
+        AutomationRunId => "automation_run_id",
+        Operation => "operation",
+        Path => "path",
+        Status => "status",
+        CreatedAt => "created_at",
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -23,6 +81,7 @@ impl AutomationLogHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<AutomationLogField>>,
     ) -> Result<(Vec<AutomationLogEntity>, PaginationInfo)> {
         let mut endpoint = "/automation_logs".to_string();
         let mut params = Vec::new();
@@ -39,28 +98,28 @@ impl AutomationLogHandler {
             endpoint.push_str(&params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-        let status = response.status();
-
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
 
-        let logs: Vec<AutomationLogEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let logs: Vec<AutomationLogEntity> = serde_json::from_value(response)?;
         Ok((logs, pagination))
     }
+
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<AutomationLogField>>,
+    ) -> Paginator<AutomationLogEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
 }
 
 #[cfg(test)]
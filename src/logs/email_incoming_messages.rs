@@ -1,12 +1,63 @@
-use crate::{Result, client::FilesClient};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{PaginationInfo, Result, Timestamp, client::FilesClient};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Email incoming message entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EmailIncomingMessageEntity {
+    /// Message ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Inbox ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inbox_id: Option<i64>,
+
+    /// Sender address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender: Option<String>,
+
+    /// Sender name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_name: Option<String>,
+
+    /// Processing status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// Message body
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    /// Processing message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+
+    /// Receive time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+
+    /// Inbox title
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inbox_title: Option<String>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+query_fields! {
+    /// Fields that incoming email messages can be sorted and filtered by
+    pub enum EmailIncomingMessageField {
+        CreatedAt => "created_at",
+        InboxId => "inbox_id",
+        Sender => "sender",
+        Status => "status",
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct EmailIncomingMessageHandler {
     client: FilesClient,
 }
@@ -16,9 +67,47 @@ impl EmailIncomingMessageHandler {
         Self { client }
     }
 
-    pub async fn list(&self) -> Result<Vec<EmailIncomingMessageEntity>> {
-        let response = self.client.get_raw("/email_incoming_messages").await?;
+    pub async fn list(
+        &self,
+        cursor: Option<String>,
+        per_page: Option<i64>,
+        query: Option<&ListQuery<EmailIncomingMessageField>>,
+    ) -> Result<(Vec<EmailIncomingMessageEntity>, PaginationInfo)> {
+        let mut endpoint = "/email_incoming_messages".to_string();
+        let mut params = Vec::new();
+
+        if let Some(c) = cursor {
+            params.push(format!("cursor={}", c));
+        }
+        if let Some(pp) = per_page {
+            params.push(format!("per_page={}", pp));
+        }
+
+        if !params.is_empty() {
+            endpoint.push('?');
+            endpoint.push_str(&params.join("&"));
+        }
+
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
         let entities: Vec<EmailIncomingMessageEntity> = serde_json::from_value(response)?;
-        Ok(entities)
+        Ok((entities, pagination))
+    }
+
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<EmailIncomingMessageField>>,
+    ) -> Paginator<EmailIncomingMessageEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
     }
 }
@@ -1,12 +1,66 @@
 //! Email operation logging
 
-use crate::{FilesClient, PaginationInfo, Result};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{FilesClient, PaginationInfo, Result, Timestamp};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Email log entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EmailLogEntity {
+    /// Start time of the action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<Timestamp>,
+
+    /// Log message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+
+    /// Delivery status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// Email subject
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+
+    /// Recipients
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+
+    /// CC recipients
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cc: Option<String>,
+
+    /// Delivery method
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_method: Option<String>,
+
+    /// SMTP server hostname
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smtp_hostname: Option<String>,
+
+    /// SMTP server IP address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smtp_ip: Option<String>,
+
+    /// Log entry time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+query_fields! {
+    /// Fields that email log entries can be sorted and filtered by
+    pub enum EmailLogField {
+        StartDate => "start_date",
+        EndDate => "end_date",
+        Status => "status",
+        CreatedAt => "created_at",
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -23,6 +77,7 @@ impl EmailLogHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<EmailLogField>>,
     ) -> Result<(Vec<EmailLogEntity>, PaginationInfo)> {
         let mut endpoint = "/email_logs".to_string();
         let mut params = Vec::new();
@@ -39,28 +94,28 @@ impl EmailLogHandler {
             endpoint.push_str(&params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-        let status = response.status();
-
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
 
-        let logs: Vec<EmailLogEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let logs: Vec<EmailLogEntity> = serde_json::from_value(response)?;
         Ok((logs, pagination))
     }
+
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<EmailLogField>>,
+    ) -> Paginator<EmailLogEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
 }
 
 #[cfg(test)]
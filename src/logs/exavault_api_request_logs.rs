@@ -1,12 +1,77 @@
-use crate::{Result, client::FilesClient};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{PaginationInfo, Result, Timestamp, client::FilesClient};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// ExaVault API request log entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExavaultApiRequestLogEntity {
+    /// Start time of the request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<Timestamp>,
+
+    /// API endpoint
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoint: Option<String>,
+
+    /// API version
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<i64>,
+
+    /// Client IP address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_ip: Option<String>,
+
+    /// HTTP method
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_method: Option<String>,
+
+    /// Error type, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_type: Option<String>,
+
+    /// Error message, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+
+    /// User agent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+
+    /// HTTP response code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_code: Option<i64>,
+
+    /// Whether the request succeeded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+
+    /// Duration in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<i64>,
+
+    /// Log entry time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+query_fields! {
+    /// Fields that ExaVault API request log entries can be sorted and filtered by
+    pub enum ExavaultApiRequestLogField {
+        StartDate => "start_date",
+        EndDate => "end_date",
+        Endpoint => "endpoint",
+        RequestIp => "request_ip",
+        Success => "success",
+        CreatedAt => "created_at",
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct ExavaultApiRequestLogHandler {
     client: FilesClient,
 }
@@ -16,9 +81,47 @@ impl ExavaultApiRequestLogHandler {
         Self { client }
     }
 
-    pub async fn list(&self) -> Result<Vec<ExavaultApiRequestLogEntity>> {
-        let response = self.client.get_raw("/exavault_api_request_logs").await?;
+    pub async fn list(
+        &self,
+        cursor: Option<String>,
+        per_page: Option<i64>,
+        query: Option<&ListQuery<ExavaultApiRequestLogField>>,
+    ) -> Result<(Vec<ExavaultApiRequestLogEntity>, PaginationInfo)> {
+        let mut endpoint = "/exavault_api_request_logs".to_string();
+        let mut params = Vec::new();
+
+        if let Some(c) = cursor {
+            params.push(format!("cursor={}", c));
+        }
+        if let Some(pp) = per_page {
+            params.push(format!("per_page={}", pp));
+        }
+
+        if !params.is_empty() {
+            endpoint.push('?');
+            endpoint.push_str(&params.join("&"));
+        }
+
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
         let entities: Vec<ExavaultApiRequestLogEntity> = serde_json::from_value(response)?;
-        Ok(entities)
+        Ok((entities, pagination))
+    }
+
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<ExavaultApiRequestLogField>>,
+    ) -> Paginator<ExavaultApiRequestLogEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
     }
 }
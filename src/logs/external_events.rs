@@ -1,12 +1,115 @@
-use crate::{Result, client::FilesClient, types::PaginationInfo};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{Result, Timestamp, client::FilesClient, types::PaginationInfo};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// External event entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExternalEventEntity {
+    /// Event ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Event type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_type: Option<String>,
+
+    /// Event status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// Event body
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    /// Event time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+
+    /// URL of the full event body
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_url: Option<String>,
+
+    /// Related folder behavior ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub folder_behavior_id: Option<i64>,
+
+    /// Related SIEM HTTP destination ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub siem_http_destination_id: Option<i64>,
+
+    /// Number of files processed successfully
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub successful_files: Option<i64>,
+
+    /// Number of files that failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errored_files: Option<i64>,
+
+    /// Total bytes synced
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bytes_synced: Option<i64>,
+
+    /// Number of files compared
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compared_files: Option<i64>,
+
+    /// Number of folders compared
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compared_folders: Option<i64>,
+
+    /// Remote server type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_server_type: Option<String>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+/// Parameters for creating an external event
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateExternalEventParams {
+    /// Event status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// Event body
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+}
+
+impl CreateExternalEventParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Event status
+    pub fn status(mut self, status: impl Into<String>) -> Self {
+        self.status = Some(status.into());
+        self
+    }
+
+    /// Event body
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+}
+
+query_fields! {
+    /// Fields that external events can be sorted and filtered by
+    pub enum ExternalEventField {
+        CreatedAt => "created_at",
+        EventType => "event_type",
+        Status => "status",
+        RemoteServerType => "remote_server_type",
+        FolderBehaviorId => "folder_behavior_id",
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct ExternalEventHandler {
     client: FilesClient,
 }
@@ -20,6 +123,7 @@ impl ExternalEventHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<ExternalEventField>>,
     ) -> Result<(Vec<ExternalEventEntity>, PaginationInfo)> {
         let mut endpoint = "/external_events".to_string();
         let mut query_params = Vec::new();
@@ -37,36 +141,36 @@ impl ExternalEventHandler {
             endpoint.push_str(&query_params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
 
-        let entities: Vec<ExternalEventEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let entities: Vec<ExternalEventEntity> = serde_json::from_value(response)?;
         Ok((entities, pagination))
     }
 
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<ExternalEventField>>,
+    ) -> Paginator<ExternalEventEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
+
     pub async fn get(&self, id: i64) -> Result<ExternalEventEntity> {
         let endpoint = format!("/external_events/{}", id);
         let response = self.client.get_raw(&endpoint).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn create(&self, params: serde_json::Value) -> Result<ExternalEventEntity> {
+    pub async fn create(&self, params: CreateExternalEventParams) -> Result<ExternalEventEntity> {
         let response = self.client.post_raw("/external_events", params).await?;
         Ok(serde_json::from_value(response)?)
     }
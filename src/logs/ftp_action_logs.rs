@@ -1,12 +1,94 @@
 //! FTP action logging
 
-use crate::{FilesClient, PaginationInfo, Result};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{FilesClient, PaginationInfo, Result, Timestamp};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// FTP action log entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FtpActionLogEntity {
+    /// Start time of the action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<Timestamp>,
+
+    /// Client IP address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_ip: Option<String>,
+
+    /// Server IP address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_ip: Option<String>,
+
+    /// Username
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    /// Session UUID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_uuid: Option<String>,
+
+    /// Sequence number within the session
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seq_id: Option<i64>,
+
+    /// Authentication ciphers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_ciphers: Option<String>,
+
+    /// Action type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_type: Option<String>,
+
+    /// File path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// Resolved file path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub true_path: Option<String>,
+
+    /// File name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// FTP command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cmd: Option<String>,
+
+    /// FTP command parameter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub param: Option<String>,
+
+    /// Response reason
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+
+    /// Failure type, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_type: Option<String>,
+
+    /// Log entry time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+query_fields! {
+    /// Fields that FTP action log entries can be sorted and filtered by
+    pub enum FtpActionLogField {
+        StartDate => "start_date",
+        EndDate => "end_date",
+        Action => "action",
+        Username => "username",
+        RemoteIp => "remote_ip",
+        ServerIp => "server_ip",
+        Path => "path",
+        CreatedAt => "created_at",
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -23,6 +105,7 @@ impl FtpActionLogHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<FtpActionLogField>>,
     ) -> Result<(Vec<FtpActionLogEntity>, PaginationInfo)> {
         let mut endpoint = "/ftp_action_logs".to_string();
         let mut params = Vec::new();
@@ -39,28 +122,28 @@ impl FtpActionLogHandler {
             endpoint.push_str(&params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-        let status = response.status();
-
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
 
-        let logs: Vec<FtpActionLogEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let logs: Vec<FtpActionLogEntity> = serde_json::from_value(response)?;
         Ok((logs, pagination))
     }
+
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<FtpActionLogField>>,
+    ) -> Paginator<FtpActionLogEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
 }
 
 #[cfg(test)]
@@ -44,7 +44,7 @@ pub use automation_logs::AutomationLogEntity;
 pub use email_incoming_messages::EmailIncomingMessageEntity;
 pub use email_logs::EmailLogEntity;
 pub use exavault_api_request_logs::ExavaultApiRequestLogEntity;
-pub use external_events::ExternalEventEntity;
+pub use external_events::{CreateExternalEventParams, ExternalEventEntity};
 pub use ftp_action_logs::FtpActionLogEntity;
 pub use outbound_connection_logs::OutboundConnectionLogEntity;
 pub use public_hosting_request_logs::PublicHostingRequestLogEntity;
@@ -52,3 +52,18 @@ pub use settings_changes::SettingsChangeEntity;
 pub use sftp_action_logs::SftpActionLogEntity;
 pub use sync_logs::SyncLogEntity;
 pub use web_dav_action_logs::WebDavActionLogEntity;
+
+// Re-export query fields
+pub use api_request_logs::ApiRequestLogField;
+pub use automation_logs::AutomationLogField;
+pub use email_incoming_messages::EmailIncomingMessageField;
+pub use email_logs::EmailLogField;
+pub use exavault_api_request_logs::ExavaultApiRequestLogField;
+pub use external_events::ExternalEventField;
+pub use ftp_action_logs::FtpActionLogField;
+pub use outbound_connection_logs::OutboundConnectionLogField;
+pub use public_hosting_request_logs::PublicHostingRequestLogField;
+pub use settings_changes::SettingsChangeField;
+pub use sftp_action_logs::SftpActionLogField;
+pub use sync_logs::SyncLogField;
+pub use web_dav_action_logs::WebDavActionLogField;
@@ -1,12 +1,64 @@
 //! Outbound connection logging
 
-use crate::{FilesClient, PaginationInfo, Result};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{FilesClient, PaginationInfo, Result, Timestamp};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Outbound connection log entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OutboundConnectionLogEntity {
+    /// Start time of the connection
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<Timestamp>,
+
+    /// File path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// Client IP address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_ip: Option<String>,
+
+    /// Source remote server ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_remote_server_id: Option<i64>,
+
+    /// Destination remote server ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dest_remote_server_id: Option<i64>,
+
+    /// Operation type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation: Option<String>,
+
+    /// Error message, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+
+    /// Error type, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_type: Option<String>,
+
+    /// Log entry time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+query_fields! {
+    /// Fields that outbound connection log entries can be sorted and filtered by
+    pub enum OutboundConnectionLogField {
+        StartDate => "start_date",
+        EndDate => "end_date",
+        ClientIp => "client_ip",
+        DestinationIp => "destination_ip",
+        RemoteServerId => "remote_server_id",
+        CreatedAt => "created_at",
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -23,6 +75,7 @@ impl OutboundConnectionLogHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<OutboundConnectionLogField>>,
     ) -> Result<(Vec<OutboundConnectionLogEntity>, PaginationInfo)> {
         let mut endpoint = "/outbound_connection_logs".to_string();
         let mut params = Vec::new();
@@ -39,28 +92,28 @@ impl OutboundConnectionLogHandler {
             endpoint.push_str(&params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-        let status = response.status();
-
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
 
-        let logs: Vec<OutboundConnectionLogEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let logs: Vec<OutboundConnectionLogEntity> = serde_json::from_value(response)?;
         Ok((logs, pagination))
     }
+
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<OutboundConnectionLogField>>,
+    ) -> Paginator<OutboundConnectionLogEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
 }
 
 #[cfg(test)]
@@ -1,12 +1,70 @@
-use crate::{Result, client::FilesClient};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{PaginationInfo, Result, Timestamp, client::FilesClient};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Public hosting request log entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PublicHostingRequestLogEntity {
+    /// Start time of the request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<Timestamp>,
+
+    /// Client IP address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_ip: Option<String>,
+
+    /// Server IP address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_ip: Option<String>,
+
+    /// Requested hostname
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+
+    /// Requested path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// HTTP response code
+    #[serde(rename = "responseCode", skip_serializing_if = "Option::is_none")]
+    pub response_code: Option<i64>,
+
+    /// Whether the request succeeded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+
+    /// Duration in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<i64>,
+
+    /// Bytes transferred
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bytes_transferred: Option<i64>,
+
+    /// Log entry time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+query_fields! {
+    /// Fields that public hosting request log entries can be sorted and filtered by
+    pub enum PublicHostingRequestLogField {
+        StartDate => "start_date",
+        EndDate => "end_date",
+        Path => "path",
+        RemoteIp => "remote_ip",
+        ServerIp => "server_ip",
+        Success => "success",
+        CreatedAt => "created_at",
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct PublicHostingRequestLogHandler {
     client: FilesClient,
 }
@@ -16,8 +74,47 @@ impl PublicHostingRequestLogHandler {
         Self { client }
     }
 
-    pub async fn list(&self) -> Result<Vec<PublicHostingRequestLogEntity>> {
-        let response = self.client.get_raw("/public_hosting_request_logs").await?;
-        Ok(serde_json::from_value(response)?)
+    pub async fn list(
+        &self,
+        cursor: Option<String>,
+        per_page: Option<i64>,
+        query: Option<&ListQuery<PublicHostingRequestLogField>>,
+    ) -> Result<(Vec<PublicHostingRequestLogEntity>, PaginationInfo)> {
+        let mut endpoint = "/public_hosting_request_logs".to_string();
+        let mut params = Vec::new();
+
+        if let Some(c) = cursor {
+            params.push(format!("cursor={}", c));
+        }
+        if let Some(pp) = per_page {
+            params.push(format!("per_page={}", pp));
+        }
+
+        if !params.is_empty() {
+            endpoint.push('?');
+            endpoint.push_str(&params.join("&"));
+        }
+
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let entities: Vec<PublicHostingRequestLogEntity> = serde_json::from_value(response)?;
+        Ok((entities, pagination))
+    }
+
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<PublicHostingRequestLogField>>,
+    ) -> Paginator<PublicHostingRequestLogEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
     }
 }
@@ -1,12 +1,102 @@
 //! WebDAV action logging
 
-use crate::{FilesClient, PaginationInfo, Result};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{FilesClient, PaginationInfo, Result, Timestamp};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// WebDAV action log entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WebDavActionLogEntity {
+    /// Start time of the action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<Timestamp>,
+
+    /// Client IP address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_ip: Option<String>,
+
+    /// Server IP address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_ip: Option<String>,
+
+    /// Username
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    /// Authentication ciphers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_ciphers: Option<String>,
+
+    /// Action type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_type: Option<String>,
+
+    /// File path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// Resolved file path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub true_path: Option<String>,
+
+    /// File name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// HTTP method
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_method: Option<String>,
+
+    /// HTTP path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_path: Option<String>,
+
+    /// HTTP response code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_response_code: Option<i64>,
+
+    /// File size in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+
+    /// Number of directory entries returned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entries_returned: Option<i64>,
+
+    /// Whether the action succeeded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+
+    /// Action status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// Duration in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<i64>,
+
+    /// Log entry time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+query_fields! {
+    /// Fields that WebDAV action log entries can be sorted and filtered by
+    pub enum WebDavActionLogField {
+        StartDate => "start_date",
+        EndDate => "end_date",
+        Action => "action",
+        Username => "username",
+        RemoteIp => "remote_ip",
+        ServerIp => "server_ip",
+        Path => "path",
+        CreatedAt => "created_at",
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -23,6 +113,7 @@ impl WebDavActionLogHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<WebDavActionLogField>>,
     ) -> Result<(Vec<WebDavActionLogEntity>, PaginationInfo)> {
         let mut endpoint = "/web_dav_action_logs".to_string();
         let mut params = Vec::new();
@@ -39,28 +130,28 @@ impl WebDavActionLogHandler {
             endpoint.push_str(&params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-        let status = response.status();
-
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
 
-        let logs: Vec<WebDavActionLogEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let logs: Vec<WebDavActionLogEntity> = serde_json::from_value(response)?;
         Ok((logs, pagination))
     }
+
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<WebDavActionLogField>>,
+    ) -> Paginator<WebDavActionLogEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
 }
 
 #[cfg(test)]
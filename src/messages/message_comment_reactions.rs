@@ -1,12 +1,64 @@
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
 use crate::{Result, client::FilesClient, types::PaginationInfo};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Message comment reaction entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MessageCommentReactionEntity {
+    /// Reaction ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Emoji used in the reaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parameters for creating a message comment reaction
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateMessageCommentReactionParams {
+    /// User ID, when acting on behalf of another user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
+
+    /// Emoji to react with
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+}
+
+impl CreateMessageCommentReactionParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// User ID, when acting on behalf of another user
+    pub fn user_id(mut self, user_id: i64) -> Self {
+        self.user_id = Some(user_id);
+        self
+    }
+
+    /// Emoji to react with
+    pub fn emoji(mut self, emoji: impl Into<String>) -> Self {
+        self.emoji = Some(emoji.into());
+        self
+    }
 }
 
+query_fields! {
+    /// Fields that message comment reactions can be sorted and filtered by
+    pub enum MessageCommentReactionField {
+        Emoji => "emoji",
+        CreatedAt => "created_at",
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct MessageCommentReactionHandler {
     client: FilesClient,
 }
@@ -21,6 +73,7 @@ impl MessageCommentReactionHandler {
         message_comment_id: i64,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<MessageCommentReactionField>>,
     ) -> Result<(Vec<MessageCommentReactionEntity>, PaginationInfo)> {
         let mut endpoint = "/message_comment_reactions".to_string();
         let mut query_params = vec![format!("message_comment_id={}", message_comment_id)];
@@ -38,36 +91,44 @@ impl MessageCommentReactionHandler {
             endpoint.push_str(&query_params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
 
-        let entities: Vec<MessageCommentReactionEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let entities: Vec<MessageCommentReactionEntity> = serde_json::from_value(response)?;
         Ok((entities, pagination))
     }
 
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        message_comment_id: i64,
+        per_page: Option<i64>,
+        query: Option<ListQuery<MessageCommentReactionField>>,
+    ) -> Paginator<MessageCommentReactionEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move {
+                handler
+                    .list(message_comment_id, cursor, per_page, query.as_ref())
+                    .await
+            }
+        })
+    }
+
     pub async fn get(&self, id: i64) -> Result<MessageCommentReactionEntity> {
         let endpoint = format!("/message_comment_reactions/{}", id);
         let response = self.client.get_raw(&endpoint).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn create(&self, params: serde_json::Value) -> Result<MessageCommentReactionEntity> {
+    pub async fn create(
+        &self,
+        params: CreateMessageCommentReactionParams,
+    ) -> Result<MessageCommentReactionEntity> {
         let response = self
             .client
             .post_raw("/message_comment_reactions", params)
@@ -1,12 +1,89 @@
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
 use crate::{Result, client::FilesClient, types::PaginationInfo};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Message comment entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MessageCommentEntity {
+    /// Comment ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Comment body
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    /// Reactions to the comment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<serde_json::Value>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parameters for creating a message comment
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateMessageCommentParams {
+    /// User ID, when acting on behalf of another user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
+
+    /// Comment body
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+}
+
+impl CreateMessageCommentParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// User ID, when acting on behalf of another user
+    pub fn user_id(mut self, user_id: i64) -> Self {
+        self.user_id = Some(user_id);
+        self
+    }
+
+    /// Comment body
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+}
+
+/// Parameters for updating a message comment
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateMessageCommentParams {
+    /// Comment body
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
 }
 
+impl UpdateMessageCommentParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Comment body
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+}
+
+query_fields! {
+    /// Fields that message comments can be sorted and filtered by
+    pub enum MessageCommentField {
+        MessageId => "message_id",
+        CreatedAt => "created_at",
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct MessageCommentHandler {
     client: FilesClient,
 }
@@ -21,6 +98,7 @@ impl MessageCommentHandler {
         user_id: Option<i64>,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<MessageCommentField>>,
     ) -> Result<(Vec<MessageCommentEntity>, PaginationInfo)> {
         let mut endpoint = "/message_comments".to_string();
         let mut query_params = Vec::new();
@@ -42,41 +120,50 @@ impl MessageCommentHandler {
             endpoint.push_str(&query_params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
 
-        let entities: Vec<MessageCommentEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let entities: Vec<MessageCommentEntity> = serde_json::from_value(response)?;
         Ok((entities, pagination))
     }
 
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        user_id: Option<i64>,
+        per_page: Option<i64>,
+        query: Option<ListQuery<MessageCommentField>>,
+    ) -> Paginator<MessageCommentEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move {
+                handler
+                    .list(user_id, cursor, per_page, query.as_ref())
+                    .await
+            }
+        })
+    }
+
     pub async fn get(&self, id: i64) -> Result<MessageCommentEntity> {
         let endpoint = format!("/message_comments/{}", id);
         let response = self.client.get_raw(&endpoint).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn create(&self, params: serde_json::Value) -> Result<MessageCommentEntity> {
+    pub async fn create(&self, params: CreateMessageCommentParams) -> Result<MessageCommentEntity> {
         let response = self.client.post_raw("/message_comments", params).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn update(&self, id: i64, params: serde_json::Value) -> Result<MessageCommentEntity> {
+    pub async fn update(
+        &self,
+        id: i64,
+        params: UpdateMessageCommentParams,
+    ) -> Result<MessageCommentEntity> {
         let endpoint = format!("/message_comments/{}", id);
         let response = self.client.patch_raw(&endpoint, params).await?;
         Ok(serde_json::from_value(response)?)
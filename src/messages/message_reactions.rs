@@ -1,12 +1,64 @@
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
 use crate::{Result, client::FilesClient, types::PaginationInfo};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Message reaction entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MessageReactionEntity {
+    /// Reaction ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Emoji used in the reaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parameters for creating a message reaction
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateMessageReactionParams {
+    /// User ID, when acting on behalf of another user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
+
+    /// Emoji to react with
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
 }
 
+impl CreateMessageReactionParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// User ID, when acting on behalf of another user
+    pub fn user_id(mut self, user_id: i64) -> Self {
+        self.user_id = Some(user_id);
+        self
+    }
+
+    /// Emoji to react with
+    pub fn emoji(mut self, emoji: impl Into<String>) -> Self {
+        self.emoji = Some(emoji.into());
+        self
+    }
+}
+
+query_fields! {
+    /// Fields that message reactions can be sorted and filtered by
+    pub enum MessageReactionField {
+        Emoji => "emoji",
+        CreatedAt => "created_at",
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct MessageReactionHandler {
     client: FilesClient,
 }
@@ -21,6 +73,7 @@ impl MessageReactionHandler {
         message_id: i64,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<MessageReactionField>>,
     ) -> Result<(Vec<MessageReactionEntity>, PaginationInfo)> {
         let mut endpoint = "/message_reactions".to_string();
         let mut query_params = vec![format!("message_id={}", message_id)];
@@ -38,36 +91,44 @@ impl MessageReactionHandler {
             endpoint.push_str(&query_params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
 
-        let entities: Vec<MessageReactionEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let entities: Vec<MessageReactionEntity> = serde_json::from_value(response)?;
         Ok((entities, pagination))
     }
 
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        message_id: i64,
+        per_page: Option<i64>,
+        query: Option<ListQuery<MessageReactionField>>,
+    ) -> Paginator<MessageReactionEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move {
+                handler
+                    .list(message_id, cursor, per_page, query.as_ref())
+                    .await
+            }
+        })
+    }
+
     pub async fn get(&self, id: i64) -> Result<MessageReactionEntity> {
         let endpoint = format!("/message_reactions/{}", id);
         let response = self.client.get_raw(&endpoint).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn create(&self, params: serde_json::Value) -> Result<MessageReactionEntity> {
+    pub async fn create(
+        &self,
+        params: CreateMessageReactionParams,
+    ) -> Result<MessageReactionEntity> {
         let response = self.client.post_raw("/message_reactions", params).await?;
         Ok(serde_json::from_value(response)?)
     }
@@ -3,6 +3,8 @@
 //! Messages are part of Files.com's project management features,
 //! representing messages posted by users to projects.
 
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
 use crate::{FilesClient, PaginationInfo, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -43,7 +45,17 @@ pub struct MessageEntity {
     pub comments: Option<Vec<MessageCommentEntity>>,
 }
 
+query_fields! {
+    /// Fields that messages can be sorted and filtered by
+    pub enum MessageField {
+        ProjectId => "project_id",
+        Subject => "subject",
+        CreatedAt => "created_at",
+    }
+}
+
 /// Handler for message operations
+#[derive(Debug, Clone)]
 pub struct MessageHandler {
     client: FilesClient,
 }
@@ -60,6 +72,7 @@ impl MessageHandler {
     /// * `cursor` - Pagination cursor
     /// * `per_page` - Results per page
     /// * `project_id` - Filter by project ID
+    /// * `query` - Sorting and filtering options (optional)
     ///
     /// # Returns
     /// Tuple of (messages, pagination_info)
@@ -71,7 +84,7 @@ impl MessageHandler {
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = FilesClient::builder().api_key("key").build()?;
     /// let handler = MessageHandler::new(client);
-    /// let (messages, _) = handler.list(None, None, Some(1)).await?;
+    /// let (messages, _) = handler.list(None, None, Some(1), None).await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -80,6 +93,7 @@ impl MessageHandler {
         cursor: Option<&str>,
         per_page: Option<i64>,
         project_id: Option<i64>,
+        query: Option<&ListQuery<MessageField>>,
     ) -> Result<(Vec<MessageEntity>, PaginationInfo)> {
         let mut params = vec![];
         if let Some(c) = cursor {
@@ -92,7 +106,7 @@ impl MessageHandler {
             params.push(("project_id", pid.to_string()));
         }
 
-        let query = if params.is_empty() {
+        let query_string = if params.is_empty() {
             String::new()
         } else {
             format!(
@@ -105,17 +119,38 @@ impl MessageHandler {
             )
         };
 
-        let response = self.client.get_raw(&format!("/messages{}", query)).await?;
-        let messages: Vec<MessageEntity> = serde_json::from_value(response)?;
+        let mut endpoint = format!("/messages{}", query_string);
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
 
-        let pagination = PaginationInfo {
-            cursor_next: None,
-            cursor_prev: None,
-        };
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let messages: Vec<MessageEntity> = serde_json::from_value(response)?;
 
         Ok((messages, pagination))
     }
 
+    /// Stream messages across all pages
+    ///
+    /// Takes the same arguments as [`list`](Self::list), without the cursor.
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        project_id: Option<i64>,
+        query: Option<ListQuery<MessageField>>,
+    ) -> Paginator<MessageEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move {
+                handler
+                    .list(cursor.as_deref(), per_page, project_id, query.as_ref())
+                    .await
+            }
+        })
+    }
+
     /// Get a specific message
     ///
     /// # Arguments
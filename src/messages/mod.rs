@@ -21,8 +21,19 @@ pub use messages::MessageHandler;
 pub use notifications::NotificationHandler;
 
 // Re-export entities
-pub use message_comment_reactions::MessageCommentReactionEntity;
-pub use message_comments::MessageCommentEntity;
-pub use message_reactions::MessageReactionEntity;
+pub use message_comment_reactions::{
+    CreateMessageCommentReactionParams, MessageCommentReactionEntity,
+};
+pub use message_comments::{
+    CreateMessageCommentParams, MessageCommentEntity, UpdateMessageCommentParams,
+};
+pub use message_reactions::{CreateMessageReactionParams, MessageReactionEntity};
 pub use messages::{MessageCommentEntity as MessageCommentEntity2, MessageEntity};
 pub use notifications::{NotificationEntity, SendInterval, UnsubscribedReason};
+
+// Re-export query fields
+pub use message_comment_reactions::MessageCommentReactionField;
+pub use message_comments::MessageCommentField;
+pub use message_reactions::MessageReactionField;
+pub use messages::MessageField;
+pub use notifications::NotificationField;
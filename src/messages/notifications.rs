@@ -3,6 +3,8 @@
 //! Notifications send emails when specific actions occur in folders.
 //! Emails are sent in batches at configured intervals (5 min, 15 min, hourly, daily).
 
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
 use crate::{FilesClient, PaginationInfo, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -127,7 +129,19 @@ pub struct NotificationEntity {
     pub suppressed_email: Option<bool>,
 }
 
+query_fields! {
+    /// Fields that notifications can be sorted and filtered by
+    pub enum NotificationField {
+        Path => "path",
+        UserId => "user_id",
+        GroupId => "group_id",
+        SendInterval => "send_interval",
+        TriggerByShareRecipients => "trigger_by_share_recipients",
+    }
+}
+
 /// Handler for notification operations
+#[derive(Debug, Clone)]
 pub struct NotificationHandler {
     client: FilesClient,
 }
@@ -145,6 +159,7 @@ impl NotificationHandler {
     /// * `per_page` - Results per page
     /// * `path` - Filter by path
     /// * `group_id` - Filter by group ID
+    /// * `query` - Sorting and filtering options (optional)
     ///
     /// # Returns
     /// Tuple of (notifications, pagination_info)
@@ -156,7 +171,7 @@ impl NotificationHandler {
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = FilesClient::builder().api_key("key").build()?;
     /// let handler = NotificationHandler::new(client);
-    /// let (notifications, _) = handler.list(None, None, None, None).await?;
+    /// let (notifications, _) = handler.list(None, None, None, None, None).await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -166,6 +181,7 @@ impl NotificationHandler {
         per_page: Option<i64>,
         path: Option<&str>,
         group_id: Option<i64>,
+        query: Option<&ListQuery<NotificationField>>,
     ) -> Result<(Vec<NotificationEntity>, PaginationInfo)> {
         let mut params = vec![];
         if let Some(c) = cursor {
@@ -181,7 +197,7 @@ impl NotificationHandler {
             params.push(("group_id", gid.to_string()));
         }
 
-        let query = if params.is_empty() {
+        let query_string = if params.is_empty() {
             String::new()
         } else {
             format!(
@@ -194,20 +210,47 @@ impl NotificationHandler {
             )
         };
 
-        let response = self
-            .client
-            .get_raw(&format!("/notifications{}", query))
-            .await?;
-        let notifications: Vec<NotificationEntity> = serde_json::from_value(response)?;
+        let mut endpoint = format!("/notifications{}", query_string);
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
 
-        let pagination = PaginationInfo {
-            cursor_next: None,
-            cursor_prev: None,
-        };
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let notifications: Vec<NotificationEntity> = serde_json::from_value(response)?;
 
         Ok((notifications, pagination))
     }
 
+    /// Stream notifications across all pages
+    ///
+    /// Takes the same arguments as [`list`](Self::list), without the cursor.
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        path: Option<&str>,
+        group_id: Option<i64>,
+        query: Option<ListQuery<NotificationField>>,
+    ) -> Paginator<NotificationEntity> {
+        let handler = self.clone();
+        let path = path.map(String::from);
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            let path = path.clone();
+            async move {
+                handler
+                    .list(
+                        cursor.as_deref(),
+                        per_page,
+                        path.as_deref(),
+                        group_id,
+                        query.as_ref(),
+                    )
+                    .await
+            }
+        })
+    }
+
     /// Get a specific notification
     ///
     /// # Arguments
@@ -0,0 +1,164 @@
+//! Tower middleware support
+//!
+//! With the `tower` feature enabled, [`FilesClientBuilder::layer`](crate::FilesClientBuilder::layer)
+//! wraps the client's HTTP transport in any [`tower::Layer`]. All traffic made
+//! by the client then runs through the resulting [`tower::Service`] stack. That
+//! covers every handler's API calls as well as multipart part uploads.
+//!
+//! The stack operates on [`HttpRequest`] and [`HttpResponse`]
+//! (`http::Request` / `http::Response` with a [`reqwest::Body`]). At the
+//! bottom of the stack the request is sent with the client's
+//! [`reqwest::Client`].
+//!
+//! Layers run in the order they are added: the first layer is the outermost,
+//! matching [`tower::ServiceBuilder`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use files_sdk::FilesClient;
+//! use std::time::Duration;
+//! use tower::limit::ConcurrencyLimitLayer;
+//! use tower::timeout::TimeoutLayer;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = FilesClient::builder()
+//!     .api_key("your-api-key")
+//!     .layer(TimeoutLayer::new(Duration::from_secs(30)))
+//!     .layer(ConcurrencyLimitLayer::new(8))
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tower::util::BoxCloneSyncService;
+use tower::{Layer, Service, ServiceExt};
+
+/// Request type handled by the middleware stack
+pub type HttpRequest = http::Request<reqwest::Body>;
+
+/// Response type produced by the middleware stack
+pub type HttpResponse = http::Response<reqwest::Body>;
+
+/// Error type produced by the middleware stack
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Type-erased middleware stack used by [`FilesClient`](crate::FilesClient)
+pub type HttpService = BoxCloneSyncService<HttpRequest, HttpResponse, BoxError>;
+
+type BoxLayerFn = Arc<dyn Fn(HttpService) -> HttpService + Send + Sync>;
+
+/// Layers collected by the builder, outermost first
+#[derive(Clone, Default)]
+pub(crate) struct LayerStack {
+    layers: Vec<BoxLayerFn>,
+}
+
+impl LayerStack {
+    /// Adds a layer inside the ones already added
+    pub(crate) fn push<L>(&mut self, layer: L)
+    where
+        L: Layer<HttpService> + Send + Sync + 'static,
+        L::Service: Service<HttpRequest, Response = HttpResponse> + Clone + Send + Sync + 'static,
+        <L::Service as Service<HttpRequest>>::Error: Into<BoxError>,
+        <L::Service as Service<HttpRequest>>::Future: Send + 'static,
+    {
+        self.layers.push(Arc::new(move |service| {
+            BoxCloneSyncService::new(layer.layer(service).map_err(Into::into))
+        }));
+    }
+
+    /// Builds the service stack on top of `client`, or `None` without layers
+    pub(crate) fn build(&self, client: reqwest::Client) -> Option<HttpService> {
+        if self.layers.is_empty() {
+            return None;
+        }
+
+        let base = BoxCloneSyncService::new(ReqwestService { client });
+        Some(
+            self.layers
+                .iter()
+                .rev()
+                .fold(base, |service, layer| layer(service)),
+        )
+    }
+}
+
+impl fmt::Debug for LayerStack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LayerStack")
+            .field("layers", &self.layers.len())
+            .finish()
+    }
+}
+
+/// Innermost service that sends requests with a [`reqwest::Client`]
+#[derive(Debug, Clone)]
+struct ReqwestService {
+    client: reqwest::Client,
+}
+
+impl Service<HttpRequest> for ReqwestService {
+    type Response = HttpResponse;
+    type Error = BoxError;
+    type Future = Pin<Box<dyn Future<Output = Result<HttpResponse, BoxError>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), BoxError>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: HttpRequest) -> Self::Future {
+        let client = self.client.clone();
+        Box::pin(async move {
+            let request = reqwest::Request::try_from(request)?;
+            let response = client.execute(request).await?;
+            Ok(HttpResponse::from(response))
+        })
+    }
+}
+
+/// Sends a request through the middleware stack
+pub(crate) async fn dispatch(
+    service: &HttpService,
+    request: reqwest::Request,
+) -> crate::Result<reqwest::Response> {
+    let request = HttpRequest::try_from(request)?;
+    let response = service
+        .clone()
+        .oneshot(request)
+        .await
+        .map_err(into_files_error)?;
+    Ok(reqwest::Response::from(response))
+}
+
+/// Converts a middleware error, keeping transport errors as [`FilesError::Request`](crate::FilesError::Request)
+fn into_files_error(error: BoxError) -> crate::FilesError {
+    match error.downcast::<reqwest::Error>() {
+        Ok(error) => crate::FilesError::Request(*error),
+        Err(error) => crate::FilesError::Middleware(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_stack_builds_nothing() {
+        let stack = LayerStack::default();
+        assert!(stack.build(reqwest::Client::new()).is_none());
+    }
+
+    #[test]
+    fn test_stack_with_layer() {
+        let mut stack = LayerStack::default();
+        stack.push(tower::limit::ConcurrencyLimitLayer::new(1));
+        assert!(stack.build(reqwest::Client::new()).is_some());
+        assert_eq!(format!("{:?}", stack), "LayerStack { layers: 1 }");
+    }
+}
@@ -0,0 +1,316 @@
+//! Handler traits for dependency injection
+//!
+//! [`FileOps`], [`FolderOps`] and [`UserOps`] describe the core operations of
+//! [`FileHandler`], [`FolderHandler`] and [`UserHandler`], which implement
+//! them by calling their inherent methods. Application code that is generic
+//! over a trait can be handed the real handler in production and a mock or
+//! hand-written fake in unit tests, without any HTTP involved.
+//!
+//! With the `mockall` feature enabled, `MockFileOps`, `MockFolderOps` and
+//! `MockUserOps` are generated by [mockall](https://docs.rs/mockall).
+//!
+//! # Examples
+//!
+//! ```rust
+//! use files_sdk::ops::FileOps;
+//! use std::time::Duration;
+//!
+//! /// Copies a report into the archive, keeping the original
+//! async fn archive<F: FileOps>(files: &F, name: &str) -> files_sdk::Result<()> {
+//!     files
+//!         .copy_file(
+//!             &format!("/reports/{}", name),
+//!             &format!("/archive/{}", name),
+//!             Duration::from_secs(60),
+//!         )
+//!         .await
+//! }
+//!
+//! # async fn run(client: files_sdk::FilesClient) -> files_sdk::Result<()> {
+//! // Production code passes the real handler
+//! archive(&files_sdk::FileHandler::new(client), "q3.pdf").await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! In tests, with the `mockall` feature:
+//!
+//! ```rust,ignore
+//! use files_sdk::ops::MockFileOps;
+//!
+//! let mut files = MockFileOps::new();
+//! files
+//!     .expect_copy_file()
+//!     .withf(|source, destination, _| source == "/reports/q3.pdf" && destination == "/archive/q3.pdf")
+//!     .times(1)
+//!     .returning(|_, _, _| Ok(()));
+//!
+//! archive(&files, "q3.pdf").await.unwrap();
+//! ```
+
+use crate::files::FolderListField;
+use crate::query::ListQuery;
+use crate::users::{UserEntity, UserField};
+use crate::{
+    FileEntity, FileHandler, FolderHandler, PaginationInfo, Result, Timestamp, UserHandler,
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Duration;
+
+/// File operations implemented by [`FileHandler`]
+#[cfg_attr(feature = "mockall", mockall::automock)]
+#[async_trait]
+pub trait FileOps: Send + Sync {
+    /// See [`FileHandler::download_file`]
+    async fn download_file(&self, path: &str) -> Result<FileEntity>;
+
+    /// See [`FileHandler::download_content`]
+    async fn download_content(&self, path: &str) -> Result<Vec<u8>>;
+
+    /// See [`FileHandler::download_to_file`]
+    async fn download_to_file(&self, remote_path: &str, local_path: &Path) -> Result<()>;
+
+    /// See [`FileHandler::get_metadata`]
+    async fn get_metadata(&self, path: &str) -> Result<FileEntity>;
+
+    /// See [`FileHandler::upload_file`]
+    async fn upload_file(&self, path: &str, data: &[u8]) -> Result<FileEntity>;
+
+    /// See [`FileHandler::update_file`]
+    async fn update_file(
+        &self,
+        path: &str,
+        custom_metadata: Option<HashMap<String, String>>,
+        provided_mtime: Option<Timestamp>,
+        priority_color: Option<String>,
+    ) -> Result<FileEntity>;
+
+    /// See [`FileHandler::delete_file`]
+    async fn delete_file(&self, path: &str, recursive: bool) -> Result<()>;
+
+    /// See [`FileHandler::copy_file`]
+    ///
+    /// Returns once the copy has finished, waiting at most `timeout` for the
+    /// file migration of folders. Use the inherent method to keep the
+    /// [`MigrationHandle`](crate::files::MigrationHandle) instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FilesError::MigrationTimedOut`](crate::FilesError::MigrationTimedOut)
+    /// if the migration has not finished within `timeout`.
+    async fn copy_file(&self, source: &str, destination: &str, timeout: Duration) -> Result<()>;
+
+    /// See [`FileHandler::move_file`]
+    ///
+    /// Returns once the move has finished, waiting at most `timeout` for the
+    /// file migration of folders. Use the inherent method to keep the
+    /// [`MigrationHandle`](crate::files::MigrationHandle) instead.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`copy_file`](Self::copy_file).
+    async fn move_file(&self, source: &str, destination: &str, timeout: Duration) -> Result<()>;
+}
+
+/// Folder operations implemented by [`FolderHandler`]
+#[cfg_attr(feature = "mockall", mockall::automock)]
+#[async_trait]
+pub trait FolderOps: Send + Sync {
+    /// See [`FolderHandler::list_folder`]
+    async fn list_folder<'a>(
+        &self,
+        path: &str,
+        per_page: Option<i32>,
+        cursor: Option<String>,
+        query: Option<&'a ListQuery<FolderListField>>,
+    ) -> Result<(Vec<FileEntity>, PaginationInfo)>;
+
+    /// See [`FolderHandler::list_folder_all`]
+    async fn list_folder_all(&self, path: &str) -> Result<Vec<FileEntity>>;
+
+    /// See [`FolderHandler::search_folder`]
+    async fn search_folder(
+        &self,
+        path: &str,
+        search: &str,
+        per_page: Option<i32>,
+        cursor: Option<String>,
+    ) -> Result<(Vec<FileEntity>, PaginationInfo)>;
+
+    /// See [`FolderHandler::create_folder`]
+    async fn create_folder(&self, path: &str, mkdir_parents: bool) -> Result<FileEntity>;
+
+    /// See [`FolderHandler::delete_folder`]
+    async fn delete_folder(&self, path: &str, recursive: bool) -> Result<()>;
+}
+
+/// User operations implemented by [`UserHandler`]
+#[cfg_attr(feature = "mockall", mockall::automock)]
+#[async_trait]
+pub trait UserOps: Send + Sync {
+    /// See [`UserHandler::list`]
+    async fn list<'a>(
+        &self,
+        cursor: Option<String>,
+        per_page: Option<i32>,
+        query: Option<&'a ListQuery<UserField>>,
+    ) -> Result<(Vec<UserEntity>, PaginationInfo)>;
+
+    /// See [`UserHandler::get`]
+    async fn get(&self, id: i64) -> Result<UserEntity>;
+
+    /// See [`UserHandler::create`]
+    async fn create<'a>(
+        &self,
+        username: &str,
+        email: Option<&'a str>,
+        password: Option<&'a str>,
+        name: Option<&'a str>,
+    ) -> Result<UserEntity>;
+
+    /// See [`UserHandler::update`]
+    async fn update<'a>(
+        &self,
+        id: i64,
+        email: Option<&'a str>,
+        name: Option<&'a str>,
+        company: Option<&'a str>,
+        notes: Option<&'a str>,
+    ) -> Result<UserEntity>;
+
+    /// See [`UserHandler::delete`]
+    async fn delete(&self, id: i64) -> Result<()>;
+}
+
+#[async_trait]
+impl FileOps for FileHandler {
+    async fn download_file(&self, path: &str) -> Result<FileEntity> {
+        FileHandler::download_file(self, path).await
+    }
+
+    async fn download_content(&self, path: &str) -> Result<Vec<u8>> {
+        FileHandler::download_content(self, path).await
+    }
+
+    async fn download_to_file(&self, remote_path: &str, local_path: &Path) -> Result<()> {
+        FileHandler::download_to_file(self, remote_path, local_path).await
+    }
+
+    async fn get_metadata(&self, path: &str) -> Result<FileEntity> {
+        FileHandler::get_metadata(self, path).await
+    }
+
+    async fn upload_file(&self, path: &str, data: &[u8]) -> Result<FileEntity> {
+        FileHandler::upload_file(self, path, data).await
+    }
+
+    async fn update_file(
+        &self,
+        path: &str,
+        custom_metadata: Option<HashMap<String, String>>,
+        provided_mtime: Option<Timestamp>,
+        priority_color: Option<String>,
+    ) -> Result<FileEntity> {
+        FileHandler::update_file(self, path, custom_metadata, provided_mtime, priority_color).await
+    }
+
+    async fn delete_file(&self, path: &str, recursive: bool) -> Result<()> {
+        FileHandler::delete_file(self, path, recursive).await
+    }
+
+    async fn copy_file(&self, source: &str, destination: &str, timeout: Duration) -> Result<()> {
+        FileHandler::copy_file(self, source, destination)
+            .await?
+            .timeout(timeout)
+            .wait()
+            .await
+            .map(|_| ())
+    }
+
+    async fn move_file(&self, source: &str, destination: &str, timeout: Duration) -> Result<()> {
+        FileHandler::move_file(self, source, destination)
+            .await?
+            .timeout(timeout)
+            .wait()
+            .await
+            .map(|_| ())
+    }
+}
+
+#[async_trait]
+impl FolderOps for FolderHandler {
+    async fn list_folder<'a>(
+        &self,
+        path: &str,
+        per_page: Option<i32>,
+        cursor: Option<String>,
+        query: Option<&'a ListQuery<FolderListField>>,
+    ) -> Result<(Vec<FileEntity>, PaginationInfo)> {
+        FolderHandler::list_folder(self, path, per_page, cursor, query).await
+    }
+
+    async fn list_folder_all(&self, path: &str) -> Result<Vec<FileEntity>> {
+        FolderHandler::list_folder_all(self, path).await
+    }
+
+    async fn search_folder(
+        &self,
+        path: &str,
+        search: &str,
+        per_page: Option<i32>,
+        cursor: Option<String>,
+    ) -> Result<(Vec<FileEntity>, PaginationInfo)> {
+        FolderHandler::search_folder(self, path, search, per_page, cursor).await
+    }
+
+    async fn create_folder(&self, path: &str, mkdir_parents: bool) -> Result<FileEntity> {
+        FolderHandler::create_folder(self, path, mkdir_parents).await
+    }
+
+    async fn delete_folder(&self, path: &str, recursive: bool) -> Result<()> {
+        FolderHandler::delete_folder(self, path, recursive).await
+    }
+}
+
+#[async_trait]
+impl UserOps for UserHandler {
+    async fn list<'a>(
+        &self,
+        cursor: Option<String>,
+        per_page: Option<i32>,
+        query: Option<&'a ListQuery<UserField>>,
+    ) -> Result<(Vec<UserEntity>, PaginationInfo)> {
+        UserHandler::list(self, cursor, per_page, query).await
+    }
+
+    async fn get(&self, id: i64) -> Result<UserEntity> {
+        UserHandler::get(self, id).await
+    }
+
+    async fn create<'a>(
+        &self,
+        username: &str,
+        email: Option<&'a str>,
+        password: Option<&'a str>,
+        name: Option<&'a str>,
+    ) -> Result<UserEntity> {
+        UserHandler::create(self, username, email, password, name).await
+    }
+
+    async fn update<'a>(
+        &self,
+        id: i64,
+        email: Option<&'a str>,
+        name: Option<&'a str>,
+        company: Option<&'a str>,
+        notes: Option<&'a str>,
+    ) -> Result<UserEntity> {
+        UserHandler::update(self, id, email, name, company, notes).await
+    }
+
+    async fn delete(&self, id: i64) -> Result<()> {
+        UserHandler::delete(self, id).await
+    }
+}
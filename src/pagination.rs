@@ -0,0 +1,304 @@
+//! Auto-paginating streams for list endpoints
+//!
+//! List endpoints return one page at a time along with a cursor for the next
+//! page. [`Paginator`] follows those cursors and yields the individual items
+//! as a [`Stream`]. Every handler list method has a matching `*_stream`
+//! variant that returns a paginator.
+//!
+//! By default the next page is requested as soon as the current one arrives.
+//! The request then runs while the caller processes the current page.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use files_sdk::{FilesClient, UserHandler};
+//! use futures::TryStreamExt;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = FilesClient::builder().api_key("your-api-key").build()?;
+//! let users = UserHandler::new(client);
+//!
+//! // Process users one at a time across all pages
+//! let mut stream = users.list_stream(Some(100), None);
+//! while let Some(user) = stream.try_next().await? {
+//!     println!("{:?}", user.username);
+//! }
+//!
+//! // Or collect the first two pages
+//! let first_users = users.list_stream(Some(100), None).take_pages(2).collect_all().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{FilesError, PaginationInfo, Result};
+use futures::{Stream, TryStreamExt};
+use std::collections::VecDeque;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::task::JoinHandle;
+
+type PageResult<T> = Result<(Vec<T>, PaginationInfo)>;
+type PageFuture<T> = Pin<Box<dyn Future<Output = PageResult<T>> + Send>>;
+type FetchPage<T> = Arc<dyn Fn(Option<String>) -> PageFuture<T> + Send + Sync>;
+
+/// State of the next page request
+enum NextPage<T> {
+    /// Not requested yet; holds the cursor to request it with
+    Idle(Option<String>),
+    /// Requested on the polling task
+    Fetching(PageFuture<T>),
+    /// Requested on a background task
+    Prefetching(JoinHandle<PageResult<T>>),
+    /// No more pages
+    Done,
+}
+
+/// Stream of items across all pages of a list endpoint
+///
+/// Created by the `*_stream` methods on handlers, or with [`Paginator::new`]
+/// for any function that fetches a page given a cursor.
+pub struct Paginator<T> {
+    fetch: FetchPage<T>,
+    next: NextPage<T>,
+    buffer: VecDeque<T>,
+    prefetch: bool,
+    max_pages: Option<usize>,
+    pages_requested: usize,
+}
+
+// Items are only moved in and out of the buffer, never pinned
+impl<T> Unpin for Paginator<T> {}
+
+impl<T: Send + 'static> Paginator<T> {
+    /// Creates a paginator from a function that fetches one page
+    ///
+    /// The function receives the cursor for the page to fetch (`None` for
+    /// the first page) and returns the page's items with its pagination info.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use files_sdk::{FilesClient, UserHandler};
+    /// use files_sdk::pagination::Paginator;
+    ///
+    /// # fn example(client: FilesClient) {
+    /// let users = UserHandler::new(client);
+    /// let paginator = Paginator::new(move |cursor| {
+    ///     let users = users.clone();
+    ///     async move { users.list(cursor, Some(50), None).await }
+    /// });
+    /// # }
+    /// ```
+    pub fn new<F, Fut>(fetch: F) -> Self
+    where
+        F: Fn(Option<String>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = PageResult<T>> + Send + 'static,
+    {
+        Self {
+            fetch: Arc::new(move |cursor| Box::pin(fetch(cursor))),
+            next: NextPage::Idle(None),
+            buffer: VecDeque::new(),
+            prefetch: true,
+            max_pages: None,
+            pages_requested: 0,
+        }
+    }
+
+    /// Enables or disables requesting the next page in the background
+    ///
+    /// Prefetching is enabled by default. Disable it to request each page
+    /// only once the previous one has been consumed.
+    pub fn prefetch(mut self, enabled: bool) -> Self {
+        self.prefetch = enabled;
+        self
+    }
+
+    /// Stops after `pages` pages have been fetched
+    pub fn take_pages(mut self, pages: usize) -> Self {
+        self.max_pages = Some(pages);
+        self
+    }
+
+    /// Collects the remaining items of all pages into a vector
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered while fetching pages
+    pub async fn collect_all(self) -> Result<Vec<T>> {
+        self.try_collect().await
+    }
+
+    /// Starts the request for the page after `cursor`, unless the page limit is reached
+    fn request(&mut self, cursor: Option<String>) {
+        if self
+            .max_pages
+            .is_some_and(|max| self.pages_requested >= max)
+        {
+            self.next = NextPage::Done;
+            return;
+        }
+
+        self.pages_requested += 1;
+        let page = (self.fetch)(cursor);
+        self.next = if self.prefetch {
+            NextPage::Prefetching(tokio::spawn(page))
+        } else {
+            NextPage::Fetching(page)
+        };
+    }
+
+    /// Buffers a fetched page and schedules the next one
+    fn receive(&mut self, page: PageResult<T>) -> Result<()> {
+        let (items, pagination) = page.inspect_err(|_| self.next = NextPage::Done)?;
+        self.buffer.extend(items);
+
+        match pagination.cursor_next {
+            Some(cursor) if self.prefetch => self.request(Some(cursor)),
+            Some(cursor) => self.next = NextPage::Idle(Some(cursor)),
+            None => self.next = NextPage::Done,
+        }
+        Ok(())
+    }
+}
+
+impl<T: Send + 'static> Stream for Paginator<T> {
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        loop {
+            if let Some(item) = this.buffer.pop_front() {
+                return Poll::Ready(Some(Ok(item)));
+            }
+
+            let page = match &mut this.next {
+                NextPage::Done => return Poll::Ready(None),
+                NextPage::Idle(cursor) => {
+                    let cursor = cursor.take();
+                    this.request(cursor);
+                    continue;
+                }
+                NextPage::Fetching(future) => match future.as_mut().poll(cx) {
+                    Poll::Ready(page) => page,
+                    Poll::Pending => return Poll::Pending,
+                },
+                NextPage::Prefetching(handle) => match Pin::new(handle).poll(cx) {
+                    Poll::Ready(Ok(page)) => page,
+                    Poll::Ready(Err(e)) => {
+                        Err(FilesError::IoError(format!("Page request failed: {}", e)))
+                    }
+                    Poll::Pending => return Poll::Pending,
+                },
+            };
+
+            if let Err(e) = this.receive(page) {
+                return Poll::Ready(Some(Err(e)));
+            }
+        }
+    }
+}
+
+impl<T> Drop for Paginator<T> {
+    fn drop(&mut self) {
+        if let NextPage::Prefetching(handle) = &self.next {
+            handle.abort();
+        }
+    }
+}
+
+impl<T> fmt::Debug for Paginator<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Paginator")
+            .field("buffered", &self.buffer.len())
+            .field("prefetch", &self.prefetch)
+            .field("max_pages", &self.max_pages)
+            .field("pages_requested", &self.pages_requested)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Serves `pages` pages of two numbers each, counting requests
+    fn numbers(pages: usize, requests: Arc<AtomicUsize>) -> Paginator<usize> {
+        Paginator::new(move |cursor: Option<String>| {
+            let requests = requests.clone();
+            async move {
+                requests.fetch_add(1, Ordering::SeqCst);
+                let page: usize = cursor.map_or(0, |c| c.parse().unwrap());
+                let next = page + 1;
+                Ok((
+                    vec![page * 2, page * 2 + 1],
+                    PaginationInfo {
+                        cursor_next: (next < pages).then(|| next.to_string()),
+                        cursor_prev: None,
+                    },
+                ))
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_collect_all_pages() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let items = numbers(3, requests.clone()).collect_all().await.unwrap();
+        assert_eq!(items, vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_take_pages() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let items = numbers(5, requests.clone())
+            .take_pages(2)
+            .collect_all()
+            .await
+            .unwrap();
+        assert_eq!(items, vec![0, 1, 2, 3]);
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_without_prefetch_fetches_lazily() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let mut stream = numbers(3, requests.clone()).prefetch(false);
+
+        assert_eq!(stream.next().await.unwrap().unwrap(), 0);
+        assert_eq!(stream.next().await.unwrap().unwrap(), 1);
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        assert_eq!(stream.next().await.unwrap().unwrap(), 2);
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_requests_next_page_early() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let mut stream = numbers(3, requests.clone());
+
+        assert_eq!(stream.next().await.unwrap().unwrap(), 0);
+        tokio::task::yield_now().await;
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_error_ends_stream() {
+        let mut stream =
+            Paginator::<usize>::new(|_| async { Err(FilesError::not_found("missing")) });
+
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(FilesError::NotFound { .. }))
+        ));
+        assert!(stream.next().await.is_none());
+    }
+}
@@ -23,6 +23,9 @@
 // Core client and error types
 pub use crate::client::{FilesClient, FilesClientBuilder};
 pub use crate::error::{FilesError, Result};
+pub use crate::pagination::Paginator;
+pub use crate::query::{ListQuery, SortDirection};
+pub use crate::retry::RetryPolicy;
 
 // Common entity types
 pub use crate::types::{FileEntity, FileUploadPartEntity, FolderEntity, PaginationInfo};
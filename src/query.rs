@@ -0,0 +1,330 @@
+//! Typed sorting and filtering for list endpoints
+//!
+//! Most list endpoints accept `sort_by` and a family of `filter` parameters.
+//! [`ListQuery`] builds them from a per-resource field enum, such as
+//! [`UserField`](crate::users::users::UserField), so only fields that belong
+//! to the resource can be used. A misspelled field name is a compile error
+//! rather than an API error.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use files_sdk::{FilesClient, ListQuery, SortDirection, UserHandler};
+//! use files_sdk::users::UserField;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = FilesClient::builder().api_key("your-api-key").build()?;
+//! let users = UserHandler::new(client);
+//!
+//! let query = ListQuery::new()
+//!     .sort_by(UserField::LastLoginAt, SortDirection::Desc)
+//!     .filter(UserField::SiteAdmin, true)
+//!     .filter_prefix(UserField::Username, "ops-");
+//!
+//! let (admins, _) = users.list(None, Some(100), Some(&query)).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+/// A field of a resource that can be used for sorting and filtering
+///
+/// Implemented by the per-resource field enums.
+pub trait QueryField: Copy + fmt::Debug {
+    /// Returns the field's name in the API
+    fn as_str(&self) -> &'static str;
+}
+
+/// A value that list results can be filtered by
+///
+/// Implemented for strings, booleans, integers and
+/// [`Timestamp`](crate::Timestamp)s. Timestamps are sent in the ISO 8601
+/// format Files.com expects, with or without the `chrono` feature.
+pub trait FilterValue {
+    /// Returns the value as sent in the query string
+    fn to_filter_value(&self) -> String;
+}
+
+macro_rules! display_filter_values {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl FilterValue for $ty {
+                fn to_filter_value(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+display_filter_values!(
+    str, String, bool, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize
+);
+
+#[cfg(feature = "chrono")]
+impl FilterValue for chrono::DateTime<chrono::Utc> {
+    fn to_filter_value(&self) -> String {
+        self.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true)
+    }
+}
+
+impl<T: FilterValue + ?Sized> FilterValue for &T {
+    fn to_filter_value(&self) -> String {
+        (**self).to_filter_value()
+    }
+}
+
+/// Direction for [`ListQuery::sort_by`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortDirection {
+    /// Ascending order
+    Asc,
+    /// Descending order
+    Desc,
+}
+
+impl SortDirection {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SortDirection::Asc => "asc",
+            SortDirection::Desc => "desc",
+        }
+    }
+}
+
+/// Comparison applied by a filter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FilterOp {
+    Eq,
+    Gt,
+    Gteq,
+    Prefix,
+    Lt,
+    Lteq,
+}
+
+impl FilterOp {
+    fn param(&self) -> &'static str {
+        match self {
+            FilterOp::Eq => "filter",
+            FilterOp::Gt => "filter_gt",
+            FilterOp::Gteq => "filter_gteq",
+            FilterOp::Prefix => "filter_prefix",
+            FilterOp::Lt => "filter_lt",
+            FilterOp::Lteq => "filter_lteq",
+        }
+    }
+}
+
+/// Sorting and filtering options for a list endpoint
+///
+/// `F` is the field enum of the listed resource. The API sorts by one field
+/// at a time, so a later [`sort_by`](Self::sort_by) replaces an earlier one.
+/// Filters are combined.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListQuery<F> {
+    sort_by: Option<(F, SortDirection)>,
+    filters: Vec<(FilterOp, F, String)>,
+}
+
+impl<F> Default for ListQuery<F> {
+    fn default() -> Self {
+        Self {
+            sort_by: None,
+            filters: Vec::new(),
+        }
+    }
+}
+
+impl<F: QueryField> ListQuery<F> {
+    /// Creates an empty query
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sorts results by a field
+    pub fn sort_by(mut self, field: F, direction: SortDirection) -> Self {
+        self.sort_by = Some((field, direction));
+        self
+    }
+
+    /// Keeps results where the field equals the value
+    pub fn filter(self, field: F, value: impl FilterValue) -> Self {
+        self.push(FilterOp::Eq, field, value)
+    }
+
+    /// Keeps results where the field is greater than the value
+    pub fn filter_gt(self, field: F, value: impl FilterValue) -> Self {
+        self.push(FilterOp::Gt, field, value)
+    }
+
+    /// Keeps results where the field is greater than or equal to the value
+    pub fn filter_gteq(self, field: F, value: impl FilterValue) -> Self {
+        self.push(FilterOp::Gteq, field, value)
+    }
+
+    /// Keeps results where the field starts with the value
+    pub fn filter_prefix(self, field: F, value: impl FilterValue) -> Self {
+        self.push(FilterOp::Prefix, field, value)
+    }
+
+    /// Keeps results where the field is less than the value
+    pub fn filter_lt(self, field: F, value: impl FilterValue) -> Self {
+        self.push(FilterOp::Lt, field, value)
+    }
+
+    /// Keeps results where the field is less than or equal to the value
+    pub fn filter_lteq(self, field: F, value: impl FilterValue) -> Self {
+        self.push(FilterOp::Lteq, field, value)
+    }
+
+    /// Returns true if neither sorting nor filters are set
+    pub fn is_empty(&self) -> bool {
+        self.sort_by.is_none() && self.filters.is_empty()
+    }
+
+    /// Encodes the query as URL query parameters, without a leading `?`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use files_sdk::{ListQuery, SortDirection};
+    /// use files_sdk::users::UserField;
+    ///
+    /// let query = ListQuery::new()
+    ///     .sort_by(UserField::Username, SortDirection::Asc)
+    ///     .filter(UserField::SiteAdmin, true);
+    /// assert_eq!(
+    ///     query.to_query_string(),
+    ///     "sort_by[username]=asc&filter[site_admin]=true"
+    /// );
+    /// ```
+    pub fn to_query_string(&self) -> String {
+        let sort = self.sort_by.iter().map(|(field, direction)| {
+            format!("sort_by[{}]={}", field.as_str(), direction.as_str())
+        });
+        let filters = self.filters.iter().map(|(op, field, value)| {
+            format!(
+                "{}[{}]={}",
+                op.param(),
+                field.as_str(),
+                url::form_urlencoded::byte_serialize(value.as_bytes()).collect::<String>()
+            )
+        });
+
+        sort.chain(filters).collect::<Vec<_>>().join("&")
+    }
+
+    /// Appends the query parameters to an endpoint that may already have some
+    pub(crate) fn append_to(&self, endpoint: &mut String) {
+        if self.is_empty() {
+            return;
+        }
+
+        if !endpoint.ends_with('?') && !endpoint.ends_with('&') {
+            endpoint.push(if endpoint.contains('?') { '&' } else { '?' });
+        }
+        endpoint.push_str(&self.to_query_string());
+    }
+
+    fn push(mut self, op: FilterOp, field: F, value: impl FilterValue) -> Self {
+        self.filters.push((op, field, value.to_filter_value()));
+        self
+    }
+}
+
+/// Defines a resource's field enum and its [`QueryField`] implementation
+macro_rules! query_fields {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($variant:ident => $field:literal),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $(
+                #[doc = concat!("`", $field, "`")]
+                $variant,
+            )+
+        }
+
+        impl $crate::query::QueryField for $name {
+            fn as_str(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $field,)+
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use query_fields;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    query_fields! {
+        enum TestField {
+            Name => "name",
+            CreatedAt => "created_at",
+        }
+    }
+
+    #[test]
+    fn test_empty_query() {
+        let query = ListQuery::<TestField>::new();
+        assert!(query.is_empty());
+
+        let mut endpoint = "/things".to_string();
+        query.append_to(&mut endpoint);
+        assert_eq!(endpoint, "/things");
+    }
+
+    #[test]
+    fn test_query_string() {
+        let query = ListQuery::new()
+            .sort_by(TestField::Name, SortDirection::Asc)
+            .sort_by(TestField::CreatedAt, SortDirection::Desc)
+            .filter_prefix(TestField::Name, "a b&c")
+            .filter_gteq(TestField::CreatedAt, "2024-01-01T00:00:00Z");
+
+        assert_eq!(
+            query.to_query_string(),
+            "sort_by[created_at]=desc&filter_prefix[name]=a+b%26c\
+             &filter_gteq[created_at]=2024-01-01T00%3A00%3A00Z"
+        );
+    }
+
+    #[test]
+    fn test_timestamp_filter() {
+        let end = crate::timestamp::timestamp("2024-02-01T00:00:00Z").unwrap();
+        let query = ListQuery::new().filter_lt(TestField::CreatedAt, end);
+
+        assert_eq!(
+            query.to_query_string(),
+            "filter_lt[created_at]=2024-02-01T00%3A00%3A00Z"
+        );
+    }
+
+    #[test]
+    fn test_append_to_existing_params() {
+        let query = ListQuery::new().filter(TestField::Name, "x");
+
+        let mut endpoint = "/things?per_page=10".to_string();
+        query.append_to(&mut endpoint);
+        assert_eq!(endpoint, "/things?per_page=10&filter[name]=x");
+
+        let mut endpoint = "/things?per_page=10&".to_string();
+        query.append_to(&mut endpoint);
+        assert_eq!(endpoint, "/things?per_page=10&filter[name]=x");
+
+        let mut endpoint = "/things".to_string();
+        query.append_to(&mut endpoint);
+        assert_eq!(endpoint, "/things?filter[name]=x");
+    }
+}
@@ -0,0 +1,407 @@
+//! Client-side rate limiting for Files.com API requests
+//!
+//! A [`RateLimit`] configured on [`FilesClientBuilder`](crate::FilesClientBuilder)
+//! throttles every API request made through [`FilesClient`](crate::FilesClient)
+//! and all of its clones with a token bucket: up to `burst` requests may be
+//! sent at once, after which requests are spaced out to the configured rate.
+//!
+//! The limiter adapts to the server. When a request is rejected with
+//! `429 Too Many Requests`, all requests are paused for the `Retry-After`
+//! duration (one second when the header is missing, and at most
+//! [`RateLimit::max_pause`]) and the rate is halved.
+//! Each successful request then raises the rate again, a twentieth of the
+//! configured rate at a time, until it is back at the configured rate.
+//!
+//! [`FilesClient::rate_limit_stats`](crate::FilesClient::rate_limit_stats)
+//! reports how often requests were throttled, whether or not a limiter is
+//! configured.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use files_sdk::{FilesClient, RateLimit, RetryPolicy};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = FilesClient::builder()
+//!     .api_key("your-api-key")
+//!     .rate_limit(RateLimit::per_second(10.0).burst(20))
+//!     .retry_policy(RetryPolicy::default())
+//!     .build()?;
+//!
+//! // ... run many requests concurrently ...
+//!
+//! let stats = client.rate_limit_stats();
+//! println!(
+//!     "{} requests, {} delayed for {:?}, {} rejected with 429",
+//!     stats.requests, stats.throttled, stats.throttled_time, stats.rate_limited
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tokio::time::Instant;
+
+#[cfg(feature = "tracing")]
+use tracing::debug;
+
+/// Pause after a 429 response without a `Retry-After` header
+const DEFAULT_PAUSE: Duration = Duration::from_secs(1);
+
+/// Longest pause after a 429 response, whatever its `Retry-After` says
+const DEFAULT_MAX_PAUSE: Duration = Duration::from_secs(60);
+
+/// Lowest rate the limiter slows down to, as a fraction of the configured rate
+const MIN_RATE_FRACTION: f64 = 1.0 / 16.0;
+
+/// Rate increase after each successful request, as a fraction of the configured rate
+const RECOVERY_FRACTION: f64 = 1.0 / 20.0;
+
+/// Configuration for client-side rate limiting
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimit {
+    /// Average number of requests per second
+    ///
+    /// Rates that are not positive are treated as one request per minute.
+    pub requests_per_second: f64,
+
+    /// Number of requests that may be sent at once
+    ///
+    /// Values below 1 are treated as 1.
+    pub burst: u32,
+
+    adaptive: bool,
+    max_pause: Duration,
+}
+
+impl RateLimit {
+    /// Limits requests to `requests_per_second` on average
+    ///
+    /// The burst defaults to one request, so requests are evenly spaced.
+    /// Rates that are not positive are treated as one request per minute.
+    pub fn per_second(requests_per_second: f64) -> Self {
+        let requests_per_second = if requests_per_second > 0.0 {
+            requests_per_second
+        } else {
+            1.0 / 60.0
+        };
+
+        Self {
+            requests_per_second,
+            burst: 1,
+            adaptive: true,
+            max_pause: DEFAULT_MAX_PAUSE,
+        }
+    }
+
+    /// Sets how many requests may be sent at once before throttling starts
+    ///
+    /// Values below 1 are treated as 1.
+    pub fn burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+
+    /// Enables or disables slowing down after `429` responses
+    ///
+    /// Enabled by default. When disabled, a `429` still pauses requests for
+    /// the `Retry-After` duration but does not lower the rate.
+    pub fn adaptive(mut self, adaptive: bool) -> Self {
+        self.adaptive = adaptive;
+        self
+    }
+
+    /// Sets the longest pause after a `429` response (default: 60 seconds)
+    ///
+    /// Longer `Retry-After` durations sent by the server are cut to this, so
+    /// a single response cannot stall the client indefinitely.
+    pub fn max_pause(mut self, max_pause: Duration) -> Self {
+        self.max_pause = max_pause;
+        self
+    }
+
+    /// Applies the lower bounds documented on the public fields
+    fn normalized(self) -> Self {
+        let requests_per_second = self.requests_per_second;
+        Self {
+            burst: self.burst.max(1),
+            ..Self::per_second(requests_per_second)
+                .adaptive(self.adaptive)
+                .max_pause(self.max_pause)
+        }
+    }
+}
+
+/// Counters describing how requests were throttled
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RateLimitStats {
+    /// Requests sent, including retries
+    pub requests: u64,
+
+    /// Requests the limiter delayed before sending
+    pub throttled: u64,
+
+    /// Total time requests spent waiting in the limiter
+    pub throttled_time: Duration,
+
+    /// Responses rejected by the server with `429 Too Many Requests`
+    pub rate_limited: u64,
+
+    /// Rate the limiter currently allows, in requests per second
+    ///
+    /// `None` when no [`RateLimit`] is configured.
+    pub current_rate: Option<f64>,
+}
+
+/// Token bucket shared by all clones of a client
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    config: Option<RateLimit>,
+    bucket: Mutex<Bucket>,
+    requests: AtomicU64,
+    throttled: AtomicU64,
+    throttled_nanos: AtomicU64,
+    rate_limited: AtomicU64,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    rate: f64,
+    refilled_at: Instant,
+    paused_until: Option<Instant>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(config: Option<RateLimit>) -> Self {
+        let config = config.map(RateLimit::normalized);
+        let bucket = Bucket {
+            tokens: config.as_ref().map_or(0.0, |c| f64::from(c.burst)),
+            rate: config.as_ref().map_or(0.0, |c| c.requests_per_second),
+            refilled_at: Instant::now(),
+            paused_until: None,
+        };
+
+        Self {
+            config,
+            bucket: Mutex::new(bucket),
+            requests: AtomicU64::new(0),
+            throttled: AtomicU64::new(0),
+            throttled_nanos: AtomicU64::new(0),
+            rate_limited: AtomicU64::new(0),
+        }
+    }
+
+    /// Waits until a request may be sent
+    pub(crate) async fn acquire(&self) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        let Some(config) = &self.config else {
+            return;
+        };
+
+        let started = Instant::now();
+        let mut waited = false;
+        loop {
+            let wait = {
+                let mut bucket = self.lock();
+                bucket.try_take(f64::from(config.burst))
+            };
+            let Some(wait) = wait else {
+                break;
+            };
+
+            #[cfg(feature = "tracing")]
+            debug!(wait_ms = wait.as_millis() as u64, "Throttling request");
+
+            waited = true;
+            tokio::time::sleep(wait).await;
+        }
+
+        if waited {
+            self.throttled.fetch_add(1, Ordering::Relaxed);
+            let nanos = u64::try_from(started.elapsed().as_nanos()).unwrap_or(u64::MAX);
+            self.throttled_nanos.fetch_add(nanos, Ordering::Relaxed);
+        }
+    }
+
+    /// Records a `429` response, pausing and slowing down subsequent requests
+    pub(crate) fn on_rate_limited(&self, retry_after: Option<u64>) {
+        self.rate_limited.fetch_add(1, Ordering::Relaxed);
+        let Some(config) = &self.config else {
+            return;
+        };
+
+        let pause = retry_after
+            .map_or(DEFAULT_PAUSE, Duration::from_secs)
+            .min(config.max_pause);
+        let mut bucket = self.lock();
+        let now = Instant::now();
+        let until = now.checked_add(pause).unwrap_or(now);
+        bucket.paused_until = Some(bucket.paused_until.map_or(until, |p| p.max(until)));
+        bucket.tokens = 0.0;
+        if config.adaptive {
+            let floor = config.requests_per_second * MIN_RATE_FRACTION;
+            bucket.rate = (bucket.rate / 2.0).max(floor);
+        }
+
+        #[cfg(feature = "tracing")]
+        debug!(
+            pause_ms = pause.as_millis() as u64,
+            rate = bucket.rate,
+            "Rate limited by server"
+        );
+    }
+
+    /// Records a request that was not rate limited, recovering the rate
+    pub(crate) fn on_success(&self) {
+        let Some(config) = &self.config else {
+            return;
+        };
+
+        let mut bucket = self.lock();
+        if bucket.rate < config.requests_per_second {
+            let step = config.requests_per_second * RECOVERY_FRACTION;
+            bucket.rate = (bucket.rate + step).min(config.requests_per_second);
+        }
+    }
+
+    pub(crate) fn stats(&self) -> RateLimitStats {
+        RateLimitStats {
+            requests: self.requests.load(Ordering::Relaxed),
+            throttled: self.throttled.load(Ordering::Relaxed),
+            throttled_time: Duration::from_nanos(self.throttled_nanos.load(Ordering::Relaxed)),
+            rate_limited: self.rate_limited.load(Ordering::Relaxed),
+            current_rate: self.config.as_ref().map(|_| self.lock().rate),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Bucket> {
+        self.bucket.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Bucket {
+    /// Takes a token, or returns how long to wait before trying again
+    fn try_take(&mut self, burst: f64) -> Option<Duration> {
+        let now = Instant::now();
+        if let Some(until) = self.paused_until {
+            if now < until {
+                return Some(until - now);
+            }
+            self.paused_until = None;
+            self.refilled_at = now;
+        }
+
+        let elapsed = now.duration_since(self.refilled_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(burst);
+        self.refilled_at = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            None
+        } else {
+            Some(Duration::from_secs_f64((1.0 - self.tokens) / self.rate))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_limit_config() {
+        let limit = RateLimit::per_second(5.0).burst(0);
+        assert_eq!(limit.requests_per_second, 5.0);
+        assert_eq!(limit.burst, 1);
+        assert!(RateLimit::per_second(0.0).requests_per_second > 0.0);
+
+        let limit = RateLimit {
+            requests_per_second: -1.0,
+            burst: 0,
+            ..RateLimit::per_second(5.0)
+        }
+        .normalized();
+        assert!(limit.requests_per_second > 0.0);
+        assert_eq!(limit.burst, 1);
+    }
+
+    #[tokio::test]
+    async fn test_burst_then_throttle() {
+        let limiter = RateLimiter::new(Some(RateLimit::per_second(20.0).burst(3)));
+
+        let started = Instant::now();
+        for _ in 0..5 {
+            limiter.acquire().await;
+        }
+        // Three requests from the burst, then two at 50ms intervals
+        assert!(started.elapsed() >= Duration::from_millis(90));
+
+        let stats = limiter.stats();
+        assert_eq!(stats.requests, 5);
+        assert_eq!(stats.throttled, 2);
+        assert!(stats.throttled_time >= Duration::from_millis(90));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_pauses_and_slows_down() {
+        let limiter = RateLimiter::new(Some(RateLimit::per_second(100.0).burst(10)));
+
+        limiter.on_rate_limited(Some(0));
+        assert_eq!(limiter.stats().current_rate, Some(50.0));
+        limiter.on_rate_limited(Some(0));
+        limiter.on_rate_limited(Some(0));
+        limiter.on_rate_limited(Some(0));
+        limiter.on_rate_limited(Some(0));
+        assert_eq!(limiter.stats().current_rate, Some(100.0 / 16.0));
+        assert_eq!(limiter.stats().rate_limited, 5);
+
+        for _ in 0..100 {
+            limiter.on_success();
+        }
+        assert_eq!(limiter.stats().current_rate, Some(100.0));
+    }
+
+    #[tokio::test]
+    async fn test_pause_honors_retry_after() {
+        let limiter = RateLimiter::new(Some(RateLimit::per_second(1000.0).burst(10)));
+        limiter.on_rate_limited(Some(1));
+
+        let started = Instant::now();
+        limiter.acquire().await;
+        assert!(started.elapsed() >= Duration::from_millis(900));
+    }
+
+    #[tokio::test]
+    async fn test_pause_capped_at_max_pause() {
+        let limiter = RateLimiter::new(Some(
+            RateLimit::per_second(1000.0)
+                .burst(10)
+                .max_pause(Duration::from_millis(200)),
+        ));
+        // Would overflow `Instant` without the cap
+        limiter.on_rate_limited(Some(u64::MAX));
+
+        let started = Instant::now();
+        limiter.acquire().await;
+        let waited = started.elapsed();
+        assert!(waited >= Duration::from_millis(150));
+        assert!(waited < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_without_limit_only_counts() {
+        let limiter = RateLimiter::new(None);
+        limiter.acquire().await;
+        limiter.on_rate_limited(Some(60));
+        limiter.acquire().await;
+
+        let stats = limiter.stats();
+        assert_eq!(stats.requests, 2);
+        assert_eq!(stats.throttled, 0);
+        assert_eq!(stats.rate_limited, 1);
+        assert_eq!(stats.current_rate, None);
+    }
+}
@@ -0,0 +1,276 @@
+//! Retry policy for Files.com API requests
+//!
+//! A [`RetryPolicy`] configured on [`FilesClientBuilder`](crate::FilesClientBuilder)
+//! is applied to every request made through [`FilesClient`](crate::FilesClient).
+//! Failed requests are retried when:
+//!
+//! - the error is retryable (429, 500, 503, connection errors and timeouts),
+//! - the HTTP method is on the policy's allowlist, and
+//! - the maximum number of attempts has not been reached.
+//!
+//! Between attempts the client waits for the `Retry-After` duration sent by the
+//! server, or otherwise for an exponentially growing backoff with jitter.
+//! Either delay is capped at the policy's maximum backoff.
+//!
+//! By default only idempotent methods (`GET`, `HEAD`, `PUT`, `DELETE`,
+//! `OPTIONS`) are retried, since repeating a `POST` or `PATCH` may apply the
+//! change twice.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use files_sdk::{FilesClient, RetryPolicy};
+//! use std::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = FilesClient::builder()
+//!     .api_key("your-api-key")
+//!     .retry_policy(
+//!         RetryPolicy::default()
+//!             .max_attempts(5)
+//!             .initial_backoff(Duration::from_millis(200))
+//!             .max_backoff(Duration::from_secs(10)),
+//!     )
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::FilesError;
+use reqwest::Method;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// Configuration for retrying failed requests
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first request
+    ///
+    /// Values below 1 are treated as 1.
+    pub max_attempts: u32,
+
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: f64,
+    jitter: bool,
+    respect_retry_after: bool,
+    methods: Vec<Method>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: true,
+            respect_retry_after: true,
+            methods: vec![
+                Method::GET,
+                Method::HEAD,
+                Method::PUT,
+                Method::DELETE,
+                Method::OPTIONS,
+            ],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the maximum number of attempts, including the first request
+    ///
+    /// Values below 1 are treated as 1.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets the backoff before the first retry
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Sets the upper bound for the delay between attempts
+    ///
+    /// Also caps `Retry-After` durations sent by the server.
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Sets the factor the backoff grows by after each attempt
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier.max(1.0);
+        self
+    }
+
+    /// Enables or disables random jitter on the backoff
+    ///
+    /// With jitter, each delay is picked at random between half and all of the
+    /// computed backoff, which keeps many clients from retrying in lockstep.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Whether to wait for the server's `Retry-After` duration when present
+    pub fn respect_retry_after(mut self, respect: bool) -> Self {
+        self.respect_retry_after = respect;
+        self
+    }
+
+    /// Replaces the list of HTTP methods that may be retried
+    pub fn retry_methods(mut self, methods: impl IntoIterator<Item = Method>) -> Self {
+        self.methods = methods.into_iter().collect();
+        self
+    }
+
+    /// Adds an HTTP method to the list of methods that may be retried
+    ///
+    /// Use this to opt non-idempotent methods such as `POST` into retries when
+    /// repeating them is known to be safe for your workload.
+    pub fn allow_method(mut self, method: Method) -> Self {
+        if !self.methods.contains(&method) {
+            self.methods.push(method);
+        }
+        self
+    }
+
+    /// Whether requests with the given method may be retried
+    pub fn is_method_allowed(&self, method: &Method) -> bool {
+        self.methods.contains(method)
+    }
+
+    /// Whether a request that failed on attempt `attempt` (1-based) should be retried
+    pub fn should_retry(&self, method: &Method, error: &FilesError, attempt: u32) -> bool {
+        attempt < self.max_attempts && self.is_method_allowed(method) && is_transient(error)
+    }
+
+    /// The computed backoff after attempt `attempt` (1-based), without jitter
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(32) as i32;
+        let secs = self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent);
+        Duration::from_secs_f64(secs.min(self.max_backoff.as_secs_f64()))
+    }
+
+    /// How long to wait before retrying after `error` on attempt `attempt`
+    pub fn delay_for(&self, error: &FilesError, attempt: u32) -> Duration {
+        if let Some(retry_after) = error.retry_after().filter(|_| self.respect_retry_after) {
+            return Duration::from_secs(retry_after).min(self.max_backoff);
+        }
+
+        let backoff = self.backoff(attempt);
+        if self.jitter {
+            let half = backoff / 2;
+            half + half.mul_f64(random_fraction())
+        } else {
+            backoff
+        }
+    }
+}
+
+/// Whether the error is worth retrying
+///
+/// In addition to [`FilesError::is_retryable`], connection failures and
+/// timeouts are treated as transient.
+fn is_transient(error: &FilesError) -> bool {
+    match error {
+        FilesError::Request(e) => e.is_connect() || e.is_timeout(),
+        _ => error.is_retryable(),
+    }
+}
+
+/// A random value in `[0, 1)`, seeded from the standard library's hasher keys
+fn random_fraction() -> f64 {
+    let value = RandomState::new().build_hasher().finish();
+    (value >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_policy() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.max_attempts, 3);
+        assert!(policy.is_method_allowed(&Method::GET));
+        assert!(policy.is_method_allowed(&Method::DELETE));
+        assert!(!policy.is_method_allowed(&Method::POST));
+        assert!(!policy.is_method_allowed(&Method::PATCH));
+    }
+
+    #[test]
+    fn test_backoff_grows_and_caps() {
+        let policy = RetryPolicy::default()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(350));
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(350));
+        assert_eq!(policy.backoff(30), Duration::from_millis(350));
+    }
+
+    #[test]
+    fn test_jitter_stays_within_bounds() {
+        let policy = RetryPolicy::default().initial_backoff(Duration::from_millis(100));
+        let error = FilesError::InternalServerError {
+            message: "boom".to_string(),
+            request_id: None,
+            detail: None,
+        };
+
+        for _ in 0..100 {
+            let delay = policy.delay_for(&error, 1);
+            assert!(delay >= Duration::from_millis(50));
+            assert!(delay <= Duration::from_millis(100));
+        }
+    }
+
+    #[test]
+    fn test_retry_after_overrides_backoff() {
+        let policy = RetryPolicy::default();
+        let error = FilesError::rate_limited("slow down", Some(7));
+        assert_eq!(policy.delay_for(&error, 1), Duration::from_secs(7));
+
+        let policy = policy.respect_retry_after(false).jitter(false);
+        assert_eq!(policy.delay_for(&error, 1), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_retry_after_capped_at_max_backoff() {
+        let policy = RetryPolicy::default().max_backoff(Duration::from_secs(10));
+        let error = FilesError::rate_limited("slow down", Some(86_400));
+        assert_eq!(policy.delay_for(&error, 1), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_should_retry() {
+        let policy = RetryPolicy::default();
+        let transient = FilesError::rate_limited("slow down", None);
+        let permanent = FilesError::not_found("missing");
+
+        assert!(policy.should_retry(&Method::GET, &transient, 1));
+        assert!(policy.should_retry(&Method::GET, &transient, 2));
+        assert!(!policy.should_retry(&Method::GET, &transient, 3));
+        assert!(!policy.should_retry(&Method::GET, &permanent, 1));
+        assert!(!policy.should_retry(&Method::POST, &transient, 1));
+
+        let policy = policy.allow_method(Method::POST);
+        assert!(policy.should_retry(&Method::POST, &transient, 1));
+
+        assert!(!RetryPolicy::none().should_retry(&Method::GET, &transient, 1));
+    }
+}
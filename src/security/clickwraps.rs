@@ -1,13 +1,172 @@
 //! Clickwrap agreements
 
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
 use crate::{FilesClient, PaginationInfo, Result};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Clickwrap entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClickwrapEntity {
+    /// Clickwrap ID
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<i64>,
+
+    /// Clickwrap name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Clickwrap text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    /// Use with users: `none` or `require`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_with_users: Option<String>,
+
+    /// Use with bundles: `none`, `available` or `require`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_with_bundles: Option<String>,
+
+    /// Use with inboxes: `none`, `available` or `require`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_with_inboxes: Option<String>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parameters for creating a clickwrap
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateClickwrapParams {
+    /// Clickwrap name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Clickwrap text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    /// Use with users: `none` or `require`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_with_users: Option<String>,
+
+    /// Use with bundles: `none`, `available` or `require`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_with_bundles: Option<String>,
+
+    /// Use with inboxes: `none`, `available` or `require`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_with_inboxes: Option<String>,
+}
+
+impl CreateClickwrapParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Clickwrap name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Clickwrap text
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Use with users: `none` or `require`
+    pub fn use_with_users(mut self, use_with_users: impl Into<String>) -> Self {
+        self.use_with_users = Some(use_with_users.into());
+        self
+    }
+
+    /// Use with bundles: `none`, `available` or `require`
+    pub fn use_with_bundles(mut self, use_with_bundles: impl Into<String>) -> Self {
+        self.use_with_bundles = Some(use_with_bundles.into());
+        self
+    }
+
+    /// Use with inboxes: `none`, `available` or `require`
+    pub fn use_with_inboxes(mut self, use_with_inboxes: impl Into<String>) -> Self {
+        self.use_with_inboxes = Some(use_with_inboxes.into());
+        self
+    }
+}
+
+/// Parameters for updating a clickwrap
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateClickwrapParams {
+    /// Clickwrap name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Clickwrap text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    /// Use with users: `none` or `require`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_with_users: Option<String>,
+
+    /// Use with bundles: `none`, `available` or `require`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_with_bundles: Option<String>,
+
+    /// Use with inboxes: `none`, `available` or `require`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_with_inboxes: Option<String>,
+}
+
+impl UpdateClickwrapParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Clickwrap name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Clickwrap text
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Use with users: `none` or `require`
+    pub fn use_with_users(mut self, use_with_users: impl Into<String>) -> Self {
+        self.use_with_users = Some(use_with_users.into());
+        self
+    }
+
+    /// Use with bundles: `none`, `available` or `require`
+    pub fn use_with_bundles(mut self, use_with_bundles: impl Into<String>) -> Self {
+        self.use_with_bundles = Some(use_with_bundles.into());
+        self
+    }
+
+    /// Use with inboxes: `none`, `available` or `require`
+    pub fn use_with_inboxes(mut self, use_with_inboxes: impl Into<String>) -> Self {
+        self.use_with_inboxes = Some(use_with_inboxes.into());
+        self
+    }
+}
+
+query_fields! {
+    /// Fields that clickwraps can be sorted and filtered by
+    pub enum ClickwrapField {
+        Name => "name",
+        UseWithBundles => "use_with_bundles",
+        UseWithInboxes => "use_with_inboxes",
+        UseWithUsers => "use_with_users",
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -24,6 +183,7 @@ impl ClickwrapHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<ClickwrapField>>,
     ) -> Result<(Vec<ClickwrapEntity>, PaginationInfo)> {
         let mut endpoint = "/clickwraps".to_string();
         let mut params = Vec::new();
@@ -38,39 +198,41 @@ impl ClickwrapHandler {
             endpoint.push_str(&params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
-        let items: Vec<ClickwrapEntity> = response.json().await?;
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let items: Vec<ClickwrapEntity> = serde_json::from_value(response)?;
         Ok((items, pagination))
     }
 
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<ClickwrapField>>,
+    ) -> Paginator<ClickwrapEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
+
     pub async fn get(&self, id: i64) -> Result<ClickwrapEntity> {
         let endpoint = format!("/clickwraps/{}", id);
         let response = self.client.get_raw(&endpoint).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn create(&self, params: serde_json::Value) -> Result<ClickwrapEntity> {
+    pub async fn create(&self, params: CreateClickwrapParams) -> Result<ClickwrapEntity> {
         let response = self.client.post_raw("/clickwraps", params).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn update(&self, id: i64, params: serde_json::Value) -> Result<ClickwrapEntity> {
+    pub async fn update(&self, id: i64, params: UpdateClickwrapParams) -> Result<ClickwrapEntity> {
         let endpoint = format!("/clickwraps/{}", id);
         let response = self.client.patch_raw(&endpoint, params).await?;
         Ok(serde_json::from_value(response)?)
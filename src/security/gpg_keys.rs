@@ -1,13 +1,245 @@
 //! GPG key management for encryption
 
-use crate::{FilesClient, PaginationInfo, Result};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{FilesClient, PaginationInfo, Result, Timestamp};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// GPG key entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GpgKeyEntity {
+    /// Key ID
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<i64>,
+
+    /// Workspace ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workspace_id: Option<i64>,
+
+    /// Expiration time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+
+    /// Key name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Partner ID that owns the key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partner_id: Option<i64>,
+
+    /// Name of the partner that owns the key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partner_name: Option<String>,
+
+    /// User ID that owns the key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
+
+    /// MD5 hash of the public key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_key_md5: Option<String>,
+
+    /// MD5 hash of the private key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_key_md5: Option<String>,
+
+    /// Generated public key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generated_public_key: Option<String>,
+
+    /// Generated private key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generated_private_key: Option<String>,
+
+    /// MD5 hash of the private key password
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_key_password_md5: Option<String>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parameters for creating a GPG key
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateGpgKeyParams {
+    /// User ID that owns the key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
+
+    /// Partner ID that owns the key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partner_id: Option<i64>,
+
+    /// Key name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Public key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<String>,
+
+    /// Private key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_key: Option<String>,
+
+    /// Password for the private key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_key_password: Option<String>,
+
+    /// Whether to generate a new key pair
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generate_keypair: Option<bool>,
+
+    /// Full name for a generated key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generate_full_name: Option<String>,
+
+    /// Email address for a generated key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generate_email: Option<String>,
+
+    /// Expiration time for a generated key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generate_expires_at: Option<Timestamp>,
+}
+
+impl CreateGpgKeyParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// User ID that owns the key
+    pub fn user_id(mut self, user_id: i64) -> Self {
+        self.user_id = Some(user_id);
+        self
+    }
+
+    /// Partner ID that owns the key
+    pub fn partner_id(mut self, partner_id: i64) -> Self {
+        self.partner_id = Some(partner_id);
+        self
+    }
+
+    /// Key name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Public key
+    pub fn public_key(mut self, public_key: impl Into<String>) -> Self {
+        self.public_key = Some(public_key.into());
+        self
+    }
+
+    /// Private key
+    pub fn private_key(mut self, private_key: impl Into<String>) -> Self {
+        self.private_key = Some(private_key.into());
+        self
+    }
+
+    /// Password for the private key
+    pub fn private_key_password(mut self, private_key_password: impl Into<String>) -> Self {
+        self.private_key_password = Some(private_key_password.into());
+        self
+    }
+
+    /// Whether to generate a new key pair
+    pub fn generate_keypair(mut self, generate_keypair: bool) -> Self {
+        self.generate_keypair = Some(generate_keypair);
+        self
+    }
+
+    /// Full name for a generated key
+    pub fn generate_full_name(mut self, generate_full_name: impl Into<String>) -> Self {
+        self.generate_full_name = Some(generate_full_name.into());
+        self
+    }
+
+    /// Email address for a generated key
+    pub fn generate_email(mut self, generate_email: impl Into<String>) -> Self {
+        self.generate_email = Some(generate_email.into());
+        self
+    }
+
+    /// Expiration time for a generated key
+    pub fn generate_expires_at(mut self, generate_expires_at: impl Into<Timestamp>) -> Self {
+        self.generate_expires_at = Some(generate_expires_at.into());
+        self
+    }
+}
+
+/// Parameters for updating a GPG key
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateGpgKeyParams {
+    /// Partner ID that owns the key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partner_id: Option<i64>,
+
+    /// Key name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Public key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<String>,
+
+    /// Private key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_key: Option<String>,
+
+    /// Password for the private key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_key_password: Option<String>,
+}
+
+impl UpdateGpgKeyParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Partner ID that owns the key
+    pub fn partner_id(mut self, partner_id: i64) -> Self {
+        self.partner_id = Some(partner_id);
+        self
+    }
+
+    /// Key name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Public key
+    pub fn public_key(mut self, public_key: impl Into<String>) -> Self {
+        self.public_key = Some(public_key.into());
+        self
+    }
+
+    /// Private key
+    pub fn private_key(mut self, private_key: impl Into<String>) -> Self {
+        self.private_key = Some(private_key.into());
+        self
+    }
+
+    /// Password for the private key
+    pub fn private_key_password(mut self, private_key_password: impl Into<String>) -> Self {
+        self.private_key_password = Some(private_key_password.into());
+        self
+    }
+}
+
+query_fields! {
+    /// Fields that GPG keys can be sorted and filtered by
+    pub enum GpgKeyField {
+        Name => "name",
+        ExpiresAt => "expires_at",
+        UserId => "user_id",
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -24,6 +256,7 @@ impl GpgKeyHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<GpgKeyField>>,
     ) -> Result<(Vec<GpgKeyEntity>, PaginationInfo)> {
         let mut endpoint = "/gpg_keys".to_string();
         let mut params = Vec::new();
@@ -38,39 +271,41 @@ impl GpgKeyHandler {
             endpoint.push_str(&params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
-        let items: Vec<GpgKeyEntity> = response.json().await?;
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let items: Vec<GpgKeyEntity> = serde_json::from_value(response)?;
         Ok((items, pagination))
     }
 
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<GpgKeyField>>,
+    ) -> Paginator<GpgKeyEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
+
     pub async fn get(&self, id: i64) -> Result<GpgKeyEntity> {
         let endpoint = format!("/gpg_keys/{}", id);
         let response = self.client.get_raw(&endpoint).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn create(&self, params: serde_json::Value) -> Result<GpgKeyEntity> {
+    pub async fn create(&self, params: CreateGpgKeyParams) -> Result<GpgKeyEntity> {
         let response = self.client.post_raw("/gpg_keys", params).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn update(&self, id: i64, params: serde_json::Value) -> Result<GpgKeyEntity> {
+    pub async fn update(&self, id: i64, params: UpdateGpgKeyParams) -> Result<GpgKeyEntity> {
         let endpoint = format!("/gpg_keys/{}", id);
         let response = self.client.patch_raw(&endpoint, params).await?;
         Ok(serde_json::from_value(response)?)
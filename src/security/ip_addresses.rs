@@ -1,12 +1,41 @@
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
 use crate::{Result, client::FilesClient, types::PaginationInfo};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// IP address entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IpAddressEntity {
+    /// Unique label for the list
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// What the addresses are used for
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub associated_with: Option<String>,
+
+    /// Group ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<i64>,
+
+    /// IP addresses
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_addresses: Option<Vec<String>>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+query_fields! {
+    /// Fields that IP addresses can be sorted and filtered by
+    pub enum IpAddressField {
+        AssociatedWith => "associated_with",
+        GroupId => "group_id",
+    }
 }
 
+#[derive(Debug, Clone)]
 pub struct IpAddressHandler {
     client: FilesClient,
 }
@@ -20,6 +49,7 @@ impl IpAddressHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<IpAddressField>>,
     ) -> Result<(Vec<IpAddressEntity>, PaginationInfo)> {
         let mut endpoint = "/ip_addresses".to_string();
         let mut query_params = Vec::new();
@@ -37,29 +67,29 @@ impl IpAddressHandler {
             endpoint.push_str(&query_params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
 
-        let entities: Vec<IpAddressEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let entities: Vec<IpAddressEntity> = serde_json::from_value(response)?;
         Ok((entities, pagination))
     }
 
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<IpAddressField>>,
+    ) -> Paginator<IpAddressEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
+
     pub async fn get_reserved(&self) -> Result<Vec<IpAddressEntity>> {
         let response = self.client.get_raw("/ip_addresses/reserved").await?;
         Ok(serde_json::from_value(response)?)
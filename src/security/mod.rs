@@ -18,7 +18,13 @@ pub use ip_addresses::IpAddressHandler;
 pub use sftp_host_keys::SftpHostKeyHandler;
 
 // Re-export entities
-pub use clickwraps::ClickwrapEntity;
-pub use gpg_keys::GpgKeyEntity;
+pub use clickwraps::{ClickwrapEntity, CreateClickwrapParams, UpdateClickwrapParams};
+pub use gpg_keys::{CreateGpgKeyParams, GpgKeyEntity, UpdateGpgKeyParams};
 pub use ip_addresses::IpAddressEntity;
-pub use sftp_host_keys::SftpHostKeyEntity;
+pub use sftp_host_keys::{CreateSftpHostKeyParams, SftpHostKeyEntity, UpdateSftpHostKeyParams};
+
+// Re-export query fields
+pub use clickwraps::ClickwrapField;
+pub use gpg_keys::GpgKeyField;
+pub use ip_addresses::IpAddressField;
+pub use sftp_host_keys::SftpHostKeyField;
@@ -1,13 +1,102 @@
 //! SFTP host key management
 
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
 use crate::{FilesClient, PaginationInfo, Result};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// SFTP host key entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SftpHostKeyEntity {
+    /// Host key ID
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<i64>,
+
+    /// Host key name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// MD5 fingerprint
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fingerprint_md5: Option<String>,
+
+    /// SHA-256 fingerprint
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fingerprint_sha256: Option<String>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parameters for creating an SFTP host key
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateSftpHostKeyParams {
+    /// Host key name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Private key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_key: Option<String>,
+}
+
+impl CreateSftpHostKeyParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Host key name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Private key
+    pub fn private_key(mut self, private_key: impl Into<String>) -> Self {
+        self.private_key = Some(private_key.into());
+        self
+    }
+}
+
+/// Parameters for updating an SFTP host key
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateSftpHostKeyParams {
+    /// Host key name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Private key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_key: Option<String>,
+}
+
+impl UpdateSftpHostKeyParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Host key name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Private key
+    pub fn private_key(mut self, private_key: impl Into<String>) -> Self {
+        self.private_key = Some(private_key.into());
+        self
+    }
+}
+
+query_fields! {
+    /// Fields that SFTP host keys can be sorted and filtered by
+    pub enum SftpHostKeyField {
+        Name => "name",
+        FingerprintMd5 => "fingerprint_md5",
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -24,6 +113,7 @@ impl SftpHostKeyHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<SftpHostKeyField>>,
     ) -> Result<(Vec<SftpHostKeyEntity>, PaginationInfo)> {
         let mut endpoint = "/sftp_host_keys".to_string();
         let mut params = Vec::new();
@@ -38,39 +128,45 @@ impl SftpHostKeyHandler {
             endpoint.push_str(&params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
-        let items: Vec<SftpHostKeyEntity> = response.json().await?;
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let items: Vec<SftpHostKeyEntity> = serde_json::from_value(response)?;
         Ok((items, pagination))
     }
 
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<SftpHostKeyField>>,
+    ) -> Paginator<SftpHostKeyEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
+
     pub async fn get(&self, id: i64) -> Result<SftpHostKeyEntity> {
         let endpoint = format!("/sftp_host_keys/{}", id);
         let response = self.client.get_raw(&endpoint).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn create(&self, params: serde_json::Value) -> Result<SftpHostKeyEntity> {
+    pub async fn create(&self, params: CreateSftpHostKeyParams) -> Result<SftpHostKeyEntity> {
         let response = self.client.post_raw("/sftp_host_keys", params).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn update(&self, id: i64, params: serde_json::Value) -> Result<SftpHostKeyEntity> {
+    pub async fn update(
+        &self,
+        id: i64,
+        params: UpdateSftpHostKeyParams,
+    ) -> Result<SftpHostKeyEntity> {
         let endpoint = format!("/sftp_host_keys/{}", id);
         let response = self.client.patch_raw(&endpoint, params).await?;
         Ok(serde_json::from_value(response)?)
@@ -1,12 +1,42 @@
-use crate::{Result, client::FilesClient};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{PaginationInfo, Result, Timestamp, client::FilesClient};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Bundle download entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BundleDownloadEntity {
+    /// Registration that downloaded the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bundle_registration: Option<serde_json::Value>,
+
+    /// `file` or `zip`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub download_method: Option<String>,
+
+    /// Downloaded path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// Download time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+query_fields! {
+    /// Fields that bundle downloads can be sorted and filtered by
+    pub enum BundleDownloadField {
+        BundleId => "bundle_id",
+        BundleRegistrationId => "bundle_registration_id",
+        CreatedAt => "created_at",
+    }
 }
 
+#[derive(Debug, Clone)]
 pub struct BundleDownloadHandler {
     client: FilesClient,
 }
@@ -16,9 +46,47 @@ impl BundleDownloadHandler {
         Self { client }
     }
 
-    pub async fn list(&self) -> Result<Vec<BundleDownloadEntity>> {
-        let response = self.client.get_raw("/bundle_downloads").await?;
+    pub async fn list(
+        &self,
+        cursor: Option<String>,
+        per_page: Option<i64>,
+        query: Option<&ListQuery<BundleDownloadField>>,
+    ) -> Result<(Vec<BundleDownloadEntity>, PaginationInfo)> {
+        let mut endpoint = "/bundle_downloads".to_string();
+        let mut params = Vec::new();
+
+        if let Some(c) = cursor {
+            params.push(format!("cursor={}", c));
+        }
+        if let Some(pp) = per_page {
+            params.push(format!("per_page={}", pp));
+        }
+
+        if !params.is_empty() {
+            endpoint.push('?');
+            endpoint.push_str(&params.join("&"));
+        }
+
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
         let entities: Vec<BundleDownloadEntity> = serde_json::from_value(response)?;
-        Ok(entities)
+        Ok((entities, pagination))
+    }
+
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<BundleDownloadField>>,
+    ) -> Paginator<BundleDownloadEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
     }
 }
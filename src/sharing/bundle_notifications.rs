@@ -1,13 +1,125 @@
 //! Bundle notification configuration
 
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
 use crate::{FilesClient, PaginationInfo, Result};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Bundle notification entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BundleNotificationEntity {
+    /// Notification ID
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<i64>,
+
+    /// Bundle ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bundle_id: Option<i64>,
+
+    /// ID of the user to notify
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_user_id: Option<i64>,
+
+    /// Whether to notify on registration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_on_registration: Option<bool>,
+
+    /// Whether to notify on upload
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_on_upload: Option<bool>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parameters for creating a bundle notification
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateBundleNotificationParams {
+    /// Bundle ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bundle_id: Option<i64>,
+
+    /// ID of the user to notify
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_user_id: Option<i64>,
+
+    /// Whether to notify on registration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_on_registration: Option<bool>,
+
+    /// Whether to notify on upload
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_on_upload: Option<bool>,
+}
+
+impl CreateBundleNotificationParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bundle ID
+    pub fn bundle_id(mut self, bundle_id: i64) -> Self {
+        self.bundle_id = Some(bundle_id);
+        self
+    }
+
+    /// ID of the user to notify
+    pub fn notify_user_id(mut self, notify_user_id: i64) -> Self {
+        self.notify_user_id = Some(notify_user_id);
+        self
+    }
+
+    /// Whether to notify on registration
+    pub fn notify_on_registration(mut self, notify_on_registration: bool) -> Self {
+        self.notify_on_registration = Some(notify_on_registration);
+        self
+    }
+
+    /// Whether to notify on upload
+    pub fn notify_on_upload(mut self, notify_on_upload: bool) -> Self {
+        self.notify_on_upload = Some(notify_on_upload);
+        self
+    }
+}
+
+/// Parameters for updating a bundle notification
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateBundleNotificationParams {
+    /// Whether to notify on registration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_on_registration: Option<bool>,
+
+    /// Whether to notify on upload
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_on_upload: Option<bool>,
+}
+
+impl UpdateBundleNotificationParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether to notify on registration
+    pub fn notify_on_registration(mut self, notify_on_registration: bool) -> Self {
+        self.notify_on_registration = Some(notify_on_registration);
+        self
+    }
+
+    /// Whether to notify on upload
+    pub fn notify_on_upload(mut self, notify_on_upload: bool) -> Self {
+        self.notify_on_upload = Some(notify_on_upload);
+        self
+    }
+}
+
+query_fields! {
+    /// Fields that bundle notifications can be sorted and filtered by
+    pub enum BundleNotificationField {
+        BundleId => "bundle_id",
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -24,6 +136,7 @@ impl BundleNotificationHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<BundleNotificationField>>,
     ) -> Result<(Vec<BundleNotificationEntity>, PaginationInfo)> {
         let mut endpoint = "/bundle_notifications".to_string();
         let mut params = Vec::new();
@@ -38,34 +151,39 @@ impl BundleNotificationHandler {
             endpoint.push_str(&params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
-        let items: Vec<BundleNotificationEntity> = response.json().await?;
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let items: Vec<BundleNotificationEntity> = serde_json::from_value(response)?;
         Ok((items, pagination))
     }
 
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<BundleNotificationField>>,
+    ) -> Paginator<BundleNotificationEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
+
     pub async fn get(&self, id: i64) -> Result<BundleNotificationEntity> {
         let endpoint = format!("/bundle_notifications/{}", id);
         let response = self.client.get_raw(&endpoint).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn create(&self, params: serde_json::Value) -> Result<BundleNotificationEntity> {
+    pub async fn create(
+        &self,
+        params: CreateBundleNotificationParams,
+    ) -> Result<BundleNotificationEntity> {
         let response = self
             .client
             .post_raw("/bundle_notifications", params)
@@ -76,7 +194,7 @@ impl BundleNotificationHandler {
     pub async fn update(
         &self,
         id: i64,
-        params: serde_json::Value,
+        params: UpdateBundleNotificationParams,
     ) -> Result<BundleNotificationEntity> {
         let endpoint = format!("/bundle_notifications/{}", id);
         let response = self.client.patch_raw(&endpoint, params).await?;
@@ -1,12 +1,115 @@
-use crate::{Result, client::FilesClient, types::PaginationInfo};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{Result, Timestamp, client::FilesClient, types::PaginationInfo};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Bundle recipient entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BundleRecipientEntity {
+    /// Recipient's company
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<String>,
+
+    /// Recipient's name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Note sent to the recipient
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+
+    /// Recipient's email address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient: Option<String>,
+
+    /// When the share was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sent_at: Option<Timestamp>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parameters for sharing a bundle with a recipient
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateBundleRecipientParams {
+    /// Bundle ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bundle_id: Option<i64>,
+
+    /// Recipient's email address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient: Option<String>,
+
+    /// Recipient's name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Recipient's company
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<String>,
+
+    /// Note sent to the recipient
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+
+    /// Whether to send the share email right away
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub share_after_create: Option<bool>,
+}
+
+impl CreateBundleRecipientParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bundle ID
+    pub fn bundle_id(mut self, bundle_id: i64) -> Self {
+        self.bundle_id = Some(bundle_id);
+        self
+    }
+
+    /// Recipient's email address
+    pub fn recipient(mut self, recipient: impl Into<String>) -> Self {
+        self.recipient = Some(recipient.into());
+        self
+    }
+
+    /// Recipient's name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Recipient's company
+    pub fn company(mut self, company: impl Into<String>) -> Self {
+        self.company = Some(company.into());
+        self
+    }
+
+    /// Note sent to the recipient
+    pub fn note(mut self, note: impl Into<String>) -> Self {
+        self.note = Some(note.into());
+        self
+    }
+
+    /// Whether to send the share email right away
+    pub fn share_after_create(mut self, share_after_create: bool) -> Self {
+        self.share_after_create = Some(share_after_create);
+        self
+    }
 }
 
+query_fields! {
+    /// Fields that bundle recipients can be sorted and filtered by
+    pub enum BundleRecipientField {
+        HasRegistrations => "has_registrations",
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct BundleRecipientHandler {
     client: FilesClient,
 }
@@ -21,6 +124,7 @@ impl BundleRecipientHandler {
         bundle_id: i64,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<BundleRecipientField>>,
     ) -> Result<(Vec<BundleRecipientEntity>, PaginationInfo)> {
         let mut endpoint = "/bundle_recipients".to_string();
         let mut query_params = vec![format!("bundle_id={}", bundle_id)];
@@ -38,30 +142,38 @@ impl BundleRecipientHandler {
             endpoint.push_str(&query_params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
 
-        let entities: Vec<BundleRecipientEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let entities: Vec<BundleRecipientEntity> = serde_json::from_value(response)?;
         Ok((entities, pagination))
     }
 
-    pub async fn create(&self, params: serde_json::Value) -> Result<BundleRecipientEntity> {
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        bundle_id: i64,
+        per_page: Option<i64>,
+        query: Option<ListQuery<BundleRecipientField>>,
+    ) -> Paginator<BundleRecipientEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move {
+                handler
+                    .list(bundle_id, cursor, per_page, query.as_ref())
+                    .await
+            }
+        })
+    }
+
+    pub async fn create(
+        &self,
+        params: CreateBundleRecipientParams,
+    ) -> Result<BundleRecipientEntity> {
         let response = self.client.post_raw("/bundle_recipients", params).await?;
         Ok(serde_json::from_value(response)?)
     }
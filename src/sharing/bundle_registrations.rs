@@ -1,12 +1,77 @@
-use crate::{Result, client::FilesClient};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{PaginationInfo, Result, Timestamp, client::FilesClient};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Bundle registration entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BundleRegistrationEntity {
+    /// Registration code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+
+    /// Registrant name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Registrant company
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<String>,
+
+    /// Registrant email address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    /// Registrant IP address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+
+    /// Inbox code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inbox_code: Option<String>,
+
+    /// Clickwrap text accepted by the registrant
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clickwrap_body: Option<String>,
+
+    /// Form field set ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form_field_set_id: Option<i64>,
+
+    /// Form field values
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form_field_data: Option<serde_json::Value>,
+
+    /// Bundle code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bundle_code: Option<String>,
+
+    /// Bundle ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bundle_id: Option<i64>,
+
+    /// Bundle recipient ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bundle_recipient_id: Option<i64>,
+
+    /// Registration time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+query_fields! {
+    /// Fields that bundle registrations can be sorted and filtered by
+    pub enum BundleRegistrationField {
+        BundleId => "bundle_id",
+        CreatedAt => "created_at",
+    }
 }
 
+#[derive(Debug, Clone)]
 pub struct BundleRegistrationHandler {
     client: FilesClient,
 }
@@ -16,9 +81,47 @@ impl BundleRegistrationHandler {
         Self { client }
     }
 
-    pub async fn list(&self) -> Result<Vec<BundleRegistrationEntity>> {
-        let response = self.client.get_raw("/bundle_registrations").await?;
+    pub async fn list(
+        &self,
+        cursor: Option<String>,
+        per_page: Option<i64>,
+        query: Option<&ListQuery<BundleRegistrationField>>,
+    ) -> Result<(Vec<BundleRegistrationEntity>, PaginationInfo)> {
+        let mut endpoint = "/bundle_registrations".to_string();
+        let mut params = Vec::new();
+
+        if let Some(c) = cursor {
+            params.push(format!("cursor={}", c));
+        }
+        if let Some(pp) = per_page {
+            params.push(format!("per_page={}", pp));
+        }
+
+        if !params.is_empty() {
+            endpoint.push('?');
+            endpoint.push_str(&params.join("&"));
+        }
+
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
         let entities: Vec<BundleRegistrationEntity> = serde_json::from_value(response)?;
-        Ok(entities)
+        Ok((entities, pagination))
+    }
+
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<BundleRegistrationField>>,
+    ) -> Paginator<BundleRegistrationEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
     }
 }
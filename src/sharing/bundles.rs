@@ -16,6 +16,7 @@
 //!
 //! ```no_run
 //! use files_sdk::{FilesClient, BundleHandler};
+//! use files_sdk::timestamp::timestamp;
 //!
 //! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
 //! let client = FilesClient::builder()
@@ -28,7 +29,7 @@
 //! let bundle = handler.create(
 //!     vec!["/reports/quarterly-2024.pdf".to_string()],
 //!     Some("secure-password"),
-//!     Some("2024-12-31T23:59:59Z"),
+//!     Some(timestamp("2024-12-31T23:59:59Z")?),
 //!     None,
 //!     Some("Q4 2024 Financial Report"),
 //!     Some("Internal sharing only"),
@@ -49,7 +50,9 @@
 //! # }
 //! ```
 
-use crate::{FilesClient, PaginationInfo, Result};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{FilesClient, PaginationInfo, Result, Timestamp};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
@@ -140,15 +143,15 @@ pub struct BundleEntity {
 
     /// Bundle expiration date/time
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expires_at: Option<String>,
+    pub expires_at: Option<Timestamp>,
 
     /// Date when share becomes accessible
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_access_on_date: Option<String>,
+    pub start_access_on_date: Option<Timestamp>,
 
     /// Bundle created at
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
 
     /// Don't create subfolders for submissions?
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -219,7 +222,18 @@ pub struct BundleEntity {
     pub color_top_text: Option<String>,
 }
 
+query_fields! {
+    /// Fields that bundles can be sorted and filtered by
+    pub enum BundleField {
+        Code => "code",
+        CreatedAt => "created_at",
+        ExpiresAt => "expires_at",
+        UserId => "user_id",
+    }
+}
+
 /// Handler for bundle operations
+#[derive(Debug, Clone)]
 pub struct BundleHandler {
     client: FilesClient,
 }
@@ -239,6 +253,7 @@ impl BundleHandler {
     /// * `user_id` - Filter bundles by user ID (None for all accessible bundles)
     /// * `cursor` - Pagination cursor from previous response
     /// * `per_page` - Number of results per page (max 10,000)
+    /// * `query` - Sorting and filtering options (optional)
     ///
     /// # Returns
     ///
@@ -256,7 +271,7 @@ impl BundleHandler {
     /// let handler = BundleHandler::new(client);
     ///
     /// // List first page of bundles
-    /// let (bundles, pagination) = handler.list(None, None, Some(50)).await?;
+    /// let (bundles, pagination) = handler.list(None, None, Some(50), None).await?;
     ///
     /// for bundle in bundles {
     ///     println!("Bundle: {} - {}",
@@ -266,7 +281,7 @@ impl BundleHandler {
     ///
     /// // Get next page if available
     /// if let Some(next_cursor) = pagination.cursor_next {
-    ///     let (more_bundles, _) = handler.list(None, Some(&next_cursor), Some(50)).await?;
+    ///     let (more_bundles, _) = handler.list(None, Some(&next_cursor), Some(50), None).await?;
     /// }
     /// # Ok(())
     /// # }
@@ -276,6 +291,7 @@ impl BundleHandler {
         user_id: Option<i64>,
         cursor: Option<&str>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<BundleField>>,
     ) -> Result<(Vec<BundleEntity>, PaginationInfo)> {
         let mut params = vec![];
         if let Some(uid) = user_id {
@@ -288,7 +304,7 @@ impl BundleHandler {
             params.push(("per_page", pp.to_string()));
         }
 
-        let query = if params.is_empty() {
+        let query_string = if params.is_empty() {
             String::new()
         } else {
             format!(
@@ -301,17 +317,38 @@ impl BundleHandler {
             )
         };
 
-        let response = self.client.get_raw(&format!("/bundles{}", query)).await?;
-        let bundles: Vec<BundleEntity> = serde_json::from_value(response)?;
+        let mut endpoint = format!("/bundles{}", query_string);
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
 
-        let pagination = PaginationInfo {
-            cursor_next: None,
-            cursor_prev: None,
-        };
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let bundles: Vec<BundleEntity> = serde_json::from_value(response)?;
 
         Ok((bundles, pagination))
     }
 
+    /// Stream all bundles accessible to the current user across all pages
+    ///
+    /// Takes the same arguments as [`list`](Self::list), without the cursor.
+    pub fn list_stream(
+        &self,
+        user_id: Option<i64>,
+        per_page: Option<i64>,
+        query: Option<ListQuery<BundleField>>,
+    ) -> Paginator<BundleEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move {
+                handler
+                    .list(user_id, cursor.as_deref(), per_page, query.as_ref())
+                    .await
+            }
+        })
+    }
+
     /// Get details of a specific bundle by ID
     ///
     /// # Arguments
@@ -394,7 +431,7 @@ impl BundleHandler {
         &self,
         paths: Vec<String>,
         password: Option<&str>,
-        expires_at: Option<&str>,
+        expires_at: Option<Timestamp>,
         max_uses: Option<i64>,
         description: Option<&str>,
         note: Option<&str>,
@@ -457,6 +494,7 @@ impl BundleHandler {
     ///
     /// ```no_run
     /// use files_sdk::{FilesClient, BundleHandler};
+    /// use files_sdk::timestamp::timestamp;
     ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = FilesClient::builder().api_key("key").build()?;
@@ -466,7 +504,7 @@ impl BundleHandler {
     /// let bundle = handler.update(
     ///     12345,
     ///     None,
-    ///     Some("2025-06-30T23:59:59Z"),
+    ///     Some(timestamp("2025-06-30T23:59:59Z")?),
     ///     None,
     ///     Some("Updated report - extended access"),
     ///     None
@@ -479,7 +517,7 @@ impl BundleHandler {
         &self,
         id: i64,
         password: Option<&str>,
-        expires_at: Option<&str>,
+        expires_at: Option<Timestamp>,
         max_uses: Option<i64>,
         description: Option<&str>,
         note: Option<&str>,
@@ -1,13 +1,177 @@
 //! Form field set management
 
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
 use crate::{FilesClient, PaginationInfo, Result};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Form field set entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FormFieldSetEntity {
+    /// Form field set ID
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<i64>,
+
+    /// Title shown on the form
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Layout of the form
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form_layout: Option<Vec<i64>>,
+
+    /// Fields of the form
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form_fields: Option<serde_json::Value>,
+
+    /// Whether to skip asking for a name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_name: Option<bool>,
+
+    /// Whether to skip asking for an email address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_email: Option<bool>,
+
+    /// Whether to skip asking for a company
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_company: Option<bool>,
+
+    /// Whether the set is in use
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_use: Option<bool>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parameters for creating a form field set
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateFormFieldSetParams {
+    /// Title shown on the form
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Whether to skip asking for a name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_name: Option<bool>,
+
+    /// Whether to skip asking for an email address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_email: Option<bool>,
+
+    /// Whether to skip asking for a company
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_company: Option<bool>,
+
+    /// Fields of the form
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form_fields: Option<serde_json::Value>,
+}
+
+impl CreateFormFieldSetParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Title shown on the form
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Whether to skip asking for a name
+    pub fn skip_name(mut self, skip_name: bool) -> Self {
+        self.skip_name = Some(skip_name);
+        self
+    }
+
+    /// Whether to skip asking for an email address
+    pub fn skip_email(mut self, skip_email: bool) -> Self {
+        self.skip_email = Some(skip_email);
+        self
+    }
+
+    /// Whether to skip asking for a company
+    pub fn skip_company(mut self, skip_company: bool) -> Self {
+        self.skip_company = Some(skip_company);
+        self
+    }
+
+    /// Fields of the form
+    pub fn form_fields(mut self, form_fields: serde_json::Value) -> Self {
+        self.form_fields = Some(form_fields);
+        self
+    }
+}
+
+/// Parameters for updating a form field set
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateFormFieldSetParams {
+    /// Title shown on the form
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Whether to skip asking for a name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_name: Option<bool>,
+
+    /// Whether to skip asking for an email address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_email: Option<bool>,
+
+    /// Whether to skip asking for a company
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_company: Option<bool>,
+
+    /// Fields of the form
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form_fields: Option<serde_json::Value>,
+}
+
+impl UpdateFormFieldSetParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Title shown on the form
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Whether to skip asking for a name
+    pub fn skip_name(mut self, skip_name: bool) -> Self {
+        self.skip_name = Some(skip_name);
+        self
+    }
+
+    /// Whether to skip asking for an email address
+    pub fn skip_email(mut self, skip_email: bool) -> Self {
+        self.skip_email = Some(skip_email);
+        self
+    }
+
+    /// Whether to skip asking for a company
+    pub fn skip_company(mut self, skip_company: bool) -> Self {
+        self.skip_company = Some(skip_company);
+        self
+    }
+
+    /// Fields of the form
+    pub fn form_fields(mut self, form_fields: serde_json::Value) -> Self {
+        self.form_fields = Some(form_fields);
+        self
+    }
+}
+
+query_fields! {
+    /// Fields that form field sets can be sorted and filtered by
+    pub enum FormFieldSetField {
+        Title => "title",
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -24,6 +188,7 @@ impl FormFieldSetHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<FormFieldSetField>>,
     ) -> Result<(Vec<FormFieldSetEntity>, PaginationInfo)> {
         let mut endpoint = "/form_field_sets".to_string();
         let mut params = Vec::new();
@@ -38,39 +203,45 @@ impl FormFieldSetHandler {
             endpoint.push_str(&params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
-        let items: Vec<FormFieldSetEntity> = response.json().await?;
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let items: Vec<FormFieldSetEntity> = serde_json::from_value(response)?;
         Ok((items, pagination))
     }
 
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<FormFieldSetField>>,
+    ) -> Paginator<FormFieldSetEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
+
     pub async fn get(&self, id: i64) -> Result<FormFieldSetEntity> {
         let endpoint = format!("/form_field_sets/{}", id);
         let response = self.client.get_raw(&endpoint).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn create(&self, params: serde_json::Value) -> Result<FormFieldSetEntity> {
+    pub async fn create(&self, params: CreateFormFieldSetParams) -> Result<FormFieldSetEntity> {
         let response = self.client.post_raw("/form_field_sets", params).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn update(&self, id: i64, params: serde_json::Value) -> Result<FormFieldSetEntity> {
+    pub async fn update(
+        &self,
+        id: i64,
+        params: UpdateFormFieldSetParams,
+    ) -> Result<FormFieldSetEntity> {
         let endpoint = format!("/form_field_sets/{}", id);
         let response = self.client.patch_raw(&endpoint, params).await?;
         Ok(serde_json::from_value(response)?)
@@ -1,12 +1,115 @@
-use crate::{Result, client::FilesClient, types::PaginationInfo};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{Result, Timestamp, client::FilesClient, types::PaginationInfo};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Inbox recipient entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InboxRecipientEntity {
+    /// Recipient's company
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<String>,
+
+    /// Recipient's name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Note sent to the recipient
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+
+    /// Recipient's email address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient: Option<String>,
+
+    /// When the share was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sent_at: Option<Timestamp>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parameters for sharing an inbox with a recipient
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateInboxRecipientParams {
+    /// Inbox ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inbox_id: Option<i64>,
+
+    /// Recipient's email address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient: Option<String>,
+
+    /// Recipient's name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Recipient's company
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<String>,
+
+    /// Note sent to the recipient
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+
+    /// Whether to send the share email right away
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub share_after_create: Option<bool>,
 }
 
+impl CreateInboxRecipientParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inbox ID
+    pub fn inbox_id(mut self, inbox_id: i64) -> Self {
+        self.inbox_id = Some(inbox_id);
+        self
+    }
+
+    /// Recipient's email address
+    pub fn recipient(mut self, recipient: impl Into<String>) -> Self {
+        self.recipient = Some(recipient.into());
+        self
+    }
+
+    /// Recipient's name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Recipient's company
+    pub fn company(mut self, company: impl Into<String>) -> Self {
+        self.company = Some(company.into());
+        self
+    }
+
+    /// Note sent to the recipient
+    pub fn note(mut self, note: impl Into<String>) -> Self {
+        self.note = Some(note.into());
+        self
+    }
+
+    /// Whether to send the share email right away
+    pub fn share_after_create(mut self, share_after_create: bool) -> Self {
+        self.share_after_create = Some(share_after_create);
+        self
+    }
+}
+
+query_fields! {
+    /// Fields that inbox recipients can be sorted and filtered by
+    pub enum InboxRecipientField {
+        HasRegistrations => "has_registrations",
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct InboxRecipientHandler {
     client: FilesClient,
 }
@@ -21,6 +124,7 @@ impl InboxRecipientHandler {
         inbox_id: i64,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<InboxRecipientField>>,
     ) -> Result<(Vec<InboxRecipientEntity>, PaginationInfo)> {
         let mut endpoint = "/inbox_recipients".to_string();
         let mut query_params = vec![format!("inbox_id={}", inbox_id)];
@@ -38,30 +142,35 @@ impl InboxRecipientHandler {
             endpoint.push_str(&query_params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
 
-        let entities: Vec<InboxRecipientEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let entities: Vec<InboxRecipientEntity> = serde_json::from_value(response)?;
         Ok((entities, pagination))
     }
 
-    pub async fn create(&self, params: serde_json::Value) -> Result<InboxRecipientEntity> {
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        inbox_id: i64,
+        per_page: Option<i64>,
+        query: Option<ListQuery<InboxRecipientField>>,
+    ) -> Paginator<InboxRecipientEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move {
+                handler
+                    .list(inbox_id, cursor, per_page, query.as_ref())
+                    .await
+            }
+        })
+    }
+
+    pub async fn create(&self, params: CreateInboxRecipientParams) -> Result<InboxRecipientEntity> {
         let response = self.client.post_raw("/inbox_recipients", params).await?;
         Ok(serde_json::from_value(response)?)
     }
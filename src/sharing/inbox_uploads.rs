@@ -2,7 +2,9 @@
 //!
 //! InboxUpload is a log record about upload operations that happened in an Inbox.
 
-use crate::{FilesClient, PaginationInfo, Result};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{FilesClient, PaginationInfo, Result, Timestamp};
 use serde::{Deserialize, Serialize};
 
 /// An Inbox Registration entity (embedded in InboxUpload)
@@ -54,7 +56,7 @@ pub struct InboxRegistrationEntity {
 
     /// Registration creation time
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
 }
 
 /// An Inbox Upload entity
@@ -70,10 +72,19 @@ pub struct InboxUploadEntity {
 
     /// Upload date/time
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
+}
+
+query_fields! {
+    /// Fields that inbox uploads can be sorted and filtered by
+    pub enum InboxUploadField {
+        CreatedAt => "created_at",
+        FolderBehaviorId => "folder_behavior_id",
+    }
 }
 
 /// Handler for inbox upload operations
+#[derive(Debug, Clone)]
 pub struct InboxUploadHandler {
     client: FilesClient,
 }
@@ -89,10 +100,9 @@ impl InboxUploadHandler {
     /// # Arguments
     /// * `cursor` - Pagination cursor
     /// * `per_page` - Results per page
-    /// * `sort_by` - Sort field and direction (e.g., `{"created_at": "desc"}`)
-    /// * `filter` - Filter criteria (e.g., `{"folder_behavior_id": 123}`)
     /// * `inbox_registration_id` - Filter by inbox registration ID
     /// * `inbox_id` - Filter by inbox ID
+    /// * `query` - Sorting and filtering options (optional)
     ///
     /// # Returns
     /// Tuple of (inbox_uploads, pagination_info)
@@ -104,19 +114,17 @@ impl InboxUploadHandler {
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = FilesClient::builder().api_key("key").build()?;
     /// let handler = InboxUploadHandler::new(client);
-    /// let (uploads, _) = handler.list(None, None, None, None, None, None).await?;
+    /// let (uploads, _) = handler.list(None, None, None, None, None).await?;
     /// # Ok(())
     /// # }
     /// ```
-    #[allow(clippy::too_many_arguments)]
     pub async fn list(
         &self,
         cursor: Option<&str>,
         per_page: Option<i64>,
-        sort_by: Option<serde_json::Value>,
-        filter: Option<serde_json::Value>,
         inbox_registration_id: Option<i64>,
         inbox_id: Option<i64>,
+        query: Option<&ListQuery<InboxUploadField>>,
     ) -> Result<(Vec<InboxUploadEntity>, PaginationInfo)> {
         let mut params = vec![];
         if let Some(c) = cursor {
@@ -125,12 +133,6 @@ impl InboxUploadHandler {
         if let Some(pp) = per_page {
             params.push(("per_page", pp.to_string()));
         }
-        if let Some(sb) = sort_by {
-            params.push(("sort_by", sb.to_string()));
-        }
-        if let Some(f) = filter {
-            params.push(("filter", f.to_string()));
-        }
         if let Some(irid) = inbox_registration_id {
             params.push(("inbox_registration_id", irid.to_string()));
         }
@@ -138,7 +140,7 @@ impl InboxUploadHandler {
             params.push(("inbox_id", iid.to_string()));
         }
 
-        let query = if params.is_empty() {
+        let query_string = if params.is_empty() {
             String::new()
         } else {
             format!(
@@ -151,19 +153,44 @@ impl InboxUploadHandler {
             )
         };
 
-        let response = self
-            .client
-            .get_raw(&format!("/inbox_uploads{}", query))
-            .await?;
-        let uploads: Vec<InboxUploadEntity> = serde_json::from_value(response)?;
+        let mut endpoint = format!("/inbox_uploads{}", query_string);
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
 
-        let pagination = PaginationInfo {
-            cursor_next: None,
-            cursor_prev: None,
-        };
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let uploads: Vec<InboxUploadEntity> = serde_json::from_value(response)?;
 
         Ok((uploads, pagination))
     }
+
+    /// Stream inbox uploads across all pages
+    ///
+    /// Takes the same arguments as [`list`](Self::list), without the cursor.
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        inbox_registration_id: Option<i64>,
+        inbox_id: Option<i64>,
+        query: Option<ListQuery<InboxUploadField>>,
+    ) -> Paginator<InboxUploadEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move {
+                handler
+                    .list(
+                        cursor.as_deref(),
+                        per_page,
+                        inbox_registration_id,
+                        inbox_id,
+                        query.as_ref(),
+                    )
+                    .await
+            }
+        })
+    }
 }
 
 #[cfg(test)]
@@ -36,13 +36,29 @@ pub use share_groups::ShareGroupHandler;
 // Re-export entities
 pub use bundle_actions::BundleActionEntity;
 pub use bundle_downloads::BundleDownloadEntity;
-pub use bundle_notifications::BundleNotificationEntity;
-pub use bundle_recipients::BundleRecipientEntity;
+pub use bundle_notifications::{
+    BundleNotificationEntity, CreateBundleNotificationParams, UpdateBundleNotificationParams,
+};
+pub use bundle_recipients::{BundleRecipientEntity, CreateBundleRecipientParams};
 pub use bundle_registrations::BundleRegistrationEntity;
 pub use bundles::{BundleEntity, BundlePermission};
-pub use form_field_sets::FormFieldSetEntity;
-pub use inbox_recipients::InboxRecipientEntity;
+pub use form_field_sets::{CreateFormFieldSetParams, FormFieldSetEntity, UpdateFormFieldSetParams};
+pub use inbox_recipients::{CreateInboxRecipientParams, InboxRecipientEntity};
 pub use inbox_registrations::InboxRegistrationEntity2;
 pub use inbox_uploads::{InboxRegistrationEntity, InboxUploadEntity};
 pub use requests::RequestEntity;
-pub use share_groups::ShareGroupEntity;
+pub use share_groups::{CreateShareGroupParams, ShareGroupEntity, UpdateShareGroupParams};
+
+// Re-export query fields
+pub use bundle_actions::BundleActionField;
+pub use bundle_downloads::BundleDownloadField;
+pub use bundle_notifications::BundleNotificationField;
+pub use bundle_recipients::BundleRecipientField;
+pub use bundle_registrations::BundleRegistrationField;
+pub use bundles::BundleField;
+pub use form_field_sets::FormFieldSetField;
+pub use inbox_recipients::InboxRecipientField;
+pub use inbox_registrations::InboxRegistrationField;
+pub use inbox_uploads::InboxUploadField;
+pub use requests::RequestField;
+pub use share_groups::ShareGroupField;
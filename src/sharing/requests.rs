@@ -3,6 +3,8 @@
 //! Requests are files that should be uploaded by a specific user or group.
 //! They can be manually created/managed or automatically managed by automations.
 
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
 use crate::{FilesClient, PaginationInfo, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -35,7 +37,17 @@ pub struct RequestEntity {
     pub user_display_name: Option<String>,
 }
 
+query_fields! {
+    /// Fields that file requests can be sorted and filtered by
+    pub enum RequestField {
+        Destination => "destination",
+        Path => "path",
+        UserId => "user_id",
+    }
+}
+
 /// Handler for request operations
+#[derive(Debug, Clone)]
 pub struct RequestHandler {
     client: FilesClient,
 }
@@ -53,6 +65,7 @@ impl RequestHandler {
     /// * `per_page` - Results per page (max 10,000)
     /// * `path` - Filter by path
     /// * `mine` - Only show requests for current user
+    /// * `query` - Sorting and filtering options (optional)
     ///
     /// # Returns
     /// Tuple of (requests, pagination_info)
@@ -64,7 +77,7 @@ impl RequestHandler {
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = FilesClient::builder().api_key("key").build()?;
     /// let handler = RequestHandler::new(client);
-    /// let (requests, pagination) = handler.list(None, None, None, None).await?;
+    /// let (requests, pagination) = handler.list(None, None, None, None, None).await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -74,6 +87,7 @@ impl RequestHandler {
         per_page: Option<i64>,
         path: Option<&str>,
         mine: Option<bool>,
+        query: Option<&ListQuery<RequestField>>,
     ) -> Result<(Vec<RequestEntity>, PaginationInfo)> {
         let mut params = vec![];
         if let Some(c) = cursor {
@@ -89,7 +103,7 @@ impl RequestHandler {
             params.push(("mine", m.to_string()));
         }
 
-        let query = if params.is_empty() {
+        let query_string = if params.is_empty() {
             String::new()
         } else {
             format!(
@@ -102,23 +116,54 @@ impl RequestHandler {
             )
         };
 
-        let response = self.client.get_raw(&format!("/requests{}", query)).await?;
-        let requests: Vec<RequestEntity> = serde_json::from_value(response)?;
+        let mut endpoint = format!("/requests{}", query_string);
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
 
-        let pagination = PaginationInfo {
-            cursor_next: None,
-            cursor_prev: None,
-        };
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let requests: Vec<RequestEntity> = serde_json::from_value(response)?;
 
         Ok((requests, pagination))
     }
 
+    /// Stream requests across all pages
+    ///
+    /// Takes the same arguments as [`list`](Self::list), without the cursor.
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        path: Option<&str>,
+        mine: Option<bool>,
+        query: Option<ListQuery<RequestField>>,
+    ) -> Paginator<RequestEntity> {
+        let handler = self.clone();
+        let path = path.map(String::from);
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            let path = path.clone();
+            async move {
+                handler
+                    .list(
+                        cursor.as_deref(),
+                        per_page,
+                        path.as_deref(),
+                        mine,
+                        query.as_ref(),
+                    )
+                    .await
+            }
+        })
+    }
+
     /// List requests for a specific folder path
     ///
     /// # Arguments
     /// * `path` - Folder path
     /// * `cursor` - Pagination cursor
     /// * `per_page` - Results per page
+    /// * `query` - Sorting and filtering options (optional)
     ///
     /// # Returns
     /// Tuple of (requests, pagination_info)
@@ -127,6 +172,7 @@ impl RequestHandler {
         path: &str,
         cursor: Option<&str>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<RequestField>>,
     ) -> Result<(Vec<RequestEntity>, PaginationInfo)> {
         let mut params = vec![];
         if let Some(c) = cursor {
@@ -136,7 +182,7 @@ impl RequestHandler {
             params.push(("per_page", pp.to_string()));
         }
 
-        let query = if params.is_empty() {
+        let query_string = if params.is_empty() {
             String::new()
         } else {
             format!(
@@ -149,20 +195,40 @@ impl RequestHandler {
             )
         };
 
-        let response = self
-            .client
-            .get_raw(&format!("/requests/folders/{}{}", path, query))
-            .await?;
-        let requests: Vec<RequestEntity> = serde_json::from_value(response)?;
+        let mut endpoint = format!("/requests/folders/{}{}", path, query_string);
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
 
-        let pagination = PaginationInfo {
-            cursor_next: None,
-            cursor_prev: None,
-        };
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let requests: Vec<RequestEntity> = serde_json::from_value(response)?;
 
         Ok((requests, pagination))
     }
 
+    /// Stream requests for a specific folder path across all pages
+    ///
+    /// Takes the same arguments as [`list_for_folder`](Self::list_for_folder), without the cursor.
+    pub fn list_for_folder_stream(
+        &self,
+        path: &str,
+        per_page: Option<i64>,
+        query: Option<ListQuery<RequestField>>,
+    ) -> Paginator<RequestEntity> {
+        let handler = self.clone();
+        let path = path.to_string();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            let path = path.clone();
+            async move {
+                handler
+                    .list_for_folder(&path, cursor.as_deref(), per_page, query.as_ref())
+                    .await
+            }
+        })
+    }
+
     /// Create a new request
     ///
     /// # Arguments
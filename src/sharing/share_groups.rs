@@ -1,13 +1,136 @@
 //! Share group management
 
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
 use crate::{FilesClient, PaginationInfo, Result};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Share group entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ShareGroupEntity {
+    /// Share group ID
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<i64>,
+
+    /// Share group name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Notes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+
+    /// Owner's user ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
+
+    /// Group members
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub members: Option<serde_json::Value>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parameters for creating a share group
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateShareGroupParams {
+    /// Owner's user ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
+
+    /// Share group name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Notes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+
+    /// Group members
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub members: Option<serde_json::Value>,
+}
+
+impl CreateShareGroupParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Owner's user ID
+    pub fn user_id(mut self, user_id: i64) -> Self {
+        self.user_id = Some(user_id);
+        self
+    }
+
+    /// Share group name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Notes
+    pub fn notes(mut self, notes: impl Into<String>) -> Self {
+        self.notes = Some(notes.into());
+        self
+    }
+
+    /// Group members
+    pub fn members(mut self, members: serde_json::Value) -> Self {
+        self.members = Some(members);
+        self
+    }
+}
+
+/// Parameters for updating a share group
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateShareGroupParams {
+    /// Share group name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Notes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+
+    /// Group members
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub members: Option<serde_json::Value>,
+}
+
+impl UpdateShareGroupParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Share group name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Notes
+    pub fn notes(mut self, notes: impl Into<String>) -> Self {
+        self.notes = Some(notes.into());
+        self
+    }
+
+    /// Group members
+    pub fn members(mut self, members: serde_json::Value) -> Self {
+        self.members = Some(members);
+        self
+    }
+}
+
+query_fields! {
+    /// Fields that share groups can be sorted and filtered by
+    pub enum ShareGroupField {
+        Name => "name",
+        UserId => "user_id",
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -24,6 +147,7 @@ impl ShareGroupHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<ShareGroupField>>,
     ) -> Result<(Vec<ShareGroupEntity>, PaginationInfo)> {
         let mut endpoint = "/share_groups".to_string();
         let mut params = Vec::new();
@@ -38,39 +162,45 @@ impl ShareGroupHandler {
             endpoint.push_str(&params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
-        let items: Vec<ShareGroupEntity> = response.json().await?;
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let items: Vec<ShareGroupEntity> = serde_json::from_value(response)?;
         Ok((items, pagination))
     }
 
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<ShareGroupField>>,
+    ) -> Paginator<ShareGroupEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
+
     pub async fn get(&self, id: i64) -> Result<ShareGroupEntity> {
         let endpoint = format!("/share_groups/{}", id);
         let response = self.client.get_raw(&endpoint).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn create(&self, params: serde_json::Value) -> Result<ShareGroupEntity> {
+    pub async fn create(&self, params: CreateShareGroupParams) -> Result<ShareGroupEntity> {
         let response = self.client.post_raw("/share_groups", params).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn update(&self, id: i64, params: serde_json::Value) -> Result<ShareGroupEntity> {
+    pub async fn update(
+        &self,
+        id: i64,
+        params: UpdateShareGroupParams,
+    ) -> Result<ShareGroupEntity> {
         let endpoint = format!("/share_groups/{}", id);
         let response = self.client.patch_raw(&endpoint, params).await?;
         Ok(serde_json::from_value(response)?)
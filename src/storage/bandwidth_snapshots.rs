@@ -1,12 +1,60 @@
-use crate::{Result, client::FilesClient};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{PaginationInfo, Result, Timestamp, client::FilesClient};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Bandwidth snapshot entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BandwidthSnapshotEntity {
+    /// Snapshot ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Bytes received
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bytes_received: Option<f64>,
+
+    /// Bytes sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bytes_sent: Option<f64>,
+
+    /// Bytes received by syncs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_bytes_received: Option<f64>,
+
+    /// Bytes sent by syncs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_bytes_sent: Option<f64>,
+
+    /// Number of GET requests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requests_get: Option<f64>,
+
+    /// Number of PUT requests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requests_put: Option<f64>,
+
+    /// Number of other requests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requests_other: Option<f64>,
+
+    /// Snapshot time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logged_at: Option<Timestamp>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+query_fields! {
+    /// Fields that bandwidth snapshots can be sorted and filtered by
+    pub enum BandwidthSnapshotField {
+        LoggedAt => "logged_at",
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct BandwidthSnapshotHandler {
     client: FilesClient,
 }
@@ -16,9 +64,47 @@ impl BandwidthSnapshotHandler {
         Self { client }
     }
 
-    pub async fn list(&self) -> Result<Vec<BandwidthSnapshotEntity>> {
-        let response = self.client.get_raw("/bandwidth_snapshots").await?;
+    pub async fn list(
+        &self,
+        cursor: Option<String>,
+        per_page: Option<i64>,
+        query: Option<&ListQuery<BandwidthSnapshotField>>,
+    ) -> Result<(Vec<BandwidthSnapshotEntity>, PaginationInfo)> {
+        let mut endpoint = "/bandwidth_snapshots".to_string();
+        let mut params = Vec::new();
+
+        if let Some(c) = cursor {
+            params.push(format!("cursor={}", c));
+        }
+        if let Some(pp) = per_page {
+            params.push(format!("per_page={}", pp));
+        }
+
+        if !params.is_empty() {
+            endpoint.push('?');
+            endpoint.push_str(&params.join("&"));
+        }
+
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
         let entities: Vec<BandwidthSnapshotEntity> = serde_json::from_value(response)?;
-        Ok(entities)
+        Ok((entities, pagination))
+    }
+
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<BandwidthSnapshotField>>,
+    ) -> Paginator<BandwidthSnapshotEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
     }
 }
@@ -1,6 +1,7 @@
 //! File locking operations
 
-use crate::{FilesClient, Result};
+use crate::pagination::Paginator;
+use crate::{FilesClient, PaginationInfo, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
@@ -33,10 +34,43 @@ impl LockHandler {
         &self,
         path: &str,
         include_children: bool,
-    ) -> Result<Vec<LockEntity>> {
-        let endpoint = format!("/locks{}?include_children={}", path, include_children);
-        let response = self.client.get_raw(&endpoint).await?;
-        Ok(serde_json::from_value(response)?)
+        cursor: Option<String>,
+        per_page: Option<i64>,
+    ) -> Result<(Vec<LockEntity>, PaginationInfo)> {
+        let mut endpoint = format!("/locks{}?include_children={}", path, include_children);
+
+        if let Some(c) = cursor {
+            endpoint.push_str(&format!("&cursor={}", c));
+        }
+        if let Some(pp) = per_page {
+            endpoint.push_str(&format!("&per_page={}", pp));
+        }
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let locks: Vec<LockEntity> = serde_json::from_value(response)?;
+        Ok((locks, pagination))
+    }
+
+    /// Stream the results of [`list_for_path`](Self::list_for_path) across all pages
+    ///
+    /// Takes the same arguments as [`list_for_path`](Self::list_for_path), without the cursor.
+    pub fn list_for_path_stream(
+        &self,
+        path: &str,
+        include_children: bool,
+        per_page: Option<i64>,
+    ) -> Paginator<LockEntity> {
+        let handler = self.clone();
+        let path = path.to_string();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let path = path.clone();
+            async move {
+                handler
+                    .list_for_path(&path, include_children, cursor, per_page)
+                    .await
+            }
+        })
     }
 
     pub async fn create(&self, path: &str, timeout: Option<i64>) -> Result<LockEntity> {
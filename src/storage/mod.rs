@@ -32,7 +32,15 @@ pub use locks::LockEntity;
 pub use priorities::PriorityEntity;
 pub use projects::ProjectEntity;
 pub use remote_bandwidth_snapshots::RemoteBandwidthSnapshotEntity;
-pub use restores::RestoreEntity;
-pub use snapshots::SnapshotEntity;
+pub use restores::{CreateRestoreParams, RestoreEntity};
+pub use snapshots::{CreateSnapshotParams, SnapshotEntity, UpdateSnapshotParams};
 pub use usage_daily_snapshots::UsageDailySnapshotEntity;
 pub use usage_snapshots::UsageSnapshotEntity;
+
+// Re-export query fields
+pub use bandwidth_snapshots::BandwidthSnapshotField;
+pub use projects::ProjectField;
+pub use remote_bandwidth_snapshots::RemoteBandwidthSnapshotField;
+pub use restores::RestoreField;
+pub use snapshots::SnapshotField;
+pub use usage_daily_snapshots::UsageDailySnapshotField;
@@ -1,12 +1,24 @@
-use crate::{Result, client::FilesClient};
+use crate::pagination::Paginator;
+use crate::{PaginationInfo, Result, client::FilesClient};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Priority entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PriorityEntity {
+    /// Folder path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// Priority color
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+#[derive(Debug, Clone)]
 pub struct PriorityHandler {
     client: FilesClient,
 }
@@ -16,9 +28,42 @@ impl PriorityHandler {
         Self { client }
     }
 
-    pub async fn list(&self, path: &str) -> Result<Vec<PriorityEntity>> {
-        let endpoint = format!("/priorities/{}", path);
-        let response = self.client.get_raw(&endpoint).await?;
-        Ok(serde_json::from_value(response)?)
+    pub async fn list(
+        &self,
+        path: &str,
+        cursor: Option<String>,
+        per_page: Option<i64>,
+    ) -> Result<(Vec<PriorityEntity>, PaginationInfo)> {
+        let mut endpoint = format!("/priorities/{}", path);
+        let mut params = Vec::new();
+
+        if let Some(c) = cursor {
+            params.push(format!("cursor={}", c));
+        }
+        if let Some(pp) = per_page {
+            params.push(format!("per_page={}", pp));
+        }
+
+        if !params.is_empty() {
+            endpoint.push('?');
+            endpoint.push_str(&params.join("&"));
+        }
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let entities: Vec<PriorityEntity> = serde_json::from_value(response)?;
+        Ok((entities, pagination))
+    }
+
+    /// Stream the results of [`list`](Self::list) across all pages
+    ///
+    /// Takes the same arguments as [`list`](Self::list), without the cursor.
+    pub fn list_stream(&self, path: &str, per_page: Option<i64>) -> Paginator<PriorityEntity> {
+        let handler = self.clone();
+        let path = path.to_string();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let path = path.clone();
+            async move { handler.list(&path, cursor, per_page).await }
+        })
     }
 }
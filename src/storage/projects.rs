@@ -1,5 +1,7 @@
 //! Project management operations
 
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
 use crate::{FilesClient, PaginationInfo, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -11,6 +13,13 @@ pub struct ProjectEntity {
     pub global_access: Option<String>,
 }
 
+query_fields! {
+    /// Fields that projects can be sorted and filtered by
+    pub enum ProjectField {
+        GlobalAccess => "global_access",
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ProjectHandler {
     client: FilesClient,
@@ -25,6 +34,7 @@ impl ProjectHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<ProjectField>>,
     ) -> Result<(Vec<ProjectEntity>, PaginationInfo)> {
         let mut endpoint = "/projects".to_string();
         let mut params = Vec::new();
@@ -41,29 +51,29 @@ impl ProjectHandler {
             endpoint.push_str(&params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-        let status = response.status();
-
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
 
-        let projects: Vec<ProjectEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let projects: Vec<ProjectEntity> = serde_json::from_value(response)?;
         Ok((projects, pagination))
     }
 
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<ProjectField>>,
+    ) -> Paginator<ProjectEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
+
     pub async fn get(&self, id: i64) -> Result<ProjectEntity> {
         let endpoint = format!("/projects/{}", id);
         let response = self.client.get_raw(&endpoint).await?;
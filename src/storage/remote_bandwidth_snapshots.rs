@@ -1,12 +1,45 @@
-use crate::{Result, client::FilesClient};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{PaginationInfo, Result, Timestamp, client::FilesClient};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Remote bandwidth snapshot entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RemoteBandwidthSnapshotEntity {
+    /// Snapshot ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Bytes received by syncs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_bytes_received: Option<f64>,
+
+    /// Bytes sent by syncs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_bytes_sent: Option<f64>,
+
+    /// Snapshot time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logged_at: Option<Timestamp>,
+
+    /// Remote server ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_server_id: Option<i64>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+query_fields! {
+    /// Fields that remote bandwidth snapshots can be sorted and filtered by
+    pub enum RemoteBandwidthSnapshotField {
+        LoggedAt => "logged_at",
+        RemoteServerId => "remote_server_id",
+    }
 }
 
+#[derive(Debug, Clone)]
 pub struct RemoteBandwidthSnapshotHandler {
     client: FilesClient,
 }
@@ -16,9 +49,47 @@ impl RemoteBandwidthSnapshotHandler {
         Self { client }
     }
 
-    pub async fn list(&self) -> Result<Vec<RemoteBandwidthSnapshotEntity>> {
-        let response = self.client.get_raw("/remote_bandwidth_snapshots").await?;
+    pub async fn list(
+        &self,
+        cursor: Option<String>,
+        per_page: Option<i64>,
+        query: Option<&ListQuery<RemoteBandwidthSnapshotField>>,
+    ) -> Result<(Vec<RemoteBandwidthSnapshotEntity>, PaginationInfo)> {
+        let mut endpoint = "/remote_bandwidth_snapshots".to_string();
+        let mut params = Vec::new();
+
+        if let Some(c) = cursor {
+            params.push(format!("cursor={}", c));
+        }
+        if let Some(pp) = per_page {
+            params.push(format!("per_page={}", pp));
+        }
+
+        if !params.is_empty() {
+            endpoint.push('?');
+            endpoint.push_str(&params.join("&"));
+        }
+
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
         let entities: Vec<RemoteBandwidthSnapshotEntity> = serde_json::from_value(response)?;
-        Ok(entities)
+        Ok((entities, pagination))
+    }
+
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<RemoteBandwidthSnapshotField>>,
+    ) -> Paginator<RemoteBandwidthSnapshotEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
     }
 }
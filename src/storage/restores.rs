@@ -1,12 +1,142 @@
-use crate::{Result, client::FilesClient, types::PaginationInfo};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{Result, Timestamp, client::FilesClient, types::PaginationInfo};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Restore entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RestoreEntity {
+    /// Restore ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Earliest date of deleted files to restore
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub earliest_date: Option<Timestamp>,
+
+    /// Prefix of the files and folders to restore
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+
+    /// Whether files are restored in place
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restore_in_place: Option<bool>,
+
+    /// Whether deleted permissions are restored
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restore_deleted_permissions: Option<bool>,
+
+    /// Whether timestamps are updated on restored files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update_timestamps: Option<bool>,
+
+    /// Restore status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// Number of folders restored
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dirs_restored: Option<i64>,
+
+    /// Number of folders that failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dirs_errored: Option<i64>,
+
+    /// Total number of folders
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dirs_total: Option<i64>,
+
+    /// Number of files restored
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files_restored: Option<i64>,
+
+    /// Number of files that failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files_errored: Option<i64>,
+
+    /// Total number of files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files_total: Option<i64>,
+
+    /// Error messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_messages: Option<Vec<String>>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parameters for creating a restore
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateRestoreParams {
+    /// Earliest date of deleted files to restore
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub earliest_date: Option<Timestamp>,
+
+    /// Prefix of the files and folders to restore
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+
+    /// Whether to restore files in place
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restore_in_place: Option<bool>,
+
+    /// Whether to restore deleted permissions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restore_deleted_permissions: Option<bool>,
+
+    /// Whether to update timestamps on restored files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update_timestamps: Option<bool>,
 }
 
+impl CreateRestoreParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Earliest date of deleted files to restore
+    pub fn earliest_date(mut self, earliest_date: impl Into<Timestamp>) -> Self {
+        self.earliest_date = Some(earliest_date.into());
+        self
+    }
+
+    /// Prefix of the files and folders to restore
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Whether to restore files in place
+    pub fn restore_in_place(mut self, restore_in_place: bool) -> Self {
+        self.restore_in_place = Some(restore_in_place);
+        self
+    }
+
+    /// Whether to restore deleted permissions
+    pub fn restore_deleted_permissions(mut self, restore_deleted_permissions: bool) -> Self {
+        self.restore_deleted_permissions = Some(restore_deleted_permissions);
+        self
+    }
+
+    /// Whether to update timestamps on restored files
+    pub fn update_timestamps(mut self, update_timestamps: bool) -> Self {
+        self.update_timestamps = Some(update_timestamps);
+        self
+    }
+}
+
+query_fields! {
+    /// Fields that restores can be sorted and filtered by
+    pub enum RestoreField {
+        Status => "status",
+        CreatedAt => "created_at",
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct RestoreHandler {
     client: FilesClient,
 }
@@ -20,6 +150,7 @@ impl RestoreHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<RestoreField>>,
     ) -> Result<(Vec<RestoreEntity>, PaginationInfo)> {
         let mut endpoint = "/restores".to_string();
         let mut query_params = Vec::new();
@@ -37,30 +168,30 @@ impl RestoreHandler {
             endpoint.push_str(&query_params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
 
-        let entities: Vec<RestoreEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let entities: Vec<RestoreEntity> = serde_json::from_value(response)?;
         Ok((entities, pagination))
     }
 
-    pub async fn create(&self, params: serde_json::Value) -> Result<RestoreEntity> {
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<RestoreField>>,
+    ) -> Paginator<RestoreEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
+
+    pub async fn create(&self, params: CreateRestoreParams) -> Result<RestoreEntity> {
         let response = self.client.post_raw("/restores", params).await?;
         Ok(serde_json::from_value(response)?)
     }
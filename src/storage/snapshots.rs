@@ -1,13 +1,135 @@
 //! Snapshot management
 
-use crate::{FilesClient, PaginationInfo, Result};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{FilesClient, PaginationInfo, Result, Timestamp};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Snapshot entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SnapshotEntity {
+    /// Snapshot ID
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<i64>,
+
+    /// Snapshot name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Expiration time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+
+    /// Finalization time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finalized_at: Option<Timestamp>,
+
+    /// ID of the user who created the snapshot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
+
+    /// ID of the bundle sharing the snapshot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bundle_id: Option<i64>,
+
+    /// Paths included in the snapshot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paths: Option<Vec<String>>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parameters for creating a snapshot
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateSnapshotParams {
+    /// Snapshot name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Expiration time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+
+    /// Paths to include in the snapshot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paths: Option<Vec<String>>,
+}
+
+impl CreateSnapshotParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Snapshot name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Expiration time
+    pub fn expires_at(mut self, expires_at: impl Into<Timestamp>) -> Self {
+        self.expires_at = Some(expires_at.into());
+        self
+    }
+
+    /// Paths to include in the snapshot
+    pub fn paths(mut self, paths: Vec<String>) -> Self {
+        self.paths = Some(paths);
+        self
+    }
+}
+
+/// Parameters for updating a snapshot
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateSnapshotParams {
+    /// Snapshot name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Expiration time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+
+    /// Paths to include in the snapshot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paths: Option<Vec<String>>,
+}
+
+impl UpdateSnapshotParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Snapshot name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Expiration time
+    pub fn expires_at(mut self, expires_at: impl Into<Timestamp>) -> Self {
+        self.expires_at = Some(expires_at.into());
+        self
+    }
+
+    /// Paths to include in the snapshot
+    pub fn paths(mut self, paths: Vec<String>) -> Self {
+        self.paths = Some(paths);
+        self
+    }
+}
+
+query_fields! {
+    /// Fields that snapshots can be sorted and filtered by
+    pub enum SnapshotField {
+        Name => "name",
+        ExpiresAt => "expires_at",
+        FinalizedAt => "finalized_at",
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -24,6 +146,7 @@ impl SnapshotHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<SnapshotField>>,
     ) -> Result<(Vec<SnapshotEntity>, PaginationInfo)> {
         let mut endpoint = "/snapshots".to_string();
         let mut params = Vec::new();
@@ -38,39 +161,41 @@ impl SnapshotHandler {
             endpoint.push_str(&params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
-        let items: Vec<SnapshotEntity> = response.json().await?;
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let items: Vec<SnapshotEntity> = serde_json::from_value(response)?;
         Ok((items, pagination))
     }
 
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<SnapshotField>>,
+    ) -> Paginator<SnapshotEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
+
     pub async fn get(&self, id: i64) -> Result<SnapshotEntity> {
         let endpoint = format!("/snapshots/{}", id);
         let response = self.client.get_raw(&endpoint).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn create(&self, params: serde_json::Value) -> Result<SnapshotEntity> {
+    pub async fn create(&self, params: CreateSnapshotParams) -> Result<SnapshotEntity> {
         let response = self.client.post_raw("/snapshots", params).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn update(&self, id: i64, params: serde_json::Value) -> Result<SnapshotEntity> {
+    pub async fn update(&self, id: i64, params: UpdateSnapshotParams) -> Result<SnapshotEntity> {
         let endpoint = format!("/snapshots/{}", id);
         let response = self.client.patch_raw(&endpoint, params).await?;
         Ok(serde_json::from_value(response)?)
@@ -1,12 +1,69 @@
-use crate::{Result, client::FilesClient};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{PaginationInfo, Result, client::FilesClient};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Daily usage snapshot entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UsageDailySnapshotEntity {
+    /// Snapshot ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Snapshot date
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+
+    /// Whether API usage data is available
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_usage_available: Option<bool>,
+
+    /// Read API calls
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_api_usage: Option<i64>,
+
+    /// Write API calls
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub write_api_usage: Option<i64>,
+
+    /// Number of users
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_count: Option<i64>,
+
+    /// Storage used in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_storage: Option<i64>,
+
+    /// Storage used by deleted files in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_files_storage: Option<i64>,
+
+    /// Deleted file storage counted toward the minimum
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_files_counted_in_minimum: Option<i64>,
+
+    /// Storage used by the root folder in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub root_storage: Option<i64>,
+
+    /// Usage by top-level folder
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage_by_top_level_dir: Option<serde_json::Value>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+query_fields! {
+    /// Fields that daily usage snapshots can be sorted and filtered by
+    pub enum UsageDailySnapshotField {
+        Date => "date",
+        UsageSnapshotId => "usage_snapshot_id",
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct UsageDailySnapshotHandler {
     client: FilesClient,
 }
@@ -16,9 +73,47 @@ impl UsageDailySnapshotHandler {
         Self { client }
     }
 
-    pub async fn list(&self) -> Result<Vec<UsageDailySnapshotEntity>> {
-        let response = self.client.get_raw("/usage_daily_snapshots").await?;
+    pub async fn list(
+        &self,
+        cursor: Option<String>,
+        per_page: Option<i64>,
+        query: Option<&ListQuery<UsageDailySnapshotField>>,
+    ) -> Result<(Vec<UsageDailySnapshotEntity>, PaginationInfo)> {
+        let mut endpoint = "/usage_daily_snapshots".to_string();
+        let mut params = Vec::new();
+
+        if let Some(c) = cursor {
+            params.push(format!("cursor={}", c));
+        }
+        if let Some(pp) = per_page {
+            params.push(format!("per_page={}", pp));
+        }
+
+        if !params.is_empty() {
+            endpoint.push('?');
+            endpoint.push_str(&params.join("&"));
+        }
+
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
         let entities: Vec<UsageDailySnapshotEntity> = serde_json::from_value(response)?;
-        Ok(entities)
+        Ok((entities, pagination))
+    }
+
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<UsageDailySnapshotField>>,
+    ) -> Paginator<UsageDailySnapshotEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
     }
 }
@@ -1,12 +1,76 @@
-use crate::{Result, client::FilesClient};
+use crate::pagination::Paginator;
+use crate::{PaginationInfo, Result, Timestamp, client::FilesClient};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Usage snapshot entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UsageSnapshotEntity {
+    /// Snapshot ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Start of the billing period
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_at: Option<Timestamp>,
+
+    /// End of the billing period
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_at: Option<Timestamp>,
+
+    /// Highest number of users in the period
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub high_water_user_count: Option<i64>,
+
+    /// Current storage in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_storage: Option<f64>,
+
+    /// Highest storage in the period in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub high_water_storage: Option<f64>,
+
+    /// Storage used by the root folder in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub root_storage: Option<f64>,
+
+    /// Deleted file storage counted toward the minimum
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_files_counted_in_minimum: Option<f64>,
+
+    /// Storage used by deleted files in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_files_storage: Option<f64>,
+
+    /// Total billable storage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_billable_usage: Option<f64>,
+
+    /// Total billable transfer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_billable_transfer_usage: Option<f64>,
+
+    /// Bytes sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bytes_sent: Option<f64>,
+
+    /// Bytes received by syncs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_bytes_received: Option<f64>,
+
+    /// Bytes sent by syncs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_bytes_sent: Option<f64>,
+
+    /// Usage by top-level folder
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage_by_top_level_dir: Option<serde_json::Value>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+#[derive(Debug, Clone)]
 pub struct UsageSnapshotHandler {
     client: FilesClient,
 }
@@ -16,9 +80,37 @@ impl UsageSnapshotHandler {
         Self { client }
     }
 
-    pub async fn list(&self) -> Result<Vec<UsageSnapshotEntity>> {
-        let response = self.client.get_raw("/usage_snapshots").await?;
+    pub async fn list(
+        &self,
+        cursor: Option<String>,
+        per_page: Option<i64>,
+    ) -> Result<(Vec<UsageSnapshotEntity>, PaginationInfo)> {
+        let mut endpoint = "/usage_snapshots".to_string();
+        let mut params = Vec::new();
+
+        if let Some(c) = cursor {
+            params.push(format!("cursor={}", c));
+        }
+        if let Some(pp) = per_page {
+            params.push(format!("per_page={}", pp));
+        }
+
+        if !params.is_empty() {
+            endpoint.push('?');
+            endpoint.push_str(&params.join("&"));
+        }
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
         let entities: Vec<UsageSnapshotEntity> = serde_json::from_value(response)?;
-        Ok(entities)
+        Ok((entities, pagination))
+    }
+
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(&self, per_page: Option<i64>) -> Paginator<UsageSnapshotEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            async move { handler.list(cursor, per_page).await }
+        })
     }
 }
@@ -0,0 +1,114 @@
+//! Enums for string values sent by Files.com
+
+/// Defines a string enum with known variants and an `Unknown` fallback, so
+/// values Files.com adds later still parse
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident => $value:literal,)+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)+
+
+            /// A value not known to this version of the SDK
+            Unknown(String),
+        }
+
+        impl $name {
+            /// The value as sent by Files.com
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)+
+                    $name::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => $name::$variant,)+
+                    other => $name::Unknown(other.to_string()),
+                }
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                match value.as_str() {
+                    $($value => $name::$variant,)+
+                    _ => $name::Unknown(value),
+                }
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                match value {
+                    $name::Unknown(value) => value,
+                    other => other.as_str().to_string(),
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+                Ok($name::from(value))
+            }
+        }
+    };
+}
+
+pub(crate) use string_enum;
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    string_enum! {
+        pub enum TestKind {
+            RestApi => "restapi",
+            InboundEmail => "inbound_email",
+        }
+    }
+
+    #[test]
+    fn test_known_and_unknown_values() {
+        assert_eq!(TestKind::from("restapi"), TestKind::RestApi);
+        assert_eq!(
+            TestKind::from("carrier_pigeon"),
+            TestKind::Unknown("carrier_pigeon".to_string())
+        );
+        assert_eq!(TestKind::InboundEmail.to_string(), "inbound_email");
+        assert_eq!(String::from(TestKind::Unknown("x".into())), "x");
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        assert_eq!(
+            serde_json::from_value::<TestKind>(json!("inbound_email")).unwrap(),
+            TestKind::InboundEmail
+        );
+        assert_eq!(
+            serde_json::to_value(TestKind::Unknown("x".into())).unwrap(),
+            json!("x")
+        );
+    }
+}
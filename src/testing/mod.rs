@@ -0,0 +1,204 @@
+//! In-memory fake of the Files.com API for offline tests
+//!
+//! Enabled with the `testing` feature. [`FakeFilesServer`] listens on a local
+//! port and keeps its state in memory, so code under test talks to it with
+//! an unmodified [`FilesClient`]: point
+//! [`base_url`](crate::FilesClientBuilder::base_url) at
+//! [`FakeFilesServer::uri`].
+//!
+//! The fake covers the core of the REST surface:
+//!
+//! - Files: metadata, download (with `Range` requests), update, delete
+//! - The upload flow: `begin_upload`, part uploads to the returned URLs and
+//!   `action=end`, including multi-part uploads
+//! - Folders: list (with `search`), create, delete
+//! - File actions: copy, move and metadata
+//! - Locks: list, create and delete; locked files cannot be uploaded to,
+//!   moved or deleted
+//! - Users, groups (including memberships) and bundles
+//!
+//! List endpoints paginate with `X-Files-Cursor-Next` / `X-Files-Cursor-Prev`
+//! headers and honour `per_page`, `sort_by[...]` and `filter*[...]`
+//! parameters. Paths are case-insensitive like on Files.com. Errors use
+//! the status codes and `{"error": ...}` bodies the SDK maps to
+//! [`FilesError`](crate::FilesError) variants. Endpoints outside this list
+//! answer 404.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use files_sdk::testing::FakeFilesServer;
+//! use files_sdk::{FileHandler, FolderHandler};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let fake = FakeFilesServer::start().await;
+//! fake.put_file("/inbox/existing.txt", "seeded");
+//!
+//! let client = fake.client();
+//! FileHandler::new(client.clone())
+//!     .upload_file("/inbox/report.csv", b"a,b\n1,2\n")
+//!     .await?;
+//!
+//! let listing = FolderHandler::new(client).list_folder_all("/inbox").await?;
+//! assert_eq!(listing.len(), 2);
+//! assert_eq!(fake.file("/inbox/report.csv").unwrap(), b"a,b\n1,2\n");
+//! # Ok(())
+//! # }
+//! ```
+
+mod state;
+
+use crate::FilesClient;
+use state::State;
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard};
+use wiremock::matchers::any;
+use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate};
+
+/// Part size handed out by `begin_upload` unless configured otherwise
+pub const DEFAULT_FAKE_PART_SIZE: u64 = 5 * 1024 * 1024;
+
+/// Largest page list endpoints return unless configured otherwise
+pub const DEFAULT_FAKE_MAX_PER_PAGE: usize = 10_000;
+
+/// Builder for [`FakeFilesServer`]
+#[derive(Debug, Clone)]
+pub struct FakeFilesServerBuilder {
+    part_size: u64,
+    max_per_page: usize,
+}
+
+impl Default for FakeFilesServerBuilder {
+    fn default() -> Self {
+        Self {
+            part_size: DEFAULT_FAKE_PART_SIZE,
+            max_per_page: DEFAULT_FAKE_MAX_PER_PAGE,
+        }
+    }
+}
+
+impl FakeFilesServerBuilder {
+    /// Sets the part size handed out by `begin_upload`
+    ///
+    /// A small part size exercises multi-part uploads with small test files.
+    pub fn part_size(mut self, part_size: u64) -> Self {
+        self.part_size = part_size.max(1);
+        self
+    }
+
+    /// Caps the number of items per page of list endpoints
+    ///
+    /// A small cap exercises pagination with few items.
+    pub fn max_per_page(mut self, max_per_page: usize) -> Self {
+        self.max_per_page = max_per_page.max(1);
+        self
+    }
+
+    /// Starts the server on a local port
+    pub async fn start(self) -> FakeFilesServer {
+        let server = MockServer::start().await;
+        let state = Arc::new(Mutex::new(State::new(
+            self.part_size,
+            self.max_per_page,
+            server.uri(),
+        )));
+        Mock::given(any())
+            .respond_with(Responder(state.clone()))
+            .mount(&server)
+            .await;
+
+        FakeFilesServer { server, state }
+    }
+}
+
+/// Answers every request from the shared state
+struct Responder(Arc<Mutex<State>>);
+
+impl Respond for Responder {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        lock(&self.0).handle(request)
+    }
+}
+
+/// Locks the state, ignoring poisoning by a panicked test
+fn lock(state: &Mutex<State>) -> MutexGuard<'_, State> {
+    state
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Stateful in-process fake of the Files.com REST API
+///
+/// The server stops when this value is dropped. Paths passed to the
+/// inspection and seeding methods may start with `/` or not.
+pub struct FakeFilesServer {
+    server: MockServer,
+    state: Arc<Mutex<State>>,
+}
+
+impl FakeFilesServer {
+    /// Starts a server with default settings
+    pub async fn start() -> Self {
+        Self::builder().start().await
+    }
+
+    /// Creates a builder to configure the server before starting it
+    pub fn builder() -> FakeFilesServerBuilder {
+        FakeFilesServerBuilder::default()
+    }
+
+    /// Base URL to pass to [`FilesClientBuilder::base_url`](crate::FilesClientBuilder::base_url)
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// Creates a client for this server
+    ///
+    /// Any API key is accepted; this client uses `test-key`.
+    pub fn client(&self) -> FilesClient {
+        FilesClient::builder()
+            .api_key("test-key")
+            .base_url(self.uri())
+            .build()
+            .expect("client configuration is valid")
+    }
+
+    /// Stores a file, creating missing parent folders
+    pub fn put_file(&self, path: &str, data: impl Into<Vec<u8>>) {
+        lock(&self.state).put_file(path, data.into());
+    }
+
+    /// Creates a folder and any missing parents
+    pub fn create_folder(&self, path: &str) {
+        lock(&self.state).put_folder(path);
+    }
+
+    /// Returns the content of a file, or `None` if it is missing or a folder
+    pub fn file(&self, path: &str) -> Option<Vec<u8>> {
+        lock(&self.state).file(path)
+    }
+
+    /// Whether a file or folder exists at `path`
+    pub fn exists(&self, path: &str) -> bool {
+        lock(&self.state).exists(path)
+    }
+
+    /// Paths of all files and folders, sorted case-insensitively
+    pub fn paths(&self) -> Vec<String> {
+        lock(&self.state).paths()
+    }
+
+    /// Requests received so far, oldest first
+    pub async fn received_requests(&self) -> Vec<Request> {
+        self.server.received_requests().await.unwrap_or_default()
+    }
+}
+
+impl fmt::Debug for FakeFilesServer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FakeFilesServer")
+            .field("uri", &self.uri())
+            .finish_non_exhaustive()
+    }
+}
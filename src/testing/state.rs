@@ -0,0 +1,1105 @@
+//! In-memory state of the fake server and its request handling
+
+use crate::files::checksum::{ChecksumAlgorithm, Hasher};
+use crate::timestamp::format_system_time;
+use crate::utils::encode_path;
+use serde_json::{Map, Value, json};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+use std::time::{Duration, SystemTime};
+use wiremock::{Request, ResponseTemplate};
+
+/// Prefix of the URLs handed out for downloads and part uploads
+const STORAGE: &str = "_storage";
+
+/// Page size used when a list request does not ask for one
+const DEFAULT_PER_PAGE: usize = 1000;
+
+/// Path without leading, trailing or repeated slashes
+fn normalize(path: &str) -> String {
+    path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Lookup key for a path; Files.com paths are case-insensitive
+fn key(path: &str) -> String {
+    normalize(path).to_lowercase()
+}
+
+/// Parent of a normalized path, `""` for the root
+fn parent(path: &str) -> &str {
+    path.rsplit_once('/').map_or("", |(parent, _)| parent)
+}
+
+/// Whether `path` is `folder` or below it; both are keys
+fn is_within(path: &str, folder: &str) -> bool {
+    folder.is_empty()
+        || path == folder
+        || path
+            .strip_prefix(folder)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = s
+            .get(i + 1..i + 3)
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn ok(status: u16, body: Value) -> ResponseTemplate {
+    ResponseTemplate::new(status).set_body_json(body)
+}
+
+fn error(status: u16, message: &str) -> ResponseTemplate {
+    ok(status, json!({"error": message, "http-code": status}))
+}
+
+fn no_content() -> ResponseTemplate {
+    ResponseTemplate::new(204)
+}
+
+fn now() -> String {
+    format_system_time(SystemTime::now())
+}
+
+/// Reads a boolean sent as JSON or as a form / query string value
+fn truthy(value: Option<&Value>) -> bool {
+    match value {
+        Some(Value::Bool(b)) => *b,
+        Some(Value::String(s)) => s == "true" || s == "1",
+        Some(Value::Number(n)) => n.as_i64() == Some(1),
+        _ => false,
+    }
+}
+
+fn as_i64(value: Option<&Value>) -> Option<i64> {
+    match value? {
+        Value::Number(n) => n.as_i64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Orders JSON values, numerically when both are numbers
+fn compare(a: &Value, b: &Value) -> Ordering {
+    match (a.as_f64(), b.as_f64()) {
+        (Some(a), Some(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        _ => text(a).cmp(&text(b)),
+    }
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+/// A request, decoded
+struct Call<'a> {
+    method: &'a str,
+    path: String,
+    query: Vec<(String, String)>,
+    params: Map<String, Value>,
+    origin: String,
+    range: Option<String>,
+    body: &'a [u8],
+}
+
+impl<'a> Call<'a> {
+    fn new(request: &'a Request, origin: &str) -> Self {
+        let is_json = request
+            .headers
+            .get("content-type")
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.contains("json"));
+        let params = if is_json {
+            serde_json::from_slice::<Map<String, Value>>(&request.body).unwrap_or_default()
+        } else if request.method.as_str() == "PUT" {
+            Map::new()
+        } else {
+            url::form_urlencoded::parse(&request.body)
+                .map(|(name, value)| (name.into_owned(), Value::String(value.into_owned())))
+                .collect()
+        };
+
+        Self {
+            method: request.method.as_str(),
+            path: percent_decode(request.url.path()),
+            query: request
+                .url
+                .query_pairs()
+                .map(|(name, value)| (name.into_owned(), value.into_owned()))
+                .collect(),
+            params,
+            origin: origin.to_string(),
+            range: request
+                .headers
+                .get("range")
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string),
+            body: &request.body,
+        }
+    }
+
+    fn query(&self, name: &str) -> Option<&str> {
+        self.query
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn param(&self, name: &str) -> Option<&Value> {
+        self.params.get(name).filter(|value| !value.is_null())
+    }
+
+    fn param_str(&self, name: &str) -> Option<&str> {
+        self.param(name).and_then(Value::as_str)
+    }
+}
+
+/// A file or folder
+#[derive(Debug, Clone)]
+struct Entry {
+    path: String,
+    folder: bool,
+    data: Vec<u8>,
+    md5: String,
+    crc32: String,
+    mtime: String,
+    provided_mtime: Option<String>,
+    custom_metadata: Option<Value>,
+    priority_color: Option<String>,
+}
+
+impl Entry {
+    fn folder(path: String) -> Self {
+        Self {
+            path,
+            folder: true,
+            data: Vec::new(),
+            md5: String::new(),
+            crc32: String::new(),
+            mtime: now(),
+            provided_mtime: None,
+            custom_metadata: None,
+            priority_color: None,
+        }
+    }
+
+    fn file(path: String, data: Vec<u8>) -> Self {
+        let mut hasher = Hasher::all();
+        hasher.update(&data);
+        Self {
+            md5: hasher.finish(ChecksumAlgorithm::Md5).unwrap_or_default(),
+            crc32: hasher.finish(ChecksumAlgorithm::Crc32).unwrap_or_default(),
+            data,
+            folder: false,
+            ..Self::folder(path)
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        let name = self.path.rsplit('/').next().unwrap_or_default();
+        let mut entity = json!({
+            "path": self.path,
+            "display_name": name,
+            "type": if self.folder { "directory" } else { "file" },
+            "mtime": self.mtime,
+            "permissions": "lrwd",
+        });
+        if !self.folder {
+            entity["size"] = json!(self.data.len());
+            entity["md5"] = json!(self.md5);
+            entity["crc32"] = json!(self.crc32);
+        }
+        if let Some(provided_mtime) = &self.provided_mtime {
+            entity["provided_mtime"] = json!(provided_mtime);
+        }
+        if let Some(custom_metadata) = &self.custom_metadata {
+            entity["custom_metadata"] = custom_metadata.clone();
+        }
+        if let Some(priority_color) = &self.priority_color {
+            entity["priority_color"] = json!(priority_color);
+        }
+        entity
+    }
+}
+
+/// An upload between `begin_upload` and `action=end`
+#[derive(Debug)]
+struct Upload {
+    path: String,
+    parts: BTreeMap<i64, Vec<u8>>,
+}
+
+#[derive(Debug, Clone)]
+struct Lock {
+    path: String,
+    token: String,
+    timeout: Option<i64>,
+}
+
+/// Everything the fake server knows
+#[derive(Debug)]
+pub(super) struct State {
+    pub(super) part_size: u64,
+    pub(super) max_per_page: usize,
+    origin: String,
+    entries: BTreeMap<String, Entry>,
+    uploads: HashMap<String, Upload>,
+    locks: Vec<Lock>,
+    users: BTreeMap<i64, Value>,
+    groups: BTreeMap<i64, Value>,
+    bundles: BTreeMap<i64, Value>,
+    next_id: i64,
+}
+
+impl State {
+    pub(super) fn new(part_size: u64, max_per_page: usize, origin: String) -> Self {
+        Self {
+            part_size,
+            max_per_page,
+            origin,
+            entries: BTreeMap::new(),
+            uploads: HashMap::new(),
+            locks: Vec::new(),
+            users: BTreeMap::new(),
+            groups: BTreeMap::new(),
+            bundles: BTreeMap::new(),
+            next_id: 1,
+        }
+    }
+
+    fn next_id(&mut self) -> i64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    // Direct access, used by `FakeFilesServer`
+
+    pub(super) fn put_file(&mut self, path: &str, data: Vec<u8>) {
+        let path = normalize(path);
+        self.create_parents(&path);
+        self.entries.insert(key(&path), Entry::file(path, data));
+    }
+
+    pub(super) fn put_folder(&mut self, path: &str) {
+        let path = normalize(path);
+        self.create_parents(&path);
+        self.entries
+            .entry(key(&path))
+            .or_insert_with(|| Entry::folder(path));
+    }
+
+    pub(super) fn file(&self, path: &str) -> Option<Vec<u8>> {
+        self.entries
+            .get(&key(path))
+            .filter(|entry| !entry.folder)
+            .map(|entry| entry.data.clone())
+    }
+
+    pub(super) fn exists(&self, path: &str) -> bool {
+        key(path).is_empty() || self.entries.contains_key(&key(path))
+    }
+
+    pub(super) fn paths(&self) -> Vec<String> {
+        self.entries
+            .values()
+            .map(|entry| entry.path.clone())
+            .collect()
+    }
+
+    /// Creates the missing folders above `path`, replacing nothing
+    fn create_parents(&mut self, path: &str) {
+        let mut folder = String::new();
+        for segment in parent(path).split('/').filter(|s| !s.is_empty()) {
+            if !folder.is_empty() {
+                folder.push('/');
+            }
+            folder.push_str(segment);
+            self.entries
+                .entry(key(&folder))
+                .or_insert_with(|| Entry::folder(folder.clone()));
+        }
+    }
+
+    fn is_folder(&self, path: &str) -> bool {
+        key(path).is_empty() || self.entries.get(&key(path)).is_some_and(|e| e.folder)
+    }
+
+    /// Checks that the parent of `path` is a folder, creating it if allowed
+    #[allow(clippy::result_large_err)]
+    fn prepare_parent(&mut self, path: &str, mkdir_parents: bool) -> Result<(), ResponseTemplate> {
+        let parent = parent(path);
+        if self.is_folder(parent) {
+            return Ok(());
+        }
+        let mut ancestor = parent;
+        while !ancestor.is_empty() {
+            if self.entries.get(&key(ancestor)).is_some_and(|e| !e.folder) {
+                return Err(error(422, "Parent path is a file"));
+            }
+            ancestor = self::parent(ancestor);
+        }
+        if !mkdir_parents {
+            return Err(error(404, "Parent folder not found"));
+        }
+        self.put_folder(parent);
+        Ok(())
+    }
+
+    fn is_locked(&self, path: &str) -> bool {
+        let path = key(path);
+        self.locks.iter().any(|lock| key(&lock.path) == path)
+    }
+
+    fn subtree(&self, path: &str) -> Vec<String> {
+        let folder = key(path);
+        self.entries
+            .keys()
+            .filter(|k| is_within(k, &folder))
+            .cloned()
+            .collect()
+    }
+
+    fn children(&self, path: &str) -> Vec<&Entry> {
+        let folder = key(path);
+        self.entries
+            .iter()
+            .filter(|(k, _)| parent(k) == folder)
+            .map(|(_, entry)| entry)
+            .collect()
+    }
+
+    // Request handling
+
+    pub(super) fn handle(&mut self, request: &Request) -> ResponseTemplate {
+        let call = Call::new(request, &self.origin);
+        let path = call.path.trim_start_matches('/').to_string();
+        let (resource, rest) = path.split_once('/').unwrap_or((&path, ""));
+
+        match (call.method, resource) {
+            ("GET", "files") => self.get_file(&call, rest),
+            ("POST", "files") => self.end_upload(&call, rest),
+            ("PATCH", "files") => self.update_file(&call, rest),
+            ("DELETE", "files" | "folders") => self.delete(&call, rest),
+            ("GET", "folders") => self.list_folder(&call, rest),
+            ("POST", "folders") => self.create_folder(&call, rest),
+            ("POST", "file_actions") => {
+                let (action, path) = rest.split_once('/').unwrap_or((rest, ""));
+                match action {
+                    "begin_upload" => self.begin_upload(&call, path),
+                    "copy" => self.copy_or_move(&call, path, false),
+                    "move" => self.copy_or_move(&call, path, true),
+                    "metadata" => self.metadata(path),
+                    _ => error(404, "Not Found"),
+                }
+            }
+            (_, "locks") => self.locks(&call, rest),
+            (_, "users") => self.users(&call, rest),
+            (_, "groups") => self.groups(&call, rest),
+            (_, "bundles") => self.bundles(&call, rest),
+            (_, STORAGE) => self.storage(&call, rest),
+            _ => error(404, "Not Found"),
+        }
+    }
+
+    /// Responds with one page of `items`, with cursor headers for the others
+    fn page(&self, call: &Call, mut items: Vec<Value>) -> ResponseTemplate {
+        apply_query(call, &mut items);
+
+        let per_page = call
+            .query("per_page")
+            .and_then(|n| n.parse().ok())
+            .unwrap_or(DEFAULT_PER_PAGE)
+            .clamp(1, self.max_per_page.max(1));
+        let start = call
+            .query("cursor")
+            .and_then(|c| c.parse::<usize>().ok())
+            .unwrap_or(0)
+            .min(items.len());
+        let end = (start + per_page).min(items.len());
+
+        let mut response = ok(200, Value::Array(items[start..end].to_vec()));
+        if end < items.len() {
+            response = response.insert_header("X-Files-Cursor-Next", end.to_string());
+        }
+        if start > 0 {
+            let prev = start.saturating_sub(per_page);
+            response = response.insert_header("X-Files-Cursor-Prev", prev.to_string());
+        }
+        response
+    }
+
+    // Files and folders
+
+    fn get_file(&self, call: &Call, path: &str) -> ResponseTemplate {
+        let Some(entry) = self.entries.get(&key(path)) else {
+            return error(404, "Not Found");
+        };
+        let mut entity = entry.to_json();
+        if !entry.folder {
+            entity["download_uri"] = json!(format!(
+                "{}/{}/download/{}",
+                call.origin,
+                STORAGE,
+                encode_path(&entry.path)
+            ));
+        }
+        ok(200, entity)
+    }
+
+    fn metadata(&self, path: &str) -> ResponseTemplate {
+        match self.entries.get(&key(path)) {
+            Some(entry) => ok(200, entry.to_json()),
+            None => error(404, "Not Found"),
+        }
+    }
+
+    fn update_file(&mut self, call: &Call, path: &str) -> ResponseTemplate {
+        let Some(entry) = self.entries.get_mut(&key(path)) else {
+            return error(404, "Not Found");
+        };
+        if let Some(provided_mtime) = call.param_str("provided_mtime") {
+            entry.provided_mtime = Some(provided_mtime.to_string());
+        }
+        if let Some(custom_metadata) = call.param("custom_metadata") {
+            entry.custom_metadata = Some(custom_metadata.clone());
+        }
+        if let Some(priority_color) = call.param_str("priority_color") {
+            entry.priority_color = Some(priority_color.to_string());
+        }
+        ok(200, entry.to_json())
+    }
+
+    fn delete(&mut self, call: &Call, path: &str) -> ResponseTemplate {
+        if key(path).is_empty() {
+            return error(400, "Cannot delete the root folder");
+        }
+        let Some(entry) = self.entries.get(&key(path)) else {
+            return error(404, "Not Found");
+        };
+        if self.is_locked(path) {
+            return error(423, "File is locked");
+        }
+        let subtree = self.subtree(path);
+        if entry.folder && subtree.len() > 1 && call.query("recursive") != Some("true") {
+            return error(422, "Folder not empty");
+        }
+        for key in subtree {
+            self.entries.remove(&key);
+        }
+        no_content()
+    }
+
+    fn list_folder(&self, call: &Call, path: &str) -> ResponseTemplate {
+        if !self.is_folder(path) {
+            return error(404, "Folder not found");
+        }
+
+        let items = match call.query("search").filter(|s| !s.is_empty()) {
+            Some(search) => {
+                let search = search.to_lowercase();
+                let folder = key(path);
+                self.entries
+                    .iter()
+                    .filter(|(k, _)| **k != folder && is_within(k, &folder))
+                    .filter(|(k, _)| k.rsplit('/').next().unwrap_or_default().contains(&search))
+                    .map(|(_, entry)| entry.to_json())
+                    .collect()
+            }
+            None => self
+                .children(path)
+                .into_iter()
+                .map(Entry::to_json)
+                .collect(),
+        };
+        self.page(call, items)
+    }
+
+    fn create_folder(&mut self, call: &Call, path: &str) -> ResponseTemplate {
+        let path = normalize(path);
+        if self.exists(&path) {
+            return error(422, "Destination exists");
+        }
+        if let Err(response) = self.prepare_parent(&path, truthy(call.param("mkdir_parents"))) {
+            return response;
+        }
+        let entry = Entry::folder(path.clone());
+        let response = ok(201, entry.to_json());
+        self.entries.insert(key(&path), entry);
+        response
+    }
+
+    fn copy_or_move(&mut self, call: &Call, path: &str, is_move: bool) -> ResponseTemplate {
+        let Some(destination) = call.param_str("destination").map(normalize) else {
+            return error(400, "destination is required");
+        };
+        let source = normalize(path);
+        if !self.entries.contains_key(&key(&source)) {
+            return error(404, "Not Found");
+        }
+        if self.exists(&destination) {
+            return error(422, "Destination exists");
+        }
+        if is_within(&key(&destination), &key(&source)) {
+            return error(422, "Destination is inside the source folder");
+        }
+        if is_move && self.is_locked(&source) {
+            return error(423, "File is locked");
+        }
+        if let Err(response) = self.prepare_parent(&destination, true) {
+            return response;
+        }
+
+        for old_key in self.subtree(&source) {
+            let entry = if is_move {
+                self.entries.remove(&old_key)
+            } else {
+                self.entries.get(&old_key).cloned()
+            };
+            let Some(mut entry) = entry else { continue };
+            entry.path = format!("{}{}", destination, &entry.path[source.len()..]);
+            self.entries.insert(key(&entry.path), entry);
+        }
+        ok(201, json!({"status": "completed"}))
+    }
+
+    // Uploads
+
+    fn upload_part_json(&self, call: &Call, upload_ref: &str, path: &str, part: i64) -> Value {
+        json!({
+            "action": "put/write",
+            "ask_about_overwrites": false,
+            "available_parts": 10_000,
+            "expires": format_system_time(SystemTime::now() + Duration::from_secs(3600)),
+            "headers": {},
+            "http_method": "PUT",
+            "next_partsize": self.part_size,
+            "parallel_parts": true,
+            "retry_parts": true,
+            "parameters": {},
+            "part_number": part,
+            "partsize": self.part_size,
+            "path": path,
+            "ref": upload_ref,
+            "upload_uri": format!("{}/{}/upload/{}/{}", call.origin, STORAGE, upload_ref, part),
+        })
+    }
+
+    fn begin_upload(&mut self, call: &Call, path: &str) -> ResponseTemplate {
+        let path = normalize(path);
+
+        if let Some(upload_ref) = call.param_str("ref") {
+            let Some(upload) = self.uploads.get(upload_ref) else {
+                return error(404, "Upload not found");
+            };
+            let part = as_i64(call.param("part")).unwrap_or(1);
+            return ok(
+                200,
+                self.upload_part_json(call, upload_ref, &upload.path, part),
+            );
+        }
+
+        if self.is_locked(&path) {
+            return error(423, "File is locked");
+        }
+        if self.is_folder(&path) {
+            return error(422, "Destination is a folder");
+        }
+        if let Err(response) = self.prepare_parent(&path, truthy(call.param("mkdir_parents"))) {
+            return response;
+        }
+
+        let upload_ref = format!("upload-{}", self.next_id());
+        let parts = as_i64(call.param("parts")).unwrap_or(1).max(1);
+        let response = (1..=parts)
+            .map(|part| self.upload_part_json(call, &upload_ref, &path, part))
+            .collect();
+        self.uploads.insert(
+            upload_ref,
+            Upload {
+                path,
+                parts: BTreeMap::new(),
+            },
+        );
+        ok(200, Value::Array(response))
+    }
+
+    fn end_upload(&mut self, call: &Call, path: &str) -> ResponseTemplate {
+        if call.param_str("action") != Some("end") {
+            return error(400, "Unsupported action");
+        }
+        let Some(upload_ref) = call.param_str("ref") else {
+            return error(400, "ref is required");
+        };
+        match self.uploads.get(upload_ref) {
+            None => return error(404, "Upload not found"),
+            Some(upload) if key(&upload.path) != key(path) => {
+                return error(400, "Upload belongs to a different path");
+            }
+            Some(_) => {}
+        }
+
+        let upload = self.uploads.remove(upload_ref).expect("upload was found");
+        let data = upload.parts.into_values().flatten().collect();
+        let entry = Entry::file(upload.path, data);
+        let response = ok(200, entry.to_json());
+        self.entries.insert(key(&entry.path), entry);
+        response
+    }
+
+    /// Download and part upload URLs
+    fn storage(&mut self, call: &Call, rest: &str) -> ResponseTemplate {
+        let (kind, rest) = rest.split_once('/').unwrap_or((rest, ""));
+        match (call.method, kind) {
+            ("GET", "download") => self.download(call, rest),
+            ("PUT" | "POST", "upload") => {
+                let Some((upload_ref, part)) = rest.split_once('/') else {
+                    return error(404, "Not Found");
+                };
+                let (Some(upload), Ok(part)) = (self.uploads.get_mut(upload_ref), part.parse())
+                else {
+                    return error(404, "Upload not found");
+                };
+                upload.parts.insert(part, call.body.to_vec());
+
+                let mut hasher = Hasher::new(ChecksumAlgorithm::Md5);
+                hasher.update(call.body);
+                let etag = hasher.finish(ChecksumAlgorithm::Md5).unwrap_or_default();
+                ResponseTemplate::new(200).insert_header("ETag", format!("\"{}\"", etag))
+            }
+            _ => error(404, "Not Found"),
+        }
+    }
+
+    fn download(&self, call: &Call, path: &str) -> ResponseTemplate {
+        let Some(entry) = self.entries.get(&key(path)).filter(|e| !e.folder) else {
+            return error(404, "Not Found");
+        };
+        let data = &entry.data;
+
+        let Some(range) = call.range.as_deref() else {
+            return ResponseTemplate::new(200).set_body_bytes(data.clone());
+        };
+
+        let len = data.len() as u64;
+        let Some((start, end)) = parse_range(range, len) else {
+            return ResponseTemplate::new(416)
+                .insert_header("Content-Range", format!("bytes */{}", len));
+        };
+        ResponseTemplate::new(206)
+            .insert_header("Content-Range", format!("bytes {}-{}/{}", start, end, len))
+            .set_body_bytes(data[start as usize..=end as usize].to_vec())
+    }
+
+    // Locks
+
+    fn locks(&mut self, call: &Call, path: &str) -> ResponseTemplate {
+        let path = normalize(path);
+        match call.method {
+            "GET" => {
+                let folder = key(&path);
+                let children = call.query("include_children") == Some("true");
+                let locks = self
+                    .locks
+                    .iter()
+                    .filter(|lock| {
+                        let lock_key = key(&lock.path);
+                        lock_key == folder || (children && is_within(&lock_key, &folder))
+                    })
+                    .map(lock_json)
+                    .collect();
+                ok(200, Value::Array(locks))
+            }
+            "POST" => {
+                if !self.exists(&path) || path.is_empty() {
+                    return error(404, "Not Found");
+                }
+                if self.is_locked(&path) {
+                    return error(409, "File is already locked");
+                }
+                let lock = Lock {
+                    token: format!("lock-{}", self.next_id()),
+                    timeout: as_i64(call.param("timeout")),
+                    path,
+                };
+                let response = ok(200, lock_json(&lock));
+                self.locks.push(lock);
+                response
+            }
+            "DELETE" => {
+                let token = call.query("token").unwrap_or_default();
+                let before = self.locks.len();
+                self.locks
+                    .retain(|lock| !(key(&lock.path) == key(&path) && lock.token == token));
+                if self.locks.len() == before {
+                    error(404, "Lock not found")
+                } else {
+                    no_content()
+                }
+            }
+            _ => error(404, "Not Found"),
+        }
+    }
+
+    // Users, groups and bundles
+
+    fn users(&mut self, call: &Call, rest: &str) -> ResponseTemplate {
+        let mut segments = rest.split('/').filter(|s| !s.is_empty());
+        let id = segments.next().map(|id| id.parse::<i64>());
+        let action = segments.next();
+
+        match (call.method, id, action) {
+            ("GET", None, _) => self.page(call, self.users.values().cloned().collect()),
+            ("POST", None, _) => {
+                let Some(username) = call.param_str("username") else {
+                    return error(400, "username is required");
+                };
+                let taken = self.users.values().any(|user| {
+                    user["username"]
+                        .as_str()
+                        .is_some_and(|u| u.eq_ignore_ascii_case(username))
+                });
+                if taken {
+                    return error(422, "Username has already been taken");
+                }
+                let id = self.next_id();
+                let mut user = without_secrets(&call.params);
+                user.insert("id".into(), json!(id));
+                user.insert("created_at".into(), json!(now()));
+                self.users.insert(id, Value::Object(user));
+                ok(201, self.users[&id].clone())
+            }
+            (_, Some(Ok(id)), action) => {
+                let Some(user) = self.users.get_mut(&id) else {
+                    return error(404, "User not found");
+                };
+                match (call.method, action) {
+                    ("GET", None) => ok(200, user.clone()),
+                    ("PATCH", None) => {
+                        merge(user, &call.params);
+                        ok(200, user.clone())
+                    }
+                    ("DELETE", None) => {
+                        self.users.remove(&id);
+                        for group in self.groups.values_mut() {
+                            let mut members = members(group);
+                            members.retain(|member| *member != id);
+                            set_members(group, &members, &self.users);
+                        }
+                        no_content()
+                    }
+                    // unlock, 2fa/reset, resend_welcome_email
+                    ("POST", Some(_)) => no_content(),
+                    _ => error(404, "Not Found"),
+                }
+            }
+            _ => error(404, "Not Found"),
+        }
+    }
+
+    fn groups(&mut self, call: &Call, rest: &str) -> ResponseTemplate {
+        let segments: Vec<&str> = rest.split('/').filter(|s| !s.is_empty()).collect();
+        let id = segments.first().map(|id| id.parse::<i64>());
+
+        match (call.method, id, &segments[segments.len().min(1)..]) {
+            ("GET", None, _) => self.page(call, self.groups.values().cloned().collect()),
+            ("POST", None, _) => {
+                let Some(name) = call.param_str("name") else {
+                    return error(400, "name is required");
+                };
+                let taken = self.groups.values().any(|group| {
+                    group["name"]
+                        .as_str()
+                        .is_some_and(|n| n.eq_ignore_ascii_case(name))
+                });
+                if taken {
+                    return error(422, "Name has already been taken");
+                }
+                let id = self.next_id();
+                let mut group = call.params.clone();
+                let members = parse_ids(group.remove("user_ids").as_ref());
+                group.insert("id".into(), json!(id));
+                let mut group = Value::Object(group);
+                set_members(&mut group, &members, &self.users);
+                self.groups.insert(id, group.clone());
+                ok(201, group)
+            }
+            (_, Some(Ok(id)), rest) => {
+                let users = &self.users;
+                let Some(group) = self.groups.get_mut(&id) else {
+                    return error(404, "Group not found");
+                };
+                match (call.method, rest) {
+                    ("GET", []) => ok(200, group.clone()),
+                    ("PATCH", []) => {
+                        let mut params = call.params.clone();
+                        let members = params.remove("user_ids");
+                        merge(group, &params);
+                        if let Some(members) = members {
+                            set_members(group, &parse_ids(Some(&members)), users);
+                        }
+                        ok(200, group.clone())
+                    }
+                    ("DELETE", []) => {
+                        self.groups.remove(&id);
+                        no_content()
+                    }
+                    ("POST", ["users"]) => {
+                        let Some(user_id) = as_i64(call.param("user_id")) else {
+                            return error(400, "user_id is required");
+                        };
+                        if !users.contains_key(&user_id) {
+                            return error(404, "User not found");
+                        }
+                        let mut members = members(group);
+                        if !members.contains(&user_id) {
+                            members.push(user_id);
+                        }
+                        set_members(group, &members, users);
+                        ok(201, json!({"group_id": id, "user_id": user_id}))
+                    }
+                    ("DELETE", ["memberships", user_id]) => {
+                        let user_id = user_id.parse::<i64>().unwrap_or_default();
+                        let mut members = members(group);
+                        if !members.contains(&user_id) {
+                            return error(404, "Membership not found");
+                        }
+                        members.retain(|member| *member != user_id);
+                        set_members(group, &members, users);
+                        no_content()
+                    }
+                    _ => error(404, "Not Found"),
+                }
+            }
+            _ => error(404, "Not Found"),
+        }
+    }
+
+    fn bundles(&mut self, call: &Call, rest: &str) -> ResponseTemplate {
+        let mut segments = rest.split('/').filter(|s| !s.is_empty());
+        let id = segments.next().map(|id| id.parse::<i64>());
+        let action = segments.next();
+
+        match (call.method, id, action) {
+            ("GET", None, _) => {
+                let user_id = call.query("user_id").and_then(|id| id.parse::<i64>().ok());
+                let bundles = self
+                    .bundles
+                    .values()
+                    .filter(|bundle| user_id.is_none_or(|id| bundle["user_id"] == json!(id)))
+                    .map(|bundle| {
+                        // Paths are only included when fetching a single bundle
+                        let mut bundle = bundle.clone();
+                        bundle.as_object_mut().map(|b| b.remove("paths"));
+                        bundle
+                    })
+                    .collect();
+                self.page(call, bundles)
+            }
+            ("POST", None, _) => {
+                let paths: Vec<String> = call
+                    .param("paths")
+                    .and_then(Value::as_array)
+                    .map(|paths| {
+                        paths
+                            .iter()
+                            .filter_map(Value::as_str)
+                            .map(normalize)
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                if paths.is_empty() {
+                    return error(400, "paths is required");
+                }
+                if let Some(missing) = paths.iter().find(|path| !self.exists(path)) {
+                    return error(404, &format!("Path not found: {}", missing));
+                }
+
+                let id = self.next_id();
+                let mut bundle = without_secrets(&call.params);
+                let code = call
+                    .param_str("code")
+                    .map_or_else(|| format!("fake{}", id), str::to_string);
+                bundle.insert("id".into(), json!(id));
+                bundle.insert("url".into(), json!(format!("{}/f/{}", call.origin, code)));
+                bundle.insert("code".into(), json!(code));
+                bundle.insert("paths".into(), json!(paths));
+                bundle.insert("created_at".into(), json!(now()));
+                bundle.insert(
+                    "password_protected".into(),
+                    json!(call.param("password").is_some()),
+                );
+                self.bundles.insert(id, Value::Object(bundle));
+                ok(201, self.bundles[&id].clone())
+            }
+            (_, Some(Ok(id)), action) => {
+                let Some(bundle) = self.bundles.get_mut(&id) else {
+                    return error(404, "Bundle not found");
+                };
+                match (call.method, action) {
+                    ("GET", None) => ok(200, bundle.clone()),
+                    ("PATCH", None) => {
+                        merge(bundle, &call.params);
+                        ok(200, bundle.clone())
+                    }
+                    ("DELETE", None) => {
+                        self.bundles.remove(&id);
+                        no_content()
+                    }
+                    ("POST", Some("share")) => no_content(),
+                    _ => error(404, "Not Found"),
+                }
+            }
+            _ => error(404, "Not Found"),
+        }
+    }
+}
+
+fn lock_json(lock: &Lock) -> Value {
+    json!({
+        "path": lock.path,
+        "timeout": lock.timeout,
+        "depth": "infinity",
+        "owner": "files-sdk",
+        "scope": "exclusive",
+        "token": lock.token,
+        "type": "write",
+    })
+}
+
+/// Parses `bytes=start-[end]` into an inclusive range within `len` bytes
+fn parse_range(range: &str, len: u64) -> Option<(u64, u64)> {
+    let (start, end) = range.trim().strip_prefix("bytes=")?.split_once('-')?;
+    let start: u64 = start.trim().parse().ok()?;
+    let end = match end.trim() {
+        "" => len.checked_sub(1)?,
+        end => end.parse::<u64>().ok()?.min(len.checked_sub(1)?),
+    };
+    (start <= end).then_some((start, end))
+}
+
+/// Applies `sort_by[...]` and `filter*[...]` query parameters to a list
+fn apply_query(call: &Call, items: &mut Vec<Value>) {
+    for (name, value) in &call.query {
+        let Some((op, field)) = name.strip_suffix(']').and_then(|name| name.split_once('[')) else {
+            continue;
+        };
+        let expected = Value::String(value.clone());
+        let keep: fn(Ordering) -> bool = match op {
+            "filter" => |o| o == Ordering::Equal,
+            "filter_gt" => |o| o == Ordering::Greater,
+            "filter_gteq" => |o| o != Ordering::Less,
+            "filter_lt" => |o| o == Ordering::Less,
+            "filter_lteq" => |o| o != Ordering::Greater,
+            "filter_prefix" => {
+                items.retain(|item| text(&item[field]).starts_with(value.as_str()));
+                continue;
+            }
+            _ => continue,
+        };
+        items.retain(|item| {
+            let actual = &item[field];
+            let expected = match actual {
+                Value::Number(_) => value.parse::<f64>().map_or(expected.clone(), |n| json!(n)),
+                Value::Bool(_) => value.parse::<bool>().map_or(expected.clone(), Value::Bool),
+                _ => expected.clone(),
+            };
+            match (actual, &expected) {
+                (Value::Bool(a), Value::Bool(b)) => keep(a.cmp(b)),
+                _ => keep(compare(actual, &expected)),
+            }
+        });
+    }
+
+    let sorts: Vec<(&str, bool)> = call
+        .query
+        .iter()
+        .filter_map(|(name, direction)| {
+            let field = name.strip_prefix("sort_by[")?.strip_suffix(']')?;
+            Some((field, direction == "desc"))
+        })
+        .collect();
+    if !sorts.is_empty() {
+        items.sort_by(|a, b| {
+            sorts
+                .iter()
+                .map(|(field, desc)| {
+                    let order = compare(&a[*field], &b[*field]);
+                    if *desc { order.reverse() } else { order }
+                })
+                .find(|order| order.is_ne())
+                .unwrap_or(Ordering::Equal)
+        });
+    }
+}
+
+/// Copies `params` without password fields
+fn without_secrets(params: &Map<String, Value>) -> Map<String, Value> {
+    params
+        .iter()
+        .filter(|(name, _)| !name.contains("password"))
+        .map(|(name, value)| (name.clone(), value.clone()))
+        .collect()
+}
+
+/// Overwrites fields of `target` with `params`, except passwords and the id
+fn merge(target: &mut Value, params: &Map<String, Value>) {
+    if let Some(target) = target.as_object_mut() {
+        for (name, value) in without_secrets(params) {
+            if name != "id" {
+                target.insert(name, value);
+            }
+        }
+    }
+}
+
+/// Reads user ids sent as an array or a comma-separated string
+fn parse_ids(value: Option<&Value>) -> Vec<i64> {
+    match value {
+        Some(Value::Array(ids)) => ids.iter().filter_map(|id| as_i64(Some(id))).collect(),
+        Some(Value::String(ids)) => ids
+            .split(',')
+            .filter_map(|id| id.trim().parse().ok())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn members(group: &Value) -> Vec<i64> {
+    parse_ids(group.get("user_ids"))
+}
+
+/// Stores group members the way Files.com reports them: comma-separated
+fn set_members(group: &mut Value, members: &[i64], users: &BTreeMap<i64, Value>) {
+    let join = |values: Vec<String>| values.join(",");
+    group["user_ids"] = json!(join(members.iter().map(i64::to_string).collect()));
+    group["usernames"] = json!(join(
+        members
+            .iter()
+            .filter_map(|id| users.get(id)?["username"].as_str().map(str::to_string))
+            .collect()
+    ));
+}
@@ -0,0 +1,230 @@
+//! Timestamps sent to and received from Files.com
+//!
+//! Files.com exchanges timestamps as ISO 8601 strings such as
+//! `2024-06-01T00:00:00Z`. By default [`Timestamp`] is a `String` holding that
+//! text unchanged. With the `chrono` feature it is a
+//! [`chrono::DateTime<Utc>`](https://docs.rs/chrono/latest/chrono/struct.DateTime.html),
+//! parsed from responses and serialized back to ISO 8601 in requests.
+//!
+//! Entity fields such as [`FileEntity::mtime`](crate::FileEntity::mtime),
+//! date parameters and [`ListQuery`](crate::ListQuery) filters all use
+//! [`Timestamp`].
+//!
+//! ```toml
+//! [dependencies]
+//! files-sdk = { version = "0.4", features = ["chrono"] }
+//! ```
+//!
+//! Code that needs to compile either way can build timestamps with
+//! [`timestamp`] from their ISO 8601 text.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A point in time, as exchanged with Files.com
+#[cfg(feature = "chrono")]
+pub type Timestamp = chrono::DateTime<chrono::Utc>;
+
+/// A point in time, as exchanged with Files.com
+///
+/// An ISO 8601 string; enable the `chrono` feature for `DateTime<Utc>`.
+#[cfg(not(feature = "chrono"))]
+pub type Timestamp = String;
+
+/// Creates a [`Timestamp`] from its ISO 8601 text
+///
+/// # Errors
+///
+/// With the `chrono` feature, returns [`FilesError::ConfigError`](crate::FilesError::ConfigError)
+/// if `text` is not a valid RFC 3339 timestamp. Without it, `text` is taken as is.
+///
+/// # Examples
+///
+/// ```rust
+/// use files_sdk::timestamp::timestamp;
+///
+/// let start = timestamp("2024-06-01T00:00:00Z").unwrap();
+/// ```
+pub fn timestamp(text: &str) -> crate::Result<Timestamp> {
+    #[cfg(feature = "chrono")]
+    {
+        chrono::DateTime::parse_from_rfc3339(text)
+            .map(|t| t.with_timezone(&chrono::Utc))
+            .map_err(|e| {
+                crate::FilesError::ConfigError(format!("Invalid timestamp '{}': {}", text, e))
+            })
+    }
+
+    #[cfg(not(feature = "chrono"))]
+    {
+        Ok(text.to_string())
+    }
+}
+
+/// Converts a [`Timestamp`] to a [`SystemTime`], if it is valid
+pub(crate) fn to_system_time(timestamp: &Timestamp) -> Option<SystemTime> {
+    #[cfg(feature = "chrono")]
+    {
+        Some(SystemTime::from(*timestamp))
+    }
+
+    #[cfg(not(feature = "chrono"))]
+    {
+        parse_system_time(timestamp)
+    }
+}
+
+/// Parses ISO 8601 text to a [`SystemTime`], if it is a valid timestamp
+///
+/// Accepts fractional seconds, a `Z` or `±HH:MM` offset, or no offset (UTC).
+pub(crate) fn parse_system_time(s: &str) -> Option<SystemTime> {
+    let s = s.trim();
+    let bytes = s.as_bytes();
+    if bytes.len() < 19 || bytes[4] != b'-' || bytes[7] != b'-' || bytes[13] != b':' {
+        return None;
+    }
+    if !matches!(bytes[10], b'T' | b't' | b' ') || bytes[16] != b':' {
+        return None;
+    }
+
+    let number = |range: std::ops::Range<usize>| -> Option<i64> {
+        let part = s.get(range)?;
+        if !part.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        part.parse().ok()
+    };
+
+    let year = number(0..4)?;
+    let month = number(5..7)?;
+    let day = number(8..10)?;
+    let hour = number(11..13)?;
+    let minute = number(14..16)?;
+    let second = number(17..19)?;
+
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 {
+        return None;
+    }
+    if second > 60 {
+        return None;
+    }
+
+    // Optional fractional seconds
+    let mut rest = &s[19..];
+    let mut nanos = 0u32;
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let digits = fraction.bytes().take_while(|b| b.is_ascii_digit()).count();
+        if digits == 0 {
+            return None;
+        }
+        let mut value = 0u32;
+        for (i, b) in fraction.bytes().take(digits).enumerate() {
+            if i < 9 {
+                value = value * 10 + u32::from(b - b'0');
+            }
+        }
+        nanos = value * 10u32.pow(9u32.saturating_sub(digits as u32));
+        rest = &fraction[digits..];
+    }
+
+    // Timezone: Z, +HH:MM, -HH:MM (or none, treated as UTC)
+    let offset_secs = match rest {
+        "" | "Z" | "z" => 0,
+        _ => {
+            let sign = match rest.as_bytes()[0] {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return None,
+            };
+            let tz = rest[1..].replace(':', "");
+            if tz.len() != 4 || !tz.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            let hours: i64 = tz[..2].parse().ok()?;
+            let minutes: i64 = tz[2..].parse().ok()?;
+            sign * (hours * 3600 + minutes * 60)
+        }
+    };
+
+    // Days since the Unix epoch (proleptic Gregorian calendar)
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+
+    let secs = days * 86_400 + hour * 3600 + minute * 60 + second - offset_secs;
+    if secs >= 0 {
+        Some(UNIX_EPOCH + Duration::new(secs as u64, nanos))
+    } else {
+        UNIX_EPOCH
+            .checked_sub(Duration::from_secs(secs.unsigned_abs()))?
+            .checked_add(Duration::from_nanos(u64::from(nanos)))
+    }
+}
+
+/// Formats a [`SystemTime`] as ISO 8601 UTC text, without sub-second precision
+///
+/// Times before the Unix epoch are clamped to it.
+pub(crate) fn format_system_time(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    let (days, rem) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));
+
+    // Civil date from days since the Unix epoch (inverse of parse_system_time)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let start = timestamp("2024-06-01T00:00:00Z").unwrap();
+        assert_eq!(
+            serde_json::to_value(start).unwrap(),
+            serde_json::json!("2024-06-01T00:00:00Z")
+        );
+    }
+
+    #[test]
+    fn test_to_system_time() {
+        let start = timestamp("1970-01-02T00:00:00Z").unwrap();
+        assert_eq!(
+            to_system_time(&start),
+            Some(SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(86_400))
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_invalid_timestamp() {
+        assert!(timestamp("yesterday").is_err());
+        assert_eq!(
+            serde_json::to_value(timestamp("2024-06-01T02:00:00+02:00").unwrap()).unwrap(),
+            serde_json::json!("2024-06-01T00:00:00Z")
+        );
+    }
+}
@@ -4,6 +4,7 @@
 //! including file entities, folder entities, pagination information,
 //! and upload-related types.
 
+use crate::Timestamp;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -30,15 +31,15 @@ pub struct FileEntity {
 
     /// Creation timestamp
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
 
     /// Modification time
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mtime: Option<String>,
+    pub mtime: Option<Timestamp>,
 
     /// Provided modification time (custom)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub provided_mtime: Option<String>,
+    pub provided_mtime: Option<Timestamp>,
 
     /// CRC32 checksum
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -209,7 +210,7 @@ pub struct FileUploadPartEntity {
 
     /// When this upload URL expires
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expires: Option<String>,
+    pub expires: Option<Timestamp>,
 
     /// Content-Type and file to send
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -224,6 +225,18 @@ pub struct FileUploadPartEntity {
     pub ask_about_overwrites: Option<bool>,
 }
 
+impl FileUploadPartEntity {
+    /// Whether the upload URL for this part has expired
+    ///
+    /// Returns `false` when the expiration time is missing or cannot be parsed.
+    pub fn is_expired(&self) -> bool {
+        self.expires
+            .as_ref()
+            .and_then(crate::timestamp::to_system_time)
+            .is_some_and(|expires| expires <= std::time::SystemTime::now())
+    }
+}
+
 /// Represents a folder (directory) in Files.com
 ///
 /// Alias for FileEntity since folders are represented as files with type="directory"
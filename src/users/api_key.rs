@@ -1,10 +1,101 @@
-use crate::{Result, client::FilesClient};
+use crate::{Result, Timestamp, client::FilesClient};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Current API key entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiKeyCurrentEntity {
+    /// API key ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// API key name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// API key description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Label for display
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub descriptive_label: Option<String>,
+
+    /// Creation time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+
+    /// Expiration time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+
+    /// API key value (only returned on creation)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+
+    /// Last use time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_use_at: Option<Timestamp>,
+
+    /// Permission set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission_set: Option<String>,
+
+    /// Platform the key is used on
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub platform: Option<String>,
+
+    /// URL the key is used with
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// ID of the user that owns the key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parameters for updating the current API key
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateCurrentApiKeyParams {
+    /// API key name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Expiration time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+
+    /// Permission set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission_set: Option<String>,
+}
+
+impl UpdateCurrentApiKeyParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// API key name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Expiration time
+    pub fn expires_at(mut self, expires_at: impl Into<Timestamp>) -> Self {
+        self.expires_at = Some(expires_at.into());
+        self
+    }
+
+    /// Permission set
+    pub fn permission_set(mut self, permission_set: impl Into<String>) -> Self {
+        self.permission_set = Some(permission_set.into());
+        self
+    }
 }
 
 pub struct ApiKeyCurrentHandler {
@@ -21,7 +112,7 @@ impl ApiKeyCurrentHandler {
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn update(&self, params: serde_json::Value) -> Result<ApiKeyCurrentEntity> {
+    pub async fn update(&self, params: UpdateCurrentApiKeyParams) -> Result<ApiKeyCurrentEntity> {
         let response = self.client.patch_raw("/api_key", params).await?;
         Ok(serde_json::from_value(response)?)
     }
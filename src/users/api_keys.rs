@@ -23,6 +23,7 @@
 //!
 //! ```no_run
 //! use files_sdk::{FilesClient, ApiKeyHandler};
+//! use files_sdk::timestamp::timestamp;
 //!
 //! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
 //! let client = FilesClient::builder()
@@ -35,7 +36,7 @@
 //! let key = handler.create(
 //!     Some("Automation Script Key"),
 //!     Some("For nightly backup automation"),
-//!     Some("2025-12-31T23:59:59Z"),
+//!     Some(timestamp("2025-12-31T23:59:59Z")?),
 //!     None
 //! ).await?;
 //!
@@ -44,17 +45,19 @@
 //! println!("Key ID: {}", key.id.unwrap());
 //!
 //! // List all API keys
-//! let (keys, _) = handler.list(None, None, Some(50)).await?;
+//! let (keys, _) = handler.list(None, None, Some(50), None).await?;
 //! for api_key in keys {
-//!     println!("{}: Last used {}",
+//!     println!("{}: Last used {:?}",
 //!         api_key.name.unwrap_or_default(),
-//!         api_key.last_use_at.unwrap_or_default());
+//!         api_key.last_use_at);
 //! }
 //! # Ok(())
 //! # }
 //! ```
 
-use crate::{FilesClient, PaginationInfo, Result};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{FilesClient, PaginationInfo, Result, Timestamp};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
@@ -99,15 +102,23 @@ pub struct ApiKeyEntity {
 
     /// Created at timestamp
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
 
     /// Expires at timestamp
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expires_at: Option<String>,
+    pub expires_at: Option<Timestamp>,
 
     /// Last use at timestamp
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_use_at: Option<String>,
+    pub last_use_at: Option<Timestamp>,
+}
+
+query_fields! {
+    /// Fields that API keys can be sorted and filtered by
+    pub enum ApiKeyField {
+        ExpiresAt => "expires_at",
+        Name => "name",
+    }
 }
 
 /// Handler for API key operations
@@ -131,6 +142,7 @@ impl ApiKeyHandler {
     /// * `user_id` - Filter by specific user ID (None for all users)
     /// * `cursor` - Pagination cursor from previous response
     /// * `per_page` - Number of results per page (max 10,000)
+    /// * `query` - Sorting and filtering options (optional)
     ///
     /// # Returns
     ///
@@ -148,7 +160,7 @@ impl ApiKeyHandler {
     /// let handler = ApiKeyHandler::new(client);
     ///
     /// // List all API keys
-    /// let (keys, pagination) = handler.list(None, None, Some(50)).await?;
+    /// let (keys, pagination) = handler.list(None, None, Some(50), None).await?;
     ///
     /// for key in keys {
     ///     println!("{}: Created {} - Last used {}",
@@ -158,7 +170,7 @@ impl ApiKeyHandler {
     /// }
     ///
     /// // Get keys for specific user
-    /// let (user_keys, _) = handler.list(Some(12345), None, None).await?;
+    /// let (user_keys, _) = handler.list(Some(12345), None, None, None).await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -167,6 +179,7 @@ impl ApiKeyHandler {
         user_id: Option<i64>,
         cursor: Option<String>,
         per_page: Option<i32>,
+        query: Option<&ListQuery<ApiKeyField>>,
     ) -> Result<(Vec<ApiKeyEntity>, PaginationInfo)> {
         let mut path = "/api_keys?".to_string();
 
@@ -180,17 +193,37 @@ impl ApiKeyHandler {
             path.push_str(&format!("per_page={}&", pp));
         }
 
-        let response = self.client.get_raw(&path).await?;
-        let keys: Vec<ApiKeyEntity> = serde_json::from_value(response)?;
+        if let Some(query) = query {
+            query.append_to(&mut path);
+        }
 
-        let pagination = PaginationInfo {
-            cursor_next: None,
-            cursor_prev: None,
-        };
+        let (response, pagination) = self.client.get_paginated(&path).await?;
+        let keys: Vec<ApiKeyEntity> = serde_json::from_value(response)?;
 
         Ok((keys, pagination))
     }
 
+    /// Stream API keys across all pages
+    ///
+    /// Takes the same arguments as [`list`](Self::list), without the cursor.
+    pub fn list_stream(
+        &self,
+        user_id: Option<i64>,
+        per_page: Option<i32>,
+        query: Option<ListQuery<ApiKeyField>>,
+    ) -> Paginator<ApiKeyEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move {
+                handler
+                    .list(user_id, cursor, per_page, query.as_ref())
+                    .await
+            }
+        })
+    }
+
     /// Get details of a specific API key
     ///
     /// # Arguments
@@ -243,6 +276,7 @@ impl ApiKeyHandler {
     ///
     /// ```no_run
     /// use files_sdk::{FilesClient, ApiKeyHandler};
+    /// use files_sdk::timestamp::timestamp;
     ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = FilesClient::builder().api_key("key").build()?;
@@ -252,7 +286,7 @@ impl ApiKeyHandler {
     /// let key = handler.create(
     ///     Some("CI/CD Pipeline Key"),
     ///     Some("For automated deployments"),
-    ///     Some("2025-12-31T23:59:59Z"),
+    ///     Some(timestamp("2025-12-31T23:59:59Z")?),
     ///     None
     /// ).await?;
     ///
@@ -266,7 +300,7 @@ impl ApiKeyHandler {
         &self,
         name: Option<&str>,
         description: Option<&str>,
-        expires_at: Option<&str>,
+        expires_at: Option<Timestamp>,
         permission_set: Option<&str>,
     ) -> Result<ApiKeyEntity> {
         let mut body = json!({});
@@ -308,6 +342,7 @@ impl ApiKeyHandler {
     ///
     /// ```no_run
     /// use files_sdk::{FilesClient, ApiKeyHandler};
+    /// use files_sdk::timestamp::timestamp;
     ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = FilesClient::builder().api_key("key").build()?;
@@ -318,7 +353,7 @@ impl ApiKeyHandler {
     ///     12345,
     ///     None,
     ///     None,
-    ///     Some("2026-12-31T23:59:59Z")
+    ///     Some(timestamp("2026-12-31T23:59:59Z")?)
     /// ).await?;
     /// # Ok(())
     /// # }
@@ -328,7 +363,7 @@ impl ApiKeyHandler {
         id: i64,
         name: Option<&str>,
         description: Option<&str>,
-        expires_at: Option<&str>,
+        expires_at: Option<Timestamp>,
     ) -> Result<ApiKeyEntity> {
         let mut body = json!({});
 
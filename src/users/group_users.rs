@@ -1,5 +1,7 @@
 //! Group user membership operations
 
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
 use crate::{FilesClient, PaginationInfo, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -15,6 +17,15 @@ pub struct GroupUserEntity {
     pub admin: Option<bool>,
 }
 
+query_fields! {
+    /// Fields that group memberships can be sorted and filtered by
+    pub enum GroupUserField {
+        GroupId => "group_id",
+        UserId => "user_id",
+        Admin => "admin",
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct GroupUserHandler {
     client: FilesClient,
@@ -30,6 +41,7 @@ impl GroupUserHandler {
         user_id: Option<i64>,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<GroupUserField>>,
     ) -> Result<(Vec<GroupUserEntity>, PaginationInfo)> {
         let mut endpoint = "/group_users".to_string();
         let mut params = Vec::new();
@@ -49,29 +61,34 @@ impl GroupUserHandler {
             endpoint.push_str(&params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-        let status = response.status();
-
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
 
-        let users: Vec<GroupUserEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let users: Vec<GroupUserEntity> = serde_json::from_value(response)?;
         Ok((users, pagination))
     }
 
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        user_id: Option<i64>,
+        per_page: Option<i64>,
+        query: Option<ListQuery<GroupUserField>>,
+    ) -> Paginator<GroupUserEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move {
+                handler
+                    .list(user_id, cursor, per_page, query.as_ref())
+                    .await
+            }
+        })
+    }
+
     pub async fn create(
         &self,
         group_id: i64,
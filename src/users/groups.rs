@@ -7,6 +7,8 @@
 //! - Delete groups
 //! - Manage group memberships
 
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
 use crate::{FilesClient, PaginationInfo, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -63,6 +65,18 @@ pub struct GroupEntity {
     pub site_id: Option<i64>,
 }
 
+query_fields! {
+    /// Fields that groups can be sorted and filtered by
+    pub enum GroupField {
+        Name => "name",
+        SiteId => "site_id",
+        FtpPermission => "ftp_permission",
+        SftpPermission => "sftp_permission",
+        DavPermission => "dav_permission",
+        RestapiPermission => "restapi_permission",
+    }
+}
+
 /// Handler for group operations
 #[derive(Debug, Clone)]
 pub struct GroupHandler {
@@ -81,6 +95,7 @@ impl GroupHandler {
     ///
     /// * `cursor` - Pagination cursor (optional)
     /// * `per_page` - Results per page (optional)
+    /// * `query` - Sorting and filtering options (optional)
     ///
     /// # Examples
     ///
@@ -90,7 +105,7 @@ impl GroupHandler {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let client = FilesClient::builder().api_key("key").build()?;
     /// let handler = GroupHandler::new(client);
-    /// let (groups, pagination) = handler.list(None, Some(10)).await?;
+    /// let (groups, pagination) = handler.list(None, Some(10), None).await?;
     ///
     /// for group in groups {
     ///     println!("Group: {:?}", group.name);
@@ -102,6 +117,7 @@ impl GroupHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i32>,
+        query: Option<&ListQuery<GroupField>>,
     ) -> Result<(Vec<GroupEntity>, PaginationInfo)> {
         let mut path = "/groups?".to_string();
 
@@ -112,17 +128,32 @@ impl GroupHandler {
             path.push_str(&format!("per_page={}&", pp));
         }
 
-        let response = self.client.get_raw(&path).await?;
-        let groups: Vec<GroupEntity> = serde_json::from_value(response)?;
+        if let Some(query) = query {
+            query.append_to(&mut path);
+        }
 
-        let pagination = PaginationInfo {
-            cursor_next: None,
-            cursor_prev: None,
-        };
+        let (response, pagination) = self.client.get_paginated(&path).await?;
+        let groups: Vec<GroupEntity> = serde_json::from_value(response)?;
 
         Ok((groups, pagination))
     }
 
+    /// Stream groups across all pages
+    ///
+    /// Takes the same arguments as [`list`](Self::list), without the cursor.
+    pub fn list_stream(
+        &self,
+        per_page: Option<i32>,
+        query: Option<ListQuery<GroupField>>,
+    ) -> Paginator<GroupEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
+
     /// Get a specific group by ID
     ///
     /// # Arguments
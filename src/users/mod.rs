@@ -44,7 +44,7 @@ pub use user_sftp_client_uses::UserSftpClientUseHandler;
 pub use users::UserHandler;
 
 // Re-export entities
-pub use api_key::ApiKeyCurrentEntity;
+pub use api_key::{ApiKeyCurrentEntity, UpdateCurrentApiKeyParams};
 pub use api_keys::ApiKeyEntity;
 pub use group_users::GroupUserEntity;
 pub use groups::GroupEntity;
@@ -52,9 +52,23 @@ pub use permissions::{PermissionEntity, PermissionType};
 pub use public_keys::PublicKeyEntity;
 pub use sessions::SessionEntity;
 pub use sso_strategies::SsoStrategyEntity;
-pub use user::UserEntity as CurrentUserEntity;
+pub use user::{Update2faParams, UpdateCurrentUserParams, UserEntity as CurrentUserEntity};
 pub use user_cipher_uses::UserCipherUseEntity;
-pub use user_lifecycle_rules::UserLifecycleRuleEntity;
-pub use user_requests::UserRequestEntity;
+pub use user_lifecycle_rules::{
+    CreateUserLifecycleRuleParams, UpdateUserLifecycleRuleParams, UserLifecycleRuleEntity,
+};
+pub use user_requests::{CreateUserRequestParams, UserRequestEntity};
 pub use user_sftp_client_uses::UserSftpClientUseEntity;
 pub use users::UserEntity;
+
+// Re-export query fields
+pub use api_keys::ApiKeyField;
+pub use group_users::GroupUserField;
+pub use groups::GroupField;
+pub use permissions::PermissionField;
+pub use public_keys::PublicKeyField;
+pub use sso_strategies::SsoStrategyField;
+pub use user_lifecycle_rules::UserLifecycleRuleField;
+pub use user_requests::UserRequestField;
+pub use user_sftp_client_uses::UserSftpClientUseField;
+pub use users::UserField;
@@ -3,6 +3,8 @@
 //! Permissions grant access to specific paths for users or groups.
 //! They can be recursive (apply to subfolders) or non-recursive.
 
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
 use crate::{FilesClient, Result};
 use serde::{Deserialize, Serialize};
 
@@ -72,7 +74,20 @@ pub struct PermissionEntity {
     pub site_id: Option<i64>,
 }
 
+query_fields! {
+    /// Fields that permissions can be sorted and filtered by
+    pub enum PermissionField {
+        GroupId => "group_id",
+        GroupName => "group_name",
+        Path => "path",
+        Permission => "permission",
+        UserId => "user_id",
+        Username => "username",
+    }
+}
+
 /// Handler for permission operations
+#[derive(Debug, Clone)]
 pub struct PermissionHandler {
     client: FilesClient,
 }
@@ -88,6 +103,7 @@ impl PermissionHandler {
     /// # Arguments
     /// * `cursor` - Pagination cursor
     /// * `per_page` - Number of records per page (max 10000)
+    /// * `query` - Sorting and filtering options (optional)
     ///
     /// # Returns
     /// A tuple of (permissions, pagination_info)
@@ -95,6 +111,7 @@ impl PermissionHandler {
         &self,
         cursor: Option<&str>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<PermissionField>>,
     ) -> Result<(Vec<PermissionEntity>, crate::types::PaginationInfo)> {
         let mut params = vec![];
         if let Some(c) = cursor {
@@ -104,7 +121,7 @@ impl PermissionHandler {
             params.push(("per_page", pp.to_string()));
         }
 
-        let query = if params.is_empty() {
+        let query_string = if params.is_empty() {
             String::new()
         } else {
             format!(
@@ -117,21 +134,37 @@ impl PermissionHandler {
             )
         };
 
-        let response = self
-            .client
-            .get_raw(&format!("/permissions{}", query))
-            .await?;
-        let permissions: Vec<PermissionEntity> = serde_json::from_value(response)?;
+        let mut endpoint = format!("/permissions{}", query_string);
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
 
-        // Get pagination info from response headers if available
-        let pagination = crate::types::PaginationInfo {
-            cursor_next: None,
-            cursor_prev: None,
-        };
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let permissions: Vec<PermissionEntity> = serde_json::from_value(response)?;
 
         Ok((permissions, pagination))
     }
 
+    /// Stream permissions across all pages
+    ///
+    /// Takes the same arguments as [`list`](Self::list), without the cursor.
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<PermissionField>>,
+    ) -> Paginator<PermissionEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move {
+                handler
+                    .list(cursor.as_deref(), per_page, query.as_ref())
+                    .await
+            }
+        })
+    }
+
     /// Create a permission
     ///
     /// # Arguments
@@ -198,11 +231,13 @@ impl PermissionHandler {
     /// * `user_id` - User ID
     /// * `cursor` - Pagination cursor
     /// * `per_page` - Number of records per page
+    /// * `query` - Sorting and filtering options (optional)
     pub async fn list_for_user(
         &self,
         user_id: i64,
         cursor: Option<&str>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<PermissionField>>,
     ) -> Result<(Vec<PermissionEntity>, crate::types::PaginationInfo)> {
         let mut params = vec![];
         if let Some(c) = cursor {
@@ -212,7 +247,7 @@ impl PermissionHandler {
             params.push(("per_page", pp.to_string()));
         }
 
-        let query = if params.is_empty() {
+        let query_string = if params.is_empty() {
             String::new()
         } else {
             format!(
@@ -225,31 +260,51 @@ impl PermissionHandler {
             )
         };
 
-        let response = self
-            .client
-            .get_raw(&format!("/users/{}/permissions{}", user_id, query))
-            .await?;
-        let permissions: Vec<PermissionEntity> = serde_json::from_value(response)?;
+        let mut endpoint = format!("/users/{}/permissions{}", user_id, query_string);
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
 
-        let pagination = crate::types::PaginationInfo {
-            cursor_next: None,
-            cursor_prev: None,
-        };
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let permissions: Vec<PermissionEntity> = serde_json::from_value(response)?;
 
         Ok((permissions, pagination))
     }
 
+    /// Stream permissions for a specific user across all pages
+    ///
+    /// Takes the same arguments as [`list_for_user`](Self::list_for_user), without the cursor.
+    pub fn list_for_user_stream(
+        &self,
+        user_id: i64,
+        per_page: Option<i64>,
+        query: Option<ListQuery<PermissionField>>,
+    ) -> Paginator<PermissionEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move {
+                handler
+                    .list_for_user(user_id, cursor.as_deref(), per_page, query.as_ref())
+                    .await
+            }
+        })
+    }
+
     /// List permissions for a specific group
     ///
     /// # Arguments
     /// * `group_id` - Group ID
     /// * `cursor` - Pagination cursor
     /// * `per_page` - Number of records per page
+    /// * `query` - Sorting and filtering options (optional)
     pub async fn list_for_group(
         &self,
         group_id: i64,
         cursor: Option<&str>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<PermissionField>>,
     ) -> Result<(Vec<PermissionEntity>, crate::types::PaginationInfo)> {
         let mut params = vec![];
         if let Some(c) = cursor {
@@ -259,7 +314,7 @@ impl PermissionHandler {
             params.push(("per_page", pp.to_string()));
         }
 
-        let query = if params.is_empty() {
+        let query_string = if params.is_empty() {
             String::new()
         } else {
             format!(
@@ -272,19 +327,37 @@ impl PermissionHandler {
             )
         };
 
-        let response = self
-            .client
-            .get_raw(&format!("/groups/{}/permissions{}", group_id, query))
-            .await?;
-        let permissions: Vec<PermissionEntity> = serde_json::from_value(response)?;
+        let mut endpoint = format!("/groups/{}/permissions{}", group_id, query_string);
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
 
-        let pagination = crate::types::PaginationInfo {
-            cursor_next: None,
-            cursor_prev: None,
-        };
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let permissions: Vec<PermissionEntity> = serde_json::from_value(response)?;
 
         Ok((permissions, pagination))
     }
+
+    /// Stream permissions for a specific group across all pages
+    ///
+    /// Takes the same arguments as [`list_for_group`](Self::list_for_group), without the cursor.
+    pub fn list_for_group_stream(
+        &self,
+        group_id: i64,
+        per_page: Option<i64>,
+        query: Option<ListQuery<PermissionField>>,
+    ) -> Paginator<PermissionEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move {
+                handler
+                    .list_for_group(group_id, cursor.as_deref(), per_page, query.as_ref())
+                    .await
+            }
+        })
+    }
 }
 
 #[cfg(test)]
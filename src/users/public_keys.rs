@@ -6,7 +6,9 @@
 //! - Update public key metadata
 //! - Delete public keys
 
-use crate::{FilesClient, PaginationInfo, Result};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{FilesClient, PaginationInfo, Result, Timestamp};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
@@ -32,10 +34,10 @@ pub struct PublicKeyEntity {
     pub fingerprint_sha256: Option<String>,
 
     /// Public key created at date/time
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
 
     /// Key's most recent login time via SFTP
-    pub last_login_at: Option<String>,
+    pub last_login_at: Option<Timestamp>,
 
     /// Only returned when generating keys. Can be invalid, not_generated, generating, complete
     pub status: Option<String>,
@@ -47,6 +49,15 @@ pub struct PublicKeyEntity {
     pub generated_public_key: Option<String>,
 }
 
+query_fields! {
+    /// Fields that public keys can be sorted and filtered by
+    pub enum PublicKeyField {
+        UserId => "user_id",
+        Title => "title",
+        CreatedAt => "created_at",
+    }
+}
+
 /// Handler for public key operations
 #[derive(Debug, Clone)]
 pub struct PublicKeyHandler {
@@ -66,6 +77,7 @@ impl PublicKeyHandler {
     /// * `user_id` - User ID (use 0 for current user, None for all users if admin)
     /// * `cursor` - Pagination cursor
     /// * `per_page` - Number of records per page
+    /// * `query` - Sorting and filtering options (optional)
     ///
     /// # Examples
     ///
@@ -77,7 +89,7 @@ impl PublicKeyHandler {
     /// let handler = PublicKeyHandler::new(client);
     ///
     /// // List keys for current user
-    /// let (keys, pagination) = handler.list(Some(0), None, None).await?;
+    /// let (keys, pagination) = handler.list(Some(0), None, None, None).await?;
     /// for key in keys {
     ///     println!("{}: {}", key.title.unwrap_or_default(), key.fingerprint.unwrap_or_default());
     /// }
@@ -89,6 +101,7 @@ impl PublicKeyHandler {
         user_id: Option<i64>,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<PublicKeyField>>,
     ) -> Result<(Vec<PublicKeyEntity>, PaginationInfo)> {
         let mut endpoint = "/public_keys".to_string();
         let mut query_params = Vec::new();
@@ -110,29 +123,36 @@ impl PublicKeyHandler {
             endpoint.push_str(&query_params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
 
-        let keys: Vec<PublicKeyEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let keys: Vec<PublicKeyEntity> = serde_json::from_value(response)?;
         Ok((keys, pagination))
     }
 
+    /// Stream public keys across all pages
+    ///
+    /// Takes the same arguments as [`list`](Self::list), without the cursor.
+    pub fn list_stream(
+        &self,
+        user_id: Option<i64>,
+        per_page: Option<i64>,
+        query: Option<ListQuery<PublicKeyField>>,
+    ) -> Paginator<PublicKeyEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move {
+                handler
+                    .list(user_id, cursor, per_page, query.as_ref())
+                    .await
+            }
+        })
+    }
+
     /// Get a specific public key by ID
     ///
     /// # Arguments
@@ -1,13 +1,211 @@
-use crate::{Result, client::FilesClient, types::PaginationInfo};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{Result, Timestamp, client::FilesClient, types::PaginationInfo};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// SSO strategy entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SsoStrategyEntity {
+    /// Strategy ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// SSO protocol
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+
+    /// Identity provider
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
+
+    /// Label shown on the login page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    /// Logo URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+
+    /// Number of users using this strategy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_count: Option<i64>,
+
+    /// Whether the strategy is enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// Subdomain used for login
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subdomain: Option<String>,
+
+    /// SAML identity provider certificate fingerprint
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub saml_provider_cert_fingerprint: Option<String>,
+
+    /// SAML identity provider issuer URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub saml_provider_issuer_url: Option<String>,
+
+    /// SAML identity provider metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub saml_provider_metadata_content: Option<String>,
+
+    /// SAML identity provider metadata URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub saml_provider_metadata_url: Option<String>,
+
+    /// SAML single logout URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub saml_provider_slo_target_url: Option<String>,
+
+    /// SAML single sign-on URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub saml_provider_sso_target_url: Option<String>,
+
+    /// SCIM authentication method
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scim_authentication_method: Option<String>,
+
+    /// SCIM username
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scim_username: Option<String>,
+
+    /// SCIM OAuth access token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scim_oauth_access_token: Option<String>,
+
+    /// SCIM OAuth access token expiration time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scim_oauth_access_token_expires_at: Option<Timestamp>,
+
+    /// Whether to provision users
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provision_users: Option<bool>,
+
+    /// Whether to provision groups
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provision_groups: Option<bool>,
+
+    /// Whether to deprovision users
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprovision_users: Option<bool>,
+
+    /// Whether to deprovision groups
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprovision_groups: Option<bool>,
+
+    /// What happens to deprovisioned users
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprovision_behavior: Option<String>,
+
+    /// Groups new users are added to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provision_group_default: Option<String>,
+
+    /// Groups excluded from provisioning
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provision_group_exclusion: Option<String>,
+
+    /// Groups included in provisioning
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provision_group_inclusion: Option<String>,
+
+    /// Groups a user must belong to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provision_group_required: Option<String>,
+
+    /// Groups added on email signup
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provision_email_signup_groups: Option<String>,
+
+    /// Groups whose members become site admins
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provision_site_admin_groups: Option<String>,
+
+    /// Groups whose members become group admins
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provision_group_admin_groups: Option<String>,
+
+    /// Grant attachments permission to new users
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provision_attachments_permission: Option<bool>,
+
+    /// Grant WebDAV permission to new users
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provision_dav_permission: Option<bool>,
+
+    /// Grant FTP permission to new users
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provision_ftp_permission: Option<bool>,
+
+    /// Grant SFTP permission to new users
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provision_sftp_permission: Option<bool>,
+
+    /// Default time zone of new users
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provision_time_zone: Option<String>,
+
+    /// Default company of new users
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provision_company: Option<String>,
+
+    /// 2FA requirement for new users
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provision_require_2fa: Option<String>,
+
+    /// LDAP base DN
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ldap_base_dn: Option<String>,
+
+    /// LDAP domain
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ldap_domain: Option<String>,
+
+    /// LDAP host
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ldap_host: Option<String>,
+
+    /// Secondary LDAP host
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ldap_host_2: Option<String>,
+
+    /// Tertiary LDAP host
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ldap_host_3: Option<String>,
+
+    /// LDAP port
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ldap_port: Option<i64>,
+
+    /// Whether to use secure LDAP
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ldap_secure: Option<bool>,
+
+    /// LDAP username
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ldap_username: Option<String>,
+
+    /// LDAP attribute holding the username
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ldap_username_field: Option<String>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+query_fields! {
+    /// Fields that SSO strategies can be sorted and filtered by
+    pub enum SsoStrategyField {
+        Name => "name",
+        Protocol => "protocol",
+        Provider => "provider",
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct SsoStrategyHandler {
     client: FilesClient,
 }
@@ -21,6 +219,7 @@ impl SsoStrategyHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<SsoStrategyField>>,
     ) -> Result<(Vec<SsoStrategyEntity>, PaginationInfo)> {
         let mut endpoint = "/sso_strategies".to_string();
         let mut query_params = Vec::new();
@@ -38,29 +237,29 @@ impl SsoStrategyHandler {
             endpoint.push_str(&query_params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
 
-        let entities: Vec<SsoStrategyEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let entities: Vec<SsoStrategyEntity> = serde_json::from_value(response)?;
         Ok((entities, pagination))
     }
 
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<SsoStrategyField>>,
+    ) -> Paginator<SsoStrategyEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
+
     pub async fn get(&self, id: i64) -> Result<SsoStrategyEntity> {
         let endpoint = format!("/sso_strategies/{}", id);
         let response = self.client.get_raw(&endpoint).await?;
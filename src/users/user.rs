@@ -1,11 +1,140 @@
 use crate::{Result, client::FilesClient};
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
 use serde_json::json;
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct UserEntity {
-    #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+/// The current user has the same shape as any other user
+pub use super::users::UserEntity;
+
+/// Parameters for updating the current user
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateCurrentUserParams {
+    /// Full name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Email address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    /// Company
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<String>,
+
+    /// Notes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+
+    /// Preferred language
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+
+    /// Time zone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+
+    /// New password
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+
+    /// Confirmation of the new password
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password_confirmation: Option<String>,
+
+    /// Whether to delete the avatar
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar_delete: Option<bool>,
+
+    /// Whether the user has read the announcements
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub announcements_read: Option<bool>,
+}
+
+impl UpdateCurrentUserParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Full name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Email address
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.email = Some(email.into());
+        self
+    }
+
+    /// Company
+    pub fn company(mut self, company: impl Into<String>) -> Self {
+        self.company = Some(company.into());
+        self
+    }
+
+    /// Notes
+    pub fn notes(mut self, notes: impl Into<String>) -> Self {
+        self.notes = Some(notes.into());
+        self
+    }
+
+    /// Preferred language
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Time zone
+    pub fn time_zone(mut self, time_zone: impl Into<String>) -> Self {
+        self.time_zone = Some(time_zone.into());
+        self
+    }
+
+    /// New password
+    pub fn password(mut self, password: impl Into<String>) -> Self {
+        self.password = Some(password.into());
+        self
+    }
+
+    /// Confirmation of the new password
+    pub fn password_confirmation(mut self, password_confirmation: impl Into<String>) -> Self {
+        self.password_confirmation = Some(password_confirmation.into());
+        self
+    }
+
+    /// Whether to delete the avatar
+    pub fn avatar_delete(mut self, avatar_delete: bool) -> Self {
+        self.avatar_delete = Some(avatar_delete);
+        self
+    }
+
+    /// Whether the user has read the announcements
+    pub fn announcements_read(mut self, announcements_read: bool) -> Self {
+        self.announcements_read = Some(announcements_read);
+        self
+    }
+}
+
+/// Parameters for updating a user's two-factor authentication settings
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Update2faParams {
+    /// 2FA requirement (`use_system_setting`, `always_require` or `never_require`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_2fa: Option<String>,
+}
+
+impl Update2faParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 2FA requirement (`use_system_setting`, `always_require` or `never_require`)
+    pub fn require_2fa(mut self, require_2fa: impl Into<String>) -> Self {
+        self.require_2fa = Some(require_2fa.into());
+        self
+    }
 }
 
 pub struct CurrentUserHandler {
@@ -22,7 +151,7 @@ impl CurrentUserHandler {
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn update(&self, params: serde_json::Value) -> Result<UserEntity> {
+    pub async fn update(&self, params: UpdateCurrentUserParams) -> Result<UserEntity> {
         let response = self.client.patch_raw("/user", params).await?;
         Ok(serde_json::from_value(response)?)
     }
@@ -45,7 +174,7 @@ impl CurrentUserHandler {
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn update_2fa(&self, id: i64, params: serde_json::Value) -> Result<UserEntity> {
+    pub async fn update_2fa(&self, id: i64, params: Update2faParams) -> Result<UserEntity> {
         let endpoint = format!("/users/{}/2fa", id);
         let response = self.client.patch_raw(&endpoint, params).await?;
         Ok(serde_json::from_value(response)?)
@@ -1,12 +1,40 @@
-use crate::{Result, client::FilesClient};
+use crate::pagination::Paginator;
+use crate::{PaginationInfo, Result, Timestamp, client::FilesClient};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// User cipher use entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserCipherUseEntity {
+    /// Record ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Protocol and cipher used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocol_cipher: Option<String>,
+
+    /// Interface used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface: Option<String>,
+
+    /// User ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
+
+    /// First use time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+
+    /// Last use time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Timestamp>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+#[derive(Debug, Clone)]
 pub struct UserCipherUseHandler {
     client: FilesClient,
 }
@@ -16,9 +44,37 @@ impl UserCipherUseHandler {
         Self { client }
     }
 
-    pub async fn list(&self) -> Result<Vec<UserCipherUseEntity>> {
-        let response = self.client.get_raw("/user_cipher_uses").await?;
+    pub async fn list(
+        &self,
+        cursor: Option<String>,
+        per_page: Option<i64>,
+    ) -> Result<(Vec<UserCipherUseEntity>, PaginationInfo)> {
+        let mut endpoint = "/user_cipher_uses".to_string();
+        let mut params = Vec::new();
+
+        if let Some(c) = cursor {
+            params.push(format!("cursor={}", c));
+        }
+        if let Some(pp) = per_page {
+            params.push(format!("per_page={}", pp));
+        }
+
+        if !params.is_empty() {
+            endpoint.push('?');
+            endpoint.push_str(&params.join("&"));
+        }
+
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
         let entities: Vec<UserCipherUseEntity> = serde_json::from_value(response)?;
-        Ok(entities)
+        Ok((entities, pagination))
+    }
+
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(&self, per_page: Option<i64>) -> Paginator<UserCipherUseEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            async move { handler.list(cursor, per_page).await }
+        })
     }
 }
@@ -1,12 +1,199 @@
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
 use crate::{Result, client::FilesClient, types::PaginationInfo};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// User lifecycle rule entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserLifecycleRuleEntity {
+    /// Rule ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Site ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub site_id: Option<i64>,
+
+    /// Action to take: `disable` or `delete`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+
+    /// Authentication method the rule applies to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authentication_method: Option<String>,
+
+    /// Days of inactivity before the action is taken
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inactivity_days: Option<i64>,
+
+    /// Whether site admins are included
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_site_admins: Option<bool>,
+
+    /// Whether folder admins are included
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_folder_admins: Option<bool>,
+
+    /// State of users the rule applies to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_state: Option<String>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parameters for creating a user lifecycle rule
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateUserLifecycleRuleParams {
+    /// Action to take: `disable` or `delete`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+
+    /// Authentication method the rule applies to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authentication_method: Option<String>,
+
+    /// Days of inactivity before the action is taken
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inactivity_days: Option<i64>,
+
+    /// Whether to include site admins
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_site_admins: Option<bool>,
+
+    /// Whether to include folder admins
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_folder_admins: Option<bool>,
+
+    /// State of users the rule applies to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_state: Option<String>,
+}
+
+impl CreateUserLifecycleRuleParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Action to take: `disable` or `delete`
+    pub fn action(mut self, action: impl Into<String>) -> Self {
+        self.action = Some(action.into());
+        self
+    }
+
+    /// Authentication method the rule applies to
+    pub fn authentication_method(mut self, authentication_method: impl Into<String>) -> Self {
+        self.authentication_method = Some(authentication_method.into());
+        self
+    }
+
+    /// Days of inactivity before the action is taken
+    pub fn inactivity_days(mut self, inactivity_days: i64) -> Self {
+        self.inactivity_days = Some(inactivity_days);
+        self
+    }
+
+    /// Whether to include site admins
+    pub fn include_site_admins(mut self, include_site_admins: bool) -> Self {
+        self.include_site_admins = Some(include_site_admins);
+        self
+    }
+
+    /// Whether to include folder admins
+    pub fn include_folder_admins(mut self, include_folder_admins: bool) -> Self {
+        self.include_folder_admins = Some(include_folder_admins);
+        self
+    }
+
+    /// State of users the rule applies to
+    pub fn user_state(mut self, user_state: impl Into<String>) -> Self {
+        self.user_state = Some(user_state.into());
+        self
+    }
+}
+
+/// Parameters for updating a user lifecycle rule
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateUserLifecycleRuleParams {
+    /// Action to take: `disable` or `delete`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+
+    /// Authentication method the rule applies to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authentication_method: Option<String>,
+
+    /// Days of inactivity before the action is taken
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inactivity_days: Option<i64>,
+
+    /// Whether to include site admins
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_site_admins: Option<bool>,
+
+    /// Whether to include folder admins
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_folder_admins: Option<bool>,
+
+    /// State of users the rule applies to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_state: Option<String>,
+}
+
+impl UpdateUserLifecycleRuleParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Action to take: `disable` or `delete`
+    pub fn action(mut self, action: impl Into<String>) -> Self {
+        self.action = Some(action.into());
+        self
+    }
+
+    /// Authentication method the rule applies to
+    pub fn authentication_method(mut self, authentication_method: impl Into<String>) -> Self {
+        self.authentication_method = Some(authentication_method.into());
+        self
+    }
+
+    /// Days of inactivity before the action is taken
+    pub fn inactivity_days(mut self, inactivity_days: i64) -> Self {
+        self.inactivity_days = Some(inactivity_days);
+        self
+    }
+
+    /// Whether to include site admins
+    pub fn include_site_admins(mut self, include_site_admins: bool) -> Self {
+        self.include_site_admins = Some(include_site_admins);
+        self
+    }
+
+    /// Whether to include folder admins
+    pub fn include_folder_admins(mut self, include_folder_admins: bool) -> Self {
+        self.include_folder_admins = Some(include_folder_admins);
+        self
+    }
+
+    /// State of users the rule applies to
+    pub fn user_state(mut self, user_state: impl Into<String>) -> Self {
+        self.user_state = Some(user_state.into());
+        self
+    }
 }
 
+query_fields! {
+    /// Fields that user lifecycle rules can be sorted and filtered by
+    pub enum UserLifecycleRuleField {
+        AuthenticationMethod => "authentication_method",
+        Action => "action",
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct UserLifecycleRuleHandler {
     client: FilesClient,
 }
@@ -20,6 +207,7 @@ impl UserLifecycleRuleHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i64>,
+        query: Option<&ListQuery<UserLifecycleRuleField>>,
     ) -> Result<(Vec<UserLifecycleRuleEntity>, PaginationInfo)> {
         let mut endpoint = "/user_lifecycle_rules".to_string();
         let mut query_params = Vec::new();
@@ -37,36 +225,39 @@ impl UserLifecycleRuleHandler {
             endpoint.push_str(&query_params.join("&"));
         }
 
-        let url = format!("{}{}", self.client.inner.base_url, endpoint);
-        let response = reqwest::Client::new()
-            .get(&url)
-            .header("X-FilesAPI-Key", &self.client.inner.api_key)
-            .send()
-            .await?;
-
-        let headers = response.headers().clone();
-        let pagination = PaginationInfo::from_headers(&headers);
-
-        let status = response.status();
-        if !status.is_success() {
-            return Err(crate::FilesError::ApiError {
-                endpoint: None,
-                code: status.as_u16(),
-                message: response.text().await.unwrap_or_default(),
-            });
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
         }
 
-        let entities: Vec<UserLifecycleRuleEntity> = response.json().await?;
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let entities: Vec<UserLifecycleRuleEntity> = serde_json::from_value(response)?;
         Ok((entities, pagination))
     }
 
+    /// Stream the results of [`list`](Self::list) across all pages
+    pub fn list_stream(
+        &self,
+        per_page: Option<i64>,
+        query: Option<ListQuery<UserLifecycleRuleField>>,
+    ) -> Paginator<UserLifecycleRuleEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, per_page, query.as_ref()).await }
+        })
+    }
+
     pub async fn get(&self, id: i64) -> Result<UserLifecycleRuleEntity> {
         let endpoint = format!("/user_lifecycle_rules/{}", id);
         let response = self.client.get_raw(&endpoint).await?;
         Ok(serde_json::from_value(response)?)
     }
 
-    pub async fn create(&self, params: serde_json::Value) -> Result<UserLifecycleRuleEntity> {
+    pub async fn create(
+        &self,
+        params: CreateUserLifecycleRuleParams,
+    ) -> Result<UserLifecycleRuleEntity> {
         let response = self
             .client
             .post_raw("/user_lifecycle_rules", params)
@@ -77,7 +268,7 @@ impl UserLifecycleRuleHandler {
     pub async fn update(
         &self,
         id: i64,
-        params: serde_json::Value,
+        params: UpdateUserLifecycleRuleParams,
     ) -> Result<UserLifecycleRuleEntity> {
         let endpoint = format!("/user_lifecycle_rules/{}", id);
         let response = self.client.patch_raw(&endpoint, params).await?;
@@ -2,7 +2,9 @@
 //!
 //! Track SFTP client usage by users.
 
-use crate::{FilesClient, PaginationInfo, Result};
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{FilesClient, PaginationInfo, Result, Timestamp};
 use serde::{Deserialize, Serialize};
 
 /// Represents a user's SFTP client usage
@@ -18,18 +20,29 @@ pub struct UserSftpClientUseEntity {
 
     /// The earliest recorded use of this SFTP client (for this user)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
 
     /// The most recent use of this SFTP client (for this user)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    pub updated_at: Option<Timestamp>,
 
     /// ID of the user who performed this access
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_id: Option<i64>,
 }
 
+query_fields! {
+    /// Fields that SFTP client uses can be sorted and filtered by
+    pub enum UserSftpClientUseField {
+        UserId => "user_id",
+        SftpClient => "sftp_client",
+        CreatedAt => "created_at",
+        UpdatedAt => "updated_at",
+    }
+}
+
 /// Handler for User SFTP Client Use operations
+#[derive(Debug, Clone)]
 pub struct UserSftpClientUseHandler {
     client: FilesClient,
 }
@@ -53,6 +66,7 @@ impl UserSftpClientUseHandler {
     /// * `user_id` - Optional user ID to filter by
     /// * `cursor` - Pagination cursor
     /// * `per_page` - Number of records per page
+    /// * `query` - Sorting and filtering options (optional)
     ///
     /// # Example
     /// ```no_run
@@ -61,7 +75,7 @@ impl UserSftpClientUseHandler {
     /// let client = FilesClient::builder().api_key("key").build()?;
     /// let handler = UserSftpClientUseHandler::new(client);
     ///
-    /// let (uses, pagination) = handler.list(None, None, Some(100)).await?;
+    /// let (uses, pagination) = handler.list(None, None, Some(100), None).await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -70,6 +84,7 @@ impl UserSftpClientUseHandler {
         user_id: Option<i64>,
         cursor: Option<String>,
         per_page: Option<i32>,
+        query: Option<&ListQuery<UserSftpClientUseField>>,
     ) -> Result<(Vec<UserSftpClientUseEntity>, PaginationInfo)> {
         let mut endpoint = "/user_sftp_client_uses".to_string();
         let mut query_params = Vec::new();
@@ -91,17 +106,36 @@ impl UserSftpClientUseHandler {
             endpoint.push_str(&query_params.join("&"));
         }
 
-        let response = self.client.get_raw(&endpoint).await?;
-        let items: Vec<UserSftpClientUseEntity> = serde_json::from_value(response)?;
+        if let Some(query) = query {
+            query.append_to(&mut endpoint);
+        }
 
-        // TODO: Extract pagination from response headers
-        let pagination = PaginationInfo {
-            cursor_next: None,
-            cursor_prev: None,
-        };
+        let (response, pagination) = self.client.get_paginated(&endpoint).await?;
+        let items: Vec<UserSftpClientUseEntity> = serde_json::from_value(response)?;
 
         Ok((items, pagination))
     }
+
+    /// Stream User SFTP Client Uses across all pages
+    ///
+    /// Takes the same arguments as [`list`](Self::list), without the cursor.
+    pub fn list_stream(
+        &self,
+        user_id: Option<i64>,
+        per_page: Option<i32>,
+        query: Option<ListQuery<UserSftpClientUseField>>,
+    ) -> Paginator<UserSftpClientUseEntity> {
+        let handler = self.clone();
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move {
+                handler
+                    .list(user_id, cursor, per_page, query.as_ref())
+                    .await
+            }
+        })
+    }
 }
 
 #[cfg(test)]
@@ -35,7 +35,7 @@
 //! println!("Created user ID: {}", user.id.unwrap());
 //!
 //! // List all users
-//! let (users, _) = handler.list(None, Some(100)).await?;
+//! let (users, _) = handler.list(None, Some(100), None).await?;
 //! for user in users {
 //!     println!("User: {} ({})",
 //!         user.username.unwrap_or_default(),
@@ -45,8 +45,9 @@
 //! # }
 //! ```
 
-use crate::{FilesClient, PaginationInfo, Result};
-use futures::stream::Stream;
+use crate::pagination::Paginator;
+use crate::query::{ListQuery, query_fields};
+use crate::{FilesClient, PaginationInfo, Result, Timestamp};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
@@ -155,15 +156,15 @@ pub struct UserEntity {
 
     /// Created at timestamp
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
 
     /// Last login at timestamp
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_login_at: Option<String>,
+    pub last_login_at: Option<Timestamp>,
 
     /// Password set at timestamp
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub password_set_at: Option<String>,
+    pub password_set_at: Option<Timestamp>,
 
     /// Password validity in days
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -176,6 +177,28 @@ pub struct UserEntity {
     /// Public keys count
     #[serde(skip_serializing_if = "Option::is_none")]
     pub public_keys_count: Option<i64>,
+
+    /// Fields not covered above, kept for forward compatibility
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+query_fields! {
+    /// Fields that users can be sorted and filtered by
+    pub enum UserField {
+        Username => "username",
+        Name => "name",
+        Email => "email",
+        Company => "company",
+        SiteAdmin => "site_admin",
+        NotSiteAdmin => "not_site_admin",
+        PasswordValidityDays => "password_validity_days",
+        SslRequired => "ssl_required",
+        LastLoginAt => "last_login_at",
+        LastDesktopLoginAt => "last_desktop_login_at",
+        AuthenticateUntil => "authenticate_until",
+        Notes => "notes",
+    }
 }
 
 /// Handler for user operations
@@ -198,6 +221,7 @@ impl UserHandler {
     ///
     /// * `cursor` - Pagination cursor from previous response
     /// * `per_page` - Number of results per page (default 100, max 10,000)
+    /// * `query` - Sorting and filtering options (optional)
     ///
     /// # Returns
     ///
@@ -215,7 +239,7 @@ impl UserHandler {
     /// let handler = UserHandler::new(client);
     ///
     /// // List first page of users
-    /// let (users, pagination) = handler.list(None, Some(50)).await?;
+    /// let (users, pagination) = handler.list(None, Some(50), None).await?;
     ///
     /// for user in users {
     ///     println!("{}: {} - Admin: {}",
@@ -226,7 +250,7 @@ impl UserHandler {
     ///
     /// // Get next page if available
     /// if let Some(next) = pagination.cursor_next {
-    ///     let (more_users, _) = handler.list(Some(next), Some(50)).await?;
+    ///     let (more_users, _) = handler.list(Some(next), Some(50), None).await?;
     /// }
     /// # Ok(())
     /// # }
@@ -235,6 +259,7 @@ impl UserHandler {
         &self,
         cursor: Option<String>,
         per_page: Option<i32>,
+        query: Option<&ListQuery<UserField>>,
     ) -> Result<(Vec<UserEntity>, PaginationInfo)> {
         let mut path = "/users?".to_string();
 
@@ -245,14 +270,12 @@ impl UserHandler {
             path.push_str(&format!("per_page={}&", pp));
         }
 
-        let response = self.client.get_raw(&path).await?;
-        let users: Vec<UserEntity> = serde_json::from_value(response)?;
+        if let Some(query) = query {
+            query.append_to(&mut path);
+        }
 
-        // TODO: Extract pagination info from response headers
-        let pagination = PaginationInfo {
-            cursor_next: None,
-            cursor_prev: None,
-        };
+        let (response, pagination) = self.client.get_paginated(&path).await?;
+        let users: Vec<UserEntity> = serde_json::from_value(response)?;
 
         Ok((users, pagination))
     }
@@ -266,6 +289,7 @@ impl UserHandler {
     /// # Arguments
     ///
     /// * `per_page` - Number of users per page (optional, default 1000)
+    /// * `query` - Sorting and filtering options (optional)
     ///
     /// # Examples
     ///
@@ -276,7 +300,7 @@ impl UserHandler {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let client = FilesClient::builder().api_key("key").build()?;
     /// let handler = UserHandler::new(client);
-    /// let stream = handler.list_stream(Some(100));
+    /// let stream = handler.list_stream(Some(100), None);
     ///
     /// tokio::pin!(stream);
     ///
@@ -290,27 +314,16 @@ impl UserHandler {
     pub fn list_stream(
         &self,
         per_page: Option<i32>,
-    ) -> impl Stream<Item = Result<UserEntity>> + '_ {
+        query: Option<ListQuery<UserField>>,
+    ) -> Paginator<UserEntity> {
+        let handler = self.clone();
         let per_page = per_page.unwrap_or(1000);
 
-        async_stream::try_stream! {
-            let mut cursor: Option<String> = None;
-
-            loop {
-                let (users, pagination) = self
-                    .list(cursor.clone(), Some(per_page))
-                    .await?;
-
-                for user in users {
-                    yield user;
-                }
-
-                match pagination.cursor_next {
-                    Some(next) => cursor = Some(next),
-                    None => break,
-                }
-            }
-        }
+        Paginator::new(move |cursor| {
+            let handler = handler.clone();
+            let query = query.clone();
+            async move { handler.list(cursor, Some(per_page), query.as_ref()).await }
+        })
     }
 
     /// Get details of a specific user by ID
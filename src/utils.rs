@@ -77,11 +77,98 @@ fn percent_encode(s: &str) -> String {
     encoded
 }
 
+/// Parses an ISO 8601 / RFC 3339 timestamp as returned by Files.com
+///
+/// Accepts timestamps such as `2024-01-15T10:30:00Z`,
+/// `2024-01-15T10:30:00.123Z` and `2024-01-15T10:30:00-05:00`.
+///
+/// # Returns
+///
+/// The timestamp as a [`SystemTime`](std::time::SystemTime), or `None` if the
+/// string is not a valid timestamp
+///
+/// # Examples
+///
+/// ```
+/// use files_sdk::utils::parse_timestamp;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let time = parse_timestamp("1970-01-02T00:00:00Z").unwrap();
+/// assert_eq!(time, UNIX_EPOCH + Duration::from_secs(86_400));
+/// assert!(parse_timestamp("not a timestamp").is_none());
+/// ```
+pub fn parse_timestamp(s: &str) -> Option<std::time::SystemTime> {
+    crate::timestamp::parse_system_time(s)
+}
+
+/// Formats a point in time as an RFC 3339 UTC timestamp, as Files.com does
+///
+/// Times before the Unix epoch are clamped to it. Sub-second precision is
+/// dropped.
+///
+/// # Examples
+///
+/// ```
+/// use files_sdk::utils::format_timestamp;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let time = UNIX_EPOCH + Duration::from_secs(1_705_314_600);
+/// assert_eq!(format_timestamp(time), "2024-01-15T10:30:00Z");
+/// ```
+pub fn format_timestamp(time: std::time::SystemTime) -> String {
+    crate::timestamp::format_system_time(time)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use proptest::prelude::*;
 
+    #[test]
+    fn test_parse_timestamp_utc() {
+        use std::time::{Duration, UNIX_EPOCH};
+
+        assert_eq!(
+            parse_timestamp("2024-01-15T10:30:00Z"),
+            Some(UNIX_EPOCH + Duration::from_secs(1_705_314_600))
+        );
+        assert_eq!(
+            parse_timestamp("2024-01-15T10:30:00.250Z"),
+            Some(UNIX_EPOCH + Duration::new(1_705_314_600, 250_000_000))
+        );
+    }
+
+    #[test]
+    fn test_parse_timestamp_offset() {
+        assert_eq!(
+            parse_timestamp("2024-01-15T05:30:00-05:00"),
+            parse_timestamp("2024-01-15T10:30:00Z")
+        );
+        assert_eq!(
+            parse_timestamp("2024-01-15T12:30:00+02:00"),
+            parse_timestamp("2024-01-15T10:30:00Z")
+        );
+    }
+
+    #[test]
+    fn test_parse_timestamp_invalid() {
+        assert!(parse_timestamp("").is_none());
+        assert!(parse_timestamp("2024-13-01T00:00:00Z").is_none());
+        assert!(parse_timestamp("2024-01-15 garbage").is_none());
+        assert!(parse_timestamp("2024-01-15T10:30:00+0x:00").is_none());
+    }
+
+    #[test]
+    fn test_format_timestamp_round_trip() {
+        for stamp in [
+            "1970-01-01T00:00:00Z",
+            "2000-02-29T23:59:59Z",
+            "2024-01-15T10:30:00Z",
+        ] {
+            assert_eq!(format_timestamp(parse_timestamp(stamp).unwrap()), stamp);
+        }
+    }
+
     #[test]
     fn test_encode_simple_path() {
         assert_eq!(encode_path("/simple/path.txt"), "/simple/path.txt");
@@ -1,10 +1,143 @@
 use crate::{Result, client::FilesClient};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Webhook test result entity from Files.com API
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WebhookTestEntity {
+    /// HTTP status code of the response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<i64>,
+
+    /// Error message, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+
+    /// Test status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// Response data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+
+    /// Whether the webhook succeeded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+
+    /// Fields not covered above, kept for forward compatibility
     #[serde(flatten)]
-    pub data: serde_json::Map<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parameters for testing a webhook
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateWebhookTestParams {
+    /// URL to send the request to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// HTTP method
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+
+    /// Body encoding
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<String>,
+
+    /// Additional request headers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<serde_json::Value>,
+
+    /// Request body parameters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<serde_json::Value>,
+
+    /// Raw request body
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_body: Option<String>,
+
+    /// Whether to send the file as the request body
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_as_body: Option<bool>,
+
+    /// Form field name for the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_form_field: Option<String>,
+
+    /// Action name to send
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+
+    /// Whether to send from dedicated IPs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_dedicated_ips: Option<bool>,
+}
+
+impl CreateWebhookTestParams {
+    /// Creates empty parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// URL to send the request to
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// HTTP method
+    pub fn method(mut self, method: impl Into<String>) -> Self {
+        self.method = Some(method.into());
+        self
+    }
+
+    /// Body encoding
+    pub fn encoding(mut self, encoding: impl Into<String>) -> Self {
+        self.encoding = Some(encoding.into());
+        self
+    }
+
+    /// Additional request headers
+    pub fn headers(mut self, headers: serde_json::Value) -> Self {
+        self.headers = Some(headers);
+        self
+    }
+
+    /// Request body parameters
+    pub fn body(mut self, body: serde_json::Value) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// Raw request body
+    pub fn raw_body(mut self, raw_body: impl Into<String>) -> Self {
+        self.raw_body = Some(raw_body.into());
+        self
+    }
+
+    /// Whether to send the file as the request body
+    pub fn file_as_body(mut self, file_as_body: bool) -> Self {
+        self.file_as_body = Some(file_as_body);
+        self
+    }
+
+    /// Form field name for the file
+    pub fn file_form_field(mut self, file_form_field: impl Into<String>) -> Self {
+        self.file_form_field = Some(file_form_field.into());
+        self
+    }
+
+    /// Action name to send
+    pub fn action(mut self, action: impl Into<String>) -> Self {
+        self.action = Some(action.into());
+        self
+    }
+
+    /// Whether to send from dedicated IPs
+    pub fn use_dedicated_ips(mut self, use_dedicated_ips: bool) -> Self {
+        self.use_dedicated_ips = Some(use_dedicated_ips);
+        self
+    }
 }
 
 pub struct WebhookTestHandler {
@@ -16,7 +149,7 @@ impl WebhookTestHandler {
         Self { client }
     }
 
-    pub async fn create(&self, params: serde_json::Value) -> Result<WebhookTestEntity> {
+    pub async fn create(&self, params: CreateWebhookTestParams) -> Result<WebhookTestEntity> {
         let response = self.client.post_raw("/webhook_tests", params).await?;
         let entity: WebhookTestEntity = serde_json::from_value(response)?;
         Ok(entity)
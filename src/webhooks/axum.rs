@@ -0,0 +1,117 @@
+//! axum integration for webhook receivers
+//!
+//! [`Webhook`] is an extractor yielding a verified [`WebhookEvent`]. It takes
+//! its [`WebhookReceiver`] from the router state, so the state must either
+//! be a receiver or implement `FromRef` for one. A rejected request is
+//! answered with [`WebhookError::status_code`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use axum::{Router, routing::post};
+//! use files_sdk::webhooks::WebhookReceiver;
+//! use files_sdk::webhooks::axum::Webhook;
+//!
+//! async fn on_webhook(Webhook(event): Webhook) {
+//!     println!("{:?} {:?}", event.action, event.path);
+//! }
+//!
+//! let receiver = WebhookReceiver::new().secret_header("X-Webhook-Secret", "s3cret");
+//! let app: Router = Router::new()
+//!     .route("/hooks/files", post(on_webhook).get(on_webhook))
+//!     .with_state(receiver);
+//! ```
+
+use super::{WebhookError, WebhookEvent, WebhookReceiver, WebhookRequest};
+use ::axum::body::Bytes;
+use ::axum::extract::{FromRef, FromRequest, Request};
+use ::axum::http::StatusCode;
+use ::axum::response::{IntoResponse, Response};
+
+/// Extractor for a verified webhook event
+#[derive(Debug, Clone)]
+pub struct Webhook(pub WebhookEvent);
+
+impl<S> FromRequest<S> for Webhook
+where
+    S: Send + Sync,
+    WebhookReceiver: FromRef<S>,
+{
+    type Rejection = WebhookError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let receiver = WebhookReceiver::from_ref(state);
+
+        let (parts, body) = req.into_parts();
+        let request = WebhookRequest::from_parts(&parts);
+        let body = Bytes::from_request(Request::from_parts(parts, body), state)
+            .await
+            .map_err(|e| WebhookError::Body(e.body_text()))?;
+
+        receiver.receive(&request.body(body)).map(Webhook)
+    }
+}
+
+impl IntoResponse for WebhookError {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status_code()).unwrap_or(StatusCode::BAD_REQUEST);
+        (status, self.to_string()).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::axum::Router;
+    use ::axum::body::Body;
+    use ::axum::routing::post;
+    use tower::ServiceExt;
+
+    async fn path(Webhook(event): Webhook) -> String {
+        event.path.unwrap_or_default()
+    }
+
+    fn app() -> Router {
+        Router::new()
+            .route("/hook", post(path).get(path))
+            .with_state(WebhookReceiver::new().secret_header("X-Webhook-Secret", "s3cret"))
+    }
+
+    #[tokio::test]
+    async fn test_extracts_verified_event() {
+        let request = Request::post("/hook")
+            .header("content-type", "application/x-www-form-urlencoded")
+            .header("x-webhook-secret", "s3cret")
+            .body(Body::from("action=create&path=inbox%2Fa.txt"))
+            .unwrap();
+
+        let response = app().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = ::axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(body, "inbox/a.txt");
+    }
+
+    #[tokio::test]
+    async fn test_extracts_query_string_event() {
+        let request = Request::get("/hook?action=read&path=a.txt")
+            .header("x-webhook-secret", "s3cret")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_unverified_request() {
+        let request = Request::post("/hook")
+            .header("x-webhook-secret", "guess")
+            .body(Body::from("action=create"))
+            .unwrap();
+
+        let response = app().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+}
@@ -0,0 +1,140 @@
+//! Typed webhook payloads
+
+use crate::string_enum::string_enum;
+use serde::{Deserialize, Deserializer, Serialize};
+
+string_enum! {
+    /// Action that triggered a webhook
+    pub enum WebhookAction {
+        /// A file or folder was created or uploaded
+        Create => "create",
+        /// A file was downloaded
+        Read => "read",
+        /// A file was overwritten
+        Update => "update",
+        /// A file or folder was deleted
+        Destroy => "destroy",
+        /// A file or folder was moved or renamed
+        Move => "move",
+        /// A file or folder was copied
+        Copy => "copy",
+        /// An archived file was deleted
+        ArchivedDelete => "archived_delete",
+    }
+}
+
+string_enum! {
+    /// Interface through which the triggering action was performed
+    pub enum WebhookInterface {
+        /// Web interface
+        Web => "web",
+        /// FTP
+        Ftp => "ftp",
+        /// SFTP
+        Sftp => "sftp",
+        /// WebDAV
+        Dav => "dav",
+        /// Desktop app
+        Desktop => "desktop",
+        /// REST API
+        RestApi => "restapi",
+        /// Automations and other server-side processes
+        Robot => "robot",
+        /// JavaScript API
+        JsApi => "jsapi",
+        /// Mobile app
+        Mobile => "mobile",
+        /// AS2
+        As2 => "as2",
+        /// Inbound email
+        InboundEmail => "inbound_email",
+        /// Remote server sync
+        Remote => "remote",
+    }
+}
+
+/// Event delivered by a Files.com `webhook` behavior
+///
+/// Form-encoded and query-string payloads carry every value as a string;
+/// numeric fields are parsed either way. Fields not covered here are kept in
+/// `extra`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebhookEvent {
+    /// What happened
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<WebhookAction>,
+
+    /// How it was done
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface: Option<WebhookInterface>,
+
+    /// Path of the affected file or folder
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// New path, for move and copy actions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<String>,
+
+    /// When the action happened
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub at: Option<String>,
+
+    /// User who performed the action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    /// Type: "file" or "directory"
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub file_type: Option<String>,
+
+    /// Size in bytes
+    #[serde(
+        default,
+        deserialize_with = "lenient_i64",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub size: Option<i64>,
+
+    /// Fields not covered above, kept for forward compatibility
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl WebhookEvent {
+    /// Whether the event is about a folder
+    pub fn is_folder(&self) -> bool {
+        self.file_type.as_deref() == Some("directory")
+    }
+
+    /// Parses [`at`](Self::at) as a point in time
+    pub fn occurred_at(&self) -> Option<std::time::SystemTime> {
+        self.at
+            .as_deref()
+            .and_then(crate::timestamp::parse_system_time)
+    }
+}
+
+/// Accepts integers as numbers or numeric strings; empty strings are `None`
+fn lenient_i64<'de, D>(deserializer: D) -> std::result::Result<Option<i64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<serde_json::Value>::deserialize(deserializer)? {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::Number(n)) => n
+            .as_i64()
+            .map(Some)
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid integer: {}", n))),
+        Some(serde_json::Value::String(s)) if s.trim().is_empty() => Ok(None),
+        Some(serde_json::Value::String(s)) => s
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|_| serde::de::Error::custom(format!("invalid integer: {:?}", s))),
+        Some(other) => Err(serde::de::Error::custom(format!(
+            "invalid integer: {}",
+            other
+        ))),
+    }
+}
@@ -0,0 +1,79 @@
+//! hyper integration for webhook receivers
+//!
+//! Works with any `http::Request` whose body implements `http_body::Body`,
+//! such as `hyper::body::Incoming`.
+
+use super::{WebhookError, WebhookEvent, WebhookReceiver, WebhookRequest};
+use http_body::Body;
+use http_body_util::BodyExt;
+
+impl WebhookReceiver {
+    /// Reads the body of `request`, then verifies and parses its event
+    ///
+    /// The body is read completely; wrap it in `http_body_util::Limited` to
+    /// bound its size.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use files_sdk::webhooks::WebhookReceiver;
+    ///
+    /// async fn handle<B>(receiver: &WebhookReceiver, request: http::Request<B>) -> http::Response<String>
+    /// where
+    ///     B: http_body::Body,
+    ///     B::Error: std::fmt::Display,
+    /// {
+    ///     match receiver.receive_http(request).await {
+    ///         Ok(event) => {
+    ///             println!("{:?} {:?}", event.action, event.path);
+    ///             http::Response::new(String::new())
+    ///         }
+    ///         Err(e) => http::Response::builder()
+    ///             .status(e.status_code())
+    ///             .body(e.to_string())
+    ///             .unwrap(),
+    ///     }
+    /// }
+    /// ```
+    pub async fn receive_http<B>(
+        &self,
+        request: http::Request<B>,
+    ) -> Result<WebhookEvent, WebhookError>
+    where
+        B: Body,
+        B::Error: std::fmt::Display,
+    {
+        let (parts, body) = request.into_parts();
+        let body = body
+            .collect()
+            .await
+            .map_err(|e| WebhookError::Body(e.to_string()))?
+            .to_bytes();
+
+        self.receive(&WebhookRequest::from_parts(&parts).body(body))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http_body_util::Full;
+
+    #[tokio::test]
+    async fn test_receive_http() {
+        let receiver = WebhookReceiver::new().hmac_sha256("X-Signature", "key");
+        let body = r#"{"action":"update","path":"a.txt","size":3}"#;
+        let request = http::Request::post("/hook")
+            .header("content-type", "application/json")
+            .header(
+                "x-signature",
+                crate::webhooks::verify::sign_hmac_sha256(b"key", body.as_bytes()),
+            )
+            .body(Full::new(bytes::Bytes::from(body)))
+            .unwrap();
+
+        let event = receiver.receive_http(request).await.unwrap();
+        assert_eq!(event.path.as_deref(), Some("a.txt"));
+        assert_eq!(event.size, Some(3));
+    }
+}
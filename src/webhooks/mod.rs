@@ -0,0 +1,428 @@
+//! Receiving webhooks sent by Files.com
+//!
+//! A `webhook` behavior (see [`BehaviorHandler`](crate::BehaviorHandler))
+//! makes Files.com call a URL whenever files in a folder change. This module
+//! is the receiving side: it checks that a request is genuine and parses it
+//! into a typed [`WebhookEvent`].
+//!
+//! Depending on the behavior's method and encoding, the event fields arrive
+//! as query parameters, a form-encoded body or a JSON body.
+//! [`WebhookReceiver::receive`] handles all three from a framework-agnostic
+//! [`WebhookRequest`].
+//!
+//! # Verification
+//!
+//! A behavior can send extra headers with every request. Configure a secret
+//! header on the behavior and have the receiver check it with
+//! [`WebhookReceiver::secret_header`]. When requests are relayed through a
+//! service that signs bodies, [`WebhookReceiver::hmac_sha256`] checks an
+//! HMAC-SHA256 signature instead. The helpers in [`verify`] can also be
+//! used on their own.
+//!
+//! # Framework Adapters
+//!
+//! - `axum` feature: `webhooks::axum::Webhook` extracts a verified event in
+//!   handlers
+//! - `hyper` feature: `WebhookReceiver::receive_http` reads any
+//!   `http::Request` whose body implements `http_body::Body`
+//!
+//! # Examples
+//!
+//! ```rust
+//! use files_sdk::webhooks::{WebhookAction, WebhookReceiver, WebhookRequest};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let receiver = WebhookReceiver::new().secret_header("X-Webhook-Secret", "s3cret");
+//!
+//! // Built from whatever your web framework hands you
+//! let request = WebhookRequest::new("POST")
+//!     .header("Content-Type", "application/json")
+//!     .header("X-Webhook-Secret", "s3cret")
+//!     .body(r#"{"action":"create","interface":"sftp","path":"inbox/report.csv"}"#);
+//!
+//! let event = receiver.receive(&request)?;
+//! assert_eq!(event.action, Some(WebhookAction::Create));
+//! assert_eq!(event.path.as_deref(), Some("inbox/report.csv"));
+//! # Ok(())
+//! # }
+//! ```
+
+mod event;
+pub mod verify;
+
+#[cfg(feature = "axum")]
+pub mod axum;
+#[cfg(feature = "hyper")]
+mod hyper;
+
+pub use event::{WebhookAction, WebhookEvent, WebhookInterface};
+
+use bytes::Bytes;
+use std::fmt;
+use thiserror::Error;
+
+/// Errors from receiving a webhook
+#[derive(Error, Debug)]
+pub enum WebhookError {
+    /// The verification header was not sent
+    #[error("Missing webhook verification header: {0}")]
+    MissingHeader(String),
+
+    /// The secret or signature did not match
+    #[error("Webhook verification failed")]
+    VerificationFailed,
+
+    /// The body is in an encoding this module does not parse
+    #[error("Unsupported webhook content type: {0}")]
+    UnsupportedContentType(String),
+
+    /// The payload could not be parsed as an event
+    #[error("Invalid webhook payload: {0}")]
+    InvalidPayload(String),
+
+    /// The request body could not be read
+    #[error("Failed to read webhook body: {0}")]
+    Body(String),
+}
+
+impl WebhookError {
+    /// HTTP status code to answer the request with
+    pub fn status_code(&self) -> u16 {
+        match self {
+            WebhookError::MissingHeader(_) | WebhookError::VerificationFailed => 401,
+            WebhookError::UnsupportedContentType(_) => 415,
+            WebhookError::InvalidPayload(_) | WebhookError::Body(_) => 400,
+        }
+    }
+}
+
+/// Framework-agnostic view of an incoming webhook request
+#[derive(Debug, Clone, Default)]
+pub struct WebhookRequest {
+    method: String,
+    query: Option<String>,
+    headers: Vec<(String, String)>,
+    body: Bytes,
+}
+
+impl WebhookRequest {
+    /// Creates a request with the given HTTP method
+    pub fn new(method: impl Into<String>) -> Self {
+        Self {
+            method: method.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the query string, without the leading `?`
+    pub fn query(mut self, query: impl Into<String>) -> Self {
+        self.query = Some(query.into());
+        self
+    }
+
+    /// Adds a header
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the raw body
+    pub fn body(mut self, body: impl Into<Bytes>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// HTTP method
+    pub fn method(&self) -> &str {
+        &self.method
+    }
+
+    /// Value of the first header named `name`, compared case-insensitively
+    pub fn header_value(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Raw body
+    pub fn body_bytes(&self) -> &[u8] {
+        &self.body
+    }
+}
+
+#[cfg(any(feature = "axum", feature = "hyper"))]
+impl WebhookRequest {
+    /// Copies method, query and headers from an `http` request head
+    ///
+    /// Headers whose values are not valid UTF-8 are skipped.
+    pub(crate) fn from_parts(parts: &http::request::Parts) -> Self {
+        Self {
+            method: parts.method.to_string(),
+            query: parts.uri.query().map(str::to_string),
+            headers: parts
+                .headers
+                .iter()
+                .filter_map(|(name, value)| {
+                    Some((name.as_str().to_string(), value.to_str().ok()?.to_string()))
+                })
+                .collect(),
+            body: Bytes::new(),
+        }
+    }
+}
+
+/// How a [`WebhookReceiver`] checks requests
+#[derive(Clone)]
+enum Verification {
+    Secret { header: String, secret: String },
+    HmacSha256 { header: String, key: Vec<u8> },
+}
+
+/// Verifies and parses incoming webhook requests
+///
+/// Without a verification method configured every request is accepted;
+/// only use that behind another form of authentication.
+#[derive(Clone, Default)]
+pub struct WebhookReceiver {
+    verification: Option<Verification>,
+}
+
+impl WebhookReceiver {
+    /// Creates a receiver that accepts every request
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requires `header` to carry `secret`
+    pub fn secret_header(mut self, header: impl Into<String>, secret: impl Into<String>) -> Self {
+        self.verification = Some(Verification::Secret {
+            header: header.into(),
+            secret: secret.into(),
+        });
+        self
+    }
+
+    /// Requires `header` to carry the hex HMAC-SHA256 of the payload under `key`
+    ///
+    /// The payload is the body, or the query string for requests without a
+    /// body, so the signature always covers the fields the event is parsed from.
+    pub fn hmac_sha256(mut self, header: impl Into<String>, key: impl AsRef<[u8]>) -> Self {
+        self.verification = Some(Verification::HmacSha256 {
+            header: header.into(),
+            key: key.as_ref().to_vec(),
+        });
+        self
+    }
+
+    /// Checks that `request` is genuine
+    pub fn verify(&self, request: &WebhookRequest) -> Result<(), WebhookError> {
+        let Some(verification) = &self.verification else {
+            return Ok(());
+        };
+        let header = match verification {
+            Verification::Secret { header, .. } | Verification::HmacSha256 { header, .. } => header,
+        };
+
+        let value = request
+            .header_value(header)
+            .ok_or_else(|| WebhookError::MissingHeader(header.clone()))?;
+        let valid = match verification {
+            Verification::Secret { secret, .. } => verify::verify_secret(secret, value),
+            Verification::HmacSha256 { key, .. } => {
+                verify::verify_hmac_sha256(key, payload(request), value)
+            }
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(WebhookError::VerificationFailed)
+        }
+    }
+
+    /// Verifies `request` and parses its event
+    pub fn receive(&self, request: &WebhookRequest) -> Result<WebhookEvent, WebhookError> {
+        self.verify(request)?;
+        parse(request)
+    }
+}
+
+impl fmt::Debug for WebhookReceiver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verification = match &self.verification {
+            None => "none",
+            Some(Verification::Secret { .. }) => "secret_header",
+            Some(Verification::HmacSha256 { .. }) => "hmac_sha256",
+        };
+        f.debug_struct("WebhookReceiver")
+            .field("verification", &verification)
+            .finish()
+    }
+}
+
+/// Bytes the event is parsed from: the body, or the query string if there is none
+fn payload(request: &WebhookRequest) -> &[u8] {
+    if request.body.is_empty() {
+        request.query.as_deref().unwrap_or_default().as_bytes()
+    } else {
+        &request.body
+    }
+}
+
+/// Parses the event from the body, or from the query string if there is none
+fn parse(request: &WebhookRequest) -> Result<WebhookEvent, WebhookError> {
+    if request.body.is_empty() {
+        return parse_form(payload(request));
+    }
+
+    let content_type = request
+        .header_value("content-type")
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    if content_type.contains("json") {
+        serde_json::from_slice(&request.body)
+            .map_err(|e| WebhookError::InvalidPayload(e.to_string()))
+    } else if content_type.is_empty() || content_type.contains("x-www-form-urlencoded") {
+        parse_form(&request.body)
+    } else {
+        Err(WebhookError::UnsupportedContentType(content_type))
+    }
+}
+
+/// Parses form-encoded fields; empty values are treated as absent
+fn parse_form(input: &[u8]) -> Result<WebhookEvent, WebhookError> {
+    let fields: serde_json::Map<String, serde_json::Value> = url::form_urlencoded::parse(input)
+        .filter(|(_, value)| !value.is_empty())
+        .map(|(name, value)| (name.into_owned(), value.into_owned().into()))
+        .collect();
+    serde_json::from_value(fields.into()).map_err(|e| WebhookError::InvalidPayload(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_receive_form_body() {
+        let request = WebhookRequest::new("POST")
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .body(
+                "action=move&interface=web&path=inbox%2Fa.txt&destination=archive%2Fa.txt\
+                 &username=jane&type=file&size=42&at=2024-01-15T10%3A30%3A00Z&custom=1",
+            );
+
+        let event = WebhookReceiver::new().receive(&request).unwrap();
+        assert_eq!(event.action, Some(WebhookAction::Move));
+        assert_eq!(event.interface, Some(WebhookInterface::Web));
+        assert_eq!(event.path.as_deref(), Some("inbox/a.txt"));
+        assert_eq!(event.destination.as_deref(), Some("archive/a.txt"));
+        assert_eq!(event.username.as_deref(), Some("jane"));
+        assert_eq!(event.size, Some(42));
+        assert!(!event.is_folder());
+        assert!(event.occurred_at().is_some());
+        assert_eq!(event.extra["custom"], "1");
+    }
+
+    #[test]
+    fn test_receive_query_string() {
+        let request = WebhookRequest::new("GET")
+            .query("action=destroy&interface=ftp&path=old&type=directory&destination=");
+
+        let event = WebhookReceiver::new().receive(&request).unwrap();
+        assert_eq!(event.action, Some(WebhookAction::Destroy));
+        assert_eq!(event.interface, Some(WebhookInterface::Ftp));
+        assert_eq!(event.destination, None);
+        assert!(event.is_folder());
+    }
+
+    #[test]
+    fn test_receive_json_body() {
+        let request = WebhookRequest::new("POST")
+            .header("content-type", "application/json; charset=utf-8")
+            .body(r#"{"action":"shred","interface":"restapi","size":"7"}"#);
+
+        let event = WebhookReceiver::new().receive(&request).unwrap();
+        assert_eq!(event.action, Some(WebhookAction::Unknown("shred".into())));
+        assert_eq!(event.interface, Some(WebhookInterface::RestApi));
+        assert_eq!(event.size, Some(7));
+    }
+
+    #[test]
+    fn test_receive_rejects_unsupported_content_type() {
+        let request = WebhookRequest::new("POST")
+            .header("Content-Type", "application/xml")
+            .body("<action>create</action>");
+
+        let err = WebhookReceiver::new().receive(&request).unwrap_err();
+        assert!(matches!(err, WebhookError::UnsupportedContentType(_)));
+        assert_eq!(err.status_code(), 415);
+    }
+
+    #[test]
+    fn test_secret_header_verification() {
+        let receiver = WebhookReceiver::new().secret_header("X-Webhook-Secret", "s3cret");
+        let request = WebhookRequest::new("GET").query("action=create");
+
+        let err = receiver.receive(&request).unwrap_err();
+        assert!(matches!(err, WebhookError::MissingHeader(_)));
+        assert_eq!(err.status_code(), 401);
+
+        let wrong = request.clone().header("x-webhook-secret", "guess");
+        assert!(matches!(
+            receiver.receive(&wrong),
+            Err(WebhookError::VerificationFailed)
+        ));
+
+        let right = request.header("x-webhook-secret", "s3cret");
+        assert!(receiver.receive(&right).is_ok());
+    }
+
+    #[test]
+    fn test_hmac_verification() {
+        let receiver = WebhookReceiver::new().hmac_sha256("X-Signature", "key");
+        let body = r#"{"action":"create"}"#;
+        let request = WebhookRequest::new("POST")
+            .header("Content-Type", "application/json")
+            .body(body);
+
+        let signed = request.clone().header(
+            "X-Signature",
+            verify::sign_hmac_sha256(b"key", body.as_bytes()),
+        );
+        assert!(receiver.receive(&signed).is_ok());
+
+        let forged = request.header("X-Signature", verify::sign_hmac_sha256(b"other", b""));
+        assert!(matches!(
+            receiver.receive(&forged),
+            Err(WebhookError::VerificationFailed)
+        ));
+    }
+
+    #[test]
+    fn test_hmac_covers_query_string_events() {
+        let receiver = WebhookReceiver::new().hmac_sha256("X-Signature", "key");
+
+        let forged = WebhookRequest::new("GET")
+            .query("action=destroy&path=inbox")
+            .header("X-Signature", verify::sign_hmac_sha256(b"key", b""));
+        assert!(matches!(
+            receiver.receive(&forged),
+            Err(WebhookError::VerificationFailed)
+        ));
+
+        let query = "action=destroy&path=inbox";
+        let signed = WebhookRequest::new("GET").query(query).header(
+            "X-Signature",
+            verify::sign_hmac_sha256(b"key", query.as_bytes()),
+        );
+        let event = receiver.receive(&signed).unwrap();
+        assert_eq!(event.action, Some(WebhookAction::Destroy));
+    }
+
+    #[test]
+    fn test_receiver_debug_hides_secrets() {
+        let receiver = WebhookReceiver::new().secret_header("X-Webhook-Secret", "s3cret");
+        assert!(!format!("{:?}", receiver).contains("s3cret"));
+    }
+}
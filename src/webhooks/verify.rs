@@ -0,0 +1,98 @@
+//! Verification helpers for incoming webhooks
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// Compares a received secret with the expected one in constant time
+///
+/// Both secrets are hashed first and the fixed-length digests are compared,
+/// so response timing reveals neither where they differ nor how long the
+/// expected secret is.
+pub fn verify_secret(expected: &str, received: &str) -> bool {
+    let digest = |secret: &str| {
+        let mut mac = Hmac::<Sha256>::new_from_slice(SECRET_DIGEST_KEY)
+            .expect("HMAC accepts keys of any length");
+        mac.update(secret.as_bytes());
+        mac
+    };
+    let expected = digest(expected).finalize().into_bytes();
+    digest(received).verify_slice(&expected).is_ok()
+}
+
+/// Key for the digests compared by [`verify_secret`]; it needs no secrecy
+const SECRET_DIGEST_KEY: &[u8] = b"files-sdk webhook secret";
+
+/// Computes the hex-encoded HMAC-SHA256 of `body`
+pub fn sign_hmac_sha256(key: &[u8], body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(body);
+    mac.finalize()
+        .into_bytes()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Checks a hex-encoded HMAC-SHA256 signature of `body`
+///
+/// The signature may carry a `sha256=` prefix. The digest comparison is
+/// constant-time.
+pub fn verify_hmac_sha256(key: &[u8], body: &[u8], signature: &str) -> bool {
+    let signature = signature.trim();
+    let signature = signature.strip_prefix("sha256=").unwrap_or(signature);
+    let Some(signature) = decode_hex(signature) else {
+        return false;
+    };
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(body);
+    mac.verify_slice(&signature).is_ok()
+}
+
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    if s.len() % 2 != 0 {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(s.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_secret() {
+        assert!(verify_secret("s3cret", "s3cret"));
+        assert!(!verify_secret("s3cret", "s3creT"));
+        assert!(!verify_secret("s3cret", "s3cre"));
+        assert!(!verify_secret("s3cret", ""));
+        assert!(!verify_secret("", "s3cret"));
+        assert!(verify_secret("", ""));
+    }
+
+    #[test]
+    fn test_hmac_sha256() {
+        // RFC 4231 test case 2
+        let signature = sign_hmac_sha256(b"Jefe", b"what do ya want for nothing?");
+        assert_eq!(
+            signature,
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+
+        assert!(verify_hmac_sha256(
+            b"Jefe",
+            b"what do ya want for nothing?",
+            &signature
+        ));
+        assert!(verify_hmac_sha256(
+            b"Jefe",
+            b"what do ya want for nothing?",
+            &format!("sha256={}", signature.to_uppercase())
+        ));
+        assert!(!verify_hmac_sha256(b"Jefe", b"tampered", &signature));
+        assert!(!verify_hmac_sha256(b"Jefe", b"", "not hex"));
+    }
+}
@@ -0,0 +1,15 @@
+//! Tests for the in-memory fake server
+//!
+//! These tests drive the SDK end-to-end against `FakeFilesServer`.
+//!
+//! Run with: cargo test --features testing --test fake
+
+#![cfg(feature = "testing")]
+
+// File, folder and lock tests
+#[path = "fake/files.rs"]
+mod files;
+
+// User, group and bundle tests
+#[path = "fake/users.rs"]
+mod users;
@@ -0,0 +1,188 @@
+//! Fake server tests for files, folders and locks
+
+use files_sdk::files::DownloadOptions;
+use files_sdk::storage::LockHandler;
+use files_sdk::testing::FakeFilesServer;
+use files_sdk::{FileHandler, FilesError, FolderHandler};
+
+fn temp_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("files-sdk-fake-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+#[tokio::test]
+async fn test_upload_and_download() {
+    let fake = FakeFilesServer::start().await;
+    let files = FileHandler::new(fake.client());
+
+    let file = files
+        .upload_file("/docs/Readme.md", b"# Hello")
+        .await
+        .unwrap();
+    assert_eq!(file.path.as_deref(), Some("docs/Readme.md"));
+    assert_eq!(file.size, Some(7));
+
+    // Paths are case-insensitive
+    let content = files.download_content("/DOCS/readme.md").await.unwrap();
+    assert_eq!(content, b"# Hello");
+    assert!(fake.exists("docs"));
+}
+
+#[tokio::test]
+async fn test_multipart_upload() {
+    let fake = FakeFilesServer::builder().part_size(4).start().await;
+    let files = FileHandler::new(fake.client());
+
+    files.upload_file("/big.bin", b"0123456789").await.unwrap();
+
+    assert_eq!(fake.file("big.bin").unwrap(), b"0123456789");
+    let part_uploads = fake
+        .received_requests()
+        .await
+        .iter()
+        .filter(|request| request.method.as_str() == "PUT")
+        .count();
+    assert_eq!(part_uploads, 3);
+}
+
+#[tokio::test]
+async fn test_ranged_download_with_checksum() {
+    let fake = FakeFilesServer::start().await;
+    fake.put_file("data.bin", "0123456789abcdefghij");
+    let files = FileHandler::new(fake.client());
+
+    let range = files.download_range("/data.bin", 5..10).await.unwrap();
+    assert_eq!(range, b"56789");
+
+    let dir = temp_dir("segments");
+    std::fs::create_dir_all(&dir).unwrap();
+    let local = dir.join("data.bin");
+    let options = DownloadOptions::default()
+        .segments(4)
+        .min_segment_size(5)
+        .verify_checksum(true);
+    files
+        .download_to_file_with_options("/data.bin", &local, &options)
+        .await
+        .unwrap();
+    assert_eq!(std::fs::read(&local).unwrap(), b"0123456789abcdefghij");
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_folder_listing_paginates() {
+    let fake = FakeFilesServer::builder().max_per_page(2).start().await;
+    for i in 0..5 {
+        fake.put_file(&format!("/reports/{}.csv", i), "x");
+    }
+    fake.create_folder("/reports/archive");
+    let folders = FolderHandler::new(fake.client());
+
+    let (page, pagination) = folders
+        .list_folder("/reports", None, None, None)
+        .await
+        .unwrap();
+    assert_eq!(page.len(), 2);
+    assert!(pagination.has_next());
+
+    let all = folders.list_folder_all("/reports").await.unwrap();
+    assert_eq!(all.len(), 6);
+    assert!(
+        all.iter()
+            .any(|entry| entry.file_type.as_deref() == Some("directory"))
+    );
+}
+
+#[tokio::test]
+async fn test_folder_create_and_delete() {
+    let fake = FakeFilesServer::start().await;
+    let folders = FolderHandler::new(fake.client());
+
+    let result = folders.create_folder("/a/b", false).await;
+    assert!(matches!(result, Err(FilesError::NotFound { .. })));
+
+    folders.create_folder("/a/b", true).await.unwrap();
+    fake.put_file("/a/b/c.txt", "c");
+
+    let result = folders.delete_folder("/a", false).await;
+    assert!(matches!(
+        result,
+        Err(FilesError::UnprocessableEntity { .. })
+    ));
+
+    folders.delete_folder("/a", true).await.unwrap();
+    assert!(fake.paths().is_empty());
+}
+
+#[tokio::test]
+async fn test_copy_and_move() {
+    let fake = FakeFilesServer::start().await;
+    fake.put_file("/src/one.txt", "1");
+    fake.put_file("/src/nested/two.txt", "2");
+    let files = FileHandler::new(fake.client());
+
+    files.copy_file("/src", "/copy").await.unwrap();
+    files
+        .move_file("/src/one.txt", "/moved/one.txt")
+        .await
+        .unwrap();
+
+    assert_eq!(fake.file("/copy/nested/two.txt").unwrap(), b"2");
+    assert_eq!(fake.file("/moved/one.txt").unwrap(), b"1");
+    assert!(!fake.exists("/src/one.txt"));
+
+    let result = files.move_file("/src/missing.txt", "/x.txt").await;
+    assert!(matches!(result, Err(FilesError::NotFound { .. })));
+}
+
+#[tokio::test]
+async fn test_locked_file_rejects_writes() {
+    let fake = FakeFilesServer::start().await;
+    fake.put_file("/shared/report.txt", "v1");
+    let files = FileHandler::new(fake.client());
+    let locks = LockHandler::new(fake.client());
+
+    let lock = locks.create("/shared/report.txt", Some(60)).await.unwrap();
+    let token = lock.token.unwrap();
+
+    let result = files.upload_file("/shared/report.txt", b"v2").await;
+    assert!(matches!(result, Err(FilesError::Locked { .. })));
+    let (listed, _) = locks
+        .list_for_path("/shared", true, None, Some(100))
+        .await
+        .unwrap();
+    assert_eq!(listed.len(), 1);
+
+    locks.delete("/shared/report.txt", &token).await.unwrap();
+    files
+        .upload_file("/shared/report.txt", b"v2")
+        .await
+        .unwrap();
+    assert_eq!(fake.file("/shared/report.txt").unwrap(), b"v2");
+}
+
+#[tokio::test]
+async fn test_directory_round_trip() {
+    let fake = FakeFilesServer::start().await;
+    let files = FileHandler::new(fake.client());
+
+    let source = temp_dir("upload");
+    std::fs::create_dir_all(source.join("sub")).unwrap();
+    std::fs::write(source.join("a.txt"), "a").unwrap();
+    std::fs::write(source.join("sub/b.txt"), "b").unwrap();
+
+    let uploaded = files
+        .upload_directory(&source, "/backup", true)
+        .await
+        .unwrap();
+    assert_eq!(uploaded.len(), 2);
+
+    let target = temp_dir("download");
+    let report = files.download_directory("/backup", &target).await.unwrap();
+    assert!(report.is_success());
+    assert_eq!(std::fs::read(target.join("sub/b.txt")).unwrap(), b"b");
+
+    std::fs::remove_dir_all(&source).unwrap();
+    std::fs::remove_dir_all(&target).unwrap();
+}
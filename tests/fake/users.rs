@@ -0,0 +1,130 @@
+//! Fake server tests for users, groups and bundles
+
+use files_sdk::testing::FakeFilesServer;
+use files_sdk::users::UserField;
+use files_sdk::{BundleHandler, FilesError, GroupHandler, ListQuery, UserHandler};
+
+#[tokio::test]
+async fn test_user_lifecycle() {
+    let fake = FakeFilesServer::builder().max_per_page(2).start().await;
+    let users = UserHandler::new(fake.client());
+
+    for name in ["carol", "alice", "bob"] {
+        users
+            .create(
+                name,
+                Some(&format!("{}@example.com", name)),
+                Some("pw"),
+                None,
+            )
+            .await
+            .unwrap();
+    }
+    let result = users.create("Alice", None, None, None).await;
+    assert!(matches!(
+        result,
+        Err(FilesError::UnprocessableEntity { .. })
+    ));
+
+    let query = ListQuery::new().sort_by(UserField::Username, files_sdk::SortDirection::Asc);
+    let all = users
+        .list_stream(None, Some(query))
+        .collect_all()
+        .await
+        .unwrap();
+    let names: Vec<_> = all.iter().filter_map(|u| u.username.clone()).collect();
+    assert_eq!(names, ["alice", "bob", "carol"]);
+
+    let id = all[0].id.unwrap();
+    let updated = users
+        .update(id, None, Some("Alice A."), None, None)
+        .await
+        .unwrap();
+    assert_eq!(updated.name.as_deref(), Some("Alice A."));
+
+    users.delete(id).await.unwrap();
+    assert!(matches!(
+        users.get(id).await,
+        Err(FilesError::NotFound { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_group_memberships() {
+    let fake = FakeFilesServer::start().await;
+    let users = UserHandler::new(fake.client());
+    let groups = GroupHandler::new(fake.client());
+
+    let alice = users
+        .create("alice", None, None, None)
+        .await
+        .unwrap()
+        .id
+        .unwrap();
+    let bob = users
+        .create("bob", None, None, None)
+        .await
+        .unwrap()
+        .id
+        .unwrap();
+
+    let group = groups
+        .create("staff", Some("Everyone"), Some(vec![alice]))
+        .await
+        .unwrap();
+    let group_id = group.id.unwrap();
+    assert_eq!(group.usernames.as_deref(), Some("alice"));
+
+    groups.add_user(group_id, bob).await.unwrap();
+    groups.remove_user(group_id, alice).await.unwrap();
+
+    let group = groups.get(group_id).await.unwrap();
+    assert_eq!(group.user_ids, Some(bob.to_string()));
+    assert_eq!(group.usernames.as_deref(), Some("bob"));
+}
+
+#[tokio::test]
+async fn test_bundles() {
+    let fake = FakeFilesServer::start().await;
+    fake.put_file("/share/report.pdf", "pdf");
+    let bundles = BundleHandler::new(fake.client());
+
+    let result = bundles
+        .create(
+            vec!["/missing".into()],
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await;
+    assert!(matches!(result, Err(FilesError::NotFound { .. })));
+
+    let bundle = bundles
+        .create(
+            vec!["/share/report.pdf".into()],
+            Some("secret"),
+            None,
+            None,
+            Some("Quarterly report"),
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(bundle.password_protected, Some(true));
+    assert!(bundle.url.unwrap().starts_with(&fake.uri()));
+
+    let (listed, _) = bundles.list(None, None, None, None).await.unwrap();
+    assert_eq!(listed.len(), 1);
+    assert!(listed[0].paths.is_none());
+
+    let fetched = bundles.get(bundle.id.unwrap()).await.unwrap();
+    assert_eq!(fetched.paths, Some(vec!["share/report.pdf".to_string()]));
+}
@@ -9,6 +9,10 @@
 #[path = "mock/mod.rs"]
 mod mock;
 
+// Client behavior tests
+#[path = "mock/client/mod.rs"]
+mod client;
+
 // File operations tests
 #[path = "mock/files/mod.rs"]
 mod files;
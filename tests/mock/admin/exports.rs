@@ -0,0 +1,195 @@
+//! Mock tests for running history and action notification exports
+
+use files_sdk::admin::export::{CancellationToken, ExportOptions};
+use files_sdk::admin::{CreateActionNotificationExportParams, HistoryAction, HistoryQuery};
+use files_sdk::timestamp::timestamp;
+use files_sdk::{ActionNotificationExportHandler, FilesClient, FilesError, HistoryHandler};
+use futures::TryStreamExt;
+use std::time::Duration;
+use wiremock::matchers::{body_partial_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn client(mock_server: &MockServer) -> FilesClient {
+    FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap()
+}
+
+fn options() -> ExportOptions {
+    ExportOptions::default().poll_interval(Duration::from_millis(5))
+}
+
+async fn mount_status(mock_server: &MockServer, endpoint: &str, status: &str, times: Option<u64>) {
+    let mock = Mock::given(method("GET")).and(path(endpoint)).respond_with(
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": 5,
+            "status": status
+        })),
+    );
+    let mock = match times {
+        Some(n) => mock.up_to_n_times(n),
+        None => mock,
+    };
+    mock.mount(mock_server).await;
+}
+
+#[tokio::test]
+async fn test_history_run_export_polls_and_pages_results() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/history_exports"))
+        .and(body_partial_json(serde_json::json!({
+            "start_at": "2024-06-01T00:00:00Z",
+            "query_action": "login"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": 5,
+            "status": "building"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    mount_status(&mock_server, "/history_exports/5", "building", Some(2)).await;
+    mount_status(&mock_server, "/history_exports/5", "ready", None).await;
+
+    Mock::given(method("GET"))
+        .and(path("/history_export_results"))
+        .and(query_param("history_export_id", "5"))
+        .and(query_param("cursor", "page2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"id": 3, "action": "login", "username": "carol"}
+        ])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/history_export_results"))
+        .and(query_param("history_export_id", "5"))
+        .and(query_param("per_page", "2"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!([
+                    {"id": 1, "action": "login", "username": "alice"},
+                    {"id": 2, "action": "login", "username": "bob"}
+                ]))
+                .insert_header("X-Files-Cursor-Next", "page2"),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let query = HistoryQuery::new()
+        .start_at(timestamp("2024-06-01T00:00:00Z").unwrap())
+        .action(HistoryAction::Login);
+    let results = HistoryHandler::new(client(&mock_server))
+        .run_export(query, options().per_page(2))
+        .collect_all()
+        .await
+        .unwrap();
+
+    let usernames: Vec<_> = results
+        .iter()
+        .map(|r| r.username.as_deref().unwrap())
+        .collect();
+    assert_eq!(usernames, ["alice", "bob", "carol"]);
+}
+
+#[tokio::test]
+async fn test_history_run_export_failed() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/history_exports"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": 5,
+            "status": "building"
+        })))
+        .mount(&mock_server)
+        .await;
+    mount_status(&mock_server, "/history_exports/5", "failed", None).await;
+
+    let result = HistoryHandler::new(client(&mock_server))
+        .run_export(HistoryQuery::new(), options())
+        .collect_all()
+        .await;
+
+    match result {
+        Err(FilesError::ExportFailed { export_id, status }) => {
+            assert_eq!(export_id, 5);
+            assert_eq!(status, "failed");
+        }
+        other => panic!("Expected ExportFailed, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_action_notification_run_export() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/action_notification_exports"))
+        .and(body_partial_json(
+            serde_json::json!({"query_success": false}),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": 5,
+            "status": "building"
+        })))
+        .mount(&mock_server)
+        .await;
+    mount_status(
+        &mock_server,
+        "/action_notification_exports/5",
+        "ready",
+        None,
+    )
+    .await;
+    Mock::given(method("GET"))
+        .and(path("/action_notification_export_results"))
+        .and(query_param("action_notification_export_id", "5"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"id": 9, "status": 500, "success": false, "request_url": "https://example.com/hook"}
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let params = CreateActionNotificationExportParams::new().query_success(false);
+    let mut stream =
+        ActionNotificationExportHandler::new(client(&mock_server)).run_export(params, options());
+
+    let result = stream.try_next().await.unwrap().unwrap();
+    assert_eq!(result.id, Some(9));
+    assert_eq!(result.success, Some(false));
+    assert!(stream.try_next().await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_run_export_cancelled_while_building() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/history_exports"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": 5,
+            "status": "building"
+        })))
+        .mount(&mock_server)
+        .await;
+    mount_status(&mock_server, "/history_exports/5", "building", None).await;
+
+    let cancel = CancellationToken::new();
+    let stream = HistoryHandler::new(client(&mock_server))
+        .run_export(HistoryQuery::new(), options().cancel_token(cancel.clone()));
+
+    let canceller = tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        cancel.cancel();
+    });
+
+    let result = tokio::time::timeout(Duration::from_secs(5), stream.collect_all())
+        .await
+        .expect("cancellation should stop polling");
+    assert!(matches!(result, Err(FilesError::Cancelled)));
+    canceller.await.unwrap();
+}
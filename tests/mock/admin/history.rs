@@ -1,9 +1,11 @@
 //! Mock tests for HistoryHandler
 
-use files_sdk::{FilesClient, HistoryHandler};
+use files_sdk::admin::{HistoryAction, HistoryField, HistoryInterface, HistoryQuery};
+use files_sdk::timestamp::timestamp;
+use files_sdk::{FilesClient, HistoryHandler, ListQuery};
 use wiremock::{
     Mock, MockServer, ResponseTemplate,
-    matchers::{method, path},
+    matchers::{body_json, method, path, query_param},
 };
 
 #[tokio::test]
@@ -41,13 +43,13 @@ async fn test_list_history_for_file() {
 
     let handler = HistoryHandler::new(client);
     let (history, _) = handler
-        .list_for_file("document.pdf", None, None)
+        .list_for_file("document.pdf", None, None, None)
         .await
         .unwrap();
 
     assert_eq!(history.len(), 2);
-    assert_eq!(history[0]["action"], "read");
-    assert_eq!(history[1]["action"], "update");
+    assert_eq!(history[0].action, Some(HistoryAction::Read));
+    assert_eq!(history[1].action, Some(HistoryAction::Update));
 }
 
 #[tokio::test]
@@ -83,12 +85,13 @@ async fn test_list_history_for_folder() {
 
     let handler = HistoryHandler::new(client);
     let (history, _) = handler
-        .list_for_folder("uploads", None, None)
+        .list_for_folder("uploads", None, None, None)
         .await
         .unwrap();
 
     assert_eq!(history.len(), 2);
-    assert_eq!(history[0]["action"], "create");
+    assert_eq!(history[0].action, Some(HistoryAction::Create));
+    assert_eq!(history[0].path.as_deref(), Some("/uploads/file1.txt"));
 }
 
 #[tokio::test]
@@ -124,11 +127,15 @@ async fn test_list_history_for_user() {
         .unwrap();
 
     let handler = HistoryHandler::new(client);
-    let (history, _) = handler.list_for_user(123, None, None).await.unwrap();
+    let (history, _) = handler.list_for_user(123, None, None, None).await.unwrap();
 
     assert_eq!(history.len(), 2);
-    assert_eq!(history[0]["action"], "login");
-    assert_eq!(history[0]["ip"], "192.168.1.100");
+    assert_eq!(history[0].action, Some(HistoryAction::Login));
+    assert_eq!(history[0].ip.as_deref(), Some("192.168.1.100"));
+    assert_eq!(
+        history[0].created_at,
+        Some(timestamp("2024-01-15T08:00:00Z").unwrap())
+    );
 }
 
 #[tokio::test]
@@ -167,11 +174,12 @@ async fn test_list_login_history() {
         .unwrap();
 
     let handler = HistoryHandler::new(client);
-    let (history, _) = handler.list_logins(None, None).await.unwrap();
+    let (history, _) = handler.list_logins(None, None, None).await.unwrap();
 
     assert_eq!(history.len(), 2);
-    assert_eq!(history[0]["success"], true);
-    assert_eq!(history[1]["success"], false);
+    assert_eq!(history[1].action, Some(HistoryAction::FailedLogin));
+    assert_eq!(history[0].extra["success"], true);
+    assert_eq!(history[1].extra["success"], false);
 }
 
 #[tokio::test]
@@ -200,8 +208,8 @@ async fn test_create_history_export() {
     let handler = HistoryHandler::new(client);
     let export = handler
         .create_export(
-            Some("2024-01-01T00:00:00Z"),
-            Some("2024-01-31T23:59:59Z"),
+            Some(timestamp("2024-01-01T00:00:00Z").unwrap()),
+            Some(timestamp("2024-01-31T23:59:59Z").unwrap()),
             Some("create,update,delete"),
             None,
             Some("/uploads"),
@@ -214,6 +222,55 @@ async fn test_create_history_export() {
     assert_eq!(export.query_folder, Some("/uploads".to_string()));
 }
 
+#[tokio::test]
+async fn test_create_export_with_query() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/history_exports"))
+        .and(body_json(serde_json::json!({
+            "start_at": "2024-01-01T00:00:00Z",
+            "end_at": "2024-01-02T00:00:00Z",
+            "query_action": "failedlogin",
+            "query_interface": "sftp",
+            "query_ip": "203.0.113.7",
+            "query_failure_type": "invalid_credentials",
+            "query_target_user_id": "7"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": 102,
+            "status": "building",
+            "query_action": "failedlogin",
+            "query_interface": "sftp",
+            "query_ip": "203.0.113.7"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let query = HistoryQuery::new()
+        .start_at(timestamp("2024-01-01T00:00:00Z").unwrap())
+        .end_at(timestamp("2024-01-02T00:00:00Z").unwrap())
+        .action(HistoryAction::FailedLogin)
+        .interface(HistoryInterface::Sftp)
+        .ip("203.0.113.7")
+        .failure_type("invalid_credentials")
+        .target_user_id(7);
+    let export = HistoryHandler::new(client)
+        .create_export_with_query(&query)
+        .await
+        .unwrap();
+
+    assert_eq!(export.id, Some(102));
+    assert_eq!(export.query_ip, Some("203.0.113.7".to_string()));
+}
+
 #[tokio::test]
 async fn test_get_history_export() {
     let mock_server = MockServer::start().await;
@@ -365,7 +422,7 @@ async fn test_list_history_authentication_failed() {
         .unwrap();
 
     let handler = HistoryHandler::new(client);
-    let result = handler.list_logins(None, None).await;
+    let result = handler.list_logins(None, None, None).await;
 
     assert!(result.is_err());
 }
@@ -394,3 +451,88 @@ async fn test_create_export_forbidden() {
 
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn test_list_history_for_user_with_date_range() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/history/users/123"))
+        .and(query_param("per_page", "50"))
+        .and(query_param(
+            "filter_gteq[created_at]",
+            "2024-01-01T00:00:00Z",
+        ))
+        .and(query_param("filter_lt[created_at]", "2024-02-01T00:00:00Z"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"id": 20, "action": "login", "user_id": 123}
+        ])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = HistoryHandler::new(client);
+    let query = ListQuery::new()
+        .filter_gteq(HistoryField::CreatedAt, "2024-01-01T00:00:00Z")
+        .filter_lt(HistoryField::CreatedAt, "2024-02-01T00:00:00Z");
+    let (history, _) = handler
+        .list_for_user(123, None, Some(50), Some(&query))
+        .await
+        .unwrap();
+
+    assert_eq!(history.len(), 1);
+}
+
+#[tokio::test]
+async fn test_list_logins_stream_typed() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/history/login"))
+        .and(query_param("cursor", "next"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"id": 2, "action": "failedlogin", "interface": "carrier_pigeon", "failure_type": "invalid_credentials"}
+        ])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/history/login"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!([
+                    {"id": 1, "action": "login", "interface": "sftp", "targets": []}
+                ]))
+                .insert_header("X-Files-Cursor-Next", "next"),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let logins = HistoryHandler::new(client)
+        .list_logins_stream(None, None)
+        .collect_all()
+        .await
+        .unwrap();
+
+    assert_eq!(logins.len(), 2);
+    assert_eq!(logins[0].interface, Some(HistoryInterface::Sftp));
+    assert_eq!(
+        logins[1].interface,
+        Some(HistoryInterface::Unknown("carrier_pigeon".to_string()))
+    );
+    assert_eq!(
+        logins[1].failure_type.as_deref(),
+        Some("invalid_credentials")
+    );
+}
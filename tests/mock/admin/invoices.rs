@@ -50,7 +50,7 @@ async fn test_list_invoices() {
         .unwrap();
 
     let handler = InvoiceHandler::new(client);
-    let (invoices, _) = handler.list(None, None).await.unwrap();
+    let (invoices, _) = handler.list(None, None, None).await.unwrap();
 
     assert_eq!(invoices.len(), 2);
     assert_eq!(invoices[0].id, Some(1));
@@ -88,7 +88,10 @@ async fn test_list_invoices_with_pagination() {
         .unwrap();
 
     let handler = InvoiceHandler::new(client);
-    let (invoices, _) = handler.list(Some("cursor123"), Some(10)).await.unwrap();
+    let (invoices, _) = handler
+        .list(Some("cursor123"), Some(10), None)
+        .await
+        .unwrap();
 
     assert_eq!(invoices.len(), 1);
     assert_eq!(invoices[0].id, Some(3));
@@ -259,7 +262,7 @@ async fn test_list_invoices_authentication_failed() {
         .unwrap();
 
     let handler = InvoiceHandler::new(client);
-    let result = handler.list(None, None).await;
+    let result = handler.list(None, None, None).await;
 
     assert!(result.is_err());
 }
@@ -1,5 +1,6 @@
 //! Mock tests for admin and monitoring features
 
+pub mod exports;
 pub mod history;
 pub mod invoices;
 pub mod payments;
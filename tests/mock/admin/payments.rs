@@ -49,7 +49,7 @@ async fn test_list_payments() {
         .unwrap();
 
     let handler = PaymentHandler::new(client);
-    let (payments, _) = handler.list(None, None).await.unwrap();
+    let (payments, _) = handler.list(None, None, None).await.unwrap();
 
     assert_eq!(payments.len(), 2);
     assert_eq!(payments[0].id, Some(1));
@@ -86,7 +86,10 @@ async fn test_list_payments_with_pagination() {
         .unwrap();
 
     let handler = PaymentHandler::new(client);
-    let (payments, _) = handler.list(Some("cursor456"), Some(10)).await.unwrap();
+    let (payments, _) = handler
+        .list(Some("cursor456"), Some(10), None)
+        .await
+        .unwrap();
 
     assert_eq!(payments.len(), 1);
     assert_eq!(payments[0].id, Some(3));
@@ -231,7 +234,7 @@ async fn test_list_payments_authentication_failed() {
         .unwrap();
 
     let handler = PaymentHandler::new(client);
-    let result = handler.list(None, None).await;
+    let result = handler.list(None, None, None).await;
 
     assert!(result.is_err());
 }
@@ -45,7 +45,7 @@ async fn test_list_automations() {
         .unwrap();
 
     let handler = AutomationHandler::new(client);
-    let (automations, _) = handler.list(None, None, None).await.unwrap();
+    let (automations, _) = handler.list(None, None, None, None).await.unwrap();
 
     assert_eq!(automations.len(), 2);
     assert_eq!(automations[0].id, Some(1));
@@ -80,7 +80,10 @@ async fn test_list_automations_with_filter() {
         .unwrap();
 
     let handler = AutomationHandler::new(client);
-    let (automations, _) = handler.list(None, None, Some("delete_file")).await.unwrap();
+    let (automations, _) = handler
+        .list(None, None, Some("delete_file"), None)
+        .await
+        .unwrap();
 
     assert_eq!(automations.len(), 1);
     assert_eq!(automations[0].automation, Some("delete_file".to_string()));
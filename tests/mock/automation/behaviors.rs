@@ -1,9 +1,10 @@
 //! Mock tests for BehaviorHandler
 
-use files_sdk::{BehaviorHandler, FilesClient};
+use files_sdk::automation::BehaviorField;
+use files_sdk::{BehaviorHandler, FilesClient, ListQuery, SortDirection};
 use wiremock::{
     Mock, MockServer, ResponseTemplate,
-    matchers::{method, path, path_regex},
+    matchers::{method, path, path_regex, query_param},
 };
 
 #[tokio::test]
@@ -40,7 +41,7 @@ async fn test_list_behaviors() {
         .unwrap();
 
     let handler = BehaviorHandler::new(client);
-    let (behaviors, _) = handler.list(None, None, None, None, None).await.unwrap();
+    let (behaviors, _) = handler.list(None, None, None).await.unwrap();
 
     assert_eq!(behaviors.len(), 2);
     assert_eq!(behaviors[0].id, Some(1));
@@ -48,6 +49,36 @@ async fn test_list_behaviors() {
     assert_eq!(behaviors[0].recursive, Some(true));
 }
 
+#[tokio::test]
+async fn test_list_behaviors_with_query() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/behaviors"))
+        .and(query_param("sort_by[behavior]", "asc"))
+        .and(query_param("filter_prefix[behavior]", "web"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"id": 1, "behavior": "webhook", "path": "/uploads"}
+        ])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = BehaviorHandler::new(client);
+    let query = ListQuery::new()
+        .sort_by(BehaviorField::Behavior, SortDirection::Asc)
+        .filter_prefix(BehaviorField::Behavior, "web");
+    let (behaviors, _) = handler.list(None, None, Some(&query)).await.unwrap();
+
+    assert_eq!(behaviors.len(), 1);
+}
+
 #[tokio::test]
 async fn test_list_behaviors_for_folder() {
     let mock_server = MockServer::start().await;
@@ -75,7 +106,7 @@ async fn test_list_behaviors_for_folder() {
 
     let handler = BehaviorHandler::new(client);
     let (behaviors, _) = handler
-        .list_for_folder("uploads", None, None)
+        .list_for_folder("uploads", None, None, None)
         .await
         .unwrap();
 
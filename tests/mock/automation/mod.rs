@@ -3,3 +3,4 @@
 pub mod automations;
 pub mod behaviors;
 pub mod remote_servers;
+pub mod syncs;
@@ -42,7 +42,7 @@ async fn test_list_remote_servers() {
         .unwrap();
 
     let handler = RemoteServerHandler::new(client);
-    let (servers, _) = handler.list(None, None).await.unwrap();
+    let (servers, _) = handler.list(None, None, None).await.unwrap();
 
     assert_eq!(servers.len(), 2);
     assert_eq!(servers[0].server_type, Some("s3".to_string()));
@@ -0,0 +1,141 @@
+//! Mock tests for SyncHandler
+
+use files_sdk::automation::{CreateSyncParams, UpdateSyncParams};
+use files_sdk::{FilesClient, SyncHandler};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_json, method, path},
+};
+
+#[tokio::test]
+async fn test_get_sync_typed_fields() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/syncs/7"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": 7,
+            "name": "Nightly backup",
+            "src_path": "/reports",
+            "dest_remote_server_id": 3,
+            "two_way": false,
+            "include_patterns": ["*.csv"],
+            "schedule_days_of_week": [1, 2, 3, 4, 5],
+            "some_new_field": "kept"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = SyncHandler::new(client);
+    let sync = handler.get(7).await.unwrap();
+
+    assert_eq!(sync.id, Some(7));
+    assert_eq!(sync.name.as_deref(), Some("Nightly backup"));
+    assert_eq!(sync.dest_remote_server_id, Some(3));
+    assert_eq!(sync.two_way, Some(false));
+    assert_eq!(sync.include_patterns, Some(vec!["*.csv".to_string()]));
+    assert_eq!(sync.schedule_days_of_week, Some(vec![1, 2, 3, 4, 5]));
+    assert_eq!(sync.extra.len(), 1);
+    assert_eq!(sync.extra["some_new_field"], "kept");
+}
+
+#[tokio::test]
+async fn test_create_sync_sends_only_set_params() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/syncs"))
+        .and(body_json(serde_json::json!({
+            "name": "Offsite copy",
+            "src_path": "/reports",
+            "dest_remote_server_id": 3,
+            "exclude_patterns": ["*.tmp"]
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": 8,
+            "name": "Offsite copy"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = SyncHandler::new(client);
+    let params = CreateSyncParams::new()
+        .name("Offsite copy")
+        .src_path("/reports")
+        .dest_remote_server_id(3)
+        .exclude_patterns(vec!["*.tmp".to_string()]);
+    let sync = handler.create(params).await.unwrap();
+
+    assert_eq!(sync.id, Some(8));
+    assert!(sync.extra.is_empty());
+}
+
+#[tokio::test]
+async fn test_update_sync() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/syncs/8"))
+        .and(body_json(serde_json::json!({ "disabled": true })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": 8,
+            "disabled": true
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = SyncHandler::new(client);
+    let sync = handler
+        .update(8, UpdateSyncParams::new().disabled(true))
+        .await
+        .unwrap();
+
+    assert_eq!(sync.disabled, Some(true));
+}
+
+#[tokio::test]
+async fn test_dry_run_sync() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/syncs/8/dry_run"))
+        .and(body_json(serde_json::json!({})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": 8,
+            "name": "Offsite copy"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = SyncHandler::new(client);
+    let sync = handler.dry_run(8).await.unwrap();
+
+    assert_eq!(sync.id, Some(8));
+}
@@ -0,0 +1,152 @@
+use async_trait::async_trait;
+use files_sdk::auth::{Auth, PasswordLogin, TokenProvider};
+use files_sdk::{FilesClient, FilesError};
+use wiremock::matchers::{body_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+struct StaticToken;
+
+#[async_trait]
+impl TokenProvider for StaticToken {
+    async fn token(&self) -> files_sdk::Result<String> {
+        Ok("oauth-token".to_string())
+    }
+}
+
+async fn mount_users(mock_server: &MockServer, header_name: &'static str, value: &'static str) {
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .and(header(header_name, value))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_login_uses_session_header() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/sessions"))
+        .and(body_json(serde_json::json!({
+            "username": "alice",
+            "password": "secret",
+            "otp": "123456"
+        })))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(serde_json::json!({"id": "session-1"})),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    mount_users(&mock_server, "X-FilesAPI-Auth", "session-1").await;
+
+    let client = FilesClient::builder()
+        .base_url(mock_server.uri())
+        .login("alice", "secret", Some("123456"))
+        .await
+        .unwrap();
+
+    client.get_raw("/users").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_bearer_token_provider() {
+    let mock_server = MockServer::start().await;
+    mount_users(&mock_server, "Authorization", "Bearer oauth-token").await;
+
+    let client = FilesClient::builder()
+        .token_provider(StaticToken)
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    client.get_raw("/users").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_set_auth_applies_to_clones() {
+    let mock_server = MockServer::start().await;
+    mount_users(&mock_server, "X-FilesAPI-Auth", "session-2").await;
+
+    let client = FilesClient::builder()
+        .api_key("test-api-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    let clone = client.clone();
+
+    client.set_auth(Auth::session("session-2"));
+    clone.get_raw("/users").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_unauthorized_refreshes_and_retries_once() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .and(header("X-FilesAPI-Auth", "expired"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/sessions"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({"id": "fresh"})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    mount_users(&mock_server, "X-FilesAPI-Auth", "fresh").await;
+
+    let client = FilesClient::builder()
+        .session_id("expired")
+        .credential_refresher(PasswordLogin::new("alice", "secret"))
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    client.get_raw("/users").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_unauthorized_after_refresh_is_returned() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/sessions"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({"id": "fresh"})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = FilesClient::builder()
+        .session_id("expired")
+        .credential_refresher(PasswordLogin::new("alice", "secret"))
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let result = client.get_raw("/users").await;
+    assert!(matches!(
+        result.unwrap_err(),
+        FilesError::AuthenticationFailed { .. }
+    ));
+}
+
+#[tokio::test]
+async fn test_build_requires_credentials() {
+    let result = FilesClient::builder().build();
+    assert!(matches!(result.unwrap_err(), FilesError::ConfigError(_)));
+}
@@ -0,0 +1,192 @@
+use files_sdk::{ErrorCategory, ErrorKind, FileHandler, FilesClient, FilesError, UserHandler};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn client(mock_server: &MockServer) -> FilesClient {
+    FilesClient::builder()
+        .api_key("test-api-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_validation_error_detail() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/users"))
+        .respond_with(
+            ResponseTemplate::new(422)
+                .insert_header("X-Request-Id", "req-123")
+                .set_body_json(serde_json::json!({
+                    "type": "processing-failure/model-save-error",
+                    "http-code": "422",
+                    "error": "Username has already been taken",
+                    "title": "Model Save Error",
+                    "detail": "The user could not be saved.",
+                    "errors": [
+                        {"type": "taken", "message": "has already been taken", "field": "username"},
+                        "Email is invalid"
+                    ],
+                    "instance": "bc14a6a9",
+                    "model": "User"
+                })),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let error = UserHandler::new(client(&mock_server))
+        .create("alice", Some("bad"), None, None)
+        .await
+        .unwrap_err();
+
+    let FilesError::UnprocessableEntity { message, field, .. } = &error else {
+        panic!("Expected UnprocessableEntity, got {:?}", error);
+    };
+    assert_eq!(message, "Username has already been taken");
+    assert_eq!(field.as_deref(), Some("username"));
+    assert_eq!(error.request_id(), Some("req-123"));
+    assert_eq!(error.kind(), ErrorKind::ModelSaveError);
+    assert_eq!(error.category(), ErrorCategory::ProcessingFailure);
+
+    let detail = error.detail().unwrap();
+    assert_eq!(detail.method, "POST");
+    assert_eq!(detail.endpoint, "/users");
+    assert_eq!(detail.status, 422);
+    assert_eq!(detail.http_code, Some(422));
+    assert_eq!(
+        detail.error_type.as_deref(),
+        Some("processing-failure/model-save-error")
+    );
+    assert_eq!(detail.title.as_deref(), Some("Model Save Error"));
+    assert_eq!(
+        detail.detail.as_deref(),
+        Some("The user could not be saved.")
+    );
+    assert_eq!(detail.instance.as_deref(), Some("bc14a6a9"));
+    assert_eq!(detail.model, Some(serde_json::json!("User")));
+    assert_eq!(detail.errors.len(), 2);
+    assert_eq!(detail.errors[0].error_type.as_deref(), Some("taken"));
+    assert_eq!(
+        detail.errors[1].message.as_deref(),
+        Some("Email is invalid")
+    );
+    assert_eq!(detail.errors[1].field, None);
+    assert_eq!(
+        detail.to_string(),
+        "POST /users returned 422 (processing-failure/model-save-error), request id req-123"
+    );
+}
+
+#[tokio::test]
+async fn test_server_error_records_request_id() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/file_actions/metadata/reports/q3.pdf"))
+        .respond_with(
+            ResponseTemplate::new(500)
+                .insert_header("X-Request-Id", "req-500")
+                .set_body_string("upstream exploded"),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let error = FileHandler::new(client(&mock_server))
+        .get_metadata("/reports/q3.pdf")
+        .await
+        .unwrap_err();
+
+    let FilesError::InternalServerError {
+        message,
+        request_id,
+        ..
+    } = &error
+    else {
+        panic!("Expected InternalServerError, got {:?}", error);
+    };
+    assert_eq!(message, "upstream exploded");
+    assert_eq!(request_id.as_deref(), Some("req-500"));
+
+    let detail = error.detail().unwrap();
+    assert_eq!(detail.method, "POST");
+    assert_eq!(detail.endpoint, "/file_actions/metadata/reports/q3.pdf");
+    assert_eq!(detail.body, "upstream exploded");
+    assert_eq!(detail.error_type, None);
+}
+
+#[tokio::test]
+async fn test_unmapped_status_records_endpoint() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/users/7"))
+        .respond_with(ResponseTemplate::new(418).set_body_json(serde_json::json!({
+            "type": "teapot",
+            "title": "I'm a teapot"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let error = UserHandler::new(client(&mock_server))
+        .delete(7)
+        .await
+        .unwrap_err();
+
+    let FilesError::ApiError {
+        code,
+        message,
+        endpoint,
+        ..
+    } = &error
+    else {
+        panic!("Expected ApiError, got {:?}", error);
+    };
+    assert_eq!(*code, 418);
+    assert_eq!(message, "I'm a teapot");
+    assert_eq!(endpoint.as_deref(), Some("/users/7"));
+    assert_eq!(error.request_id(), None);
+    assert_eq!(error.kind(), ErrorKind::Unknown);
+    assert_eq!(error.category(), ErrorCategory::Unknown);
+}
+
+#[tokio::test]
+async fn test_kind_distinguishes_errors_with_same_status() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/file_actions/move/inbox/a.txt"))
+        .respond_with(ResponseTemplate::new(422).set_body_json(serde_json::json!({
+            "type": "processing-failure/destination-exists",
+            "http-code": 422,
+            "error": "The destination exists."
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/file_actions/move/inbox/b.txt"))
+        .respond_with(ResponseTemplate::new(422).set_body_json(serde_json::json!({
+            "type": "processing-failure/invalid-filename",
+            "http-code": 422,
+            "error": "The filename is invalid."
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let files = FileHandler::new(client(&mock_server));
+    let exists = files
+        .move_file("/inbox/a.txt", "/archive/a.txt")
+        .await
+        .unwrap_err();
+    let invalid = files
+        .move_file("/inbox/b.txt", "/archive/b:?.txt")
+        .await
+        .unwrap_err();
+
+    assert_eq!(exists.status_code(), invalid.status_code());
+    assert_eq!(exists.kind(), ErrorKind::DestinationExists);
+    assert_eq!(invalid.kind(), ErrorKind::InvalidFilename);
+    assert_eq!(FilesError::not_found("local").kind(), ErrorKind::Unknown);
+}
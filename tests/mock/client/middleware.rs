@@ -0,0 +1,95 @@
+use files_sdk::middleware::{HttpRequest, HttpService};
+use files_sdk::{FilesClient, FilesError};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tower::timeout::TimeoutLayer;
+use tower::{ServiceExt, layer::layer_fn, service_fn};
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_layers_see_every_request() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .and(header("X-Layer", "outer"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let seen = Arc::new(AtomicUsize::new(0));
+    let counter = seen.clone();
+
+    let tagging = layer_fn(move |inner: HttpService| {
+        let counter = counter.clone();
+        service_fn(move |mut request: HttpRequest| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            request
+                .headers_mut()
+                .insert("X-Layer", "outer".parse().unwrap());
+            inner.clone().oneshot(request)
+        })
+    });
+
+    let client = FilesClient::builder()
+        .api_key("test-api-key")
+        .base_url(mock_server.uri())
+        .layer(tagging)
+        .build()
+        .unwrap();
+
+    client.get_raw("/users").await.unwrap();
+    client.clone().get_raw("/users").await.unwrap();
+    assert_eq!(seen.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_layer_error_is_surfaced() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_millis(500)))
+        .mount(&mock_server)
+        .await;
+
+    let client = FilesClient::builder()
+        .api_key("test-api-key")
+        .base_url(mock_server.uri())
+        .layer(TimeoutLayer::new(Duration::from_millis(20)))
+        .build()
+        .unwrap();
+
+    let error = client.get_raw("/users").await.unwrap_err();
+    assert!(matches!(error, FilesError::Middleware(_)));
+}
+
+#[tokio::test]
+async fn test_error_endpoint_with_layer() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/users/42"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "type": "not-found",
+            "title": "Not Found",
+            "http-code": 404
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = FilesClient::builder()
+        .api_key("test-api-key")
+        .base_url(mock_server.uri())
+        .layer(TimeoutLayer::new(Duration::from_secs(5)))
+        .build()
+        .unwrap();
+
+    let error = client.get_raw("/users/42").await.unwrap_err();
+    let detail = error.detail().unwrap();
+    assert_eq!(detail.method, "GET");
+    assert_eq!(detail.endpoint, "/users/42");
+}
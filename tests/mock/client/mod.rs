@@ -0,0 +1,10 @@
+//! Mock tests for client-level behavior
+
+pub mod auth;
+pub mod errors;
+#[cfg(feature = "tower")]
+pub mod middleware;
+pub mod ops;
+pub mod pagination;
+pub mod rate_limit;
+pub mod retry;
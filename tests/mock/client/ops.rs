@@ -0,0 +1,121 @@
+//! Mock tests for the handler traits
+
+use files_sdk::{
+    FileHandler, FileOps, FilesClient, FilesError, FolderHandler, FolderOps, UserHandler, UserOps,
+};
+use std::time::Duration;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn client(mock_server: &MockServer) -> FilesClient {
+    FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap()
+}
+
+/// Application code written against the traits
+async fn archive_if_present<F: FileOps, D: FolderOps>(
+    files: &F,
+    folders: &D,
+    name: &str,
+) -> files_sdk::Result<bool> {
+    let listing = folders.list_folder_all("/reports").await?;
+    if !listing
+        .iter()
+        .any(|entry| entry.path.as_deref() == Some(&format!("reports/{}", name)))
+    {
+        return Ok(false);
+    }
+    files
+        .move_file(
+            &format!("/reports/{}", name),
+            &format!("/archive/{}", name),
+            Duration::from_secs(60),
+        )
+        .await?;
+    Ok(true)
+}
+
+#[tokio::test]
+async fn test_handlers_implement_traits() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/folders/reports"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"path": "reports/q3.pdf", "type": "file"}
+        ])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/file_actions/move/reports/q3.pdf"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = client(&mock_server);
+    let moved = archive_if_present(
+        &FileHandler::new(client.clone()),
+        &FolderHandler::new(client),
+        "q3.pdf",
+    )
+    .await
+    .unwrap();
+    assert!(moved);
+}
+
+#[tokio::test]
+async fn test_file_ops_migration_wait_is_bounded() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/file_actions/copy/reports"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "file_migration_id": 5,
+            "status": "pending"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/file_migrations/5"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({"id": 5, "status": "processing"})),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let files = FileHandler::new(client(&mock_server));
+    let result =
+        FileOps::copy_file(&files, "/reports", "/archive", Duration::from_millis(100)).await;
+
+    match result {
+        Err(FilesError::MigrationTimedOut { migration_id, .. }) => assert_eq!(migration_id, 5),
+        other => panic!("Expected MigrationTimedOut, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_user_handler_as_trait_object() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/users/7"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({"id": 7, "username": "alice"})),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let users: Box<dyn UserOps> = Box::new(UserHandler::new(client(&mock_server)));
+    let user = users.get(7).await.unwrap();
+    assert_eq!(user.username.as_deref(), Some("alice"));
+}
@@ -0,0 +1,123 @@
+use files_sdk::{FilesClient, FilesError, FolderHandler, SyncLogHandler};
+use futures::TryStreamExt;
+use wiremock::matchers::{
+    header, header_exists, method, path, query_param, query_param_is_missing,
+};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn client(mock_server: &MockServer) -> FilesClient {
+    FilesClient::builder()
+        .api_key("test-api-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_get_paginated_returns_cursors() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .and(header("X-FilesAPI-Key", "test-api-key"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!([{"id": 1}]))
+                .insert_header("X-Files-Cursor-Next", "next")
+                .insert_header("X-Files-Cursor-Prev", "prev"),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let (body, pagination) = client(&mock_server).get_paginated("/users").await.unwrap();
+
+    assert_eq!(body[0]["id"], 1);
+    assert_eq!(pagination.cursor_next.as_deref(), Some("next"));
+    assert_eq!(pagination.cursor_prev.as_deref(), Some("prev"));
+}
+
+#[tokio::test]
+async fn test_list_folder_sends_user_agent() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/folders/docs"))
+        .and(query_param("per_page", "10"))
+        .and(header_exists("User-Agent"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!([{"path": "docs/a.txt", "type": "file"}]))
+                .insert_header("X-Files-Cursor-Next", "cursor-2"),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let handler = FolderHandler::new(client(&mock_server));
+    let (files, pagination) = handler
+        .list_folder("/docs", Some(10), None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(files.len(), 1);
+    assert_eq!(pagination.cursor_next.as_deref(), Some("cursor-2"));
+}
+
+#[tokio::test]
+async fn test_search_folder_stream_follows_cursor() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/folders/docs"))
+        .and(query_param("search", "q3 report"))
+        .and(query_param_is_missing("cursor"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!([{"path": "docs/a.txt", "type": "file"}]))
+                .insert_header("X-Files-Cursor-Next", "cursor-2"),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/folders/docs"))
+        .and(query_param("search", "q3 report"))
+        .and(query_param("cursor", "cursor-2"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!([{"path": "docs/b.txt", "type": "file"}])),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let handler = FolderHandler::new(client(&mock_server));
+    let files: Vec<_> = handler
+        .search_folder_stream("/docs", "q3 report", Some(1))
+        .try_collect()
+        .await
+        .unwrap();
+
+    let paths: Vec<_> = files.iter().filter_map(|f| f.path.as_deref()).collect();
+    assert_eq!(paths, ["docs/a.txt", "docs/b.txt"]);
+}
+
+#[tokio::test]
+async fn test_list_errors_are_typed() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/sync_logs"))
+        .respond_with(
+            ResponseTemplate::new(401)
+                .set_body_json(serde_json::json!({"error": "Invalid API key"})),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let handler = SyncLogHandler::new(client(&mock_server));
+    let error = handler.list(None, None, None).await.unwrap_err();
+
+    assert!(matches!(error, FilesError::AuthenticationFailed { .. }));
+}
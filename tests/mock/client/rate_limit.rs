@@ -0,0 +1,122 @@
+use files_sdk::{FilesClient, FilesError, RateLimit, RetryPolicy, UserHandler};
+use std::time::{Duration, Instant};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn builder(mock_server: &MockServer) -> files_sdk::FilesClientBuilder {
+    FilesClient::builder()
+        .api_key("test-api-key")
+        .base_url(mock_server.uri())
+}
+
+#[tokio::test]
+async fn test_parallel_requests_are_throttled() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/users"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({"id": 1})))
+        .expect(6)
+        .mount(&mock_server)
+        .await;
+
+    let client = builder(&mock_server)
+        .rate_limit(RateLimit::per_second(20.0).burst(2))
+        .build()
+        .unwrap();
+    let users = UserHandler::new(client.clone());
+
+    let started = Instant::now();
+    let results = futures::future::join_all((0..6).map(|i| {
+        let users = users.clone();
+        async move { users.create(&format!("user{}", i), None, None, None).await }
+    }))
+    .await;
+    assert!(results.iter().all(|r| r.is_ok()));
+
+    // Two requests from the burst, then four at 50ms intervals
+    assert!(started.elapsed() >= Duration::from_millis(190));
+    let stats = client.rate_limit_stats();
+    assert_eq!(stats.requests, 6);
+    assert_eq!(stats.throttled, 4);
+    assert_eq!(stats.rate_limited, 0);
+    assert_eq!(stats.current_rate, Some(20.0));
+}
+
+#[tokio::test]
+async fn test_429_pauses_for_retry_after() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "1"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = builder(&mock_server)
+        .rate_limit(RateLimit::per_second(100.0).burst(10))
+        .build()
+        .unwrap();
+
+    let result = client.get_raw("/users").await;
+    assert!(matches!(
+        result,
+        Err(FilesError::RateLimited {
+            retry_after: Some(1),
+            ..
+        })
+    ));
+    assert_eq!(client.rate_limit_stats().current_rate, Some(50.0));
+
+    // The next request waits out the pause even without a retry policy
+    let started = Instant::now();
+    client.get_raw("/users").await.unwrap();
+    assert!(started.elapsed() >= Duration::from_millis(900));
+
+    let stats = client.rate_limit_stats();
+    assert_eq!(stats.rate_limited, 1);
+    assert_eq!(stats.throttled, 1);
+    assert_eq!(stats.current_rate, Some(55.0));
+}
+
+#[tokio::test]
+async fn test_retried_429_is_counted_without_limiter() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = builder(&mock_server)
+        .retry_policy(RetryPolicy::default())
+        .build()
+        .unwrap();
+
+    client.get_raw("/users").await.unwrap();
+
+    let stats = client.rate_limit_stats();
+    assert_eq!(stats.requests, 3);
+    assert_eq!(stats.rate_limited, 2);
+    assert_eq!(stats.throttled, 0);
+    assert_eq!(stats.current_rate, None);
+}
@@ -0,0 +1,128 @@
+use files_sdk::{FilesClient, FilesError, RetryPolicy};
+use std::time::Duration;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn client(mock_server: &MockServer, policy: RetryPolicy) -> FilesClient {
+    FilesClient::builder()
+        .api_key("test-api-key")
+        .base_url(mock_server.uri())
+        .retry_policy(policy)
+        .build()
+        .unwrap()
+}
+
+fn fast_policy() -> RetryPolicy {
+    RetryPolicy::default()
+        .initial_backoff(Duration::from_millis(1))
+        .max_backoff(Duration::from_millis(5))
+}
+
+#[tokio::test]
+async fn test_get_retries_until_success() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let result = client(&mock_server, fast_policy()).get_raw("/users").await;
+    assert_eq!(result.unwrap(), serde_json::json!([]));
+}
+
+#[tokio::test]
+async fn test_gives_up_after_max_attempts() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let result = client(&mock_server, fast_policy().max_attempts(2))
+        .get_raw("/users")
+        .await;
+    assert!(matches!(
+        result.unwrap_err(),
+        FilesError::InternalServerError { .. }
+    ));
+}
+
+#[tokio::test]
+async fn test_post_not_retried_by_default() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/users"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let result = client(&mock_server, fast_policy())
+        .post_raw("/users", serde_json::json!({"username": "new"}))
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_post_retried_when_allowed() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/users"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/users"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"id": 1})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let policy = fast_policy().allow_method(reqwest::Method::POST);
+    let result = client(&mock_server, policy)
+        .post_raw("/users", serde_json::json!({"username": "new"}))
+        .await;
+    assert_eq!(result.unwrap()["id"], 1);
+}
+
+#[tokio::test]
+async fn test_retry_after_header_parsed() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "30"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // Without a retry policy the error is returned immediately
+    let client = FilesClient::builder()
+        .api_key("test-api-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let error = client.get_raw("/users").await.unwrap_err();
+    assert!(matches!(error, FilesError::RateLimited { .. }));
+    assert_eq!(error.retry_after(), Some(30));
+}
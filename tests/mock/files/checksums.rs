@@ -0,0 +1,414 @@
+//! Mock tests for checksum verification of uploads and downloads
+
+use files_sdk::files::{ChecksumAlgorithm, DownloadOptions, UploadCheckpoint, UploadOptions};
+use files_sdk::{FileHandler, FilesClient, FilesError};
+use std::time::Duration;
+use wiremock::matchers::{body_partial_json, body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const CONTENT: &[u8] = b"hello world";
+const MD5: &str = "5eb63bbbe01eeed093cb22bb8f5acdc3";
+const SHA256: &str = "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9";
+
+fn handler(mock_server: &MockServer) -> FileHandler {
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    FileHandler::new(client)
+}
+
+/// Serves `CONTENT` as `/report.txt` with the given checksum fields
+async fn mount_download(mock_server: &MockServer, checksums: serde_json::Value) {
+    let mut metadata = serde_json::json!({
+        "path": "report.txt",
+        "type": "file",
+        "size": CONTENT.len(),
+        "download_uri": format!("{}/dl/report", mock_server.uri())
+    });
+    metadata
+        .as_object_mut()
+        .unwrap()
+        .extend(checksums.as_object().unwrap().clone());
+
+    Mock::given(method("GET"))
+        .and(path("/files/report.txt"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(metadata))
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/dl/report"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(CONTENT))
+        .mount(mock_server)
+        .await;
+}
+
+/// Mounts a single-part upload of `/report.txt` finalized with `checksums`
+async fn mount_upload(mock_server: &MockServer, checksums: serde_json::Value) {
+    Mock::given(method("POST"))
+        .and(path("/file_actions/begin_upload/report.txt"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "upload_uri": format!("{}/s3/report", mock_server.uri()),
+            "http_method": "PUT",
+            "part_number": 1,
+            "partsize": 5242880,
+            "ref": "upload-ref"
+        })))
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/s3/report"))
+        .respond_with(ResponseTemplate::new(200).insert_header("ETag", "\"etag\""))
+        .mount(mock_server)
+        .await;
+
+    let mut entity = serde_json::json!({"path": "report.txt", "type": "file", "size": 11});
+    entity
+        .as_object_mut()
+        .unwrap()
+        .extend(checksums.as_object().unwrap().clone());
+
+    Mock::given(method("POST"))
+        .and(path("/files/report.txt"))
+        .and(body_string_contains("action=end"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(entity))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_download_verifies_checksum() {
+    let mock_server = MockServer::start().await;
+    mount_download(
+        &mock_server,
+        serde_json::json!({"md5": MD5, "sha256": SHA256}),
+    )
+    .await;
+
+    let options = DownloadOptions::default().verify_checksum(true);
+    let mut buffer = Vec::new();
+    handler(&mock_server)
+        .download_stream_with_options("/report.txt", &mut buffer, None, &options)
+        .await
+        .unwrap();
+
+    assert_eq!(buffer, CONTENT);
+}
+
+#[tokio::test]
+async fn test_download_checksum_mismatch() {
+    let mock_server = MockServer::start().await;
+    mount_download(
+        &mock_server,
+        serde_json::json!({"md5": "00000000000000000000000000000000"}),
+    )
+    .await;
+
+    let options = DownloadOptions::default().verify_checksum(true);
+    let mut buffer = Vec::new();
+    let result = handler(&mock_server)
+        .download_stream_with_options("/report.txt", &mut buffer, None, &options)
+        .await;
+
+    match result {
+        Err(FilesError::ChecksumMismatch {
+            expected,
+            actual,
+            algorithm,
+        }) => {
+            assert_eq!(expected, "00000000000000000000000000000000");
+            assert_eq!(actual, MD5);
+            assert_eq!(algorithm, ChecksumAlgorithm::Md5);
+        }
+        other => panic!("Expected ChecksumMismatch, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_download_without_verification_ignores_checksum() {
+    let mock_server = MockServer::start().await;
+    mount_download(&mock_server, serde_json::json!({"crc32": "deadbeef"})).await;
+
+    let mut buffer = Vec::new();
+    handler(&mock_server)
+        .download_stream("/report.txt", &mut buffer, None)
+        .await
+        .unwrap();
+
+    assert_eq!(buffer, CONTENT);
+}
+
+#[tokio::test]
+async fn test_download_without_server_checksum_fails() {
+    let mock_server = MockServer::start().await;
+    mount_download(&mock_server, serde_json::json!({})).await;
+
+    let options = DownloadOptions::default().verify_checksum(true);
+    let mut buffer = Vec::new();
+    let result = handler(&mock_server)
+        .download_stream_with_options("/report.txt", &mut buffer, None, &options)
+        .await;
+
+    match result {
+        Err(FilesError::ChecksumUnavailable { path }) => assert_eq!(path, "/report.txt"),
+        other => panic!("Expected ChecksumUnavailable, got {:?}", other),
+    }
+    assert!(buffer.is_empty());
+}
+
+#[tokio::test]
+async fn test_download_to_file_removes_corrupted_file() {
+    let mock_server = MockServer::start().await;
+    mount_download(&mock_server, serde_json::json!({"crc32": "deadbeef"})).await;
+
+    let local = std::env::temp_dir().join(format!("files-sdk-checksum-{}", std::process::id()));
+    let options = DownloadOptions::default().verify_checksum(true);
+    let result = handler(&mock_server)
+        .download_to_file_with_options("/report.txt", &local, &options)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(FilesError::ChecksumMismatch {
+            algorithm: ChecksumAlgorithm::Crc32,
+            ..
+        })
+    ));
+    assert!(!local.exists());
+}
+
+#[tokio::test]
+async fn test_upload_verifies_checksum() {
+    let mock_server = MockServer::start().await;
+    mount_upload(&mock_server, serde_json::json!({"sha256": SHA256})).await;
+
+    let options = UploadOptions::default().verify_checksum(true);
+    let file = handler(&mock_server)
+        .upload_stream_with_options("/report.txt", CONTENT, Some(11), None, &options)
+        .await
+        .unwrap();
+
+    assert_eq!(file.sha256.as_deref(), Some(SHA256));
+}
+
+#[tokio::test]
+async fn test_upload_checksum_mismatch() {
+    let mock_server = MockServer::start().await;
+    mount_upload(
+        &mock_server,
+        serde_json::json!({"md5": "00000000000000000000000000000000"}),
+    )
+    .await;
+
+    let options = UploadOptions::default().verify_checksum(true);
+    let result = handler(&mock_server)
+        .upload_stream_with_options("/report.txt", CONTENT, Some(11), None, &options)
+        .await;
+
+    match result {
+        Err(FilesError::ChecksumMismatch {
+            actual, algorithm, ..
+        }) => {
+            assert_eq!(actual, MD5);
+            assert_eq!(algorithm, ChecksumAlgorithm::Md5);
+        }
+        other => panic!("Expected ChecksumMismatch, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_upload_polls_for_checksum_after_finalize() {
+    let mock_server = MockServer::start().await;
+    mount_upload(&mock_server, serde_json::json!({})).await;
+
+    // The checksum only shows up on the second metadata request
+    Mock::given(method("POST"))
+        .and(path("/file_actions/metadata/report.txt"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({"path": "report.txt", "type": "file"})),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/file_actions/metadata/report.txt"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "path": "report.txt",
+            "type": "file",
+            "size": 11,
+            "md5": MD5
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let options = UploadOptions::default().verify_checksum(true);
+    let file = handler(&mock_server)
+        .upload_stream_with_options("/report.txt", CONTENT, Some(11), None, &options)
+        .await
+        .unwrap();
+
+    assert_eq!(file.md5.as_deref(), Some(MD5));
+}
+
+#[tokio::test]
+async fn test_upload_without_server_checksum_fails() {
+    let mock_server = MockServer::start().await;
+    mount_upload(&mock_server, serde_json::json!({})).await;
+
+    Mock::given(method("POST"))
+        .and(path("/file_actions/metadata/report.txt"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({"path": "report.txt", "type": "file"})),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let options = UploadOptions::default()
+        .verify_checksum(true)
+        .checksum_wait(Duration::from_millis(300));
+    let result = handler(&mock_server)
+        .upload_stream_with_options("/report.txt", CONTENT, Some(11), None, &options)
+        .await;
+
+    match result {
+        Err(FilesError::ChecksumUnavailable { path }) => assert_eq!(path, "/report.txt"),
+        other => panic!("Expected ChecksumUnavailable, got {:?}", other),
+    }
+
+    let polls = mock_server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .filter(|r| r.url.path() == "/file_actions/metadata/report.txt")
+        .count();
+    assert!(polls >= 2, "metadata fetched {} times", polls);
+}
+
+/// Mounts the remaining part and finalization of an upload of `/report.txt`
+/// interrupted after its first 4-byte part
+async fn mount_resumed_upload(mock_server: &MockServer, checksums: serde_json::Value) {
+    Mock::given(method("POST"))
+        .and(path("/file_actions/begin_upload/report.txt"))
+        .and(body_partial_json(
+            serde_json::json!({"ref": "upload-ref", "part": 2}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "upload_uri": format!("{}/s3/part2", mock_server.uri()),
+            "http_method": "PUT",
+            "part_number": 2,
+            "partsize": 4,
+            "next_partsize": 4,
+            "ref": "upload-ref"
+        })))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/file_actions/begin_upload/report.txt"))
+        .and(body_partial_json(
+            serde_json::json!({"ref": "upload-ref", "part": 3}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "upload_uri": format!("{}/s3/part3", mock_server.uri()),
+            "http_method": "PUT",
+            "part_number": 3,
+            "partsize": 4,
+            "ref": "upload-ref"
+        })))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .respond_with(ResponseTemplate::new(200).insert_header("ETag", "\"etag\""))
+        .mount(mock_server)
+        .await;
+
+    let mut entity = serde_json::json!({"path": "report.txt", "type": "file", "size": 11});
+    entity
+        .as_object_mut()
+        .unwrap()
+        .extend(checksums.as_object().unwrap().clone());
+
+    Mock::given(method("POST"))
+        .and(path("/files/report.txt"))
+        .and(body_string_contains("action=end"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(entity))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+}
+
+fn interrupted_checkpoint() -> UploadCheckpoint {
+    serde_json::from_value(serde_json::json!({
+        "path": "/report.txt",
+        "ref": "upload-ref",
+        "completed_parts": [{"part_number": 1, "etag": "etag-1", "size": 4}],
+        "offset": 4,
+        "fingerprint": "fp"
+    }))
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_resumed_upload_verifies_whole_file() {
+    let mock_server = MockServer::start().await;
+    mount_resumed_upload(&mock_server, serde_json::json!({"md5": MD5})).await;
+
+    let options = UploadOptions::default().verify_checksum(true);
+    let file = handler(&mock_server)
+        .upload_stream_resumable_with_options(
+            "/report.txt",
+            std::io::Cursor::new(CONTENT.to_vec()),
+            11,
+            interrupted_checkpoint(),
+            &options,
+            |_| Ok(()),
+        )
+        .await
+        .unwrap();
+    assert_eq!(file.md5.as_deref(), Some(MD5));
+
+    // Only the bytes after the checkpoint are uploaded again
+    let uploaded: usize = mock_server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .filter(|r| r.method.as_str() == "PUT")
+        .map(|r| r.body.len())
+        .sum();
+    assert_eq!(uploaded, CONTENT.len() - 4);
+}
+
+#[tokio::test]
+async fn test_resumed_upload_detects_changed_prefix() {
+    let mock_server = MockServer::start().await;
+    mount_resumed_upload(&mock_server, serde_json::json!({"md5": MD5})).await;
+
+    // The first four bytes differ from what was uploaded before the interruption
+    let options = UploadOptions::default().verify_checksum(true);
+    let result = handler(&mock_server)
+        .upload_stream_resumable_with_options(
+            "/report.txt",
+            std::io::Cursor::new(b"HELLO world".to_vec()),
+            11,
+            interrupted_checkpoint(),
+            &options,
+            |_| Ok(()),
+        )
+        .await;
+
+    assert!(matches!(result, Err(FilesError::ChecksumMismatch { .. })));
+}
@@ -0,0 +1,220 @@
+//! Mock tests for directory downloads
+
+use files_sdk::files::{DirectoryDownloadOptions, SkipReason};
+use files_sdk::{FileHandler, FilesClient, FilesError, FolderHandler};
+use std::path::PathBuf;
+use std::time::{Duration, UNIX_EPOCH};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Returns a fresh, not yet created local directory
+fn local_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("files-sdk-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+async fn mount_folder(mock_server: &MockServer, folder: &str, entries: serde_json::Value) {
+    Mock::given(method("GET"))
+        .and(path(format!("/folders/{}", folder)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(entries))
+        .mount(mock_server)
+        .await;
+}
+
+/// Mounts the metadata and content of a remote file and returns its listing entry
+async fn mount_file(
+    mock_server: &MockServer,
+    remote: &str,
+    content: &str,
+    expected_downloads: u64,
+) -> serde_json::Value {
+    let id = remote.replace('/', "_");
+    let entry = serde_json::json!({
+        "path": remote,
+        "type": "file",
+        "size": content.len(),
+        "md5": md5_of(content),
+        "mtime": "2024-01-15T10:00:00Z",
+        "provided_mtime": "2023-06-01T00:00:00Z"
+    });
+
+    let mut metadata = entry.clone();
+    metadata["download_uri"] = format!("{}/dl/{}", mock_server.uri(), id).into();
+    Mock::given(method("GET"))
+        .and(path(format!("/files/{}", remote)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(metadata))
+        .expect(expected_downloads)
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/dl/{}", id)))
+        .respond_with(ResponseTemplate::new(200).set_body_string(content))
+        .expect(expected_downloads)
+        .mount(mock_server)
+        .await;
+
+    entry
+}
+
+/// Known MD5 digests of the contents used below
+fn md5_of(content: &str) -> &'static str {
+    match content {
+        "hello" => "5d41402abc4b2a76b9719d911017c592",
+        "world" => "7d793037a0760186574b0282f2f435e7",
+        other => panic!("no digest for {:?}", other),
+    }
+}
+
+fn client(mock_server: &MockServer) -> FilesClient {
+    FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_download_directory_mirrors_tree() {
+    let mock_server = MockServer::start().await;
+    let dir = local_dir("dir-download");
+
+    let a = mount_file(&mock_server, "src/a.txt", "hello", 1).await;
+    let b = mount_file(&mock_server, "src/sub/b.txt", "world", 1).await;
+    mount_folder(
+        &mock_server,
+        "src",
+        serde_json::json!([
+            a,
+            {"path": "src/sub", "type": "directory"},
+            {"path": "src/empty", "type": "directory"}
+        ]),
+    )
+    .await;
+    mount_folder(&mock_server, "src/sub", serde_json::json!([b])).await;
+    mount_folder(&mock_server, "src/empty", serde_json::json!([])).await;
+
+    let report = FileHandler::new(client(&mock_server))
+        .download_directory("/src", &dir)
+        .await
+        .unwrap();
+
+    assert!(report.is_success());
+    assert_eq!(report.downloaded.len(), 2);
+    assert_eq!(report.bytes_downloaded(), 10);
+    assert_eq!(report.downloaded[0].remote_path, "/src/a.txt");
+    assert_eq!(std::fs::read_to_string(dir.join("a.txt")).unwrap(), "hello");
+    assert_eq!(
+        std::fs::read_to_string(dir.join("sub").join("b.txt")).unwrap(),
+        "world"
+    );
+    assert!(dir.join("empty").is_dir());
+    assert!(!dir.join("a.txt.part").exists());
+
+    // provided_mtime takes precedence over mtime
+    let modified = std::fs::metadata(dir.join("a.txt"))
+        .unwrap()
+        .modified()
+        .unwrap();
+    assert_eq!(modified, UNIX_EPOCH + Duration::from_secs(1_685_577_600));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_download_directory_skips_unchanged_files() {
+    let mock_server = MockServer::start().await;
+    let dir = local_dir("dir-download-skip");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("same.txt"), "hello").unwrap();
+    std::fs::write(dir.join("changed.txt"), "hellx").unwrap();
+
+    let same = mount_file(&mock_server, "src/same.txt", "hello", 0).await;
+    let changed = mount_file(&mock_server, "src/changed.txt", "world", 1).await;
+    mount_folder(&mock_server, "src", serde_json::json!([same, changed])).await;
+
+    let options = DirectoryDownloadOptions::default().skip_unchanged(true);
+    let report = FolderHandler::new(client(&mock_server))
+        .download_directory("/src", &dir, &options)
+        .await
+        .unwrap();
+
+    assert_eq!(report.downloaded.len(), 1);
+    assert_eq!(report.downloaded[0].local_path, dir.join("changed.txt"));
+    assert_eq!(report.skipped.len(), 1);
+    assert_eq!(report.skipped[0].local_path, dir.join("same.txt"));
+    assert_eq!(report.skipped[0].reason, SkipReason::Unchanged);
+    assert_eq!(
+        std::fs::read_to_string(dir.join("changed.txt")).unwrap(),
+        "world"
+    );
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_download_directory_reports_failed_files() {
+    let mock_server = MockServer::start().await;
+    let dir = local_dir("dir-download-failed");
+
+    let ok = mount_file(&mock_server, "src/ok.txt", "hello", 1).await;
+    Mock::given(method("GET"))
+        .and(path("/files/src/gone.txt"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "error": "Not Found",
+            "http-code": 404
+        })))
+        .mount(&mock_server)
+        .await;
+    mount_folder(
+        &mock_server,
+        "src",
+        serde_json::json!([ok, {"path": "src/gone.txt", "type": "file", "size": 3}]),
+    )
+    .await;
+
+    let report = FileHandler::new(client(&mock_server))
+        .download_directory("/src", &dir)
+        .await
+        .unwrap();
+
+    assert!(!report.is_success());
+    assert_eq!(report.downloaded.len(), 1);
+    assert_eq!(report.failed.len(), 1);
+    assert_eq!(
+        report.failed[0].remote_path.as_deref(),
+        Some("/src/gone.txt")
+    );
+    assert!(matches!(
+        report.failed[0].error,
+        FilesError::NotFound { .. }
+    ));
+    assert!(!dir.join("gone.txt").exists());
+    assert!(!dir.join("gone.txt.part").exists());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_download_directory_missing_root_is_error() {
+    let mock_server = MockServer::start().await;
+    let dir = local_dir("dir-download-missing");
+
+    Mock::given(method("GET"))
+        .and(path("/folders/missing"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "error": "Not Found",
+            "http-code": 404
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let result = FileHandler::new(client(&mock_server))
+        .download_directory("/missing", &dir)
+        .await;
+
+    assert!(matches!(result, Err(FilesError::NotFound { .. })));
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
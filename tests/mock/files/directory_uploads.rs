@@ -0,0 +1,188 @@
+//! Mock tests for directory uploads
+
+use files_sdk::files::{DirectoryUploadOptions, SkipReason};
+use files_sdk::{FileHandler, FilesClient};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Creates a fresh local directory with the given files
+fn local_tree(name: &str, files: &[&str]) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("files-sdk-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    for file in files {
+        let local = dir.join(file);
+        std::fs::create_dir_all(local.parent().unwrap()).unwrap();
+        std::fs::write(&local, file.as_bytes()).unwrap();
+    }
+    dir
+}
+
+/// Mounts a single-part upload of `remote` and expects it to be finalized
+async fn mount_file(mock_server: &MockServer, remote: &str, part_status: u16) {
+    let id = remote.replace('/', "_");
+
+    Mock::given(method("POST"))
+        .and(path(format!("/file_actions/begin_upload{}", remote)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "upload_uri": format!("{}/s3/{}", mock_server.uri(), id),
+            "http_method": "PUT",
+            "part_number": 1,
+            "partsize": 5242880,
+            "ref": format!("ref{}", id)
+        })))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path(format!("/s3/{}", id)))
+        .respond_with(ResponseTemplate::new(part_status).insert_header("ETag", "\"etag\""))
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("/files{}", remote)))
+        .and(body_string_contains("action=end"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "path": remote })),
+        )
+        .expect(if part_status == 200 { 1 } else { 0 })
+        .mount(mock_server)
+        .await;
+}
+
+fn handler(mock_server: &MockServer) -> FileHandler {
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    FileHandler::new(client)
+}
+
+#[tokio::test]
+async fn test_upload_directory_with_filters() {
+    let mock_server = MockServer::start().await;
+    let dir = local_tree(
+        "dir-filters",
+        &["a.csv", "b.txt", "sub/c.csv", "archive/d.csv"],
+    );
+
+    mount_file(&mock_server, "/dest/a.csv", 200).await;
+    mount_file(&mock_server, "/dest/sub/c.csv", 200).await;
+
+    let options = DirectoryUploadOptions::default()
+        .concurrency(2)
+        .include("**/*.csv")
+        .exclude("archive/**");
+    let report = handler(&mock_server)
+        .upload_directory_with_options(&dir, "/dest/", &options)
+        .await
+        .unwrap();
+
+    let uploaded: Vec<_> = report
+        .uploaded
+        .iter()
+        .map(|f| f.remote_path.as_str())
+        .collect();
+    assert_eq!(uploaded, vec!["/dest/a.csv", "/dest/sub/c.csv"]);
+    assert_eq!(report.bytes_uploaded(), 5 + 9);
+
+    let skipped: Vec<_> = report
+        .skipped
+        .iter()
+        .map(|f| (f.local_path.strip_prefix(&dir).unwrap(), f.reason))
+        .collect();
+    assert_eq!(
+        skipped,
+        vec![
+            (Path::new("archive/d.csv"), SkipReason::Excluded),
+            (Path::new("b.txt"), SkipReason::NotIncluded),
+        ]
+    );
+    assert!(report.is_success());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_upload_directory_reports_failures() {
+    let mock_server = MockServer::start().await;
+    let dir = local_tree("dir-failures", &["ok.txt", "bad.txt"]);
+
+    mount_file(&mock_server, "/dest/ok.txt", 200).await;
+    mount_file(&mock_server, "/dest/bad.txt", 500).await;
+
+    let report = handler(&mock_server)
+        .upload_directory_with_options(&dir, "/dest", &DirectoryUploadOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(report.uploaded.len(), 1);
+    assert_eq!(report.failed.len(), 1);
+    assert_eq!(
+        report.failed[0].remote_path.as_deref(),
+        Some("/dest/bad.txt")
+    );
+    assert_eq!(report.failed[0].error.status_code(), Some(500));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_upload_directory_returns_first_error() {
+    let mock_server = MockServer::start().await;
+    let dir = local_tree("dir-first-error", &["ok.txt", "bad.txt"]);
+
+    mount_file(&mock_server, "/dest/ok.txt", 200).await;
+    mount_file(&mock_server, "/dest/bad.txt", 500).await;
+
+    let calls = AtomicUsize::new(0);
+    let result = handler(&mock_server)
+        .upload_directory_with_progress(&dir, "/dest", true, |current, total| {
+            assert_eq!(total, 2);
+            calls.fetch_add(1, Ordering::SeqCst);
+            assert!(current <= total);
+        })
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_upload_directory_missing_dir() {
+    let mock_server = MockServer::start().await;
+    let dir = std::env::temp_dir().join("files-sdk-does-not-exist");
+
+    let result = handler(&mock_server)
+        .upload_directory(&dir, "/dest", true)
+        .await;
+
+    assert!(matches!(result, Err(files_sdk::FilesError::IoError(_))));
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_upload_directory_skips_symlinks() {
+    let mock_server = MockServer::start().await;
+    let dir = local_tree("dir-symlinks", &["real.txt"]);
+    std::os::unix::fs::symlink(dir.join("real.txt"), dir.join("link.txt")).unwrap();
+
+    mount_file(&mock_server, "/dest/real.txt", 200).await;
+
+    let report = handler(&mock_server)
+        .upload_directory_with_options(&dir, "/dest", &DirectoryUploadOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(report.uploaded.len(), 1);
+    assert_eq!(report.skipped.len(), 1);
+    assert_eq!(report.skipped[0].reason, SkipReason::Symlink);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
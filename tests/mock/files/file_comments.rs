@@ -1,7 +1,7 @@
 use files_sdk::{FileCommentHandler, FilesClient};
 use wiremock::{
     Mock, MockServer, ResponseTemplate,
-    matchers::{method, path},
+    matchers::{method, path, query_param},
 };
 
 async fn setup() -> (MockServer, FileCommentHandler) {
@@ -39,7 +39,7 @@ async fn test_list_file_comments() {
         .mount(&mock_server)
         .await;
 
-    let comments = handler.list("/test.txt").await.unwrap();
+    let (comments, _) = handler.list("/test.txt", None, None).await.unwrap();
     assert_eq!(comments.len(), 2);
     assert_eq!(
         comments[0].body,
@@ -48,6 +48,66 @@ async fn test_list_file_comments() {
     assert_eq!(comments[0].reactions.as_ref().unwrap().len(), 2);
 }
 
+#[tokio::test]
+async fn test_list_file_comments_pagination() {
+    let (mock_server, handler) = setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/file_comments/files/test.txt"))
+        .and(query_param("cursor", "abc"))
+        .and(query_param("per_page", "1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!([
+                    {"id": 2, "body": "Second page", "reactions": []}
+                ]))
+                .insert_header("X-Files-Cursor-Next", "def"),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let (comments, pagination) = handler
+        .list("/test.txt", Some("abc".to_string()), Some(1))
+        .await
+        .unwrap();
+    assert_eq!(comments.len(), 1);
+    assert_eq!(comments[0].id, Some(2));
+    assert_eq!(pagination.cursor_next, Some("def".to_string()));
+}
+
+#[tokio::test]
+async fn test_list_file_comments_stream_follows_cursors() {
+    let (mock_server, handler) = setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/file_comments/files/test.txt"))
+        .and(query_param("cursor", "page-2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{"id": 3}])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/file_comments/files/test.txt"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!([{"id": 1}, {"id": 2}]))
+                .insert_header("X-Files-Cursor-Next", "page-2"),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let comments = handler
+        .list_stream("/test.txt", Some(2))
+        .collect_all()
+        .await
+        .unwrap();
+
+    let ids: Vec<_> = comments.iter().map(|c| c.id).collect();
+    assert_eq!(ids, vec![Some(1), Some(2), Some(3)]);
+}
+
 #[tokio::test]
 async fn test_create_file_comment() {
     let (mock_server, handler) = setup().await;
@@ -1,5 +1,6 @@
+use files_sdk::timestamp::timestamp;
 use files_sdk::{FileHandler, FilesClient};
-use wiremock::matchers::{header, method, path};
+use wiremock::matchers::{body_json, header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[tokio::test]
@@ -84,6 +85,9 @@ async fn test_update_file_success() {
     Mock::given(method("PATCH"))
         .and(path("/files/documents/report.pdf"))
         .and(header("X-FilesAPI-Key", "test-key"))
+        .and(body_json(serde_json::json!({
+            "provided_mtime": "2024-01-15T10:30:00Z"
+        })))
         .respond_with(ResponseTemplate::new(200).set_body_json(&response_body))
         .mount(&mock_server)
         .await;
@@ -99,7 +103,7 @@ async fn test_update_file_success() {
         .update_file(
             "/documents/report.pdf",
             None,
-            Some("monthly_report.pdf".to_string()),
+            Some(timestamp("2024-01-15T10:30:00Z").unwrap()),
             None,
         )
         .await
@@ -0,0 +1,198 @@
+//! Mock tests for tracking copy and move file migrations
+
+use files_sdk::{FileHandler, FilesClient, FilesError};
+use std::time::Duration;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn handler(mock_server: &MockServer) -> FileHandler {
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    FileHandler::new(client)
+}
+
+async fn mount_move(mock_server: &MockServer, migration_id: i64) {
+    Mock::given(method("POST"))
+        .and(path("/file_actions/move/incoming"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "status": "pending",
+            "file_migration_id": migration_id
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+/// Serves `status` for the migration, `times` times if given
+async fn mount_status(
+    mock_server: &MockServer,
+    migration_id: i64,
+    status: serde_json::Value,
+    times: Option<u64>,
+) {
+    let mock = Mock::given(method("GET"))
+        .and(path(format!("/file_migrations/{}", migration_id)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(status));
+    let mock = match times {
+        Some(n) => mock.up_to_n_times(n),
+        None => mock,
+    };
+    mock.mount(mock_server).await;
+}
+
+#[tokio::test]
+async fn test_move_waits_until_migration_completes() {
+    let mock_server = MockServer::start().await;
+    mount_move(&mock_server, 7).await;
+    mount_status(
+        &mock_server,
+        7,
+        serde_json::json!({"id": 7, "status": "counting", "files_moved": 0}),
+        Some(1),
+    )
+    .await;
+    mount_status(
+        &mock_server,
+        7,
+        serde_json::json!({
+            "id": 7,
+            "status": "processing",
+            "files_moved": 100,
+            "files_total": 200,
+            "bytes_moved": 1000,
+            "bytes_total": 2000
+        }),
+        Some(1),
+    )
+    .await;
+    mount_status(
+        &mock_server,
+        7,
+        serde_json::json!({"id": 7, "status": "completed", "files_moved": 200, "files_total": 200}),
+        None,
+    )
+    .await;
+
+    let migration = handler(&mock_server)
+        .move_file("/incoming", "/archive")
+        .await
+        .unwrap()
+        .poll_interval(Duration::from_millis(10));
+    assert_eq!(migration.migration_id(), Some(7));
+    assert_eq!(migration.status(), Some("pending"));
+
+    let mut seen = Vec::new();
+    let progress = migration
+        .wait_with_progress(|p| seen.push(p.clone()))
+        .await
+        .unwrap();
+
+    assert!(progress.is_completed());
+    assert_eq!(progress.files_moved, 200);
+    let statuses: Vec<_> = seen.iter().map(|p| p.status.as_str()).collect();
+    assert_eq!(statuses, ["counting", "processing", "completed"]);
+    assert_eq!(seen[1].fraction(), Some(0.5));
+    assert_eq!(seen[1].bytes_moved, Some(1000));
+    assert_eq!(seen[1].bytes_total, Some(2000));
+}
+
+#[tokio::test]
+async fn test_failed_migration_returns_error_and_logs() {
+    let mock_server = MockServer::start().await;
+    mount_move(&mock_server, 8).await;
+    mount_status(
+        &mock_server,
+        8,
+        serde_json::json!({"id": 8, "status": "failed", "files_moved": 1, "files_total": 2}),
+        None,
+    )
+    .await;
+    Mock::given(method("GET"))
+        .and(path("/file_migration_logs"))
+        .and(query_param("filter[file_migration_id]", "8"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                "file_migration_id": 8,
+                "path": "/incoming/locked.csv",
+                "status": "failed",
+                "message": "File is locked"
+            }])),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let migration = handler(&mock_server)
+        .move_file("/incoming", "/archive")
+        .await
+        .unwrap();
+
+    match migration.wait().await {
+        Err(FilesError::MigrationFailed {
+            migration_id,
+            status,
+        }) => {
+            assert_eq!(migration_id, 8);
+            assert_eq!(status, "failed");
+        }
+        other => panic!("Expected MigrationFailed, got {:?}", other),
+    }
+
+    let logs = migration.logs().await.unwrap();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].path.as_deref(), Some("/incoming/locked.csv"));
+    assert_eq!(logs[0].message.as_deref(), Some("File is locked"));
+}
+
+#[tokio::test]
+async fn test_wait_times_out() {
+    let mock_server = MockServer::start().await;
+    mount_move(&mock_server, 9).await;
+    mount_status(
+        &mock_server,
+        9,
+        serde_json::json!({"id": 9, "status": "processing", "files_moved": 1}),
+        None,
+    )
+    .await;
+
+    let migration = handler(&mock_server)
+        .move_file("/incoming", "/archive")
+        .await
+        .unwrap()
+        .poll_interval(Duration::from_millis(20))
+        .timeout(Duration::from_millis(100));
+
+    match migration.wait().await {
+        Err(FilesError::MigrationTimedOut {
+            migration_id,
+            elapsed,
+        }) => {
+            assert_eq!(migration_id, 9);
+            assert!(elapsed >= Duration::from_millis(100));
+        }
+        other => panic!("Expected MigrationTimedOut, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_immediate_copy_has_no_migration() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/file_actions/copy/report.pdf"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "status": "completed"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let migration = handler(&mock_server)
+        .copy_file("/report.pdf", "/backup/report.pdf")
+        .await
+        .unwrap();
+
+    assert_eq!(migration.migration_id(), None);
+    assert!(migration.wait().await.unwrap().is_completed());
+}
@@ -1,6 +1,14 @@
 //! Mock tests for file-related operations
 
+pub mod checksums;
+pub mod directory_downloads;
+pub mod directory_uploads;
 pub mod file_comments;
 
 #[allow(clippy::module_inception)]
 pub mod files;
+
+pub mod migrations;
+pub mod ranged_downloads;
+pub mod uploads;
+pub mod walk;
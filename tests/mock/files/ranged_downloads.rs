@@ -0,0 +1,306 @@
+//! Mock tests for ranged, resumable and segmented downloads
+
+use files_sdk::files::DownloadOptions;
+use files_sdk::{FileHandler, FilesClient, RetryPolicy};
+use std::path::PathBuf;
+use std::time::Duration;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate};
+
+const CONTENT: &[u8] = b"0123456789abcdefghij";
+
+/// Serves `CONTENT`, honoring `Range: bytes=start-[end]` headers
+struct RangeServer;
+
+impl Respond for RangeServer {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let Some(range) = request.headers.get("range") else {
+            return ResponseTemplate::new(200).set_body_bytes(CONTENT);
+        };
+        let range = range.to_str().unwrap().strip_prefix("bytes=").unwrap();
+        let (start, end) = range.split_once('-').unwrap();
+        let start: usize = start.parse().unwrap();
+        let end: usize = if end.is_empty() {
+            CONTENT.len() - 1
+        } else {
+            end.parse::<usize>().unwrap().min(CONTENT.len() - 1)
+        };
+        ResponseTemplate::new(206).set_body_bytes(&CONTENT[start..=end])
+    }
+}
+
+/// Mounts metadata for `/data.bin` pointing at `/dl/<name>`
+async fn mount_metadata(mock_server: &MockServer, name: &str, extra: serde_json::Value) {
+    let mut metadata = serde_json::json!({
+        "path": "data.bin",
+        "type": "file",
+        "size": CONTENT.len(),
+        "download_uri": format!("{}/dl/{}", mock_server.uri(), name)
+    });
+    metadata
+        .as_object_mut()
+        .unwrap()
+        .extend(extra.as_object().unwrap().clone());
+
+    Mock::given(method("GET"))
+        .and(path("/files/data.bin"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(metadata))
+        .mount(mock_server)
+        .await;
+}
+
+fn handler(mock_server: &MockServer) -> FileHandler {
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    FileHandler::new(client)
+}
+
+/// Returns a local path with no file or `.part` file present
+fn local_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("files-sdk-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let _ = std::fs::remove_file(path.with_extension("part"));
+    path
+}
+
+#[tokio::test]
+async fn test_download_range() {
+    let mock_server = MockServer::start().await;
+    mount_metadata(&mock_server, "data", serde_json::json!({})).await;
+    Mock::given(method("GET"))
+        .and(path("/dl/data"))
+        .and(header("range", "bytes=2-5"))
+        .respond_with(RangeServer)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let bytes = handler(&mock_server)
+        .download_range("/data.bin", 2..6)
+        .await
+        .unwrap();
+
+    assert_eq!(bytes, b"2345");
+}
+
+#[tokio::test]
+async fn test_download_range_open_ended() {
+    let mock_server = MockServer::start().await;
+    mount_metadata(&mock_server, "data", serde_json::json!({})).await;
+    Mock::given(method("GET"))
+        .and(path("/dl/data"))
+        .and(header("range", "bytes=16-"))
+        .respond_with(RangeServer)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let bytes = handler(&mock_server)
+        .download_range("/data.bin", 16..)
+        .await
+        .unwrap();
+
+    assert_eq!(bytes, b"ghij");
+}
+
+#[tokio::test]
+async fn test_download_range_when_server_ignores_range() {
+    let mock_server = MockServer::start().await;
+    mount_metadata(&mock_server, "data", serde_json::json!({})).await;
+    Mock::given(method("GET"))
+        .and(path("/dl/data"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(CONTENT))
+        .mount(&mock_server)
+        .await;
+
+    let bytes = handler(&mock_server)
+        .download_range("/data.bin", 10..=12)
+        .await
+        .unwrap();
+
+    assert_eq!(bytes, b"abc");
+}
+
+#[tokio::test]
+async fn test_download_to_file_resumes_part_file() {
+    let mock_server = MockServer::start().await;
+    mount_metadata(
+        &mock_server,
+        "data",
+        serde_json::json!({"md5": "644be06dfc54061fd1e67f5ebbabcd58"}),
+    )
+    .await;
+    Mock::given(method("GET"))
+        .and(path("/dl/data"))
+        .and(header("range", "bytes=8-"))
+        .respond_with(RangeServer)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let local = local_path("resume");
+    let part = PathBuf::from(format!("{}.part", local.display()));
+    std::fs::write(&part, &CONTENT[..8]).unwrap();
+
+    handler(&mock_server)
+        .download_to_file("/data.bin", &local)
+        .await
+        .unwrap();
+
+    assert_eq!(std::fs::read(&local).unwrap(), CONTENT);
+    assert!(!part.exists());
+    std::fs::remove_file(&local).unwrap();
+}
+
+#[tokio::test]
+async fn test_download_refreshes_expired_uri() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/files/data.bin"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "path": "data.bin",
+            "size": CONTENT.len(),
+            "download_uri": format!("{}/dl/expired", mock_server.uri())
+        })))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+    mount_metadata(&mock_server, "fresh", serde_json::json!({})).await;
+
+    Mock::given(method("GET"))
+        .and(path("/dl/expired"))
+        .respond_with(ResponseTemplate::new(403).set_body_string("Request has expired"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/dl/fresh"))
+        .respond_with(RangeServer)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let local = local_path("expired");
+    handler(&mock_server)
+        .download_to_file("/data.bin", &local)
+        .await
+        .unwrap();
+
+    assert_eq!(std::fs::read(&local).unwrap(), CONTENT);
+    std::fs::remove_file(&local).unwrap();
+}
+
+#[tokio::test]
+async fn test_download_uses_client_retry_policy() {
+    let mock_server = MockServer::start().await;
+    mount_metadata(&mock_server, "flaky", serde_json::json!({})).await;
+
+    Mock::given(method("GET"))
+        .and(path("/dl/flaky"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/dl/flaky"))
+        .respond_with(RangeServer)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .retry_policy(RetryPolicy::default().initial_backoff(Duration::from_millis(1)))
+        .build()
+        .unwrap();
+    let bytes = FileHandler::new(client)
+        .download_content("/data.bin")
+        .await
+        .unwrap();
+    assert_eq!(bytes, CONTENT);
+
+    // Download URIs are presigned; the API key must not be sent with them
+    let requests = mock_server.received_requests().await.unwrap();
+    assert!(
+        requests
+            .iter()
+            .filter(|r| r.url.path().starts_with("/dl/"))
+            .all(|r| !r.headers.contains_key("x-filesapi-key"))
+    );
+}
+
+#[tokio::test]
+async fn test_segmented_download() {
+    let mock_server = MockServer::start().await;
+    mount_metadata(
+        &mock_server,
+        "data",
+        serde_json::json!({"md5": "644be06dfc54061fd1e67f5ebbabcd58"}),
+    )
+    .await;
+    Mock::given(method("GET"))
+        .and(path("/dl/data"))
+        .respond_with(RangeServer)
+        .expect(4)
+        .mount(&mock_server)
+        .await;
+
+    let local = local_path("segmented");
+    let options = DownloadOptions::default().segments(4).min_segment_size(5);
+    handler(&mock_server)
+        .download_to_file_with_options("/data.bin", &local, &options)
+        .await
+        .unwrap();
+
+    assert_eq!(std::fs::read(&local).unwrap(), CONTENT);
+    std::fs::remove_file(&local).unwrap();
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let mut ranges: Vec<_> = requests
+        .iter()
+        .filter_map(|request| request.headers.get("range"))
+        .map(|range| range.to_str().unwrap().to_string())
+        .collect();
+    ranges.sort();
+    assert_eq!(
+        ranges,
+        vec!["bytes=0-4", "bytes=10-14", "bytes=15-19", "bytes=5-9"]
+    );
+}
+
+#[tokio::test]
+async fn test_segmented_download_verifies_checksum() {
+    let mock_server = MockServer::start().await;
+    mount_metadata(
+        &mock_server,
+        "data",
+        serde_json::json!({"md5": "00000000000000000000000000000000"}),
+    )
+    .await;
+    Mock::given(method("GET"))
+        .and(path("/dl/data"))
+        .respond_with(RangeServer)
+        .mount(&mock_server)
+        .await;
+
+    let local = local_path("segmented-checksum");
+    let options = DownloadOptions::default()
+        .segments(2)
+        .min_segment_size(5)
+        .verify_checksum(true);
+    let result = handler(&mock_server)
+        .download_to_file_with_options("/data.bin", &local, &options)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(files_sdk::FilesError::ChecksumMismatch { .. })
+    ));
+    assert!(!local.exists());
+}
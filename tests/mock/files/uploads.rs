@@ -0,0 +1,475 @@
+use files_sdk::files::{UploadCheckpoint, UploadOptions};
+use files_sdk::{FileHandler, FilesClient, RetryPolicy};
+use std::time::Duration;
+use wiremock::matchers::{body_partial_json, body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Mounts a begin_upload response for `part` with 4-byte parts
+async fn mount_part(mock_server: &MockServer, part: i32, first: bool) {
+    let body = serde_json::json!({
+        "upload_uri": format!("{}/s3/part{}", mock_server.uri(), part),
+        "http_method": "PUT",
+        "part_number": part,
+        "partsize": 4,
+        "next_partsize": 4,
+        "ref": "upload-ref",
+        "parallel_parts": true,
+        "available_parts": 10000,
+        "path": "/uploads/data.bin"
+    });
+
+    let mock = Mock::given(method("POST")).and(path("/file_actions/begin_upload/uploads/data.bin"));
+    let mock = if first {
+        mock.and(body_partial_json(serde_json::json!({"size": 10})))
+    } else {
+        mock.and(body_partial_json(
+            serde_json::json!({"ref": "upload-ref", "part": part}),
+        ))
+    };
+
+    mock.respond_with(ResponseTemplate::new(200).set_body_json(&body))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path(format!("/s3/part{}", part)))
+        .respond_with(
+            ResponseTemplate::new(200).insert_header("ETag", format!("\"etag-{}\"", part)),
+        )
+        .expect(1)
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_upload_stream_multipart() {
+    let mock_server = MockServer::start().await;
+
+    mount_part(&mock_server, 1, true).await;
+    mount_part(&mock_server, 2, false).await;
+    mount_part(&mock_server, 3, false).await;
+
+    Mock::given(method("POST"))
+        .and(path("/files/uploads/data.bin"))
+        .and(body_string_contains("action=end"))
+        .and(body_string_contains("ref=upload-ref"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "path": "/uploads/data.bin",
+            "type": "file",
+            "size": 10
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = FileHandler::new(client);
+    let data = b"0123456789".to_vec();
+    let options = UploadOptions::default().max_concurrent_parts(2);
+    let file = handler
+        .upload_stream_with_options("/uploads/data.bin", &data[..], Some(10), None, &options)
+        .await
+        .unwrap();
+
+    assert_eq!(file.size, Some(10));
+
+    let requests = mock_server.received_requests().await.unwrap();
+
+    let mut part_bodies: Vec<(String, Vec<u8>)> = requests
+        .iter()
+        .filter(|r| r.method.as_str() == "PUT")
+        .map(|r| (r.url.path().to_string(), r.body.clone()))
+        .collect();
+    part_bodies.sort();
+    assert_eq!(
+        part_bodies,
+        vec![
+            ("/s3/part1".to_string(), b"0123".to_vec()),
+            ("/s3/part2".to_string(), b"4567".to_vec()),
+            ("/s3/part3".to_string(), b"89".to_vec()),
+        ]
+    );
+
+    let finalize = requests
+        .iter()
+        .find(|r| r.url.path() == "/files/uploads/data.bin")
+        .unwrap();
+    let form: Vec<(String, String)> = url::form_urlencoded::parse(&finalize.body)
+        .into_owned()
+        .collect();
+    let etags: Vec<&str> = form
+        .iter()
+        .filter(|(k, _)| k == "etags[][etag]")
+        .map(|(_, v)| v.as_str())
+        .collect();
+    let parts: Vec<&str> = form
+        .iter()
+        .filter(|(k, _)| k == "etags[][part]")
+        .map(|(_, v)| v.as_str())
+        .collect();
+    assert_eq!(etags, vec!["etag-1", "etag-2", "etag-3"]);
+    assert_eq!(parts, vec!["1", "2", "3"]);
+}
+
+#[tokio::test]
+async fn test_upload_file_empty() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/file_actions/begin_upload/empty.txt"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "upload_uri": format!("{}/s3/empty", mock_server.uri()),
+            "http_method": "PUT",
+            "part_number": 1,
+            "partsize": 5242880,
+            "ref": "empty-ref"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/s3/empty"))
+        .respond_with(ResponseTemplate::new(200).insert_header("ETag", "\"empty\""))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/files/empty.txt"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "path": "/empty.txt",
+            "size": 0
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = FileHandler::new(client);
+    let file = handler.upload_file("/empty.txt", b"").await.unwrap();
+    assert_eq!(file.size, Some(0));
+}
+
+#[tokio::test]
+async fn test_upload_part_failure() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/file_actions/begin_upload/fail.txt"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "upload_uri": format!("{}/s3/fail", mock_server.uri()),
+            "http_method": "PUT",
+            "part_number": 1,
+            "partsize": 5242880,
+            "ref": "fail-ref"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/s3/fail"))
+        .respond_with(ResponseTemplate::new(500).set_body_string("storage unavailable"))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/files/fail.txt"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap();
+
+    let handler = FileHandler::new(client);
+    let error = handler.upload_file("/fail.txt", b"data").await.unwrap_err();
+    assert_eq!(error.status_code(), Some(500));
+
+    let detail = error.detail().expect("error detail");
+    assert_eq!(detail.method, "PUT");
+    assert_eq!(detail.endpoint, "/s3/fail");
+    assert_eq!(detail.body, "storage unavailable");
+}
+
+#[tokio::test]
+async fn test_upload_part_retried_after_transient_failure() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/file_actions/begin_upload/flaky.txt"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "upload_uri": format!("{}/s3/flaky?X-Amz-Signature=sig", mock_server.uri()),
+            "http_method": "PUT",
+            "part_number": 1,
+            "partsize": 5242880,
+            "ref": "flaky-ref"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/s3/flaky"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/s3/flaky"))
+        .respond_with(ResponseTemplate::new(200).insert_header("ETag", "\"flaky\""))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/files/flaky.txt"))
+        .and(body_string_contains("flaky"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "path": "/flaky.txt",
+            "size": 4
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .retry_policy(
+            RetryPolicy::default()
+                .initial_backoff(Duration::from_millis(10))
+                .jitter(false),
+        )
+        .build()
+        .unwrap();
+
+    let handler = FileHandler::new(client);
+    let file = handler.upload_file("/flaky.txt", b"data").await.unwrap();
+    assert_eq!(file.size, Some(4));
+
+    // Presigned storage URLs never receive the API key
+    let requests = mock_server.received_requests().await.unwrap();
+    assert!(
+        requests
+            .iter()
+            .filter(|r| r.method.as_str() == "PUT")
+            .all(|r| !r.headers.contains_key("x-filesapi-key"))
+    );
+}
+
+#[tokio::test]
+async fn test_upload_resumes_from_checkpoint() {
+    let mock_server = MockServer::start().await;
+
+    // Part 1 was stored before the interruption; only parts 2 and 3 are sent
+    mount_part(&mock_server, 2, false).await;
+    mount_part(&mock_server, 3, false).await;
+
+    Mock::given(method("POST"))
+        .and(path("/files/uploads/data.bin"))
+        .and(body_string_contains("action=end"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "path": "/uploads/data.bin",
+            "size": 10
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let mut checkpoint: UploadCheckpoint = serde_json::from_value(serde_json::json!({
+        "path": "/uploads/data.bin",
+        "ref": "upload-ref",
+        "completed_parts": [{"part_number": 1, "etag": "etag-1", "size": 4}],
+        "offset": 4,
+        "fingerprint": "fp"
+    }))
+    .unwrap();
+
+    let handler = FileHandler::new(client);
+    let data = std::io::Cursor::new(b"0123456789".to_vec());
+    let mut saved = Vec::new();
+    handler
+        .upload_stream_resumable("/uploads/data.bin", data, 10, checkpoint.clone(), |cp| {
+            saved.push(cp.clone());
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+    checkpoint = saved.last().unwrap().clone();
+    assert_eq!(checkpoint.offset, 10);
+    assert_eq!(checkpoint.completed_parts.len(), 3);
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let mut part_bodies: Vec<(String, Vec<u8>)> = requests
+        .iter()
+        .filter(|r| r.method.as_str() == "PUT")
+        .map(|r| (r.url.path().to_string(), r.body.clone()))
+        .collect();
+    part_bodies.sort();
+    assert_eq!(
+        part_bodies,
+        vec![
+            ("/s3/part2".to_string(), b"4567".to_vec()),
+            ("/s3/part3".to_string(), b"89".to_vec()),
+        ]
+    );
+
+    let finalize = requests
+        .iter()
+        .find(|r| r.url.path() == "/files/uploads/data.bin")
+        .unwrap();
+    let body = String::from_utf8(finalize.body.clone()).unwrap();
+    assert!(body.contains("etag-1"));
+    assert!(body.contains("etag-3"));
+}
+
+#[tokio::test]
+async fn test_upload_rerequests_expired_part() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/file_actions/begin_upload/expired.txt"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "upload_uri": format!("{}/s3/stale", mock_server.uri()),
+            "http_method": "PUT",
+            "part_number": 1,
+            "partsize": 5242880,
+            "ref": "expired-ref",
+            "expires": "2000-01-01T00:00:00Z"
+        })))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/file_actions/begin_upload/expired.txt"))
+        .and(body_partial_json(
+            serde_json::json!({"ref": "expired-ref", "part": 1}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "upload_uri": format!("{}/s3/fresh", mock_server.uri()),
+            "http_method": "PUT",
+            "part_number": 1,
+            "partsize": 5242880,
+            "ref": "expired-ref",
+            "expires": "2999-01-01T00:00:00Z"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/s3/stale"))
+        .respond_with(ResponseTemplate::new(403))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/s3/fresh"))
+        .respond_with(ResponseTemplate::new(200).insert_header("ETag", "\"fresh\""))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/files/expired.txt"))
+        .and(body_string_contains("fresh"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "path": "/expired.txt",
+            "size": 4
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = FileHandler::new(client);
+    let file = handler.upload_file("/expired.txt", b"data").await.unwrap();
+    assert_eq!(file.size, Some(4));
+}
+
+#[tokio::test]
+async fn test_upload_file_resumable_removes_checkpoint() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/file_actions/begin_upload/local.txt"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "upload_uri": format!("{}/s3/local", mock_server.uri()),
+            "http_method": "PUT",
+            "part_number": 1,
+            "partsize": 5242880,
+            "ref": "local-ref"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/s3/local"))
+        .respond_with(ResponseTemplate::new(200).insert_header("ETag", "\"local\""))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/files/local.txt"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "path": "/local.txt",
+            "size": 5
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let dir = std::env::temp_dir().join(format!("files-sdk-resumable-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let local = dir.join("local.txt");
+    let checkpoint_path = dir.join("local.txt.checkpoint");
+    std::fs::write(&local, b"hello").unwrap();
+
+    let handler = FileHandler::new(client);
+    let file = handler
+        .upload_file_resumable(&local, "/local.txt", &checkpoint_path, None)
+        .await
+        .unwrap();
+
+    assert_eq!(file.size, Some(5));
+    assert!(!checkpoint_path.exists());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,221 @@
+//! Mock tests for recursive folder walks
+
+use files_sdk::files::WalkOrder;
+use files_sdk::{FilesClient, FolderHandler};
+use futures::StreamExt;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn folder(path: &str) -> serde_json::Value {
+    serde_json::json!({"path": path, "type": "directory"})
+}
+
+fn file(path: &str) -> serde_json::Value {
+    serde_json::json!({"path": path, "type": "file", "size": 10})
+}
+
+async fn mount_folder(mock_server: &MockServer, remote: &str, entries: serde_json::Value) {
+    Mock::given(method("GET"))
+        .and(path(format!("/folders/{}", remote)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(entries))
+        .mount(mock_server)
+        .await;
+}
+
+/// Mounts this tree below `tree`:
+///
+/// ```text
+/// a/
+///   a1/
+///     z.txt
+///   y.txt
+/// x.txt
+/// b/
+///   .git/
+///     HEAD
+///   w.txt
+/// ```
+async fn mount_tree(mock_server: &MockServer) {
+    mount_folder(
+        mock_server,
+        "tree",
+        serde_json::json!([folder("tree/a"), file("tree/x.txt"), folder("tree/b")]),
+    )
+    .await;
+    mount_folder(
+        mock_server,
+        "tree/a",
+        serde_json::json!([folder("tree/a/a1"), file("tree/a/y.txt")]),
+    )
+    .await;
+    mount_folder(
+        mock_server,
+        "tree/a/a1",
+        serde_json::json!([file("tree/a/a1/z.txt")]),
+    )
+    .await;
+    mount_folder(
+        mock_server,
+        "tree/b",
+        serde_json::json!([folder("tree/b/.git"), file("tree/b/w.txt")]),
+    )
+    .await;
+    mount_folder(
+        mock_server,
+        "tree/b/.git",
+        serde_json::json!([file("tree/b/.git/HEAD")]),
+    )
+    .await;
+}
+
+fn handler(mock_server: &MockServer) -> FolderHandler {
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+    FolderHandler::new(client)
+}
+
+/// Collects a walk as `(depth, path)` pairs, panicking on errors
+async fn collect(walk: files_sdk::files::Walk) -> Vec<(usize, String)> {
+    walk.map(|item| {
+        let (depth, entry) = item.unwrap();
+        (depth, entry.path.unwrap())
+    })
+    .collect()
+    .await
+}
+
+fn pairs(expected: &[(usize, &str)]) -> Vec<(usize, String)> {
+    expected
+        .iter()
+        .map(|(depth, path)| (*depth, path.to_string()))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_walk_breadth_first() {
+    let mock_server = MockServer::start().await;
+    mount_tree(&mock_server).await;
+
+    let entries = collect(handler(&mock_server).walk("/tree")).await;
+
+    assert_eq!(
+        entries,
+        pairs(&[
+            (1, "tree/a"),
+            (1, "tree/x.txt"),
+            (1, "tree/b"),
+            (2, "tree/a/a1"),
+            (2, "tree/a/y.txt"),
+            (2, "tree/b/.git"),
+            (2, "tree/b/w.txt"),
+            (3, "tree/a/a1/z.txt"),
+            (3, "tree/b/.git/HEAD"),
+        ])
+    );
+}
+
+#[tokio::test]
+async fn test_walk_depth_first() {
+    let mock_server = MockServer::start().await;
+    mount_tree(&mock_server).await;
+
+    for concurrency in [1, 8] {
+        let walk = handler(&mock_server)
+            .walk("/tree")
+            .order(WalkOrder::DepthFirst)
+            .concurrency(concurrency);
+
+        assert_eq!(
+            collect(walk).await,
+            pairs(&[
+                (1, "tree/a"),
+                (2, "tree/a/a1"),
+                (3, "tree/a/a1/z.txt"),
+                (2, "tree/a/y.txt"),
+                (1, "tree/x.txt"),
+                (1, "tree/b"),
+                (2, "tree/b/.git"),
+                (3, "tree/b/.git/HEAD"),
+                (2, "tree/b/w.txt"),
+            ])
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_walk_max_depth_does_not_list_deeper_folders() {
+    let mock_server = MockServer::start().await;
+    mount_folder(
+        &mock_server,
+        "tree",
+        serde_json::json!([folder("tree/a"), file("tree/x.txt")]),
+    )
+    .await;
+    Mock::given(method("GET"))
+        .and(path("/folders/tree/a"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let entries = collect(handler(&mock_server).walk("/tree").max_depth(1)).await;
+
+    assert_eq!(entries, pairs(&[(1, "tree/a"), (1, "tree/x.txt")]));
+}
+
+#[tokio::test]
+async fn test_walk_prunes_folders() {
+    let mock_server = MockServer::start().await;
+    mount_tree(&mock_server).await;
+
+    let walk = handler(&mock_server)
+        .walk("/tree")
+        .prune(|entry| entry.path.as_deref().is_some_and(|p| p.ends_with("/.git")));
+    let entries = collect(walk).await;
+
+    assert_eq!(entries.len(), 7);
+    assert!(entries.iter().all(|(_, path)| !path.contains(".git")));
+
+    let requests = mock_server.received_requests().await.unwrap();
+    assert!(
+        requests
+            .iter()
+            .all(|request| !request.url.path().contains(".git"))
+    );
+}
+
+#[tokio::test]
+async fn test_walk_continues_after_failed_folder() {
+    let mock_server = MockServer::start().await;
+    mount_folder(
+        &mock_server,
+        "tree",
+        serde_json::json!([folder("tree/locked"), folder("tree/open")]),
+    )
+    .await;
+    Mock::given(method("GET"))
+        .and(path("/folders/tree/locked"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+            "error": "Forbidden",
+            "http-code": 403
+        })))
+        .mount(&mock_server)
+        .await;
+    mount_folder(
+        &mock_server,
+        "tree/open",
+        serde_json::json!([file("tree/open/file.txt")]),
+    )
+    .await;
+
+    let items: Vec<_> = handler(&mock_server).walk("/tree").collect().await;
+
+    assert_eq!(items.len(), 4);
+    assert!(items[2].is_err());
+    let (depth, entry) = items[3].as_ref().unwrap();
+    assert_eq!(*depth, 2);
+    assert_eq!(entry.path.as_deref(), Some("tree/open/file.txt"));
+}
@@ -42,7 +42,7 @@ async fn test_list_messages() {
         .unwrap();
 
     let handler = MessageHandler::new(client);
-    let (messages, _) = handler.list(None, None, None).await.unwrap();
+    let (messages, _) = handler.list(None, None, None, None).await.unwrap();
 
     assert_eq!(messages.len(), 2);
     assert_eq!(messages[0].id, Some(1));
@@ -76,7 +76,7 @@ async fn test_list_messages_with_project_filter() {
         .unwrap();
 
     let handler = MessageHandler::new(client);
-    let (messages, _) = handler.list(None, None, Some(1)).await.unwrap();
+    let (messages, _) = handler.list(None, None, Some(1), None).await.unwrap();
 
     assert_eq!(messages.len(), 1);
     assert_eq!(messages[0].subject, Some("Project A Update".to_string()));
@@ -107,7 +107,7 @@ async fn test_list_messages_with_pagination() {
 
     let handler = MessageHandler::new(client);
     let (messages, _) = handler
-        .list(Some("cursor123"), Some(10), None)
+        .list(Some("cursor123"), Some(10), None, None)
         .await
         .unwrap();
 
@@ -41,7 +41,7 @@ async fn test_list_notifications_success() {
         .unwrap();
 
     let handler = NotificationHandler::new(client);
-    let (notifications, _) = handler.list(None, None, None, None).await.unwrap();
+    let (notifications, _) = handler.list(None, None, None, None, None).await.unwrap();
 
     assert_eq!(notifications.len(), 2);
     assert_eq!(notifications[0].id, Some(1));
@@ -82,7 +82,7 @@ async fn test_list_notifications_with_filters() {
 
     let handler = NotificationHandler::new(client);
     let (notifications, _) = handler
-        .list(None, Some(10), Some("/uploads"), Some(10))
+        .list(None, Some(10), Some("/uploads"), Some(10), None)
         .await
         .unwrap();
 
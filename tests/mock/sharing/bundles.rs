@@ -43,7 +43,7 @@ async fn test_list_bundles_success() {
         .unwrap();
 
     let handler = BundleHandler::new(client);
-    let (bundles, _pagination) = handler.list(None, None, None).await.unwrap();
+    let (bundles, _pagination) = handler.list(None, None, None, None).await.unwrap();
 
     assert_eq!(bundles.len(), 2);
     assert_eq!(bundles[0].id, Some(1));
@@ -80,7 +80,7 @@ async fn test_list_bundles_with_filters() {
         .unwrap();
 
     let handler = BundleHandler::new(client);
-    let (bundles, _pagination) = handler.list(Some(123), None, Some(10)).await.unwrap();
+    let (bundles, _pagination) = handler.list(Some(123), None, Some(10), None).await.unwrap();
 
     assert_eq!(bundles.len(), 1);
     assert_eq!(bundles[0].user_id, Some(123));
@@ -1,9 +1,10 @@
 //! Mock tests for InboxUploadHandler
 
-use files_sdk::{FilesClient, InboxUploadHandler};
+use files_sdk::sharing::InboxUploadField;
+use files_sdk::{FilesClient, InboxUploadHandler, ListQuery, SortDirection};
 use wiremock::{
     Mock, MockServer, ResponseTemplate,
-    matchers::{method, path},
+    matchers::{method, path, query_param},
 };
 
 #[tokio::test]
@@ -50,10 +51,7 @@ async fn test_list_inbox_uploads() {
         .unwrap();
 
     let handler = InboxUploadHandler::new(client);
-    let (uploads, _) = handler
-        .list(None, None, None, None, None, None)
-        .await
-        .unwrap();
+    let (uploads, _) = handler.list(None, None, None, None, None).await.unwrap();
 
     assert_eq!(uploads.len(), 2);
     assert_eq!(uploads[0].path, Some("uploads/resume.pdf".to_string()));
@@ -96,7 +94,7 @@ async fn test_list_inbox_uploads_with_pagination() {
 
     let handler = InboxUploadHandler::new(client);
     let (uploads, _) = handler
-        .list(Some("cursor123"), Some(10), None, None, None, None)
+        .list(Some("cursor123"), Some(10), None, None, None)
         .await
         .unwrap();
 
@@ -110,6 +108,7 @@ async fn test_list_inbox_uploads_with_sort() {
 
     Mock::given(method("GET"))
         .and(path("/inbox_uploads"))
+        .and(query_param("sort_by[created_at]", "desc"))
         .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
             {
                 "inbox_registration": {
@@ -132,9 +131,9 @@ async fn test_list_inbox_uploads_with_sort() {
         .unwrap();
 
     let handler = InboxUploadHandler::new(client);
-    let sort_by = serde_json::json!({"created_at": "desc"});
+    let query = ListQuery::new().sort_by(InboxUploadField::CreatedAt, SortDirection::Desc);
     let (uploads, _) = handler
-        .list(None, None, Some(sort_by), None, None, None)
+        .list(None, None, None, None, Some(&query))
         .await
         .unwrap();
 
@@ -147,6 +146,7 @@ async fn test_list_inbox_uploads_with_filter() {
 
     Mock::given(method("GET"))
         .and(path("/inbox_uploads"))
+        .and(query_param("filter[folder_behavior_id]", "123"))
         .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
             {
                 "inbox_registration": {
@@ -170,9 +170,9 @@ async fn test_list_inbox_uploads_with_filter() {
         .unwrap();
 
     let handler = InboxUploadHandler::new(client);
-    let filter = serde_json::json!({"folder_behavior_id": 123});
+    let query = ListQuery::new().filter(InboxUploadField::FolderBehaviorId, 123);
     let (uploads, _) = handler
-        .list(None, None, None, Some(filter), None, None)
+        .list(None, None, None, None, Some(&query))
         .await
         .unwrap();
 
@@ -220,7 +220,7 @@ async fn test_list_inbox_uploads_by_registration_id() {
 
     let handler = InboxUploadHandler::new(client);
     let (uploads, _) = handler
-        .list(None, None, None, None, Some(789), None)
+        .list(None, None, Some(789), None, None)
         .await
         .unwrap();
 
@@ -278,7 +278,7 @@ async fn test_list_inbox_uploads_by_inbox_id() {
 
     let handler = InboxUploadHandler::new(client);
     let (uploads, _) = handler
-        .list(None, None, None, None, None, Some(42))
+        .list(None, None, None, Some(42), None)
         .await
         .unwrap();
 
@@ -322,10 +322,7 @@ async fn test_list_inbox_uploads_with_form_data() {
         .unwrap();
 
     let handler = InboxUploadHandler::new(client);
-    let (uploads, _) = handler
-        .list(None, None, None, None, None, None)
-        .await
-        .unwrap();
+    let (uploads, _) = handler.list(None, None, None, None, None).await.unwrap();
 
     assert_eq!(uploads.len(), 1);
     let reg = uploads[0].inbox_registration.as_ref().unwrap();
@@ -366,10 +363,7 @@ async fn test_list_inbox_uploads_with_clickwrap() {
         .unwrap();
 
     let handler = InboxUploadHandler::new(client);
-    let (uploads, _) = handler
-        .list(None, None, None, None, None, None)
-        .await
-        .unwrap();
+    let (uploads, _) = handler.list(None, None, None, None, None).await.unwrap();
 
     assert_eq!(uploads.len(), 1);
     let reg = uploads[0].inbox_registration.as_ref().unwrap();
@@ -399,10 +393,7 @@ async fn test_list_inbox_uploads_empty() {
         .unwrap();
 
     let handler = InboxUploadHandler::new(client);
-    let (uploads, _) = handler
-        .list(None, None, None, None, None, None)
-        .await
-        .unwrap();
+    let (uploads, _) = handler.list(None, None, None, None, None).await.unwrap();
 
     assert_eq!(uploads.len(), 0);
 }
@@ -427,7 +418,7 @@ async fn test_list_inbox_uploads_authentication_failed() {
         .unwrap();
 
     let handler = InboxUploadHandler::new(client);
-    let result = handler.list(None, None, None, None, None, None).await;
+    let result = handler.list(None, None, None, None, None).await;
 
     assert!(result.is_err());
 }
@@ -452,7 +443,7 @@ async fn test_list_inbox_uploads_forbidden() {
         .unwrap();
 
     let handler = InboxUploadHandler::new(client);
-    let result = handler.list(None, None, None, None, None, None).await;
+    let result = handler.list(None, None, None, None, None).await;
 
     assert!(result.is_err());
 }
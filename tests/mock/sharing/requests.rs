@@ -35,7 +35,7 @@ async fn test_list_requests_success() {
         .unwrap();
 
     let handler = RequestHandler::new(client);
-    let (requests, _pagination) = handler.list(None, None, None, None).await.unwrap();
+    let (requests, _pagination) = handler.list(None, None, None, None, None).await.unwrap();
 
     assert_eq!(requests.len(), 2);
     assert_eq!(requests[0].id, Some(1));
@@ -75,7 +75,7 @@ async fn test_list_requests_with_filters() {
 
     let handler = RequestHandler::new(client);
     let (requests, _pagination) = handler
-        .list(None, Some(10), Some("/uploads"), Some(true))
+        .list(None, Some(10), Some("/uploads"), Some(true), None)
         .await
         .unwrap();
 
@@ -115,7 +115,7 @@ async fn test_list_for_folder_success() {
 
     let handler = RequestHandler::new(client);
     let (requests, _pagination) = handler
-        .list_for_folder("uploads", None, None)
+        .list_for_folder("uploads", None, None, None)
         .await
         .unwrap();
 
@@ -332,7 +332,9 @@ async fn test_list_for_folder_forbidden() {
         .unwrap();
 
     let handler = RequestHandler::new(client);
-    let result = handler.list_for_folder("restricted", None, None).await;
+    let result = handler
+        .list_for_folder("restricted", None, None, None)
+        .await;
 
     assert!(result.is_err());
     match result {
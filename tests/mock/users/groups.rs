@@ -34,7 +34,7 @@ async fn test_list_groups_success() {
         .unwrap();
 
     let handler = GroupHandler::new(client);
-    let (groups, _pagination) = handler.list(None, None).await.unwrap();
+    let (groups, _pagination) = handler.list(None, None, None).await.unwrap();
 
     assert_eq!(groups.len(), 1);
     assert_eq!(groups[0].id, Some(1));
@@ -66,7 +66,7 @@ async fn test_list_groups_with_pagination() {
         .unwrap();
 
     let handler = GroupHandler::new(client);
-    let (groups, _pagination) = handler.list(None, Some(2)).await.unwrap();
+    let (groups, _pagination) = handler.list(None, Some(2), None).await.unwrap();
 
     assert_eq!(groups.len(), 2);
 }
@@ -39,7 +39,7 @@ async fn test_list_permissions_success() {
         .unwrap();
 
     let handler = PermissionHandler::new(client);
-    let (permissions, _pagination) = handler.list(None, None).await.unwrap();
+    let (permissions, _pagination) = handler.list(None, None, None).await.unwrap();
 
     assert_eq!(permissions.len(), 2);
     assert_eq!(permissions[0].id, Some(1));
@@ -72,7 +72,7 @@ async fn test_list_permissions_with_pagination() {
         .unwrap();
 
     let handler = PermissionHandler::new(client);
-    let (permissions, _pagination) = handler.list(None, Some(2)).await.unwrap();
+    let (permissions, _pagination) = handler.list(None, Some(2), None).await.unwrap();
 
     assert_eq!(permissions.len(), 2);
 }
@@ -225,7 +225,7 @@ async fn test_list_permissions_for_user_success() {
         .unwrap();
 
     let handler = PermissionHandler::new(client);
-    let (permissions, _pagination) = handler.list_for_user(123, None, None).await.unwrap();
+    let (permissions, _pagination) = handler.list_for_user(123, None, None, None).await.unwrap();
 
     assert_eq!(permissions.len(), 2);
     assert_eq!(permissions[0].user_id, Some(123));
@@ -259,7 +259,7 @@ async fn test_list_permissions_for_group_success() {
         .unwrap();
 
     let handler = PermissionHandler::new(client);
-    let (permissions, _pagination) = handler.list_for_group(456, None, None).await.unwrap();
+    let (permissions, _pagination) = handler.list_for_group(456, None, None, None).await.unwrap();
 
     assert_eq!(permissions.len(), 1);
     assert_eq!(permissions[0].group_id, Some(456));
@@ -46,7 +46,7 @@ async fn test_list_public_keys() {
         .mount(&mock_server)
         .await;
 
-    let (keys, _) = handler.list(None, None, None).await.unwrap();
+    let (keys, _) = handler.list(None, None, None, None).await.unwrap();
     assert_eq!(keys.len(), 2);
     assert_eq!(keys[0].title, Some("Work Laptop".to_string()));
     assert_eq!(keys[1].title, Some("Home Desktop".to_string()));
@@ -71,7 +71,7 @@ async fn test_list_public_keys_for_user() {
         .mount(&mock_server)
         .await;
 
-    let (keys, _) = handler.list(Some(456), None, None).await.unwrap();
+    let (keys, _) = handler.list(Some(456), None, None, None).await.unwrap();
     assert_eq!(keys.len(), 1);
     assert_eq!(keys[0].user_id, Some(456));
 }
@@ -272,7 +272,7 @@ async fn test_list_public_keys_with_pagination() {
         .mount(&mock_server)
         .await;
 
-    let (keys, pagination) = handler.list(Some(123), None, Some(2)).await.unwrap();
+    let (keys, pagination) = handler.list(Some(123), None, Some(2), None).await.unwrap();
     assert_eq!(keys.len(), 2);
     assert!(pagination.has_next());
     assert_eq!(
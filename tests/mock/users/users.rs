@@ -1,5 +1,6 @@
-use files_sdk::{FilesClient, UserHandler};
-use wiremock::matchers::{header, method, path, query_param};
+use files_sdk::users::{Update2faParams, UserField};
+use files_sdk::{CurrentUserHandler, FilesClient, ListQuery, SortDirection, UserHandler};
+use wiremock::matchers::{body_json, header, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[tokio::test]
@@ -31,7 +32,7 @@ async fn test_list_users_success() {
         .unwrap();
 
     let handler = UserHandler::new(client);
-    let (users, _pagination) = handler.list(None, None).await.unwrap();
+    let (users, _pagination) = handler.list(None, None, None).await.unwrap();
 
     assert_eq!(users.len(), 1);
     assert_eq!(users[0].id, Some(1));
@@ -63,7 +64,7 @@ async fn test_list_users_with_pagination() {
         .unwrap();
 
     let handler = UserHandler::new(client);
-    let (users, _pagination) = handler.list(None, Some(2)).await.unwrap();
+    let (users, _pagination) = handler.list(None, Some(2), None).await.unwrap();
 
     assert_eq!(users.len(), 2);
 }
@@ -156,7 +157,7 @@ async fn test_authentication_failed() {
         .unwrap();
 
     let handler = UserHandler::new(client);
-    let result = handler.list(None, None).await;
+    let result = handler.list(None, None, None).await;
 
     assert!(result.is_err());
     match result {
@@ -187,3 +188,207 @@ async fn test_delete_user_success() {
 
     assert!(result.is_ok());
 }
+
+#[tokio::test]
+async fn test_list_users_returns_cursor() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!([{"id": 1}]))
+                .insert_header("X-Files-Cursor-Next", "page-2"),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = UserHandler::new(client);
+    let (_users, pagination) = handler.list(None, None, None).await.unwrap();
+
+    assert!(pagination.has_next());
+    assert_eq!(pagination.cursor_next.as_deref(), Some("page-2"));
+}
+
+#[tokio::test]
+async fn test_list_users_stream_follows_cursors() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .and(query_param("cursor", "page-2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{"id": 3}])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!([{"id": 1}, {"id": 2}]))
+                .insert_header("X-Files-Cursor-Next", "page-2"),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = UserHandler::new(client);
+    let users = handler
+        .list_stream(Some(2), None)
+        .collect_all()
+        .await
+        .unwrap();
+
+    let ids: Vec<_> = users.iter().map(|u| u.id).collect();
+    assert_eq!(ids, vec![Some(1), Some(2), Some(3)]);
+}
+
+#[tokio::test]
+async fn test_list_users_stream_take_pages() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!([{"id": 1}]))
+                .insert_header("X-Files-Cursor-Next", "page-2"),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = UserHandler::new(client);
+    let users = handler
+        .list_stream(None, None)
+        .take_pages(1)
+        .collect_all()
+        .await
+        .unwrap();
+
+    assert_eq!(users.len(), 1);
+}
+
+#[tokio::test]
+async fn test_list_users_with_query() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .and(query_param("per_page", "10"))
+        .and(query_param("sort_by[last_login_at]", "desc"))
+        .and(query_param("filter[site_admin]", "true"))
+        .and(query_param("filter_prefix[username]", "ops-"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{"id": 1}])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = UserHandler::new(client);
+    let query = ListQuery::new()
+        .sort_by(UserField::LastLoginAt, SortDirection::Desc)
+        .filter(UserField::SiteAdmin, true)
+        .filter_prefix(UserField::Username, "ops-");
+    let (users, _) = handler.list(None, Some(10), Some(&query)).await.unwrap();
+
+    assert_eq!(users.len(), 1);
+}
+
+#[tokio::test]
+async fn test_list_users_stream_keeps_query_across_pages() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .and(query_param("cursor", "page-2"))
+        .and(query_param("filter[company]", "Acme"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{"id": 2}])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .and(query_param("filter[company]", "Acme"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!([{"id": 1}]))
+                .insert_header("X-Files-Cursor-Next", "page-2"),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = UserHandler::new(client);
+    let query = ListQuery::new().filter(UserField::Company, "Acme");
+    let users = handler
+        .list_stream(Some(1), Some(query))
+        .collect_all()
+        .await
+        .unwrap();
+
+    assert_eq!(users.len(), 2);
+}
+
+#[tokio::test]
+async fn test_update_2fa() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/users/5/2fa"))
+        .and(body_json(
+            serde_json::json!({ "require_2fa": "always_require" }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": 5,
+            "require_2fa": "always_require"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = FilesClient::builder()
+        .api_key("test-key")
+        .base_url(mock_server.uri())
+        .build()
+        .unwrap();
+
+    let handler = CurrentUserHandler::new(client);
+    let user = handler
+        .update_2fa(5, Update2faParams::new().require_2fa("always_require"))
+        .await
+        .unwrap();
+
+    assert_eq!(user.require_2fa.as_deref(), Some("always_require"));
+}
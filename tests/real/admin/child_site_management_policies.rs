@@ -8,7 +8,7 @@ async fn test_real_api_list_child_site_management_policies() {
     let client = get_test_client();
     let handler = ChildSiteManagementPolicyHandler::new(client);
 
-    let result = handler.list(None, Some(10)).await;
+    let result = handler.list(None, Some(10), None).await;
 
     match result {
         Ok((policies, pagination)) => {
@@ -29,14 +29,14 @@ async fn test_real_api_get_child_site_management_policy() {
     let client = get_test_client();
     let handler = ChildSiteManagementPolicyHandler::new(client);
 
-    let list_result = handler.list(None, Some(1)).await;
+    let list_result = handler.list(None, Some(1), None).await;
 
     match list_result {
         Ok((policies, _)) =>
         {
             #[allow(clippy::collapsible_if)]
             if let Some(policy) = policies.first() {
-                if let Some(id) = policy.data.get("id").and_then(|v| v.as_i64()) {
+                if let Some(id) = policy.id {
                     let get_result = handler.get(id).await;
                     match get_result {
                         Ok(retrieved) => {
@@ -60,14 +60,14 @@ async fn test_real_api_child_site_management_policies_pagination() {
     let client = get_test_client();
     let handler = ChildSiteManagementPolicyHandler::new(client);
 
-    let result = handler.list(None, Some(1)).await;
+    let result = handler.list(None, Some(1), None).await;
 
     match result {
         Ok((policies, pagination)) => {
             println!("First page: {} policies", policies.len());
 
             if let Some(cursor) = pagination.cursor_next {
-                let result2 = handler.list(Some(cursor), Some(1)).await;
+                let result2 = handler.list(Some(cursor), Some(1), None).await;
                 match result2 {
                     Ok((policies2, _)) => {
                         println!("Second page: {} policies", policies2.len());
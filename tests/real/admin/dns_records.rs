@@ -8,10 +8,10 @@ async fn test_real_api_list_dns_records() {
     let client = get_test_client();
     let handler = DnsRecordHandler::new(client);
 
-    let result = handler.list().await;
+    let result = handler.list(None, None).await;
 
     match result {
-        Ok(records) => {
+        Ok((records, _)) => {
             println!("Listed {} DNS records", records.len());
             if let Some(first) = records.first() {
                 println!("Sample record: {:?}", first);
@@ -9,7 +9,7 @@ async fn test_real_api_list_history_for_folder() {
     let handler = HistoryHandler::new(client);
 
     let result = handler
-        .list_for_folder("/integration-tests", None, Some(10))
+        .list_for_folder("/integration-tests", None, Some(10), None)
         .await;
 
     match result {
@@ -29,7 +29,7 @@ async fn test_real_api_list_history_for_file() {
     let handler = HistoryHandler::new(client);
 
     let result = handler
-        .list_for_file("/integration-tests/test.txt", None, Some(10))
+        .list_for_file("/integration-tests/test.txt", None, Some(10), None)
         .await;
 
     match result {
@@ -48,7 +48,7 @@ async fn test_real_api_list_logins() {
     let client = get_test_client();
     let handler = HistoryHandler::new(client);
 
-    let result = handler.list_logins(None, Some(10)).await;
+    let result = handler.list_logins(None, Some(10), None).await;
 
     match result {
         Ok((entries, pagination)) => {
@@ -66,14 +66,16 @@ async fn test_real_api_history_pagination() {
     let client = get_test_client();
     let handler = HistoryHandler::new(client);
 
-    let result = handler.list_for_folder("/", None, Some(1)).await;
+    let result = handler.list_for_folder("/", None, Some(1), None).await;
 
     match result {
         Ok((entries, pagination)) => {
             println!("First page: {} entries", entries.len());
 
             if let Some(cursor) = pagination.cursor_next {
-                let result2 = handler.list_for_folder("/", Some(&cursor), Some(1)).await;
+                let result2 = handler
+                    .list_for_folder("/", Some(&cursor), Some(1), None)
+                    .await;
                 match result2 {
                     Ok((entries2, _)) => {
                         println!("Second page: {} entries", entries2.len());
@@ -8,10 +8,10 @@ async fn test_real_api_list_holiday_regions() {
     let client = get_test_client();
     let handler = HolidayRegionHandler::new(client);
 
-    let result = handler.list().await;
+    let result = handler.list(None, None).await;
 
     match result {
-        Ok(regions) => {
+        Ok((regions, _)) => {
             println!("Listed {} holiday regions", regions.len());
             if let Some(first) = regions.first() {
                 println!("Sample region: {:?}", first);
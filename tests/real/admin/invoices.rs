@@ -8,7 +8,7 @@ async fn test_real_api_list_invoices() {
     let client = get_test_client();
     let handler = InvoiceHandler::new(client);
 
-    let result = handler.list(None, None).await;
+    let result = handler.list(None, None, None).await;
 
     match result {
         Ok((invoices, pagination)) => {
@@ -29,7 +29,7 @@ async fn test_real_api_get_invoice() {
     let client = get_test_client();
     let handler = InvoiceHandler::new(client);
 
-    let list_result = handler.list(None, None).await;
+    let list_result = handler.list(None, None, None).await;
 
     match list_result {
         Ok((invoices, _)) =>
@@ -61,14 +61,14 @@ async fn test_real_api_invoices_pagination() {
     let client = get_test_client();
     let handler = InvoiceHandler::new(client);
 
-    let result = handler.list(None, Some(1)).await;
+    let result = handler.list(None, Some(1), None).await;
 
     match result {
         Ok((invoices, pagination)) => {
             println!("First page: {} invoices", invoices.len());
 
             if let Some(cursor) = pagination.cursor_next {
-                let result2 = handler.list(Some(&cursor), Some(1)).await;
+                let result2 = handler.list(Some(&cursor), Some(1), None).await;
                 match result2 {
                     Ok((invoices2, _)) => {
                         println!("Second page: {} invoices", invoices2.len());
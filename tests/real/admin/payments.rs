@@ -8,7 +8,7 @@ async fn test_real_api_list_payments() {
     let client = get_test_client();
     let handler = PaymentHandler::new(client);
 
-    let result = handler.list(None, None).await;
+    let result = handler.list(None, None, None).await;
 
     match result {
         Ok((payments, pagination)) => {
@@ -29,7 +29,7 @@ async fn test_real_api_get_payment() {
     let client = get_test_client();
     let handler = PaymentHandler::new(client);
 
-    let list_result = handler.list(None, None).await;
+    let list_result = handler.list(None, None, None).await;
 
     match list_result {
         Ok((payments, _)) =>
@@ -61,14 +61,14 @@ async fn test_real_api_payments_pagination() {
     let client = get_test_client();
     let handler = PaymentHandler::new(client);
 
-    let result = handler.list(None, Some(1)).await;
+    let result = handler.list(None, Some(1), None).await;
 
     match result {
         Ok((payments, pagination)) => {
             println!("First page: {} payments", payments.len());
 
             if let Some(cursor) = pagination.cursor_next {
-                let result2 = handler.list(Some(&cursor), Some(1)).await;
+                let result2 = handler.list(Some(&cursor), Some(1), None).await;
                 match result2 {
                     Ok((payments2, _)) => {
                         println!("Second page: {} payments", payments2.len());
@@ -11,10 +11,10 @@ async fn test_real_api_list_as2_incoming_messages() {
 
     println!("Testing AS2 incoming message listing");
 
-    let result = handler.list().await;
+    let result = handler.list(None, None, None).await;
 
     match result {
-        Ok(messages) => {
+        Ok((messages, _)) => {
             println!(
                 "Successfully listed {} AS2 incoming messages",
                 messages.len()
@@ -22,10 +22,7 @@ async fn test_real_api_list_as2_incoming_messages() {
 
             if !messages.is_empty() {
                 let first = &messages[0];
-                println!(
-                    "First AS2 incoming message fields: {:?}",
-                    first.data.keys().collect::<Vec<_>>()
-                );
+                println!("First AS2 incoming message: {:?}", first);
             } else {
                 println!("No AS2 incoming messages available");
             }
@@ -11,7 +11,7 @@ async fn test_real_api_list_as2_stations() {
 
     println!("Testing AS2 station listing");
 
-    let result = handler.list(None, Some(10)).await;
+    let result = handler.list(None, Some(10), None).await;
 
     match result {
         Ok((stations, pagination)) => {
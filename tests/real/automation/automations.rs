@@ -10,7 +10,7 @@ async fn test_list_automations() {
 
     println!("Testing automation listing");
 
-    let result = handler.list(None, Some(10), None).await;
+    let result = handler.list(None, Some(10), None, None).await;
 
     match result {
         Ok((automations, pagination)) => {
@@ -9,7 +9,7 @@ async fn test_real_api_list_behaviors() {
     let client = get_test_client();
     let handler = BehaviorHandler::new(client);
 
-    let result = handler.list(None, Some(10), None, None, None).await;
+    let result = handler.list(None, Some(10), None).await;
 
     match result {
         Ok((behaviors, pagination)) => {
@@ -63,7 +63,7 @@ async fn test_real_api_list_behaviors_for_folder() {
     let _ = folder_handler.create_folder(test_folder, true).await;
 
     let result = behavior_handler
-        .list_for_folder(test_folder, None, None)
+        .list_for_folder(test_folder, None, None, None)
         .await;
 
     match result {
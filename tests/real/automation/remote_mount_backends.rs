@@ -14,7 +14,7 @@ async fn test_real_api_list_remote_mount_backends() {
     let client = get_test_client();
     let handler = RemoteMountBackendHandler::new(client);
 
-    let result = handler.list(None, Some(10)).await;
+    let result = handler.list(None, Some(10), None).await;
 
     match result {
         Ok((backends, pagination)) => {
@@ -40,13 +40,13 @@ async fn test_real_api_get_remote_mount_backend() {
     let handler = RemoteMountBackendHandler::new(client);
 
     // First, get a list to find an ID
-    let list_result = handler.list(None, Some(1)).await;
+    let list_result = handler.list(None, Some(1), None).await;
 
     match list_result {
         Ok((backends, _)) => {
             if let Some(backend) = backends.first() {
-                // Extract ID from the backend data
-                if let Some(id) = backend.data.get("id").and_then(|v| v.as_i64()) {
+                // Use the ID of the first backend
+                if let Some(id) = backend.id {
                     // Try to get that specific backend
                     let get_result = handler.get(id).await;
                     match get_result {
@@ -74,7 +74,7 @@ async fn test_real_api_remote_mount_backends_pagination() {
     let handler = RemoteMountBackendHandler::new(client);
 
     // Test pagination with small page size
-    let result = handler.list(None, Some(1)).await;
+    let result = handler.list(None, Some(1), None).await;
 
     match result {
         Ok((backends, pagination)) => {
@@ -82,7 +82,7 @@ async fn test_real_api_remote_mount_backends_pagination() {
 
             if let Some(cursor) = pagination.cursor_next {
                 // Fetch second page
-                let result2 = handler.list(Some(cursor), Some(1)).await;
+                let result2 = handler.list(Some(cursor), Some(1), None).await;
                 match result2 {
                     Ok((backends2, _)) => {
                         println!("Second page: {} remote mount backends", backends2.len());
@@ -104,14 +104,14 @@ async fn test_real_api_remote_mount_backends_structure() {
     let client = get_test_client();
     let handler = RemoteMountBackendHandler::new(client);
 
-    let result = handler.list(None, Some(5)).await;
+    let result = handler.list(None, Some(5), None).await;
 
     match result {
         Ok((backends, _)) => {
             println!("Remote mount backends count: {}", backends.len());
 
             for backend in backends.iter() {
-                println!("Backend data keys: {:?}", backend.data.keys());
+                println!("Backend: {:?}", backend);
             }
         }
         Err(e) => {
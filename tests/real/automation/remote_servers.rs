@@ -14,7 +14,7 @@ async fn test_real_api_list_remote_servers() {
     let client = get_test_client();
     let handler = RemoteServerHandler::new(client);
 
-    let result = handler.list(None, None).await;
+    let result = handler.list(None, None, None).await;
 
     match result {
         Ok((servers, pagination)) => {
@@ -40,7 +40,7 @@ async fn test_real_api_get_remote_server() {
     let handler = RemoteServerHandler::new(client);
 
     // First, get a list to find an ID
-    let list_result = handler.list(None, None).await;
+    let list_result = handler.list(None, None, None).await;
 
     match list_result {
         Ok((servers, _)) => {
@@ -74,7 +74,7 @@ async fn test_real_api_remote_servers_pagination() {
     let handler = RemoteServerHandler::new(client);
 
     // Test pagination with small page size
-    let result = handler.list(None, Some(1)).await;
+    let result = handler.list(None, Some(1), None).await;
 
     match result {
         Ok((servers, pagination)) => {
@@ -82,7 +82,7 @@ async fn test_real_api_remote_servers_pagination() {
 
             if let Some(cursor) = pagination.cursor_next {
                 // Fetch second page
-                let result2 = handler.list(Some(&cursor), Some(1)).await;
+                let result2 = handler.list(Some(&cursor), Some(1), None).await;
                 match result2 {
                     Ok((servers2, _)) => {
                         println!("Second page: {} remote servers", servers2.len());
@@ -104,7 +104,7 @@ async fn test_real_api_remote_servers_structure() {
     let client = get_test_client();
     let handler = RemoteServerHandler::new(client);
 
-    let result = handler.list(None, Some(5)).await;
+    let result = handler.list(None, Some(5), None).await;
 
     match result {
         Ok((servers, _)) => {
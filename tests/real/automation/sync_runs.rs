@@ -14,7 +14,7 @@ async fn test_real_api_list_sync_runs() {
     let client = get_test_client();
     let handler = SyncRunHandler::new(client);
 
-    let result = handler.list(None, Some(10)).await;
+    let result = handler.list(None, Some(10), None).await;
 
     match result {
         Ok((runs, pagination)) => {
@@ -40,13 +40,13 @@ async fn test_real_api_get_sync_run() {
     let handler = SyncRunHandler::new(client);
 
     // First, get a list to find an ID
-    let list_result = handler.list(None, Some(1)).await;
+    let list_result = handler.list(None, Some(1), None).await;
 
     match list_result {
         Ok((runs, _)) => {
             if let Some(run) = runs.first() {
-                // Extract ID from the run data
-                if let Some(id) = run.data.get("id").and_then(|v| v.as_i64()) {
+                // Use the ID of the first run
+                if let Some(id) = run.id {
                     // Try to get that specific run
                     let get_result = handler.get(id).await;
                     match get_result {
@@ -74,7 +74,7 @@ async fn test_real_api_sync_runs_pagination() {
     let handler = SyncRunHandler::new(client);
 
     // Test pagination with small page size
-    let result = handler.list(None, Some(1)).await;
+    let result = handler.list(None, Some(1), None).await;
 
     match result {
         Ok((runs, pagination)) => {
@@ -82,7 +82,7 @@ async fn test_real_api_sync_runs_pagination() {
 
             if let Some(cursor) = pagination.cursor_next {
                 // Fetch second page
-                let result2 = handler.list(Some(cursor), Some(1)).await;
+                let result2 = handler.list(Some(cursor), Some(1), None).await;
                 match result2 {
                     Ok((runs2, _)) => {
                         println!("Second page: {} sync runs", runs2.len());
@@ -104,14 +104,14 @@ async fn test_real_api_sync_runs_structure() {
     let client = get_test_client();
     let handler = SyncRunHandler::new(client);
 
-    let result = handler.list(None, Some(5)).await;
+    let result = handler.list(None, Some(5), None).await;
 
     match result {
         Ok((runs, _)) => {
             println!("Sync runs count: {}", runs.len());
 
             for run in runs.iter() {
-                println!("Sync run data keys: {:?}", run.data.keys());
+                println!("Sync run: {:?}", run);
             }
         }
         Err(e) => {
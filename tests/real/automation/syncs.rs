@@ -14,7 +14,7 @@ async fn test_real_api_list_syncs() {
     let client = get_test_client();
     let handler = SyncHandler::new(client);
 
-    let result = handler.list(None, Some(10)).await;
+    let result = handler.list(None, Some(10), None).await;
 
     match result {
         Ok((syncs, pagination)) => {
@@ -37,13 +37,13 @@ async fn test_real_api_get_sync() {
     let handler = SyncHandler::new(client);
 
     // First, get a list to find an ID
-    let list_result = handler.list(None, Some(1)).await;
+    let list_result = handler.list(None, Some(1), None).await;
 
     match list_result {
         Ok((syncs, _)) => {
             if let Some(sync) = syncs.first() {
-                // Extract ID from the sync data
-                if let Some(id) = sync.data.get("id").and_then(|v| v.as_i64()) {
+                // Use the ID of the first sync
+                if let Some(id) = sync.id {
                     // Try to get that specific sync
                     let get_result = handler.get(id).await;
                     match get_result {
@@ -71,7 +71,7 @@ async fn test_real_api_syncs_pagination() {
     let handler = SyncHandler::new(client);
 
     // Test pagination with small page size
-    let result = handler.list(None, Some(1)).await;
+    let result = handler.list(None, Some(1), None).await;
 
     match result {
         Ok((syncs, pagination)) => {
@@ -79,7 +79,7 @@ async fn test_real_api_syncs_pagination() {
 
             if let Some(cursor) = pagination.cursor_next {
                 // Fetch second page
-                let result2 = handler.list(Some(cursor), Some(1)).await;
+                let result2 = handler.list(Some(cursor), Some(1), None).await;
                 match result2 {
                     Ok((syncs2, _)) => {
                         println!("Second page: {} syncs", syncs2.len());
@@ -101,14 +101,14 @@ async fn test_real_api_syncs_structure() {
     let client = get_test_client();
     let handler = SyncHandler::new(client);
 
-    let result = handler.list(None, Some(5)).await;
+    let result = handler.list(None, Some(5), None).await;
 
     match result {
         Ok((syncs, _)) => {
             println!("Syncs count: {}", syncs.len());
 
             for sync in syncs.iter() {
-                println!("Sync data keys: {:?}", sync.data.keys());
+                println!("Sync: {:?}", sync);
             }
         }
         Err(e) => {
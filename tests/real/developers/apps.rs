@@ -11,18 +11,15 @@ async fn test_real_api_list_apps() {
 
     println!("Testing app listing");
 
-    let result = handler.list().await;
+    let result = handler.list(None, None, None).await;
 
     match result {
-        Ok(apps) => {
+        Ok((apps, _)) => {
             println!("Successfully listed {} apps", apps.len());
 
             if !apps.is_empty() {
                 let first = &apps[0];
-                println!(
-                    "First app fields: {:?}",
-                    first.data.keys().collect::<Vec<_>>()
-                );
+                println!("First app: {:?}", first);
             } else {
                 println!("No apps available");
             }
@@ -53,10 +53,10 @@ async fn test_file_comment_workflow() {
     };
 
     // Step 2: List comments on the file
-    let list_result = comment_handler.list(test_file).await;
+    let list_result = comment_handler.list(test_file, None, None).await;
 
     match list_result {
-        Ok(comments) => {
+        Ok((comments, _)) => {
             println!("Listed {} comments on file", comments.len());
             assert!(!comments.is_empty(), "Should have at least one comment");
         }
@@ -234,10 +234,10 @@ async fn test_multiple_comments_on_file() {
     }
 
     // List all comments
-    let list_result = comment_handler.list(test_file).await;
+    let list_result = comment_handler.list(test_file, None, None).await;
 
     match list_result {
-        Ok(comments) => {
+        Ok((comments, _)) => {
             println!("Listed {} comments", comments.len());
             assert!(
                 comments.len() >= comment_ids.len(),
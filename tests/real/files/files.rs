@@ -170,7 +170,7 @@ async fn test_real_api_folder_operations() {
 
             // List folder contents
             let list_result = folder_handler
-                .list_folder("/integration-tests", None, None)
+                .list_folder("/integration-tests", None, None, None)
                 .await;
 
             match list_result {
@@ -287,7 +287,7 @@ async fn test_upload_directory() {
 
             // Verify files exist
             let (files, _) = folder_handler
-                .list_folder(remote_path, None, None)
+                .list_folder(remote_path, None, None, None)
                 .await
                 .unwrap();
             println!("Found {} items in remote directory", files.len());
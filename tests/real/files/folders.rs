@@ -29,7 +29,7 @@ async fn test_folder_list_with_pagination() {
 
     // List with small page size
     let list_result = folder_handler
-        .list_folder(test_folder, Some(2), None) // 2 items per page
+        .list_folder(test_folder, Some(2), None, None) // 2 items per page
         .await;
 
     match list_result {
@@ -42,7 +42,7 @@ async fn test_folder_list_with_pagination() {
                 println!("Testing next page with cursor: {}", next_cursor);
 
                 let next_page = folder_handler
-                    .list_folder(test_folder, Some(2), Some(next_cursor))
+                    .list_folder(test_folder, Some(2), Some(next_cursor), None)
                     .await;
 
                 match next_page {
@@ -98,7 +98,7 @@ async fn test_folder_search_functionality() {
 
     // Search for files containing "important"
     let search_result = folder_handler
-        .search_folder(test_folder, "important", None)
+        .search_folder(test_folder, "important", None, None)
         .await;
 
     match search_result {
@@ -154,7 +154,7 @@ async fn test_folder_create_with_mkdir_parents() {
 
             // Verify parent folders were created
             let parent_list = folder_handler
-                .list_folder("/integration-tests", None, None)
+                .list_folder("/integration-tests", None, None, None)
                 .await;
 
             if let Ok((files, _)) = parent_list {
@@ -216,7 +216,7 @@ async fn test_folder_delete_recursive() {
 
             // Verify folder is gone
             let list_result = folder_handler
-                .list_folder("/integration-tests", None, None)
+                .list_folder("/integration-tests", None, None, None)
                 .await;
 
             if let Ok((files, _)) = list_result {
@@ -259,7 +259,9 @@ async fn test_folder_list_empty_folder() {
 
     println!("Testing listing of empty folder");
 
-    let list_result = folder_handler.list_folder(empty_folder, None, None).await;
+    let list_result = folder_handler
+        .list_folder(empty_folder, None, None, None)
+        .await;
 
     match list_result {
         Ok((files, _)) => {
@@ -288,7 +290,9 @@ async fn test_folder_not_found_error() {
 
     println!("Testing folder not found error");
 
-    let list_result = folder_handler.list_folder(nonexistent, None, None).await;
+    let list_result = folder_handler
+        .list_folder(nonexistent, None, None, None)
+        .await;
 
     match list_result {
         Err(FilesError::NotFound { message, .. }) => {
@@ -368,7 +372,7 @@ async fn test_folder_with_special_characters() {
 
                 // Verify it exists by listing parent
                 let list_result = folder_handler
-                    .list_folder("/integration-tests", None, None)
+                    .list_folder("/integration-tests", None, None, None)
                     .await;
 
                 if let Ok((files, _)) = list_result {
@@ -412,7 +416,7 @@ async fn test_folder_list_stream() {
     println!("Testing streaming pagination with per_page=3");
 
     // Use streaming with small page size to test pagination
-    let stream = folder_handler.list_stream(test_folder, Some(3));
+    let stream = folder_handler.list_stream(test_folder, Some(3), None);
     let mut count = 0;
 
     tokio::pin!(stream);
@@ -447,7 +451,7 @@ async fn test_folder_list_stream_collect() {
     println!("Testing stream collect on integration-tests folder");
 
     // Collect all files using stream
-    let stream = folder_handler.list_stream("/integration-tests", Some(10));
+    let stream = folder_handler.list_stream("/integration-tests", Some(10), None);
     let files: Vec<_> = stream.try_collect().await.unwrap_or_default();
 
     println!("Collected {} files via stream", files.len());
@@ -141,7 +141,7 @@ async fn test_folder_with_spaces() {
     let result = folder_handler.create_folder(path, true).await;
     if result.is_err() {
         // Folder might still exist from previous run, verify we can access it instead
-        let list_result = folder_handler.list_folder(path, None, None).await;
+        let list_result = folder_handler.list_folder(path, None, None, None).await;
         assert!(
             list_result.is_ok(),
             "Failed to access existing folder with spaces"
@@ -156,7 +156,10 @@ async fn test_folder_with_spaces() {
     );
 
     // List the folder
-    let (files, _) = folder_handler.list_folder(path, None, None).await.unwrap();
+    let (files, _) = folder_handler
+        .list_folder(path, None, None, None)
+        .await
+        .unwrap();
     assert_eq!(files.len(), 0, "Newly created folder should be empty");
 
     // Cleanup
@@ -11,7 +11,7 @@ async fn test_real_api_list_siem_http_destinations() {
 
     println!("Testing SIEM HTTP destination listing");
 
-    let result = handler.list(None, Some(10)).await;
+    let result = handler.list(None, Some(10), None).await;
 
     match result {
         Ok((destinations, pagination)) => {
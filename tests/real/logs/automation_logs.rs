@@ -11,7 +11,7 @@ async fn test_real_api_list_automation_logs() {
 
     println!("Testing automation log listing");
 
-    let result = handler.list(None, Some(10)).await;
+    let result = handler.list(None, Some(10), None).await;
 
     match result {
         Ok((logs, pagination)) => {
@@ -23,10 +23,7 @@ async fn test_real_api_list_automation_logs() {
 
             if !logs.is_empty() {
                 let first = &logs[0];
-                println!(
-                    "First automation log fields: {:?}",
-                    first.data.keys().collect::<Vec<_>>()
-                );
+                println!("First automation log: {:?}", first);
             } else {
                 println!("No automation logs available");
             }
@@ -11,10 +11,10 @@ async fn test_real_api_list_email_incoming_messages() {
 
     println!("Testing email incoming message listing");
 
-    let result = handler.list().await;
+    let result = handler.list(None, None, None).await;
 
     match result {
-        Ok(messages) => {
+        Ok((messages, _)) => {
             println!(
                 "Successfully listed {} email incoming messages",
                 messages.len()
@@ -22,10 +22,7 @@ async fn test_real_api_list_email_incoming_messages() {
 
             if !messages.is_empty() {
                 let first = &messages[0];
-                println!(
-                    "First email incoming message fields: {:?}",
-                    first.data.keys().collect::<Vec<_>>()
-                );
+                println!("First email incoming message: {:?}", first);
             } else {
                 println!("No email incoming messages available");
             }
@@ -11,7 +11,7 @@ async fn test_real_api_list_external_events() {
 
     println!("Testing external event listing");
 
-    let result = handler.list(None, Some(10)).await;
+    let result = handler.list(None, Some(10), None).await;
 
     match result {
         Ok((events, pagination)) => {
@@ -23,10 +23,7 @@ async fn test_real_api_list_external_events() {
 
             if !events.is_empty() {
                 let first = &events[0];
-                println!(
-                    "First external event fields: {:?}",
-                    first.data.keys().collect::<Vec<_>>()
-                );
+                println!("First external event: {:?}", first);
             } else {
                 println!("No external events available");
             }
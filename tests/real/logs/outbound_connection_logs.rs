@@ -11,7 +11,7 @@ async fn test_real_api_list_outbound_connection_logs() {
 
     println!("Testing outbound connection log listing");
 
-    let result = handler.list(None, Some(10)).await;
+    let result = handler.list(None, Some(10), None).await;
 
     match result {
         Ok((logs, pagination)) => {
@@ -26,10 +26,7 @@ async fn test_real_api_list_outbound_connection_logs() {
 
             if !logs.is_empty() {
                 let first = &logs[0];
-                println!(
-                    "First outbound connection log fields: {:?}",
-                    first.data.keys().collect::<Vec<_>>()
-                );
+                println!("First outbound connection log: {:?}", first);
             } else {
                 println!("No outbound connection logs available");
             }
@@ -11,10 +11,10 @@ async fn test_real_api_list_public_hosting_request_logs() {
 
     println!("Testing public hosting request log listing");
 
-    let result = handler.list().await;
+    let result = handler.list(None, None, None).await;
 
     match result {
-        Ok(logs) => {
+        Ok((logs, _)) => {
             println!(
                 "Successfully listed {} public hosting request logs",
                 logs.len()
@@ -22,10 +22,7 @@ async fn test_real_api_list_public_hosting_request_logs() {
 
             if !logs.is_empty() {
                 let first = &logs[0];
-                println!(
-                    "First public hosting request log fields: {:?}",
-                    first.data.keys().collect::<Vec<_>>()
-                );
+                println!("First public hosting request log: {:?}", first);
             } else {
                 println!("No public hosting request logs available");
             }
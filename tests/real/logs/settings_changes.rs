@@ -11,7 +11,7 @@ async fn test_real_api_list_settings_changes() {
 
     println!("Testing settings change listing");
 
-    let result = handler.list(None, Some(10)).await;
+    let result = handler.list(None, Some(10), None).await;
 
     match result {
         Ok((changes, pagination)) => {
@@ -23,10 +23,7 @@ async fn test_real_api_list_settings_changes() {
 
             if !changes.is_empty() {
                 let first = &changes[0];
-                println!(
-                    "First settings change fields: {:?}",
-                    first.data.keys().collect::<Vec<_>>()
-                );
+                println!("First settings change: {:?}", first);
             } else {
                 println!("No settings changes available");
             }
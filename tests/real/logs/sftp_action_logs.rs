@@ -11,7 +11,7 @@ async fn test_real_api_list_sftp_action_logs() {
 
     println!("Testing SFTP action log listing");
 
-    let result = handler.list(None, Some(10)).await;
+    let result = handler.list(None, Some(10), None).await;
 
     match result {
         Ok((logs, pagination)) => {
@@ -23,10 +23,7 @@ async fn test_real_api_list_sftp_action_logs() {
 
             if !logs.is_empty() {
                 let first = &logs[0];
-                println!(
-                    "First SFTP action log fields: {:?}",
-                    first.data.keys().collect::<Vec<_>>()
-                );
+                println!("First SFTP action log: {:?}", first);
             } else {
                 println!("No SFTP action logs available");
             }
@@ -11,7 +11,7 @@ async fn test_real_api_list_message_comments() {
 
     println!("Testing message comment listing");
 
-    let result = handler.list(None, None, Some(10)).await;
+    let result = handler.list(None, None, Some(10), None).await;
 
     match result {
         Ok((comments, pagination)) => {
@@ -23,10 +23,7 @@ async fn test_real_api_list_message_comments() {
 
             if !comments.is_empty() {
                 let first = &comments[0];
-                println!(
-                    "First message comment fields: {:?}",
-                    first.data.keys().collect::<Vec<_>>()
-                );
+                println!("First message comment: {:?}", first);
             } else {
                 println!("No message comments available");
             }
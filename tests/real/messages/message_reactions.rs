@@ -13,7 +13,7 @@ async fn test_real_api_list_message_reactions() {
 
     // Note: This endpoint requires a message_id, which we may not have in test data.
     // Using a test ID of 1 - will likely return empty results or error
-    let result = handler.list(1, None, Some(10)).await;
+    let result = handler.list(1, None, Some(10), None).await;
 
     match result {
         Ok((reactions, pagination)) => {
@@ -25,10 +25,7 @@ async fn test_real_api_list_message_reactions() {
 
             if !reactions.is_empty() {
                 let first = &reactions[0];
-                println!(
-                    "First message reaction fields: {:?}",
-                    first.data.keys().collect::<Vec<_>>()
-                );
+                println!("First message reaction: {:?}", first);
             } else {
                 println!("No message reactions available for test message ID");
             }
@@ -11,7 +11,7 @@ async fn test_real_api_list_messages() {
 
     println!("Testing message listing");
 
-    let result = handler.list(None, Some(10), None).await;
+    let result = handler.list(None, Some(10), None, None).await;
 
     match result {
         Ok((messages, pagination)) => {
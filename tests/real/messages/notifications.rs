@@ -11,7 +11,7 @@ async fn test_real_api_list_notifications() {
 
     println!("Testing notification listing");
 
-    let result = handler.list(None, Some(10), None, None).await;
+    let result = handler.list(None, Some(10), None, None, None).await;
 
     match result {
         Ok((notifications, pagination)) => {
@@ -11,7 +11,7 @@ async fn test_real_api_list_clickwraps() {
 
     println!("Testing clickwrap listing");
 
-    let result = handler.list(None, Some(10)).await;
+    let result = handler.list(None, Some(10), None).await;
 
     match result {
         Ok((clickwraps, pagination)) => {
@@ -23,11 +23,7 @@ async fn test_real_api_list_clickwraps() {
 
             if !clickwraps.is_empty() {
                 let first = &clickwraps[0];
-                println!(
-                    "First clickwrap: id={:?}, fields={:?}",
-                    first.id,
-                    first.data.keys().collect::<Vec<_>>()
-                );
+                println!("First clickwrap: id={:?}", first.id);
             } else {
                 println!("No clickwraps found");
             }
@@ -11,7 +11,7 @@ async fn test_real_api_list_gpg_keys() {
 
     println!("Testing GPG key listing");
 
-    let result = handler.list(None, Some(10)).await;
+    let result = handler.list(None, Some(10), None).await;
 
     match result {
         Ok((keys, pagination)) => {
@@ -23,11 +23,7 @@ async fn test_real_api_list_gpg_keys() {
 
             if !keys.is_empty() {
                 let first = &keys[0];
-                println!(
-                    "First GPG key: id={:?}, fields={:?}",
-                    first.id,
-                    first.data.keys().collect::<Vec<_>>()
-                );
+                println!("First GPG key: id={:?}", first.id);
             } else {
                 println!("No GPG keys found");
             }
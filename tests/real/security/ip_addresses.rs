@@ -11,7 +11,7 @@ async fn test_real_api_list_ip_addresses() {
 
     println!("Testing IP address listing");
 
-    let result = handler.list(None, Some(10)).await;
+    let result = handler.list(None, Some(10), None).await;
 
     match result {
         Ok((ip_addresses, pagination)) => {
@@ -23,10 +23,7 @@ async fn test_real_api_list_ip_addresses() {
 
             if !ip_addresses.is_empty() {
                 let first = &ip_addresses[0];
-                println!(
-                    "First IP address fields: {:?}",
-                    first.data.keys().collect::<Vec<_>>()
-                );
+                println!("First IP address: {:?}", first);
             } else {
                 println!("No IP addresses found");
             }
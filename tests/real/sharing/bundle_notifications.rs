@@ -7,15 +7,15 @@
 //! BundleNotifications configure email notifications for bundle activity.
 
 use crate::real::*;
+use files_sdk::sharing::{CreateBundleNotificationParams, UpdateBundleNotificationParams};
 use files_sdk::{BundleHandler, BundleNotificationHandler, FileHandler, FolderHandler};
-use serde_json::json;
 
 #[tokio::test]
 async fn test_real_api_list_bundle_notifications() {
     let client = get_test_client();
     let handler = BundleNotificationHandler::new(client);
 
-    let result = handler.list(None, Some(10)).await;
+    let result = handler.list(None, Some(10), None).await;
 
     match result {
         Ok((notifications, pagination)) => {
@@ -77,11 +77,10 @@ async fn test_real_api_create_and_delete_bundle_notification() {
     let bundle_id = bundle.id.expect("Bundle should have an ID");
 
     // Create notification for the bundle
-    let params = json!({
-        "bundle_id": bundle_id,
-        "notify_on_registration": true,
-        "notify_on_upload": true
-    });
+    let params = CreateBundleNotificationParams::new()
+        .bundle_id(bundle_id)
+        .notify_on_registration(true)
+        .notify_on_upload(true);
 
     let create_result = notification_handler.create(params).await;
 
@@ -163,10 +162,9 @@ async fn test_real_api_update_bundle_notification() {
     let bundle_id = bundle.id.expect("Bundle should have ID");
 
     // Create notification
-    let params = json!({
-        "bundle_id": bundle_id,
-        "notify_on_registration": false
-    });
+    let params = CreateBundleNotificationParams::new()
+        .bundle_id(bundle_id)
+        .notify_on_registration(false);
 
     let notification = match notification_handler.create(params).await {
         Ok(n) => n,
@@ -181,10 +179,9 @@ async fn test_real_api_update_bundle_notification() {
     let notification_id = notification.id.expect("Notification should have ID");
 
     // Update notification
-    let update_params = json!({
-        "notify_on_registration": true,
-        "notify_on_upload": false
-    });
+    let update_params = UpdateBundleNotificationParams::new()
+        .notify_on_registration(true)
+        .notify_on_upload(false);
 
     let update_result = notification_handler
         .update(notification_id, update_params)
@@ -211,7 +208,7 @@ async fn test_real_api_bundle_notification_pagination() {
     let handler = BundleNotificationHandler::new(client);
 
     // Test pagination with small page size
-    let result = handler.list(None, Some(1)).await;
+    let result = handler.list(None, Some(1), None).await;
 
     match result {
         Ok((notifications, pagination)) => {
@@ -219,7 +216,7 @@ async fn test_real_api_bundle_notification_pagination() {
 
             if let Some(cursor) = pagination.cursor_next {
                 // Fetch second page
-                let result2 = handler.list(Some(cursor), Some(1)).await;
+                let result2 = handler.list(Some(cursor), Some(1), None).await;
                 match result2 {
                     Ok((notifications2, _)) => {
                         println!("Second page: {} notifications", notifications2.len());
@@ -7,8 +7,8 @@
 //! BundleRecipients track who bundles are shared with.
 
 use crate::real::*;
+use files_sdk::sharing::CreateBundleRecipientParams;
 use files_sdk::{BundleHandler, BundleRecipientHandler, FileHandler, FolderHandler};
-use serde_json::json;
 
 #[tokio::test]
 async fn test_real_api_list_bundle_recipients() {
@@ -55,7 +55,9 @@ async fn test_real_api_list_bundle_recipients() {
     let bundle_id = bundle.id.expect("Bundle should have ID");
 
     // List recipients (may be empty)
-    let result = recipient_handler.list(bundle_id, None, Some(10)).await;
+    let result = recipient_handler
+        .list(bundle_id, None, Some(10), None)
+        .await;
 
     match result {
         Ok((recipients, pagination)) => {
@@ -117,12 +119,11 @@ async fn test_real_api_create_bundle_recipient() {
     let bundle_id = bundle.id.expect("Bundle should have ID");
 
     // Create recipient
-    let params = json!({
-        "bundle_id": bundle_id,
-        "recipient": "test@example.com",
-        "name": "Test Recipient",
-        "share_after_create": false
-    });
+    let params = CreateBundleRecipientParams::new()
+        .bundle_id(bundle_id)
+        .recipient("test@example.com")
+        .name("Test Recipient")
+        .share_after_create(false);
 
     let create_result = recipient_handler.create(params).await;
 
@@ -131,7 +132,7 @@ async fn test_real_api_create_bundle_recipient() {
             println!("Created bundle recipient: {:?}", recipient);
 
             // Verify it appears in list
-            let list_result = recipient_handler.list(bundle_id, None, None).await;
+            let list_result = recipient_handler.list(bundle_id, None, None, None).await;
             match list_result {
                 Ok((recipients, _)) => {
                     println!("Bundle has {} recipients", recipients.len());
@@ -199,7 +200,7 @@ async fn test_real_api_bundle_recipient_pagination() {
     let bundle_id = bundle.id.expect("Bundle should have ID");
 
     // Test pagination with small page size
-    let result = recipient_handler.list(bundle_id, None, Some(1)).await;
+    let result = recipient_handler.list(bundle_id, None, Some(1), None).await;
 
     match result {
         Ok((recipients, pagination)) => {
@@ -208,7 +209,7 @@ async fn test_real_api_bundle_recipient_pagination() {
             if let Some(cursor) = pagination.cursor_next {
                 // Fetch second page
                 let result2 = recipient_handler
-                    .list(bundle_id, Some(cursor), Some(1))
+                    .list(bundle_id, Some(cursor), Some(1), None)
                     .await;
                 match result2 {
                     Ok((recipients2, _)) => {
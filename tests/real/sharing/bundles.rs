@@ -65,7 +65,7 @@ async fn test_real_api_create_and_delete_bundle() {
             }
 
             // List bundles
-            let list_result = bundle_handler.list(None, None, None).await;
+            let list_result = bundle_handler.list(None, None, None, None).await;
 
             match list_result {
                 Ok((bundles, _)) => {
@@ -8,14 +8,14 @@
 
 use crate::real::*;
 use files_sdk::FormFieldSetHandler;
-use serde_json::json;
+use files_sdk::sharing::{CreateFormFieldSetParams, UpdateFormFieldSetParams};
 
 #[tokio::test]
 async fn test_real_api_list_form_field_sets() {
     let client = get_test_client();
     let handler = FormFieldSetHandler::new(client);
 
-    let result = handler.list(None, Some(10)).await;
+    let result = handler.list(None, Some(10), None).await;
 
     match result {
         Ok((field_sets, pagination)) => {
@@ -37,12 +37,11 @@ async fn test_real_api_create_and_delete_form_field_set() {
     let handler = FormFieldSetHandler::new(client);
 
     // Create form field set
-    let params = json!({
-        "title": "Integration Test Form",
-        "skip_name": false,
-        "skip_email": false,
-        "skip_company": true
-    });
+    let params = CreateFormFieldSetParams::new()
+        .title("Integration Test Form")
+        .skip_name(false)
+        .skip_email(false)
+        .skip_company(true);
 
     let create_result = handler.create(params).await;
 
@@ -64,7 +63,7 @@ async fn test_real_api_create_and_delete_form_field_set() {
             }
 
             // List and verify it's there
-            let list_result = handler.list(None, None).await;
+            let list_result = handler.list(None, None, None).await;
             match list_result {
                 Ok((field_sets, _)) => {
                     let found = field_sets.iter().any(|fs| fs.id == Some(field_set_id));
@@ -97,9 +96,7 @@ async fn test_real_api_update_form_field_set() {
     let handler = FormFieldSetHandler::new(client);
 
     // Create form field set
-    let params = json!({
-        "title": "Original Title"
-    });
+    let params = CreateFormFieldSetParams::new().title("Original Title");
 
     let field_set = match handler.create(params).await {
         Ok(fs) => fs,
@@ -112,9 +109,7 @@ async fn test_real_api_update_form_field_set() {
     let field_set_id = field_set.id.expect("Field set should have ID");
 
     // Update it
-    let update_params = json!({
-        "title": "Updated Title"
-    });
+    let update_params = UpdateFormFieldSetParams::new().title("Updated Title");
 
     let update_result = handler.update(field_set_id, update_params).await;
 
@@ -137,7 +132,7 @@ async fn test_real_api_form_field_set_pagination() {
     let handler = FormFieldSetHandler::new(client);
 
     // Test pagination with small page size
-    let result = handler.list(None, Some(1)).await;
+    let result = handler.list(None, Some(1), None).await;
 
     match result {
         Ok((field_sets, pagination)) => {
@@ -145,7 +140,7 @@ async fn test_real_api_form_field_set_pagination() {
 
             if let Some(cursor) = pagination.cursor_next {
                 // Fetch second page
-                let result2 = handler.list(Some(cursor), Some(1)).await;
+                let result2 = handler.list(Some(cursor), Some(1), None).await;
                 match result2 {
                     Ok((field_sets2, _)) => {
                         println!("Second page: {} form field sets", field_sets2.len());
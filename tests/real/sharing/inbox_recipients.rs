@@ -16,7 +16,7 @@ async fn test_real_api_list_inbox_recipients() {
 
     // Note: This requires an inbox_id which we may not have in test environment
     // Using a placeholder ID to test error handling
-    let result = handler.list(1, None, Some(10)).await;
+    let result = handler.list(1, None, Some(10), None).await;
 
     match result {
         Ok((recipients, pagination)) => {
@@ -37,7 +37,7 @@ async fn test_real_api_inbox_recipients_structure() {
     let client = get_test_client();
     let handler = InboxRecipientHandler::new(client);
 
-    let result = handler.list(1, None, None).await;
+    let result = handler.list(1, None, None, None).await;
 
     match result {
         Ok((recipients, _)) => {
@@ -45,7 +45,10 @@ async fn test_real_api_inbox_recipients_structure() {
 
             if let Some(first) = recipients.first() {
                 println!("Sample recipient: {:?}", first);
-                assert!(!first.data.is_empty(), "Recipient should have data");
+                assert!(
+                    first.recipient.is_some(),
+                    "Recipient should have an email address"
+                );
             }
         }
         Err(e) => {
@@ -60,7 +63,7 @@ async fn test_real_api_inbox_recipients_error_handling() {
     let handler = InboxRecipientHandler::new(client);
 
     // Test with invalid inbox_id
-    let result = handler.list(999999, None, None).await;
+    let result = handler.list(999999, None, None, None).await;
 
     // Should return a proper error (not panic)
     match result {
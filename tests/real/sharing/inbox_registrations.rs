@@ -14,15 +14,15 @@ async fn test_real_api_list_inbox_registrations() {
     let client = get_test_client();
     let handler = InboxRegistrationHandler2::new(client);
 
-    let result = handler.list().await;
+    let result = handler.list(None, None, None).await;
 
     match result {
-        Ok(registrations) => {
+        Ok((registrations, _)) => {
             println!("Listed {} inbox registrations", registrations.len());
 
             if let Some(first) = registrations.first() {
                 println!("Sample registration: {:?}", first);
-                assert!(!first.data.is_empty(), "Registration should have data");
+                assert!(first.code.is_some(), "Registration should have a code");
             }
         }
         Err(e) => {
@@ -39,14 +39,14 @@ async fn test_real_api_inbox_registrations_structure() {
     let client = get_test_client();
     let handler = InboxRegistrationHandler2::new(client);
 
-    let result = handler.list().await;
+    let result = handler.list(None, None, None).await;
 
     match result {
-        Ok(registrations) => {
+        Ok((registrations, _)) => {
             println!("Inbox registrations count: {}", registrations.len());
 
             for registration in registrations.iter().take(3) {
-                println!("Registration data: {:?}", registration.data);
+                println!("Registration: {:?}", registration);
             }
         }
         Err(e) => {
@@ -60,11 +60,11 @@ async fn test_real_api_inbox_registrations_error_handling() {
     let client = get_test_client();
     let handler = InboxRegistrationHandler2::new(client);
 
-    let result = handler.list().await;
+    let result = handler.list(None, None, None).await;
 
     // Should either succeed or return a proper error (not panic)
     match result {
-        Ok(registrations) => {
+        Ok((registrations, _)) => {
             println!(
                 "Successfully listed {} inbox registrations",
                 registrations.len()
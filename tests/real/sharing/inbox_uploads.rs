@@ -14,7 +14,7 @@ async fn test_real_api_list_inbox_uploads() {
     let client = get_test_client();
     let handler = InboxUploadHandler::new(client);
 
-    let result = handler.list(None, Some(10), None, None, None, None).await;
+    let result = handler.list(None, Some(10), None, None, None).await;
 
     match result {
         Ok((uploads, pagination)) => {
@@ -37,9 +37,7 @@ async fn test_real_api_inbox_uploads_with_filter() {
     let handler = InboxUploadHandler::new(client);
 
     // Test filtering by inbox_id
-    let result = handler
-        .list(None, Some(10), None, None, None, Some(1))
-        .await;
+    let result = handler.list(None, Some(10), None, Some(1), None).await;
 
     match result {
         Ok((uploads, _)) => {
@@ -56,7 +54,7 @@ async fn test_real_api_inbox_uploads_structure() {
     let client = get_test_client();
     let handler = InboxUploadHandler::new(client);
 
-    let result = handler.list(None, Some(5), None, None, None, None).await;
+    let result = handler.list(None, Some(5), None, None, None).await;
 
     match result {
         Ok((uploads, _)) => {
@@ -82,7 +80,7 @@ async fn test_real_api_inbox_uploads_error_handling() {
     let client = get_test_client();
     let handler = InboxUploadHandler::new(client);
 
-    let result = handler.list(None, None, None, None, None, None).await;
+    let result = handler.list(None, None, None, None, None).await;
 
     // Should either succeed or return a proper error (not panic)
     match result {
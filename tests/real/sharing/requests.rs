@@ -14,7 +14,7 @@ async fn test_real_api_list_requests() {
     let client = get_test_client();
     let handler = RequestHandler::new(client);
 
-    let result = handler.list(None, Some(10), None, None).await;
+    let result = handler.list(None, Some(10), None, None, None).await;
 
     match result {
         Ok((requests, pagination)) => {
@@ -57,7 +57,7 @@ async fn test_real_api_create_and_delete_request() {
             let request_id = request.id.expect("Request should have ID");
 
             // Verify it appears in list
-            let list_result = request_handler.list(None, None, None, None).await;
+            let list_result = request_handler.list(None, None, None, None, None).await;
             match list_result {
                 Ok((requests, _)) => {
                     let found = requests.iter().any(|r| r.id == Some(request_id));
@@ -98,7 +98,7 @@ async fn test_real_api_list_requests_for_folder() {
 
     // List requests for specific folder
     let result = request_handler
-        .list_for_folder(test_folder, None, Some(10))
+        .list_for_folder(test_folder, None, Some(10), None)
         .await;
 
     match result {
@@ -118,7 +118,7 @@ async fn test_real_api_list_my_requests() {
     let handler = RequestHandler::new(client);
 
     // List only my requests
-    let result = handler.list(None, Some(10), None, Some(true)).await;
+    let result = handler.list(None, Some(10), None, Some(true), None).await;
 
     match result {
         Ok((requests, _)) => {
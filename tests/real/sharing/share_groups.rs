@@ -8,14 +8,14 @@
 
 use crate::real::*;
 use files_sdk::ShareGroupHandler;
-use serde_json::json;
+use files_sdk::sharing::{CreateShareGroupParams, UpdateShareGroupParams};
 
 #[tokio::test]
 async fn test_real_api_list_share_groups() {
     let client = get_test_client();
     let handler = ShareGroupHandler::new(client);
 
-    let result = handler.list(None, Some(10)).await;
+    let result = handler.list(None, Some(10), None).await;
 
     match result {
         Ok((share_groups, pagination)) => {
@@ -34,10 +34,9 @@ async fn test_real_api_create_and_delete_share_group() {
     let handler = ShareGroupHandler::new(client);
 
     // Create share group
-    let params = json!({
-        "name": "Integration Test Group",
-        "notes": "Created by integration tests"
-    });
+    let params = CreateShareGroupParams::new()
+        .name("Integration Test Group")
+        .notes("Created by integration tests");
 
     let create_result = handler.create(params).await;
 
@@ -59,7 +58,7 @@ async fn test_real_api_create_and_delete_share_group() {
             }
 
             // List and verify it's there
-            let list_result = handler.list(None, None).await;
+            let list_result = handler.list(None, None, None).await;
             match list_result {
                 Ok((groups, _)) => {
                     let found = groups.iter().any(|g| g.id == Some(group_id));
@@ -92,9 +91,7 @@ async fn test_real_api_update_share_group() {
     let handler = ShareGroupHandler::new(client);
 
     // Create share group
-    let params = json!({
-        "name": "Original Name"
-    });
+    let params = CreateShareGroupParams::new().name("Original Name");
 
     let share_group = match handler.create(params).await {
         Ok(g) => g,
@@ -107,10 +104,9 @@ async fn test_real_api_update_share_group() {
     let group_id = share_group.id.expect("Share group should have ID");
 
     // Update it
-    let update_params = json!({
-        "name": "Updated Name",
-        "notes": "Updated by test"
-    });
+    let update_params = UpdateShareGroupParams::new()
+        .name("Updated Name")
+        .notes("Updated by test");
 
     let update_result = handler.update(group_id, update_params).await;
 
@@ -133,7 +129,7 @@ async fn test_real_api_share_group_pagination() {
     let handler = ShareGroupHandler::new(client);
 
     // Test pagination with small page size
-    let result = handler.list(None, Some(1)).await;
+    let result = handler.list(None, Some(1), None).await;
 
     match result {
         Ok((groups, pagination)) => {
@@ -141,7 +137,7 @@ async fn test_real_api_share_group_pagination() {
 
             if let Some(cursor) = pagination.cursor_next {
                 // Fetch second page
-                let result2 = handler.list(Some(cursor), Some(1)).await;
+                let result2 = handler.list(Some(cursor), Some(1), None).await;
                 match result2 {
                     Ok((groups2, _)) => {
                         println!("Second page: {} share groups", groups2.len());
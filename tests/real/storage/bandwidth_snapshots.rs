@@ -11,10 +11,10 @@ async fn test_real_api_list_bandwidth_snapshots() {
 
     println!("Testing bandwidth snapshot listing");
 
-    let result = handler.list().await;
+    let result = handler.list(None, None, None).await;
 
     match result {
-        Ok(snapshots) => {
+        Ok((snapshots, _)) => {
             println!(
                 "Successfully listed {} bandwidth snapshots",
                 snapshots.len()
@@ -22,10 +22,7 @@ async fn test_real_api_list_bandwidth_snapshots() {
 
             if !snapshots.is_empty() {
                 let first = &snapshots[0];
-                println!(
-                    "First bandwidth snapshot fields: {:?}",
-                    first.data.keys().collect::<Vec<_>>()
-                );
+                println!("First bandwidth snapshot: {:?}", first);
             } else {
                 println!("No bandwidth snapshots available");
             }
@@ -11,10 +11,10 @@ async fn test_real_api_list_locks() {
 
     println!("Testing lock listing");
 
-    let result = handler.list_for_path("/", false).await;
+    let result = handler.list_for_path("/", false, None, None).await;
 
     match result {
-        Ok(locks) => {
+        Ok((locks, _)) => {
             println!("Successfully listed {} locks", locks.len());
 
             if !locks.is_empty() {
@@ -11,18 +11,15 @@ async fn test_real_api_list_priorities() {
 
     println!("Testing priority listing");
 
-    let result = handler.list("/").await;
+    let result = handler.list("/", None, None).await;
 
     match result {
-        Ok(priorities) => {
+        Ok((priorities, _)) => {
             println!("Successfully listed {} priorities", priorities.len());
 
             if !priorities.is_empty() {
                 let first = &priorities[0];
-                println!(
-                    "First priority fields: {:?}",
-                    first.data.keys().collect::<Vec<_>>()
-                );
+                println!("First priority: {:?}", first);
             } else {
                 println!("No priorities found");
             }
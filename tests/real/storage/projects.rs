@@ -11,7 +11,7 @@ async fn test_real_api_list_projects() {
 
     println!("Testing project listing");
 
-    let result = handler.list(None, Some(10)).await;
+    let result = handler.list(None, Some(10), None).await;
 
     match result {
         Ok((projects, pagination)) => {
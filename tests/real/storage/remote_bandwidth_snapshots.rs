@@ -11,10 +11,10 @@ async fn test_real_api_list_remote_bandwidth_snapshots() {
 
     println!("Testing remote bandwidth snapshot listing");
 
-    let result = handler.list().await;
+    let result = handler.list(None, None, None).await;
 
     match result {
-        Ok(snapshots) => {
+        Ok((snapshots, _)) => {
             println!(
                 "Successfully listed {} remote bandwidth snapshots",
                 snapshots.len()
@@ -22,10 +22,7 @@ async fn test_real_api_list_remote_bandwidth_snapshots() {
 
             if !snapshots.is_empty() {
                 let first = &snapshots[0];
-                println!(
-                    "First remote bandwidth snapshot fields: {:?}",
-                    first.data.keys().collect::<Vec<_>>()
-                );
+                println!("First remote bandwidth snapshot: {:?}", first);
             } else {
                 println!("No remote bandwidth snapshots available");
             }
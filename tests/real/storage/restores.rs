@@ -11,7 +11,7 @@ async fn test_real_api_list_restores() {
 
     println!("Testing restore listing");
 
-    let result = handler.list(None, Some(10)).await;
+    let result = handler.list(None, Some(10), None).await;
 
     match result {
         Ok((restores, pagination)) => {
@@ -23,10 +23,7 @@ async fn test_real_api_list_restores() {
 
             if !restores.is_empty() {
                 let first = &restores[0];
-                println!(
-                    "First restore fields: {:?}",
-                    first.data.keys().collect::<Vec<_>>()
-                );
+                println!("First restore: {:?}", first);
             } else {
                 println!("No restores found");
             }
@@ -11,7 +11,7 @@ async fn test_real_api_list_snapshots() {
 
     println!("Testing snapshot listing");
 
-    let result = handler.list(None, Some(10)).await;
+    let result = handler.list(None, Some(10), None).await;
 
     match result {
         Ok((snapshots, pagination)) => {
@@ -23,10 +23,7 @@ async fn test_real_api_list_snapshots() {
 
             if !snapshots.is_empty() {
                 let first = &snapshots[0];
-                println!(
-                    "First snapshot fields: {:?}",
-                    first.data.keys().collect::<Vec<_>>()
-                );
+                println!("First snapshot: {:?}", first);
             } else {
                 println!("No snapshots found");
             }
@@ -11,10 +11,10 @@ async fn test_real_api_list_usage_daily_snapshots() {
 
     println!("Testing usage daily snapshot listing");
 
-    let result = handler.list().await;
+    let result = handler.list(None, None, None).await;
 
     match result {
-        Ok(snapshots) => {
+        Ok((snapshots, _)) => {
             println!(
                 "Successfully listed {} usage daily snapshots",
                 snapshots.len()
@@ -22,10 +22,7 @@ async fn test_real_api_list_usage_daily_snapshots() {
 
             if !snapshots.is_empty() {
                 let first = &snapshots[0];
-                println!(
-                    "First usage daily snapshot fields: {:?}",
-                    first.data.keys().collect::<Vec<_>>()
-                );
+                println!("First usage daily snapshot: {:?}", first);
             } else {
                 println!("No usage daily snapshots available");
             }
@@ -11,18 +11,15 @@ async fn test_real_api_list_usage_snapshots() {
 
     println!("Testing usage snapshot listing");
 
-    let result = handler.list().await;
+    let result = handler.list(None, None).await;
 
     match result {
-        Ok(snapshots) => {
+        Ok((snapshots, _)) => {
             println!("Successfully listed {} usage snapshots", snapshots.len());
 
             if !snapshots.is_empty() {
                 let first = &snapshots[0];
-                println!(
-                    "First usage snapshot fields: {:?}",
-                    first.data.keys().collect::<Vec<_>>()
-                );
+                println!("First usage snapshot: {:?}", first);
             } else {
                 println!("No usage snapshots available");
             }
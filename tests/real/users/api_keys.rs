@@ -10,7 +10,7 @@ async fn test_list_api_keys() {
 
     println!("Testing API key listing");
 
-    let result = handler.list(None, None, Some(10)).await;
+    let result = handler.list(None, None, Some(10), None).await;
 
     match result {
         Ok((keys, pagination)) => {
@@ -10,7 +10,7 @@ async fn test_list_groups() {
 
     println!("Testing group listing");
 
-    let result = handler.list(None, Some(10)).await;
+    let result = handler.list(None, Some(10), None).await;
 
     match result {
         Ok((groups, pagination)) => {
@@ -15,7 +15,7 @@ async fn test_real_api_list_users() {
     let handler = UserHandler::new(client);
 
     // List users - this is a read-only operation
-    let result = handler.list(None, Some(10)).await;
+    let result = handler.list(None, Some(10), None).await;
 
     match result {
         Ok((users, pagination)) => {
@@ -51,7 +51,7 @@ async fn test_real_api_get_user_by_id() {
 
     // First, list users to get a valid user ID
     let (users, _) = handler
-        .list(None, Some(1))
+        .list(None, Some(1), None)
         .await
         .expect("Should be able to list users");
 
@@ -88,7 +88,7 @@ async fn test_real_api_authentication_error() {
 
     let handler = UserHandler::new(client);
 
-    let result = handler.list(None, None).await;
+    let result = handler.list(None, None, None).await;
 
     assert!(result.is_err(), "Should fail with invalid API key");
 
@@ -114,7 +114,7 @@ async fn test_list_users_with_pagination() {
 
     // Get first page with small page size
     let (first_page, pagination) = handler
-        .list(None, Some(2))
+        .list(None, Some(2), None)
         .await
         .expect("Should list users");
 
@@ -125,7 +125,7 @@ async fn test_list_users_with_pagination() {
         println!("Fetching next page with cursor: {}", next_cursor);
 
         let (second_page, _) = handler
-            .list(Some(next_cursor), Some(2))
+            .list(Some(next_cursor), Some(2), None)
             .await
             .expect("Should list next page");
 
@@ -167,7 +167,7 @@ async fn test_list_users_empty_page() {
     println!("Testing list with very large per_page");
 
     // Request a large number per page
-    let result = handler.list(None, Some(1000)).await;
+    let result = handler.list(None, Some(1000), None).await;
 
     match result {
         Ok((users, pagination)) => {